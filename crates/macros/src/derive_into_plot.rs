@@ -18,7 +18,14 @@ pub fn derive_into_plot(input: TokenStream) -> TokenStream {
 
         impl #impl_generics gpui::Element for #type_name #type_generics #where_clause {
             type RequestLayoutState = ();
-            type PrepaintState = ();
+            type PrepaintState = (
+                Option<gpui::Hitbox>,
+                Option<gpui::AnyElement>,
+                Option<(
+                    std::rc::Rc<dyn Fn(usize, &mut gpui::Window, &mut gpui::App)>,
+                    Vec<(usize, gpui::Bounds<gpui::Pixels>)>,
+                )>,
+            );
 
             fn id(&self) -> Option<gpui::ElementId> {
                 None
@@ -47,11 +54,29 @@ pub fn derive_into_plot(input: TokenStream) -> TokenStream {
                 &mut self,
                 _: Option<&gpui::GlobalElementId>,
                 _: Option<&gpui::InspectorElementId>,
-                _: gpui::Bounds<gpui::Pixels>,
+                bounds: gpui::Bounds<gpui::Pixels>,
                 _: &mut Self::RequestLayoutState,
-                _: &mut gpui::Window,
-                _: &mut gpui::App,
+                window: &mut gpui::Window,
+                cx: &mut gpui::App,
             ) -> Self::PrepaintState {
+                let click_regions = <Self as Plot>::click_regions(self, bounds);
+                // Only the interactive plots need to be hit tested.
+                let hitbox = (<Self as Plot>::has_tooltip(self) || click_regions.is_some())
+                    .then(|| window.insert_hitbox(bounds, gpui::HitboxBehavior::Normal));
+
+                let mut tooltip = if <Self as Plot>::has_tooltip(self)
+                    && hitbox.as_ref().is_some_and(|hitbox| hitbox.is_hovered(window))
+                {
+                    let position = window.mouse_position() - bounds.origin;
+                    <Self as Plot>::tooltip(self, bounds, position, window, cx)
+                } else {
+                    None
+                };
+                if let Some(tooltip) = tooltip.as_mut() {
+                    tooltip.prepaint_as_root(bounds.origin, bounds.size.into(), window, cx);
+                }
+
+                (hitbox, tooltip, click_regions)
             }
 
             fn paint(
@@ -60,11 +85,62 @@ pub fn derive_into_plot(input: TokenStream) -> TokenStream {
                 _: Option<&gpui::InspectorElementId>,
                 bounds: gpui::Bounds<gpui::Pixels>,
                 _: &mut Self::RequestLayoutState,
-                _: &mut Self::PrepaintState,
+                prepaint: &mut Self::PrepaintState,
                 window: &mut gpui::Window,
                 cx: &mut gpui::App,
             ) {
-                <Self as Plot>::paint(self, bounds, window, cx)
+                <Self as Plot>::paint(self, bounds, window, cx);
+
+                let (hitbox, tooltip, click_regions) = prepaint;
+                let Some(hitbox) = hitbox.as_ref() else {
+                    return;
+                };
+
+                if let Some((handler, regions)) = click_regions.take() {
+                    let index_at = move |position: gpui::Point<gpui::Pixels>| {
+                        let position = position - bounds.origin;
+                        regions
+                            .iter()
+                            .find(|(_, region)| region.contains(&position))
+                            .map(|(ix, _)| *ix)
+                    };
+
+                    if hitbox.is_hovered(window) && index_at(window.mouse_position()).is_some() {
+                        window.set_cursor_style(gpui::CursorStyle::PointingHand, hitbox);
+                    }
+
+                    let hitbox = hitbox.clone();
+                    window.on_mouse_event(move |event: &gpui::MouseDownEvent, phase, window, cx| {
+                        if !phase.bubble()
+                            || event.button != gpui::MouseButton::Left
+                            || !hitbox.is_hovered(window)
+                        {
+                            return;
+                        }
+
+                        if let Some(ix) = index_at(event.position) {
+                            handler(ix, window, cx);
+                        }
+                    });
+                }
+
+                if !<Self as Plot>::has_tooltip(self) {
+                    return;
+                }
+
+                let has_tooltip = tooltip.is_some();
+                if let Some(tooltip) = tooltip.as_mut() {
+                    tooltip.paint(window, cx);
+                }
+
+                // Notify the view to update the tooltip by the mouse position.
+                let view_id = window.current_view();
+                let hitbox = hitbox.clone();
+                window.on_mouse_event(move |_: &gpui::MouseMoveEvent, phase, window, cx| {
+                    if phase.bubble() && (has_tooltip || hitbox.is_hovered(window)) {
+                        cx.notify(view_id);
+                    }
+                });
             }
         }
     };
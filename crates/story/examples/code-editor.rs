@@ -1,10 +1,14 @@
+use std::rc::Rc;
+
 use gpui::{prelude::FluentBuilder, *};
 use gpui_component::{
     button::{Button, ButtonVariants as _},
     dropdown::{Dropdown, DropdownEvent, DropdownState},
     h_flex,
     highlighter::{Language, LanguageConfig, LanguageRegistry},
-    input::{InputEvent, InputState, Marker, TabSize, TextInput},
+    input::{
+        Completion, CompletionProvider, InputEvent, InputState, Marker, Rope, TabSize, TextInput,
+    },
     v_flex, ActiveTheme, ContextModal, IconName, IndexPath, Selectable, Sizable,
 };
 use story::Assets;
@@ -23,6 +27,31 @@ fn init(cx: &mut App) {
     );
 }
 
+/// Complete the words in the document, starting with the word under the cursor.
+struct WordCompletionProvider;
+
+impl CompletionProvider for WordCompletionProvider {
+    fn completions(&self, text: &Rope, offset: usize, _: &App) -> Vec<Completion> {
+        let text = text.to_string();
+        let prefix = text[..offset]
+            .rsplit(|c: char| !c.is_alphanumeric() && c != '_')
+            .next()
+            .unwrap_or_default();
+
+        let mut words = text
+            .split(|c: char| !c.is_alphanumeric() && c != '_')
+            .filter(|word| word.len() > prefix.len() && word.starts_with(prefix))
+            .collect::<Vec<_>>();
+        words.sort();
+        words.dedup();
+        words
+            .into_iter()
+            .take(50)
+            .map(|word| Completion::new(word.to_string()).detail("word"))
+            .collect()
+    }
+}
+
 pub struct Example {
     editor: Entity<InputState>,
     go_to_line_state: Entity<InputState>,
@@ -96,6 +125,7 @@ impl Example {
             InputState::new(window, cx)
                 .code_editor(default_language.0.name().to_string())
                 .line_number(true)
+                .auto_pairs(true)
                 .tab_size(TabSize {
                     tab_size: 4,
                     hard_tabs: false,
@@ -104,6 +134,9 @@ impl Example {
                 .default_value(default_language.1)
                 .placeholder("Enter your code here...")
         });
+        editor.update(cx, |state, cx| {
+            state.set_completion_provider(Rc::new(WordCompletionProvider), window, cx);
+        });
         let go_to_line_state = cx.new(|cx| InputState::new(window, cx));
         let language_state = cx.new(|cx| {
             DropdownState::new(
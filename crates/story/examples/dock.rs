@@ -2,7 +2,10 @@ use anyhow::{Context as _, Result};
 use gpui::*;
 use gpui_component::{
     button::{Button, ButtonVariants as _},
-    dock::{ClosePanel, DockArea, DockAreaState, DockEvent, DockItem, DockPlacement, ToggleZoom},
+    dock::{
+        ClosePanel, DockArea, DockAreaState, DockEvent, DockItem, DockPlacement, MaximizePanel,
+        ToggleZoom,
+    },
     popup_menu::PopupMenuExt,
     IconName, Root, Sizable,
 };
@@ -43,6 +46,7 @@ pub fn init(cx: &mut App) {
     cx.bind_keys(vec![
         KeyBinding::new("shift-escape", ToggleZoom, None),
         KeyBinding::new("ctrl-w", ClosePanel, None),
+        KeyBinding::new("ctrl-shift-m", MaximizePanel, None),
     ]);
 
     cx.activate(true);
@@ -201,7 +205,7 @@ impl StoryWorkspace {
 
     fn save_state(state: &DockAreaState) -> Result<()> {
         println!("Save layout...");
-        let json = serde_json::to_string_pretty(state)?;
+        let json = state.to_json()?;
         std::fs::write(STATE_FILE, json)?;
         Ok(())
     }
@@ -212,7 +216,7 @@ impl StoryWorkspace {
         cx: &mut Context<Self>,
     ) -> Result<()> {
         let json = std::fs::read_to_string(STATE_FILE)?;
-        let state = serde_json::from_str::<DockAreaState>(&json)?;
+        let state = DockAreaState::from_json(&json)?;
 
         // Check if the saved layout version is different from the current version
         // Notify the user and ask if they want to reset the layout to default.
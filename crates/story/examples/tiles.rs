@@ -147,8 +147,6 @@ pub fn init(cx: &mut App) {
 
 pub struct StoryTiles {
     dock_area: Entity<DockArea>,
-    last_layout_state: Option<DockAreaState>,
-    _save_layout_task: Option<Task<()>>,
 }
 
 struct DockAreaTab {
@@ -178,18 +176,30 @@ impl StoryTiles {
             }
         };
 
-        cx.subscribe_in(
-            &dock_area,
-            window,
-            |this, dock_area, ev: &DockEvent, window, cx| match ev {
-                DockEvent::LayoutChanged => this.save_layout(dock_area, window, cx),
-                DockEvent::DragDrop(item) => {
-                    println!("drag drop: {:?}", item);
-                }
-            },
-        )
+        cx.subscribe_in(&dock_area, window, |_, _, ev: &DockEvent, _, _| match ev {
+            DockEvent::LayoutChanged => {}
+            DockEvent::DragDrop(item) => {
+                println!("drag drop: {:?}", item);
+            }
+            DockEvent::ActivePanelChanged {
+                panel_name,
+                placement,
+                ..
+            } => {
+                println!("active panel: {} in {:?}", panel_name, placement);
+            }
+        })
         .detach();
 
+        dock_area.update(cx, |dock_area, cx| {
+            dock_area.auto_persist(
+                |state, _| Self::save_tiles(&state).unwrap(),
+                Duration::from_secs(10),
+                window,
+                cx,
+            );
+        });
+
         cx.on_app_quit({
             let dock_area = dock_area.clone();
             move |_, cx| {
@@ -202,38 +212,7 @@ impl StoryTiles {
         })
         .detach();
 
-        Self {
-            dock_area,
-            last_layout_state: None,
-            _save_layout_task: None,
-        }
-    }
-
-    fn save_layout(
-        &mut self,
-        dock_area: &Entity<DockArea>,
-        _: &mut Window,
-        cx: &mut Context<Self>,
-    ) {
-        let dock_area = dock_area.clone();
-        self._save_layout_task = Some(cx.spawn(async move |this, cx| {
-            Timer::after(Duration::from_secs(10)).await;
-
-            let _ = cx.update(|cx| {
-                let dock_area = dock_area.read(cx);
-                let state = dock_area.dump(cx);
-
-                let last_layout_state = this.upgrade().unwrap().read(cx).last_layout_state.clone();
-                if Some(&state) == last_layout_state.as_ref() {
-                    return;
-                }
-
-                Self::save_tiles(&state).unwrap();
-                let _ = this.update(cx, |this, _| {
-                    this.last_layout_state = Some(state);
-                });
-            });
-        }));
+        Self { dock_area }
     }
 
     fn save_tiles(state: &DockAreaState) -> Result<()> {
@@ -15,6 +15,7 @@ use crate::section;
 
 pub struct AccordionStory {
     open_ixs: Vec<usize>,
+    controlled_open_ixs: Vec<usize>,
     size: Size,
     bordered: bool,
     disabled: bool,
@@ -46,6 +47,7 @@ impl AccordionStory {
         Self {
             bordered: false,
             open_ixs: vec![0, 1, 2],
+            controlled_open_ixs: vec![0],
             size: Size::default(),
             disabled: false,
             multiple: true,
@@ -59,6 +61,21 @@ impl AccordionStory {
         cx.notify();
     }
 
+    fn toggle_controlled_accordion(
+        &mut self,
+        ix: usize,
+        open: bool,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if open {
+            self.controlled_open_ixs.push(ix);
+        } else {
+            self.controlled_open_ixs.retain(|&i| i != ix);
+        }
+        cx.notify();
+    }
+
     fn set_size(&mut self, size: Size, _: &mut Window, cx: &mut Context<Self>) {
         self.size = size;
         cx.notify();
@@ -204,5 +221,30 @@ impl Render for AccordionStory {
                         })),
                 ),
             )
+            .child(
+                section("Controlled").max_w_md().child(
+                    Accordion::new("controlled")
+                        .bordered(self.bordered)
+                        .with_size(self.size)
+                        .multiple(true)
+                        .open(self.controlled_open_ixs.clone())
+                        .item(|this| {
+                            this.title("First section")
+                                .content("The open sections are kept in the story state.")
+                        })
+                        .item(|this| {
+                            this.title("Second section")
+                                .content("Expanding a section doesn't collapse the others.")
+                        })
+                        .item(|this| {
+                            this.title("Third section").content(
+                                "The `on_toggle` is called with the index and the open state.",
+                            )
+                        })
+                        .on_toggle(cx.listener(|this, (ix, open): &(usize, bool), window, cx| {
+                            this.toggle_controlled_accordion(*ix, *open, window, cx);
+                        })),
+                ),
+            )
     }
 }
@@ -3,7 +3,7 @@ use gpui::{
     Render, Styled, Window,
 };
 use gpui_component::{
-    avatar::{Avatar, AvatarGroup},
+    avatar::{Avatar, AvatarGroup, AvatarStatus},
     dock::PanelControl,
     v_flex, ActiveTheme, IconName, Sizable as _, StyledExt,
 };
@@ -76,6 +76,16 @@ impl Render for AvatarStory {
                     .child(Avatar::new().name("xda").small())
                     .child(Avatar::new().name("ihavecoke").xsmall()),
             )
+            .child(
+                section("Image load failed")
+                    .max_w_md()
+                    .child(
+                        Avatar::new()
+                            .name("Jason Lee")
+                            .src("https://example.invalid/avatar.png"),
+                    )
+                    .child(Avatar::new().src("https://example.invalid/avatar.png")),
+            )
             .child(
                 section("Placeholder")
                     .max_w_md()
@@ -130,6 +140,45 @@ impl Render for AvatarStory {
                             .child(Avatar::new().src("https://i.pravatar.cc/200?u=i"))
                             .child(Avatar::new().src("https://i.pravatar.cc/200?u=j"))
                             .child(Avatar::new().src("https://i.pravatar.cc/200?u=k")),
+                    )
+                    .child(
+                        AvatarGroup::new()
+                            .small()
+                            .max(4)
+                            .child(Avatar::new().src("https://i.pravatar.cc/200?u=a"))
+                            .child(Avatar::new().src("https://i.pravatar.cc/200?u=b"))
+                            .child(Avatar::new().src("https://i.pravatar.cc/200?u=c"))
+                            .child(Avatar::new().src("https://i.pravatar.cc/200?u=d"))
+                            .child(Avatar::new().src("https://i.pravatar.cc/200?u=e"))
+                            .child(Avatar::new().src("https://i.pravatar.cc/200?u=f"))
+                            .child(Avatar::new().src("https://i.pravatar.cc/200?u=g")),
+                    ),
+            )
+            .child(
+                section("Status")
+                    .max_w_md()
+                    .child(
+                        Avatar::new()
+                            .src("https://i.pravatar.cc/200?u=a")
+                            .large()
+                            .status(AvatarStatus::Online),
+                    )
+                    .child(
+                        Avatar::new()
+                            .src("https://i.pravatar.cc/200?u=b")
+                            .status(AvatarStatus::Away),
+                    )
+                    .child(
+                        Avatar::new()
+                            .name("Jason Lee")
+                            .small()
+                            .status(AvatarStatus::Busy),
+                    )
+                    .child(
+                        Avatar::new()
+                            .name("Floyd Wang")
+                            .xsmall()
+                            .status(AvatarStatus::Offline),
                     ),
             )
             .child(
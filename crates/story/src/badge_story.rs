@@ -1,10 +1,13 @@
 use gpui::{
-    App, AppContext, Context, Entity, FocusHandle, Focusable, IntoElement, ParentElement, Render,
-    Styled, Window,
+    App, AppContext, Context, Corner, Entity, FocusHandle, Focusable, IntoElement, ParentElement,
+    Render, Styled, Window,
 };
 use gpui_component::{
-    avatar::Avatar, badge::Badge, dock::PanelControl, v_flex, ActiveTheme as _, Icon, IconName,
-    Sizable as _,
+    avatar::Avatar,
+    badge::Badge,
+    button::{Button, ButtonVariants as _},
+    dock::PanelControl,
+    v_flex, ActiveTheme as _, Icon, IconName, Sizable as _,
 };
 
 use crate::section;
@@ -171,5 +174,36 @@ impl Render for BadgeStory {
                         ),
                     ),
             )
+            .child(
+                section("Badge position")
+                    .max_w_md()
+                    .child(
+                        Badge::new()
+                            .dot()
+                            .child(Button::new("notifications").ghost().icon(IconName::Bell)),
+                    )
+                    .children(
+                        [
+                            Corner::TopLeft,
+                            Corner::TopRight,
+                            Corner::BottomLeft,
+                            Corner::BottomRight,
+                        ]
+                        .map(|corner| {
+                            Badge::new().count(5).position(corner).child(
+                                Avatar::new()
+                                    .src("https://avatars.githubusercontent.com/u/5518?v=4"),
+                            )
+                        }),
+                    ),
+            )
+            .child(
+                section("Badge with max").max_w_md().child(
+                    Badge::new()
+                        .count(1000)
+                        .max(999)
+                        .child(Icon::new(IconName::Inbox).large()),
+                ),
+            )
     }
 }
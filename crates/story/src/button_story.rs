@@ -6,8 +6,8 @@ use gpui::{
 use gpui_component::{
     button::{Button, ButtonCustomVariant, ButtonGroup, ButtonVariants as _, DropdownButton},
     checkbox::Checkbox,
-    h_flex, v_flex, ActiveTheme, Disableable as _, Icon, IconName, Selectable as _, Sizable as _,
-    Theme,
+    h_flex, themed, v_flex, ActiveTheme, Disableable as _, Icon, IconName, Selectable as _,
+    Sizable as _, Theme, ThemeRegistry,
 };
 use serde::Deserialize;
 
@@ -916,5 +916,33 @@ impl Render for ButtonStory {
                             .on_click(Self::on_click),
                     ),
             )
+            .child(section("Themed Subtree").child({
+                // Render the buttons with the opposite theme of the app.
+                let mut theme = cx.theme().clone();
+                let registry = ThemeRegistry::global(cx);
+                let config = if theme.is_dark() {
+                    registry.default_light_theme().clone()
+                } else {
+                    registry.default_dark_theme().clone()
+                };
+                theme.apply_config(&config);
+
+                themed(theme, |_, cx| {
+                    h_flex()
+                        .gap_4()
+                        .p_4()
+                        .rounded(cx.theme().radius)
+                        .bg(cx.theme().background)
+                        .text_color(cx.theme().foreground)
+                        .child(Button::new("themed-primary").primary().label("Primary"))
+                        .child(Button::new("themed-outline").outline().label("Outline"))
+                        .child(Button::new("themed-ghost").ghost().label("Ghost"))
+                        .child(
+                            Checkbox::new("themed-checkbox")
+                                .label("Checkbox")
+                                .checked(true),
+                        )
+                })
+            }))
     }
 }
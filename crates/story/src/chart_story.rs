@@ -129,6 +129,7 @@ fn chart_container(
 impl Render for ChartStory {
     fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
         let color = cx.theme().chart_3;
+        let (loss_color, profit_color) = (cx.theme().red, cx.theme().green);
         v_flex()
             .size_full()
             .gap_y_4()
@@ -175,8 +176,22 @@ impl Render for ChartStory {
                         PieChart::new(self.monthly_devices.clone())
                             .value(|d| d.desktop as f32)
                             .outer_radius(100.)
-                            .inner_radius(60.)
-                            .color(move |d| d.color(color)),
+                            .inner_radius(0.6)
+                            .color(move |d| d.color(color))
+                            .center_content(
+                                v_flex()
+                                    .items_center()
+                                    .child(div().text_2xl().font_bold().child(format!(
+                                        "{}",
+                                        self.monthly_devices.iter().map(|d| d.desktop).sum::<f64>()
+                                    )))
+                                    .child(
+                                        div()
+                                            .text_xs()
+                                            .text_color(cx.theme().muted_foreground)
+                                            .child("Visitors"),
+                                    ),
+                            ),
                         true,
                         cx,
                     ))
@@ -185,7 +200,7 @@ impl Render for ChartStory {
                         PieChart::new(self.monthly_devices.clone())
                             .value(|d| d.desktop as f32)
                             .outer_radius(100.)
-                            .inner_radius(60.)
+                            .inner_radius(0.6)
                             .pad_angle(4. / 100.)
                             .color(move |d| d.color(color)),
                         true,
@@ -201,7 +216,13 @@ impl Render for ChartStory {
                         "Bar Chart",
                         BarChart::new(self.monthly_devices.clone())
                             .x(|d| d.month.clone())
-                            .y(|d| d.desktop),
+                            .y(|d| d.desktop)
+                            .on_click({
+                                let monthly_devices = self.monthly_devices.clone();
+                                move |ix, _, _| {
+                                    println!("Clicked month: {}", monthly_devices[ix].month);
+                                }
+                            }),
                         false,
                         cx,
                     ))
@@ -222,6 +243,23 @@ impl Render for ChartStory {
                             .label(|d| d.desktop.to_string()),
                         false,
                         cx,
+                    ))
+                    .child(chart_container(
+                        "Bar Chart - Horizontal",
+                        BarChart::new(self.monthly_devices.clone())
+                            .horizontal()
+                            .x(|d| d.month.clone())
+                            .y(|d| d.desktop - 200.)
+                            .fill(move |d| {
+                                if d.desktop < 200. {
+                                    loss_color
+                                } else {
+                                    profit_color
+                                }
+                            })
+                            .label(|d| (d.desktop - 200.).to_string()),
+                        false,
+                        cx,
                     )),
             )
             .child(Divider::horizontal())
@@ -242,7 +280,8 @@ impl Render for ChartStory {
                         LineChart::new(self.monthly_devices.clone())
                             .x(|d| d.month.clone())
                             .y(|d| d.desktop)
-                            .linear(),
+                            .linear()
+                            .tooltip_formatter(|d| format!("{}: {} visitors", d.month, d.desktop)),
                         false,
                         cx,
                     ))
@@ -292,5 +331,28 @@ impl Render for ChartStory {
                         cx,
                     )),
             )
+            .child(Divider::horizontal())
+            .child(
+                h_flex()
+                    .gap_x_8()
+                    .h(px(400.))
+                    .child(chart_container(
+                        "Line Chart - Loading",
+                        LineChart::new(self.monthly_devices.clone())
+                            .x(|d| d.month.clone())
+                            .y(|d| d.desktop)
+                            .loading(true),
+                        false,
+                        cx,
+                    ))
+                    .child(chart_container(
+                        "Bar Chart - Empty",
+                        BarChart::new(Vec::<MonthlyDevice>::new())
+                            .x(|d| d.month.clone())
+                            .y(|d| d.desktop),
+                        false,
+                        cx,
+                    )),
+            )
     }
 }
@@ -1,17 +1,21 @@
 use gpui::{
-    prelude::FluentBuilder as _, App, AppContext, Context, Entity, Focusable, Hsla, IntoElement,
-    ParentElement as _, Render, Styled as _, Subscription, Window,
+    hsla, prelude::FluentBuilder as _, App, AppContext, Context, Entity, Focusable, Hsla,
+    IntoElement, ParentElement as _, Render, Styled as _, Subscription, Window,
 };
 use gpui_component::{
-    color_picker::{ColorPicker, ColorPickerEvent, ColorPickerState},
-    v_flex, ActiveTheme as _, Colorize, Sizable,
+    button::{Button, ButtonVariants as _},
+    color_picker::{ColorPicker, ColorPickerEvent, ColorPickerMode, ColorPickerState, Gradient},
+    h_flex, v_flex, ActiveTheme as _, Colorize, Sizable, Theme,
 };
 
 use crate::section;
 
 pub struct ColorPickerStory {
     color: Entity<ColorPickerState>,
+    gradient: Entity<ColorPickerState>,
+    accent: Entity<ColorPickerState>,
     selected_color: Option<Hsla>,
+    selected_gradient: Gradient,
     _subscriptions: Vec<Subscription>,
 }
 
@@ -35,19 +39,59 @@ impl ColorPickerStory {
     }
 
     fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
-        let color =
+        let color = cx.new(|cx| {
+            ColorPickerState::new(window, cx)
+                .default_value(cx.theme().primary)
+                // A demo sampler, the hue of the picked color is by the x position in the window.
+                .eyedropper_sampler(|position, window, _| {
+                    let width = window.viewport_size().width;
+                    Some(hsla(position.x / width, 0.8, 0.5, 1.))
+                })
+        });
+
+        let default_gradient = Gradient::new(
+            90.,
+            [
+                (0., cx.theme().blue),
+                (0.5, cx.theme().cyan),
+                (1., cx.theme().green),
+            ],
+        );
+        let gradient = cx.new(|cx| {
+            ColorPickerState::new(window, cx)
+                .mode(ColorPickerMode::Gradient)
+                .default_gradient(default_gradient.clone())
+        });
+
+        let accent =
             cx.new(|cx| ColorPickerState::new(window, cx).default_value(cx.theme().primary));
 
-        let _subscriptions = vec![cx.subscribe(&color, |this, _, ev, _| match ev {
-            ColorPickerEvent::Change(color) => {
-                this.selected_color = *color;
-                println!("Color changed to: {:?}", color);
-            }
-        })];
+        let _subscriptions = vec![
+            cx.subscribe(&color, |this, _, ev, _| {
+                if let ColorPickerEvent::Change(color) = ev {
+                    this.selected_color = *color;
+                    println!("Color changed to: {:?}", color);
+                }
+            }),
+            cx.subscribe(&gradient, |this, _, ev, cx| {
+                if let ColorPickerEvent::ChangeGradient(gradient) = ev {
+                    this.selected_gradient = gradient.clone();
+                    cx.notify();
+                }
+            }),
+            cx.subscribe(&accent, |_, _, ev, cx| {
+                if let ColorPickerEvent::Change(Some(color)) = ev {
+                    Theme::set_accent(*color, cx);
+                }
+            }),
+        ];
 
         Self {
             color,
+            gradient,
+            accent,
             selected_color: Some(cx.theme().primary),
+            selected_gradient: default_gradient,
             _subscriptions,
         }
     }
@@ -60,14 +104,60 @@ impl Focusable for ColorPickerStory {
 }
 
 impl Render for ColorPickerStory {
-    fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
-        v_flex().gap_3().child(
-            section("Normal")
-                .max_w_md()
-                .child(ColorPicker::new(&self.color).small())
-                .when_some(self.selected_color, |this, color| {
-                    this.child(color.to_hex())
-                }),
-        )
+    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        v_flex()
+            .gap_3()
+            .child(
+                section("Normal")
+                    .max_w_md()
+                    .child(ColorPicker::new(&self.color).small().eyedropper(true))
+                    .when_some(self.selected_color, |this, color| {
+                        this.child(color.to_hex())
+                    }),
+            )
+            .child(
+                section("Gradient")
+                    .max_w_md()
+                    .child(ColorPicker::new(&self.gradient).small())
+                    .child(format!(
+                        "{}° {}",
+                        self.selected_gradient.angle,
+                        self.selected_gradient
+                            .stops
+                            .iter()
+                            .map(|(position, color)| format!(
+                                "{} {:.0}%",
+                                color.to_hex(),
+                                position * 100.
+                            ))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    )),
+            )
+            .child(
+                section("Theme Accent").max_w_md().child(
+                    h_flex()
+                        .gap_2()
+                        .child(ColorPicker::new(&self.accent).small())
+                        .child(Button::new("primary").small().primary().label("Primary"))
+                        .child(Button::new("reset-accent").small().label("Reset").on_click(
+                            cx.listener(|this, _, window, cx| {
+                                // Reapply the active theme config to revert the accent.
+                                let theme = cx.theme();
+                                let config = if theme.is_dark() {
+                                    theme.dark_theme.clone()
+                                } else {
+                                    theme.light_theme.clone()
+                                };
+                                Theme::global_mut(cx).apply_config(&config);
+                                let primary = cx.theme().primary;
+                                this.accent.update(cx, |state, cx| {
+                                    state.set_value(primary, window, cx);
+                                });
+                                cx.refresh_windows();
+                            }),
+                        )),
+                ),
+            )
     }
 }
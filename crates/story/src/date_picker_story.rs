@@ -145,26 +145,13 @@ impl Render for DatePickerStory {
             ),
         ];
         let range_presets = vec![
-            DateRangePreset::range(
-                "Last 7 Days",
-                (Utc::now() - Duration::days(7)).naive_local().date(),
-                Utc::now().naive_local().date(),
-            ),
-            DateRangePreset::range(
-                "Last 14 Days",
-                (Utc::now() - Duration::days(14)).naive_local().date(),
-                Utc::now().naive_local().date(),
-            ),
-            DateRangePreset::range(
-                "Last 30 Days",
-                (Utc::now() - Duration::days(30)).naive_local().date(),
-                Utc::now().naive_local().date(),
-            ),
-            DateRangePreset::range(
-                "Last 90 Days",
-                (Utc::now() - Duration::days(90)).naive_local().date(),
-                Utc::now().naive_local().date(),
-            ),
+            DateRangePreset::relative("Today", |today| (today, today)),
+            DateRangePreset::relative("Last 7 Days", |today| (today - Days::new(6), today)),
+            DateRangePreset::relative("This Month", |today| {
+                (today.with_day(1).unwrap_or(today), today)
+            }),
+            DateRangePreset::relative("Last 30 Days", |today| (today - Days::new(29), today)),
+            DateRangePreset::relative("Last 90 Days", |today| (today - Days::new(89), today)),
         ];
 
         v_flex()
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use gpui::*;
 use gpui_component::{button::*, checkbox::*, divider::*, dropdown::*, input::*, *};
 use itertools::Itertools as _;
@@ -51,6 +53,8 @@ pub struct DropdownStory {
     simple_dropdown3: Entity<DropdownState<Vec<SharedString>>>,
     disabled_dropdown: Entity<DropdownState<Vec<SharedString>>>,
     appearance_dropdown: Entity<DropdownState<Vec<SharedString>>>,
+    async_dropdown: Entity<DropdownState<SearchableVec<SharedString>>>,
+    async_loading: bool,
     input_state: Entity<InputState>,
 }
 
@@ -120,10 +124,14 @@ impl DropdownStory {
             "Avocado".into(),
         ]);
         let fruit_dropdown = cx.new(|cx| DropdownState::new(fruits, None, window, cx));
+        let async_dropdown =
+            cx.new(|cx| DropdownState::new(SearchableVec::new(vec![]), None, window, cx));
 
         cx.new(|cx| {
             cx.subscribe_in(&country_dropdown, window, Self::on_dropdown_event)
                 .detach();
+            cx.subscribe_in(&async_dropdown, window, Self::on_async_dropdown_event)
+                .detach();
 
             Self {
                 disabled: false,
@@ -169,6 +177,8 @@ impl DropdownStory {
                 disabled_dropdown: cx
                     .new(|cx| DropdownState::new(Vec::<SharedString>::new(), None, window, cx)),
                 appearance_dropdown,
+                async_dropdown,
+                async_loading: false,
                 input_state,
             }
         })
@@ -187,9 +197,51 @@ impl DropdownStory {
     ) {
         match event {
             DropdownEvent::Confirm(value) => println!("Selected country: {:?}", value),
+            DropdownEvent::OpenRequested => {}
         }
     }
 
+    fn on_async_dropdown_event(
+        &mut self,
+        _: &Entity<DropdownState<SearchableVec<SharedString>>>,
+        event: &DropdownEvent<SearchableVec<SharedString>>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if !matches!(event, DropdownEvent::OpenRequested) || self.async_loading {
+            return;
+        }
+        if self.async_dropdown.read(cx).selected_value().is_some() {
+            return;
+        }
+
+        self.async_loading = true;
+        cx.notify();
+        cx.spawn_in(window, async move |this, window| {
+            // Simulate network request, delay 1s to load data.
+            Timer::after(Duration::from_secs(1)).await;
+
+            _ = this.update_in(window, |this, window, cx| {
+                this.async_loading = false;
+                this.async_dropdown.update(cx, |dropdown, cx| {
+                    dropdown.set_items(
+                        SearchableVec::new(vec![
+                            "Tokyo".into(),
+                            "Paris".into(),
+                            "New York".into(),
+                            "Shanghai".into(),
+                            "London".into(),
+                        ]),
+                        window,
+                        cx,
+                    );
+                });
+                cx.notify();
+            });
+        })
+        .detach();
+    }
+
     fn on_key_tab(&mut self, _: &Tab, window: &mut Window, cx: &mut Context<Self>) {
         self.cycle_focus(true, window, cx);
         cx.notify();
@@ -259,10 +311,11 @@ impl Render for DropdownStory {
                     .child(Dropdown::new(&self.disabled_dropdown).disabled(true)),
             )
             .child(
-                section("With preview label").max_w_128().child(
+                section("With preview label and filter").max_w_128().child(
                     Dropdown::new(&self.simple_dropdown1)
                         .disabled(self.disabled)
                         .small()
+                        .searchable(true)
                         .placeholder("UI")
                         .title_prefix("UI: "),
                 ),
@@ -290,6 +343,14 @@ impl Render for DropdownStory {
                         ),
                 ),
             )
+            .child(
+                section("Async Loading").max_w_128().child(
+                    Dropdown::new(&self.async_dropdown)
+                        .disabled(self.disabled)
+                        .loading(self.async_loading)
+                        .placeholder("City"),
+                ),
+            )
             .child(
                 section("Appearance false with TextInput")
                     .max_w_128()
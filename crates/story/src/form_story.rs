@@ -1,6 +1,9 @@
+use std::time::Duration;
+
 use gpui::{
     div, prelude::FluentBuilder as _, px, App, AppContext, Axis, Context, Entity, Focusable,
-    InteractiveElement, IntoElement, ParentElement as _, Render, Styled, Window,
+    InteractiveElement, IntoElement, ParentElement as _, Render, ScrollHandle,
+    StatefulInteractiveElement as _, Styled, Timer, Window,
 };
 use gpui_component::{
     button::{Button, ButtonGroup},
@@ -9,21 +12,27 @@ use gpui_component::{
     date_picker::{DatePicker, DatePickerState},
     divider::Divider,
     dropdown::{Dropdown, DropdownState},
-    form::{form_field, v_form},
+    form::{form_field, v_form, FormState, ValidateMode},
     h_flex,
     input::{InputState, TextInput},
+    scroll::ScrollToTopButton,
     switch::Switch,
-    v_flex, ActiveTheme, AxisExt, FocusableCycle, IndexPath, Selectable, Sizable, Size,
+    v_flex, ActiveTheme, AxisExt, ContextModal as _, FocusableCycle, IndexPath, Selectable,
+    Sizable, Size,
 };
 
 pub struct FormStory {
     name_prefix_state: Entity<DropdownState<Vec<String>>>,
     name_input: Entity<InputState>,
+    username_input: Entity<InputState>,
     email_input: Entity<InputState>,
     bio_input: Entity<InputState>,
     color_state: Entity<ColorPickerState>,
     subscribe_email: bool,
     date: Entity<DatePickerState>,
+    form_state: Entity<FormState>,
+    scroll_handle: ScrollHandle,
+    validate_on_blur: bool,
     layout: Axis,
     size: Size,
 }
@@ -67,6 +76,7 @@ impl FormStory {
         });
 
         let name_input = cx.new(|cx| InputState::new(window, cx).default_value("Jason Lee"));
+        let username_input = cx.new(|cx| InputState::new(window, cx).placeholder("Username"));
         let color_state = cx.new(|cx| ColorPickerState::new(window, cx));
 
         let email_input =
@@ -78,14 +88,20 @@ impl FormStory {
                 .default_value("Hello 世界，this is GPUI component.")
         });
         let date = cx.new(|cx| DatePickerState::new(window, cx));
+        let scroll_handle = ScrollHandle::new();
+        let form_state = cx.new(|cx| FormState::new(window, cx).scroll_handle(&scroll_handle));
 
         Self {
             name_prefix_state,
             name_input,
+            username_input,
             email_input,
             bio_input,
             date,
             color_state,
+            form_state,
+            scroll_handle,
+            validate_on_blur: false,
             subscribe_email: false,
             layout: Axis::Vertical,
             size: Size::default(),
@@ -100,6 +116,7 @@ impl FocusableCycle for FormStory {
     {
         vec![
             self.name_input.focus_handle(cx),
+            self.username_input.focus_handle(cx),
             self.email_input.focus_handle(cx),
             self.bio_input.focus_handle(cx),
         ]
@@ -113,145 +130,251 @@ impl Focusable for FormStory {
 }
 
 impl Render for FormStory {
-    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
-        v_flex()
-            .id("form-story")
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let name_input = self.name_input.clone();
+        let username_input = self.username_input.clone();
+        let email_input = self.email_input.clone();
+        let bio_input = self.bio_input.clone();
+
+        div()
+            .relative()
             .size_full()
-            .p_4()
-            .justify_start()
-            .gap_3()
             .child(
-                h_flex()
+                v_flex()
+                    .id("form-story")
+                    .size_full()
+                    .overflow_y_scroll()
+                    .track_scroll(&self.scroll_handle)
+                    .p_4()
+                    .justify_start()
                     .gap_3()
-                    .flex_wrap()
-                    .justify_between()
                     .child(
-                        Switch::new("layout")
-                            .checked(self.layout.is_horizontal())
-                            .label("Horizontal")
-                            .on_click(cx.listener(|this, checked: &bool, _, cx| {
-                                if *checked {
-                                    this.layout = Axis::Horizontal;
-                                } else {
-                                    this.layout = Axis::Vertical;
-                                }
-                                cx.notify();
-                            })),
+                        h_flex()
+                            .gap_3()
+                            .flex_wrap()
+                            .justify_between()
+                            .child(
+                                Switch::new("layout")
+                                    .checked(self.layout.is_horizontal())
+                                    .label("Horizontal")
+                                    .on_click(cx.listener(|this, checked: &bool, _, cx| {
+                                        if *checked {
+                                            this.layout = Axis::Horizontal;
+                                        } else {
+                                            this.layout = Axis::Vertical;
+                                        }
+                                        cx.notify();
+                                    })),
+                            )
+                            .child(
+                                ButtonGroup::new("size")
+                                    .outline()
+                                    .small()
+                                    .child(
+                                        Button::new("large")
+                                            .selected(self.size == Size::Large)
+                                            .child("Large"),
+                                    )
+                                    .child(
+                                        Button::new("medium")
+                                            .child("Medium")
+                                            .selected(self.size == Size::Medium),
+                                    )
+                                    .child(
+                                        Button::new("small")
+                                            .child("Small")
+                                            .selected(self.size == Size::Small),
+                                    )
+                                    .on_click(cx.listener(
+                                        |this, selecteds: &Vec<usize>, _, cx| {
+                                            if selecteds.contains(&0) {
+                                                this.size = Size::Large;
+                                            } else if selecteds.contains(&1) {
+                                                this.size = Size::Medium;
+                                            } else if selecteds.contains(&2) {
+                                                this.size = Size::Small;
+                                            }
+                                            cx.notify();
+                                        },
+                                    )),
+                            )
+                            .child(
+                                Switch::new("validate-on-blur")
+                                    .checked(self.validate_on_blur)
+                                    .label("Validate on blur")
+                                    .on_click(cx.listener(|this, checked: &bool, window, cx| {
+                                        this.validate_on_blur = *checked;
+                                        let mode = if *checked {
+                                            ValidateMode::OnBlur
+                                        } else {
+                                            ValidateMode::OnSubmit
+                                        };
+                                        this.form_state = cx.new(|cx| {
+                                            FormState::new(window, cx)
+                                                .validate_mode(mode)
+                                                .scroll_handle(&this.scroll_handle)
+                                        });
+                                        cx.notify();
+                                    })),
+                            ),
                     )
+                    .child(Divider::horizontal())
                     .child(
-                        ButtonGroup::new("size")
-                            .outline()
-                            .small()
+                        v_form()
+                            .layout(self.layout)
+                            .with_size(self.size)
+                            .state(&self.form_state)
+                            .on_submit(|window, cx| {
+                                window.push_notification("The form has been submitted.", cx);
+                            })
                             .child(
-                                Button::new("large")
-                                    .selected(self.size == Size::Large)
-                                    .child("Large"),
+                                form_field()
+                                    .label_fn(|_, _| "Name")
+                                    .track_focus(&self.name_input.focus_handle(cx))
+                                    .validator(
+                                        move |cx| name_input.read(cx).value(),
+                                        |value| match value.trim().is_empty() {
+                                            true => Err("Name can't be blank.".into()),
+                                            false => Ok(()),
+                                        },
+                                    )
+                                    .child(
+                                        h_flex()
+                                            .gap_2()
+                                            .border_1()
+                                            .border_color(cx.theme().border)
+                                            .rounded(cx.theme().radius)
+                                            .child(
+                                                div().w(px(90.)).child(
+                                                    Dropdown::new(&self.name_prefix_state)
+                                                        .pr_0()
+                                                        .appearance(false),
+                                                ),
+                                            )
+                                            .child(
+                                                div().flex_1().child(
+                                                    TextInput::new(&self.name_input)
+                                                        .pl_0()
+                                                        .appearance(false),
+                                                ),
+                                            ),
+                                    ),
                             )
                             .child(
-                                Button::new("medium")
-                                    .child("Medium")
-                                    .selected(self.size == Size::Medium),
+                                form_field()
+                                    .label("Username")
+                                    .child(TextInput::new(&self.username_input))
+                                    .track_focus(&self.username_input.focus_handle(cx))
+                                    .description("Try `admin` or `root`, they are taken.")
+                                    .async_validator(
+                                        move |cx| username_input.read(cx).value(),
+                                        |value, cx| {
+                                            cx.spawn(async move |_| {
+                                                // Simulate a request to the server.
+                                                Timer::after(Duration::from_millis(800)).await;
+                                                match value.trim() {
+                                                    "" => Err("Username can't be blank.".into()),
+                                                    "admin" | "root" => Err(format!(
+                                                        "Username `{}` is already taken.",
+                                                        value.trim()
+                                                    )
+                                                    .into()),
+                                                    _ => Ok(()),
+                                                }
+                                            })
+                                        },
+                                    )
+                                    .required(true),
                             )
                             .child(
-                                Button::new("small")
-                                    .child("Small")
-                                    .selected(self.size == Size::Small),
+                                form_field()
+                                    .label("Email")
+                                    .child(TextInput::new(&self.email_input))
+                                    .track_focus(&self.email_input.focus_handle(cx))
+                                    .validator(
+                                        move |cx| email_input.read(cx).value(),
+                                        |value| match value.contains('@') {
+                                            true => Ok(()),
+                                            false => {
+                                                Err("Please enter a valid email address.".into())
+                                            }
+                                        },
+                                    )
+                                    .required(true),
                             )
-                            .on_click(cx.listener(|this, selecteds: &Vec<usize>, _, cx| {
-                                if selecteds.contains(&0) {
-                                    this.size = Size::Large;
-                                } else if selecteds.contains(&1) {
-                                    this.size = Size::Medium;
-                                } else if selecteds.contains(&2) {
-                                    this.size = Size::Small;
-                                }
-                                cx.notify();
-                            })),
-                    ),
-            )
-            .child(Divider::horizontal())
-            .child(
-                v_form()
-                    .layout(self.layout)
-                    .with_size(self.size)
-                    .child(
-                        form_field().label_fn(|_, _| "Name").child(
-                            h_flex()
-                                .gap_2()
-                                .border_1()
-                                .border_color(cx.theme().border)
-                                .rounded(cx.theme().radius)
-                                .child(
-                                    div().w(px(90.)).child(
-                                        Dropdown::new(&self.name_prefix_state)
-                                            .pr_0()
-                                            .appearance(false),
+                            .child(
+                                form_field()
+                                    .label("Bio")
+                                    .when(self.layout.is_vertical(), |this| this.items_start())
+                                    .child(TextInput::new(&self.bio_input))
+                                    .track_focus(&self.bio_input.focus_handle(cx))
+                                    .validator(
+                                        move |cx| bio_input.read(cx).value(),
+                                        |value| match value.split_whitespace().count() > 100 {
+                                            true => Err("Bio must be at most 100 words.".into()),
+                                            false => Ok(()),
+                                        },
+                                    )
+                                    .description_fn(|_, _| {
+                                        div().child("Use at most 100 words to describe yourself.")
+                                    }),
+                            )
+                            .child(
+                                form_field()
+                                    .no_label_indent()
+                                    .child("This is a full width form field."),
+                            )
+                            .child(
+                                form_field()
+                                    .label("Please select your birthday")
+                                    .child(DatePicker::new(&self.date))
+                                    .description("Select your birthday, we will send you a gift."),
+                            )
+                            .child(
+                                form_field().child(
+                                    Switch::new("subscribe-newsletter")
+                                        .label("Subscribe our newsletter")
+                                        .checked(self.subscribe_email)
+                                        .on_click(cx.listener(|this, checked: &bool, _, cx| {
+                                            this.subscribe_email = *checked;
+                                            cx.notify();
+                                        })),
+                                ),
+                            )
+                            .child(
+                                form_field().child(
+                                    ColorPicker::new(&self.color_state)
+                                        .small()
+                                        .label("Theme color"),
+                                ),
+                            )
+                            .child(
+                                form_field().child(
+                                    Checkbox::new("use-vertical-layout")
+                                        .label("Vertical layout")
+                                        .checked(self.layout.is_vertical())
+                                        .on_click(cx.listener(|this, checked: &bool, _, cx| {
+                                            this.layout = if *checked {
+                                                Axis::Vertical
+                                            } else {
+                                                Axis::Horizontal
+                                            };
+                                            cx.notify();
+                                        })),
+                                ),
+                            )
+                            .child(form_field().no_label_indent().child(
+                                Button::new("submit").primary().label("Submit").on_click(
+                                    window.listener_for(
+                                        &self.form_state,
+                                        |state, _, window, cx| {
+                                            state.submit(window, cx);
+                                        },
                                     ),
-                                )
-                                .child(div().flex_1().child(
-                                    TextInput::new(&self.name_input).pl_0().appearance(false),
-                                )),
-                        ),
-                    )
-                    .child(
-                        form_field()
-                            .label("Email")
-                            .child(TextInput::new(&self.email_input))
-                            .required(true),
-                    )
-                    .child(
-                        form_field()
-                            .label("Bio")
-                            .when(self.layout.is_vertical(), |this| this.items_start())
-                            .child(TextInput::new(&self.bio_input))
-                            .description_fn(|_, _| {
-                                div().child("Use at most 100 words to describe yourself.")
-                            }),
-                    )
-                    .child(
-                        form_field()
-                            .no_label_indent()
-                            .child("This is a full width form field."),
-                    )
-                    .child(
-                        form_field()
-                            .label("Please select your birthday")
-                            .child(DatePicker::new(&self.date))
-                            .description("Select your birthday, we will send you a gift."),
-                    )
-                    .child(
-                        form_field().child(
-                            Switch::new("subscribe-newsletter")
-                                .label("Subscribe our newsletter")
-                                .checked(self.subscribe_email)
-                                .on_click(cx.listener(|this, checked: &bool, _, cx| {
-                                    this.subscribe_email = *checked;
-                                    cx.notify();
-                                })),
-                        ),
-                    )
-                    .child(
-                        form_field().child(
-                            ColorPicker::new(&self.color_state)
-                                .small()
-                                .label("Theme color"),
-                        ),
-                    )
-                    .child(
-                        form_field().child(
-                            Checkbox::new("use-vertical-layout")
-                                .label("Vertical layout")
-                                .checked(self.layout.is_vertical())
-                                .on_click(cx.listener(|this, checked: &bool, _, cx| {
-                                    this.layout = if *checked {
-                                        Axis::Vertical
-                                    } else {
-                                        Axis::Horizontal
-                                    };
-                                    cx.notify();
-                                })),
-                        ),
+                                ),
+                            )),
                     ),
             )
+            .child(ScrollToTopButton::new("scroll-to-top", &self.scroll_handle))
     }
 }
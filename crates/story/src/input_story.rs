@@ -1,5 +1,5 @@
 use gpui::{
-    div, App, AppContext as _, Context, Entity, FocusHandle, Focusable, InteractiveElement,
+    div, px, App, AppContext as _, Context, Entity, FocusHandle, Focusable, InteractiveElement,
     IntoElement, KeyBinding, ParentElement as _, Render, Styled, Subscription, Window,
 };
 
@@ -8,6 +8,35 @@ use gpui_component::{button::*, input::*, *};
 
 const CONTEXT: &str = "InputStory";
 
+const CODE_SAMPLES: [(&str, &str, &str); 2] = [
+    (
+        "TOML",
+        "toml",
+        r#"[package]
+name = "story"
+version = "0.1.0"
+
+[dependencies]
+gpui = { git = "https://github.com/zed-industries/zed" }
+"#,
+    ),
+    (
+        "YAML",
+        "yaml",
+        r#"name: CI
+on:
+  push:
+    branches: [main]
+jobs:
+  test:
+    runs-on: ubuntu-latest
+    steps:
+      - uses: actions/checkout@v4
+      - run: cargo test --all
+"#,
+    ),
+];
+
 pub fn init(cx: &mut App) {
     cx.bind_keys([
         KeyBinding::new("shift-tab", TabPrev, Some(CONTEXT)),
@@ -30,6 +59,13 @@ pub struct InputStory {
     mask_input2: Entity<InputState>,
     currency_input: Entity<InputState>,
     custom_input: Entity<InputState>,
+    rtl_input: Entity<InputState>,
+    auto_direction_input: Entity<InputState>,
+    floating_input: Entity<InputState>,
+    count_input: Entity<InputState>,
+    code_input: Entity<InputState>,
+    code_sample_ix: usize,
+    code_highlighted: bool,
 
     _subscriptions: Vec<Subscription>,
 }
@@ -94,6 +130,28 @@ impl InputStory {
         let custom_input =
             cx.new(|cx| InputState::new(window, cx).placeholder("here is a custom input"));
 
+        let rtl_input = cx.new(|cx| {
+            InputState::new(window, cx)
+                .direction(TextDirection::Rtl)
+                .default_value("مرحبا بالعالم")
+        });
+        let auto_direction_input = cx.new(|cx| {
+            InputState::new(window, cx)
+                .direction(TextDirection::Auto)
+                .placeholder("Type Hebrew or Arabic to switch to RTL")
+        });
+
+        let floating_input =
+            cx.new(|cx| InputState::new(window, cx).focused_placeholder("name@example.com"));
+
+        let count_input = cx.new(|cx| InputState::new(window, cx).placeholder("Write a short bio"));
+
+        let code_input = cx.new(|cx| {
+            InputState::new(window, cx)
+                .code_editor(CODE_SAMPLES[0].1)
+                .default_value(CODE_SAMPLES[0].2)
+        });
+
         let _subscriptions = vec![
             cx.subscribe_in(&input1, window, Self::on_input_event),
             cx.subscribe_in(&input2, window, Self::on_input_event),
@@ -120,10 +178,41 @@ impl InputStory {
             mask_input2,
             currency_input,
             custom_input,
+            rtl_input,
+            count_input,
+            auto_direction_input,
+            floating_input,
+            code_input,
+            code_sample_ix: 0,
+            code_highlighted: false,
             _subscriptions,
         }
     }
 
+    fn set_code_sample(&mut self, ix: usize, window: &mut Window, cx: &mut Context<Self>) {
+        let (_, language, code) = CODE_SAMPLES[ix];
+        self.code_sample_ix = ix;
+        self.code_input.update(cx, |state, cx| {
+            state.set_highlighter(language, cx);
+            state.set_value(code, window, cx);
+        });
+        cx.notify();
+    }
+
+    fn toggle_code_highlight(&mut self, cx: &mut Context<Self>) {
+        self.code_highlighted = !self.code_highlighted;
+        let ranges = if self.code_highlighted {
+            vec![
+                (1..2, cx.theme().danger.opacity(0.2)),
+                (3..5, cx.theme().warning.opacity(0.2)),
+            ]
+        } else {
+            vec![]
+        };
+        self.code_input
+            .update(cx, |state, cx| state.highlight_lines(ranges, cx));
+    }
+
     fn tab(&mut self, _: &Tab, window: &mut Window, cx: &mut Context<Self>) {
         self.cycle_focus(true, window, cx);
     }
@@ -164,6 +253,7 @@ impl FocusableCycle for InputStory {
             self.large_input.focus_handle(cx),
             self.small_input.focus_handle(cx),
             self.input_esc.focus_handle(cx),
+            self.floating_input.focus_handle(cx),
         ]
         .to_vec()
     }
@@ -256,11 +346,32 @@ impl Render for InputStory {
                     .child(TextInput::new(&self.large_input).large().cleanable())
                     .child(TextInput::new(&self.small_input).small().cleanable()),
             )
+            .child(
+                section("Text Direction")
+                    .max_w_md()
+                    .child(TextInput::new(&self.rtl_input))
+                    .child(TextInput::new(&self.auto_direction_input)),
+            )
             .child(
                 section("Cleanable and ESC to clean")
                     .max_w_md()
                     .child(TextInput::new(&self.input_esc).cleanable()),
             )
+            .child(
+                section("Floating Label").max_w_md().child(
+                    TextInput::new(&self.floating_input)
+                        .floating_label("Email")
+                        .cleanable(),
+                ),
+            )
+            .child(
+                section("Max Length and Counter").max_w_md().child(
+                    TextInput::new(&self.count_input)
+                        .max_length(40)
+                        .soft_limit(30)
+                        .show_count(true),
+                ),
+            )
             .child(
                 section("Focused Input")
                     .max_w_md()
@@ -271,6 +382,46 @@ impl Render for InputStory {
                         window.focused_input(cx).map(|input| input.read(cx).value())
                     ))),
             )
+            .child(
+                section("Code Editor")
+                    .max_w_md()
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .child(
+                                ButtonGroup::new("code-languages")
+                                    .outline()
+                                    .compact()
+                                    .children(CODE_SAMPLES.iter().enumerate().map(
+                                        |(ix, (label, _, _))| {
+                                            Button::new(ix)
+                                                .label(*label)
+                                                .selected(self.code_sample_ix == ix)
+                                        },
+                                    ))
+                                    .on_click(cx.listener(
+                                        |this, selecteds: &Vec<usize>, window, cx| {
+                                            if let Some(&ix) = selecteds.first() {
+                                                this.set_code_sample(ix, window, cx);
+                                            }
+                                        },
+                                    )),
+                            )
+                            .child(
+                                Button::new("highlight-lines")
+                                    .outline()
+                                    .compact()
+                                    .label("Highlight Lines")
+                                    .selected(self.code_highlighted)
+                                    .on_click(
+                                        cx.listener(|this, _, _, cx| {
+                                            this.toggle_code_highlight(cx)
+                                        }),
+                                    ),
+                            ),
+                    )
+                    .child(TextInput::new(&self.code_input).h(px(200.))),
+            )
             .child(
                 section("Appearance false").max_w_md().child(
                     div()
@@ -3,7 +3,7 @@ use gpui::{
     Styled, Window,
 };
 
-use gpui_component::{h_flex, v_flex, Kbd};
+use gpui_component::{h_flex, v_flex, Kbd, Os};
 
 use crate::section;
 
@@ -43,19 +43,41 @@ impl Focusable for KbdStory {
 }
 impl Render for KbdStory {
     fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
-        v_flex().gap_6().child(
-            section("Kbd").child(
-                h_flex()
-                    .gap_2()
-                    .child(Kbd::new(Keystroke::parse("cmd-shift-p").unwrap()))
-                    .child(Kbd::new(Keystroke::parse("cmd-ctrl-t").unwrap()))
-                    .child(Kbd::new(Keystroke::parse("cmd--").unwrap()))
-                    .child(Kbd::new(Keystroke::parse("cmd-+").unwrap()))
-                    .child(Kbd::new(Keystroke::parse("escape").unwrap()))
-                    .child(Kbd::new(Keystroke::parse("backspace").unwrap()))
-                    .child(Kbd::new(Keystroke::parse("/").unwrap()))
-                    .child(Kbd::new(Keystroke::parse("enter").unwrap())),
-            ),
-        )
+        v_flex()
+            .gap_6()
+            .child(
+                section("Kbd").child(
+                    h_flex()
+                        .gap_2()
+                        .child(Kbd::new(Keystroke::parse("cmd-shift-p").unwrap()))
+                        .child(Kbd::new(Keystroke::parse("cmd-ctrl-t").unwrap()))
+                        .child(Kbd::new(Keystroke::parse("cmd--").unwrap()))
+                        .child(Kbd::new(Keystroke::parse("cmd-+").unwrap()))
+                        .child(Kbd::new(Keystroke::parse("escape").unwrap()))
+                        .child(Kbd::new(Keystroke::parse("backspace").unwrap()))
+                        .child(Kbd::new(Keystroke::parse("/").unwrap()))
+                        .child(Kbd::new(Keystroke::parse("enter").unwrap())),
+                ),
+            )
+            .child(
+                section("Platform").child(
+                    v_flex().gap_2().children(
+                        [
+                            ("macOS", Os::MacOS),
+                            ("Windows", Os::Windows),
+                            ("Linux", Os::Linux),
+                        ]
+                        .into_iter()
+                        .map(|(label, os)| {
+                            h_flex().gap_2().child(label).children(
+                                ["cmd-shift-p", "ctrl-alt-delete", "alt-enter"].map(|key| {
+                                    Kbd::from_keystroke(&Keystroke::parse(key).unwrap())
+                                        .platform(os)
+                                }),
+                            )
+                        }),
+                    ),
+                ),
+            )
     }
 }
@@ -162,6 +162,7 @@ struct CompanyListDelegate {
     query: SharedString,
     loading: bool,
     eof: bool,
+    movable: bool,
 }
 
 impl CompanyListDelegate {
@@ -217,6 +218,11 @@ impl ListDelegate for CompanyListDelegate {
         self.matched_companies[section].len()
     }
 
+    fn separator_after(&self, ix: IndexPath) -> bool {
+        // Divide every 5 companies into a group.
+        ix.row % 5 == 4
+    }
+
     fn perform_search(
         &mut self,
         query: &str,
@@ -306,6 +312,22 @@ impl ListDelegate for CompanyListDelegate {
         self.loading
     }
 
+    fn can_move_item(&self, _: IndexPath, _: &App) -> bool {
+        self.movable
+    }
+
+    fn move_item(
+        &mut self,
+        from: IndexPath,
+        to: IndexPath,
+        _: &mut Window,
+        _: &mut Context<List<Self>>,
+    ) {
+        let companies = &mut self.matched_companies[from.section];
+        let company = companies.remove(from.row);
+        companies.insert(to.row, company);
+    }
+
     fn is_eof(&self, _: &App) -> bool {
         return !self.loading && !self.eof;
     }
@@ -368,6 +390,7 @@ impl ListStory {
             query: "".into(),
             loading: false,
             eof: false,
+            movable: false,
         };
         delegate.extend_more(100);
 
@@ -386,6 +409,9 @@ impl ListStory {
                     ListEvent::Cancel => {
                         println!("List Cancelled");
                     }
+                    ListEvent::Move(from, to) => {
+                        println!("List Moved: {:?} -> {:?}", from, to);
+                    }
                 }),
             ];
 
@@ -495,7 +521,7 @@ impl Render for ListStory {
                             .small()
                             .on_click(cx.listener(|this, _, window, cx| {
                                 this.company_list.update(cx, |list, cx| {
-                                    list.scroll_to_item(
+                                    list.scroll_to_item_animated(
                                         IndexPath::new(1).section(5),
                                         ScrollStrategy::Center,
                                         window,
@@ -537,6 +563,17 @@ impl Render for ListStory {
                                     cx.notify();
                                 })
                             })),
+                    )
+                    .child(
+                        Checkbox::new("movable")
+                            .label("Drag to Reorder")
+                            .checked(self.company_list.read(cx).delegate().movable)
+                            .on_click(cx.listener(|this, check: &bool, _, cx| {
+                                this.company_list.update(cx, |this, cx| {
+                                    this.delegate_mut().movable = *check;
+                                    cx.notify();
+                                })
+                            })),
                     ),
             )
             .child(
@@ -135,7 +135,7 @@ impl ModalStory {
                             }),
                             Button::new("new-modal").label("Open Other Modal").on_click(
                                 move |_, window, cx| {
-                                    window.open_modal(cx, move |modal, _, _| {
+                                    window.push_modal(cx, move |modal, _, _| {
                                         modal
                                             .title("Other Modal")
                                             .child("This is another modal.")
@@ -350,6 +350,37 @@ impl Render for ModalStory {
                                 })),
                         ),
                     )
+                    .child(
+                        section("Non-dismissable Modal").child(
+                            Button::new("non-dismissable-modal")
+                                .outline()
+                                .label("Unsaved Changes")
+                                .on_click(cx.listener(move |_, _, window, cx| {
+                                    window.open_modal(cx, move |modal, _, _| {
+                                        modal
+                                            .title("Unsaved Changes")
+                                            .child(
+                                                "You have unsaved changes, do you want to save them before closing?",
+                                            )
+                                            .confirm()
+                                            .dismissable(false)
+                                            .button_props(
+                                                ModalButtonProps::default()
+                                                    .ok_text("Save")
+                                                    .cancel_text("Discard"),
+                                            )
+                                            .on_ok(|_, window, cx| {
+                                                window.push_notification("Changes saved.", cx);
+                                                true
+                                            })
+                                            .on_cancel(|_, window, cx| {
+                                                window.push_notification("Changes discarded.", cx);
+                                                true
+                                            })
+                                    });
+                                })),
+                        ),
+                    )
                     .child(
                         section("Scrollable Modal").child(
                             Button::new("scrollable-modal")
@@ -372,6 +403,22 @@ impl Render for ModalStory {
                                 })),
                         ),
                     )
+                    .child(
+                        section("Draggable and Resizable").child(
+                            Button::new("draggable-modal")
+                                .outline()
+                                .label("Draggable Modal")
+                                .on_click(cx.listener(move |_, _, window, cx| {
+                                    window.open_modal(cx, move |modal, _, _| {
+                                        modal
+                                            .title("Drag the Title to Move")
+                                            .draggable(true)
+                                            .resizable(true)
+                                            .child("Drag the edges or corners to resize the modal.")
+                                    });
+                                })),
+                        ),
+                    )
                     .child(
                         section("Custom Paddings").child(
                             Button::new("custom-modal-paddings")
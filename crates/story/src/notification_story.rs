@@ -1,13 +1,15 @@
+use std::time::Duration;
+
 use gpui::{
-    div, App, AppContext, Context, Entity, FocusHandle, Focusable, InteractiveElement as _,
-    IntoElement, ParentElement, Render, Styled, Window,
+    div, App, AppContext, Context, Corner, Entity, FocusHandle, Focusable, InteractiveElement as _,
+    IntoElement, ParentElement, Render, Styled, Timer, Window,
 };
 
 use gpui_component::{
-    button::{Button, ButtonVariants},
+    button::{Button, ButtonGroup, ButtonVariants},
     notification::{Notification, NotificationType},
     text::TextView,
-    ContextModal as _,
+    ContextModal as _, Selectable as _,
 };
 
 use crate::section;
@@ -56,21 +58,61 @@ impl Focusable for NotificationStory {
 }
 
 impl Render for NotificationStory {
-    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        const CORNERS: [(&str, Corner); 4] = [
+            ("Top Left", Corner::TopLeft),
+            ("Top Right", Corner::TopRight),
+            ("Bottom Left", Corner::BottomLeft),
+            ("Bottom Right", Corner::BottomRight),
+        ];
+        let layout = window.notification_layout(cx);
+
         div()
             .id("notification-story")
             .track_focus(&self.focus_handle)
             .size_full()
             .child(
-                section("Simple Notification").child(
-                    Button::new("show-notify-0")
+                section("Position").child(
+                    ButtonGroup::new("notification-corner")
                         .outline()
-                        .label("Show Notification")
-                        .on_click(cx.listener(|_, _, window, cx| {
-                            window.push_notification("This is a notification.", cx)
+                        .compact()
+                        .children(CORNERS.iter().enumerate().map(|(ix, (label, corner))| {
+                            Button::new(ix)
+                                .label(*label)
+                                .selected(layout.corner == *corner)
+                        }))
+                        .on_click(cx.listener(|_, selecteds: &Vec<usize>, window, cx| {
+                            let layout = window
+                                .notification_layout(cx)
+                                .corner(CORNERS[selecteds[0]].1);
+                            window.set_notification_layout(layout, cx);
                         })),
                 ),
             )
+            .child(
+                section("Simple Notification")
+                    .child(
+                        Button::new("show-notify-0")
+                            .outline()
+                            .label("Show Notification")
+                            .on_click(cx.listener(|_, _, window, cx| {
+                                window.push_notification("This is a notification.", cx)
+                            })),
+                    )
+                    .child(
+                        Button::new("show-notify-burst")
+                            .outline()
+                            .label("Show 20 Notifications")
+                            .on_click(cx.listener(|_, _, window, cx| {
+                                for i in 1..=20 {
+                                    window.push_notification(
+                                        format!("Batch operation {} finished.", i),
+                                        cx,
+                                    )
+                                }
+                            })),
+                    ),
+            )
             .child(
                 section("Notification with Type")
                     .child(
@@ -145,7 +187,7 @@ impl Render for NotificationStory {
                                     .title("Uh oh! Something went wrong.")
                                     .message("There was a problem with your request.")
                                     .autohide(false)
-                                    .action(|_, cx| {
+                                    .custom_action(|_, cx| {
                                         Button::new("try-again").primary().label("Retry").on_click(
                                             cx.listener(|this, _, window, cx| {
                                                 println!("You have clicked the try again action.");
@@ -162,6 +204,65 @@ impl Render for NotificationStory {
                         })),
                 ),
             )
+            .child(
+                section("With Action Buttons").child(
+                    Button::new("show-notify-with-actions")
+                        .outline()
+                        .label("Delete File")
+                        .on_click(cx.listener(|_, _, window, cx| {
+                            struct DeletedNotification;
+
+                            window.push_notification(
+                                Notification::new()
+                                    .id::<DeletedNotification>()
+                                    .message("The file has been deleted.")
+                                    .action("Undo", |_, _| {
+                                        println!("You have clicked the undo action.");
+                                    })
+                                    .action("Dismiss", |_, _| {}),
+                                cx,
+                            )
+                        })),
+                ),
+            )
+            .child(
+                section("Progress Notification").child(
+                    Button::new("show-notify-progress")
+                        .outline()
+                        .label("Download File")
+                        .on_click(cx.listener(|_, _, window, cx| {
+                            struct DownloadNotification;
+
+                            let note = Notification::progress()
+                                .id::<DownloadNotification>()
+                                .title("Downloading")
+                                .message("Starting download...");
+                            let handle = note.handle();
+                            window.push_notification(note, cx);
+
+                            // Simulate a download in 5 seconds.
+                            cx.spawn_in(window, async move |_, cx| {
+                                for i in 1..=20 {
+                                    Timer::after(Duration::from_millis(250)).await;
+                                    _ = cx.update(|window, cx| {
+                                        let value = i as f32 * 5.;
+                                        handle.set_progress(value, window, cx);
+                                        handle.set_message(
+                                            format!("Downloaded {}%", value),
+                                            window,
+                                            cx,
+                                        );
+                                    });
+                                }
+
+                                _ = cx.update(|window, cx| {
+                                    handle.complete("The file has been downloaded.", window, cx);
+                                });
+                            })
+                            .detach();
+                        })),
+                ),
+            )
             .child(
                 section("Custom Notification").child(
                     Button::new("show-notify-custom")
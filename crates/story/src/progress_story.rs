@@ -1,7 +1,10 @@
 use gpui::{
     App, AppContext, Context, Entity, Focusable, IntoElement, ParentElement, Render, Styled, Window,
 };
-use gpui_component::{button::Button, h_flex, progress::Progress, v_flex, IconName, Sizable};
+use gpui_component::{
+    button::Button, h_flex, progress::Progress, v_flex, ActiveTheme as _, FillColor, IconName,
+    Sizable,
+};
 
 use crate::section;
 
@@ -81,6 +84,24 @@ impl Render for ProgressStory {
                             )),
                     )
                     .child(Progress::new().value(self.value))
+                    .child(
+                        Progress::new()
+                            .value(self.value)
+                            .fill_color(FillColor::Thresholds(vec![
+                                (0., cx.theme().success),
+                                (0.6, cx.theme().warning),
+                                (0.85, cx.theme().danger),
+                            ])),
+                    )
+                    .child(
+                        Progress::new()
+                            .value(self.value)
+                            .fill_color(FillColor::Gradient(vec![
+                                (0., cx.theme().success),
+                                (0.5, cx.theme().warning),
+                                (1., cx.theme().danger),
+                            ])),
+                    )
                     .child(
                         h_flex()
                             .gap_x_2()
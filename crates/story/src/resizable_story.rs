@@ -3,8 +3,10 @@ use gpui::{
     ParentElement as _, Pixels, Render, SharedString, Styled, Window,
 };
 use gpui_component::{
+    button::{Button, ButtonVariants as _},
+    h_flex,
     resizable::{h_resizable, resizable_panel, v_resizable, ResizableState},
-    v_flex, ActiveTheme,
+    v_flex, ActiveTheme, Disableable as _, Sizable as _,
 };
 
 pub struct ResizableStory {
@@ -12,6 +14,7 @@ pub struct ResizableStory {
     state1: Entity<ResizableState>,
     state2: Entity<ResizableState>,
     state3: Entity<ResizableState>,
+    saved_sizes: Option<Vec<f32>>,
 }
 
 impl super::Story for ResizableStory {
@@ -49,6 +52,7 @@ impl ResizableStory {
             state1,
             state2,
             state3,
+            saved_sizes: None,
         }
     }
 }
@@ -80,9 +84,17 @@ impl Render for ResizableStory {
                                         resizable_panel()
                                             .size(px(150.))
                                             .size_range(px(120.)..px(300.))
-                                            .child(panel_box("Left (120px .. 300px)", cx)),
+                                            .collapsed_size(px(40.))
+                                            .child(panel_box(
+                                                "Left (120px .. 300px), double click the handle to collapse",
+                                                cx,
+                                            )),
+                                    )
+                                    .child(
+                                        resizable_panel()
+                                            .collapsible(false)
+                                            .child(panel_box("Center", cx)),
                                     )
-                                    .child(resizable_panel().child(panel_box("Center", cx)))
                                     .child(
                                         resizable_panel()
                                             .size(px(300.))
@@ -98,6 +110,34 @@ impl Render for ResizableStory {
                             ),
                     ),
             )
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        Button::new("save-layout")
+                            .outline()
+                            .small()
+                            .label("Save Layout")
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                this.saved_sizes = Some(this.state3.read(cx).serialized());
+                                cx.notify();
+                            })),
+                    )
+                    .child(
+                        Button::new("restore-layout")
+                            .outline()
+                            .small()
+                            .label("Restore Layout")
+                            .disabled(self.saved_sizes.is_none())
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                let Some(sizes) = this.saved_sizes.clone() else {
+                                    return;
+                                };
+                                this.state3
+                                    .update(cx, |state, cx| state.restore(sizes, cx));
+                            })),
+                    ),
+            )
             .child(
                 div()
                     .h(px(400.))
@@ -8,6 +8,7 @@ use gpui::{
 use gpui_component::{
     badge::Badge,
     breadcrumb::{Breadcrumb, BreadcrumbItem},
+    button::{Button, ButtonVariants as _},
     divider::Divider,
     h_flex,
     popup_menu::PopupMenuExt,
@@ -28,9 +29,11 @@ pub struct SidebarStory {
     active_items: HashMap<Item, bool>,
     last_active_item: Item,
     active_subitem: Option<SubItem>,
+    active_lesson: Option<&'static str>,
     collapsed: bool,
     side: Side,
     focus_handle: gpui::FocusHandle,
+    sidebar_focus_handle: gpui::FocusHandle,
     checked: bool,
 }
 
@@ -47,24 +50,38 @@ impl SidebarStory {
             active_items,
             last_active_item: Item::Playground,
             active_subitem: None,
+            active_lesson: None,
             collapsed: false,
             side: Side::Left,
             focus_handle: cx.focus_handle(),
+            sidebar_focus_handle: cx.focus_handle(),
             checked: false,
         }
     }
 
     fn render_content(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
         v_flex().child(
-            h_flex().gap_2().child(
-                Switch::new("side")
-                    .label("Placement Right")
-                    .checked(self.side.is_right())
-                    .on_click(cx.listener(|this, checked: &bool, _, cx| {
-                        this.side = if *checked { Side::Right } else { Side::Left };
-                        cx.notify();
-                    })),
-            ),
+            h_flex()
+                .gap_2()
+                .child(
+                    Switch::new("side")
+                        .label("Placement Right")
+                        .checked(self.side.is_right())
+                        .on_click(cx.listener(|this, checked: &bool, _, cx| {
+                            this.side = if *checked { Side::Right } else { Side::Left };
+                            cx.notify();
+                        })),
+                )
+                .child(
+                    Button::new("focus-sidebar")
+                        .small()
+                        .outline()
+                        .label("Focus Sidebar")
+                        .tooltip("Use Up/Down to move, Enter to activate, Left/Right to collapse/expand.")
+                        .on_click(cx.listener(|this, _, window, _| {
+                            window.focus(&this.sidebar_focus_handle);
+                        })),
+                ),
         )
     }
 }
@@ -197,6 +214,7 @@ impl SubItem {
             this.active_items.insert(item, true);
             this.last_active_item = item;
             this.active_subitem = Some(subitem);
+            this.active_lesson = None;
             cx.notify();
         }
     }
@@ -251,6 +269,7 @@ impl Render for SidebarStory {
             .child(
                 Sidebar::new(self.side)
                     .collapsed(self.collapsed)
+                    .track_focus(&self.sidebar_focus_handle)
                     .header(
                         SidebarHeader::new()
                             .w_full()
@@ -329,6 +348,29 @@ impl Render for SidebarStory {
                                                             )),
                                                     )
                                                 })
+                                                .when(sub_item == SubItem::Tutorial, |this| {
+                                                    this.submenu(
+                                                        ["Basics", "Layout", "Events"]
+                                                            .into_iter()
+                                                            .map(|lesson| {
+                                                                SidebarMenuItem::new(lesson)
+                                                                    .active(
+                                                                        self.active_lesson
+                                                                            == Some(lesson),
+                                                                    )
+                                                                    .on_click(cx.listener(
+                                                                        move |this, _, _, cx| {
+                                                                            this.active_subitem =
+                                                                                None;
+                                                                            this.active_lesson =
+                                                                                Some(lesson);
+                                                                            cx.notify();
+                                                                        },
+                                                                    ))
+                                                            })
+                                                            .collect(),
+                                                    )
+                                                })
                                                 .on_click(cx.listener(sub_item.handler(&item)))
                                         },
                                     ))
@@ -336,9 +378,9 @@ impl Render for SidebarStory {
                             }),
                         )),
                     )
-                    .child(
-                        SidebarGroup::new("Projects").child(SidebarMenu::new().children(
-                            groups[1].iter().enumerate().map(|(ix, item)| {
+                    .child(SidebarGroup::new("Projects").collapsible(true).child(
+                        SidebarMenu::new().children(groups[1].iter().enumerate().map(
+                            |(ix, item)| {
                                 SidebarMenuItem::new(item.label())
                                     .icon(item.icon())
                                     .active(self.last_active_item == *item)
@@ -350,9 +392,10 @@ impl Render for SidebarStory {
                                         )
                                     })
                                     .when(ix == 1, |this| this.suffix(IconName::Settings2))
-                            }),
+                                    .when(ix == 2, |this| this.badge("12"))
+                            },
                         )),
-                    )
+                    ))
                     .footer(
                         SidebarFooter::new()
                             .justify_between()
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use gpui::{
     px, App, AppContext, Context, Entity, Focusable, IntoElement, ParentElement, Render, Styled,
     Window,
@@ -77,5 +79,31 @@ impl Render for SkeletonStory {
                         ),
                 ),
             )
+            .child(
+                section("Shimmer").max_w_md().child(
+                    v_flex()
+                        .gap_2()
+                        .child(
+                            Skeleton::new()
+                                .shimmer(true)
+                                .w(px(250.))
+                                .h(px(125.))
+                                .rounded(px(8.)),
+                        )
+                        .child(
+                            Skeleton::new()
+                                .shimmer(true)
+                                .speed(Duration::from_millis(1500))
+                                .w(px(250.))
+                                .h_4()
+                                .rounded(px(4.)),
+                        ),
+                ),
+            )
+            .child(
+                section("Lines")
+                    .max_w_md()
+                    .child(Skeleton::new().lines(4).w(px(300.)).rounded(px(4.))),
+            )
     }
 }
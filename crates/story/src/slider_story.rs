@@ -7,7 +7,7 @@ use gpui_component::{
     clipboard::Clipboard,
     h_flex,
     slider::{Slider, SliderEvent, SliderState},
-    v_flex, ActiveTheme, Colorize as _, ContextModal, StyledExt,
+    v_flex, ActiveTheme, Colorize as _, ContextModal, FillColor, StyledExt,
 };
 
 use crate::section;
@@ -22,6 +22,7 @@ pub struct SliderStory {
     slider_hsl: [Entity<SliderState>; 4],
     slider_hsl_value: Hsla,
     slider4: Entity<SliderState>,
+    slider5: Entity<SliderState>,
     disabled: bool,
     _subscritions: Vec<Subscription>,
 }
@@ -92,13 +93,7 @@ impl SliderStory {
             }),
         ];
 
-        let slider3 = cx.new(|_| {
-            SliderState::new()
-                .min(0.)
-                .max(100.)
-                .default_value(12.0..45.0)
-                .step(1.)
-        });
+        let slider3 = cx.new(|_| SliderState::range(12., 45.).min(0.).max(100.).step(1.));
 
         let slider4 = cx.new(|_| {
             SliderState::new()
@@ -108,15 +103,17 @@ impl SliderStory {
                 .step(1.)
         });
 
+        let slider5 = cx.new(|_| SliderState::new().min(0.).max(100.).default_value(50.));
+
         let mut _subscritions = vec![
-            cx.subscribe(&slider1, |this, _, event: &SliderEvent, cx| match event {
-                SliderEvent::Change(value) => {
+            cx.subscribe(&slider1, |this, _, event: &SliderEvent, cx| {
+                if let SliderEvent::Change(value) = event {
                     this.slider1_value = value.start();
                     cx.notify();
                 }
             }),
-            cx.subscribe(&slider2, |this, _, event: &SliderEvent, cx| match event {
-                SliderEvent::Change(value) => {
+            cx.subscribe(&slider2, |this, _, event: &SliderEvent, cx| {
+                if let SliderEvent::Change(value) = event {
                     this.slider2_value = value.start();
                     cx.notify();
                 }
@@ -137,6 +134,7 @@ impl SliderStory {
                             );
                             cx.notify();
                         }
+                        SliderEvent::RangeChange(_) => {}
                     })
                 })
                 .collect::<Vec<_>>(),
@@ -154,6 +152,7 @@ impl SliderStory {
             slider2,
             slider3,
             slider4,
+            slider5,
             slider_hsl,
             slider_hsl_value: gpui::red(),
             disabled: false,
@@ -190,6 +189,15 @@ impl Render for SliderStory {
                     .max_w_md()
                     .v_flex()
                     .child(Slider::new(&self.slider1).disabled(self.disabled))
+                    .child(
+                        Slider::new(&self.slider1)
+                            .disabled(self.disabled)
+                            .fill_color(FillColor::Gradient(vec![
+                                (0., cx.theme().success),
+                                (0.5, cx.theme().warning),
+                                (1., cx.theme().danger),
+                            ])),
+                    )
                     .child(format!("Value: {}", self.slider1_value)),
             )
             .child(
@@ -211,6 +219,24 @@ impl Render for SliderStory {
                     .child(Slider::new(&self.slider3).disabled(self.disabled))
                     .child(format!("Value: {}", self.slider3.read(cx).value())),
             )
+            .child(
+                section("Marks with Snapping")
+                    .max_w_md()
+                    .v_flex()
+                    .child(
+                        Slider::new(&self.slider5)
+                            .disabled(self.disabled)
+                            .marks(vec![0., 25., 50., 75., 100.])
+                            .snap_to_marks(true)
+                            .mark_labels(
+                                [0., 25., 50., 75., 100.]
+                                    .into_iter()
+                                    .map(|value| (value, format!("{}%", value).into()))
+                                    .collect(),
+                            ),
+                    )
+                    .child(format!("Value: {}", self.slider5.read(cx).value())),
+            )
             .child(
                 section("Vertical with Range")
                     .max_w_md()
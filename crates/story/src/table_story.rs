@@ -6,19 +6,19 @@ use std::{
 
 use fake::Fake;
 use gpui::{
-    div, prelude::FluentBuilder as _, Action, AnyElement, App, AppContext, ClickEvent, Context,
-    Entity, Focusable, InteractiveElement, IntoElement, ParentElement, Render, SharedString,
-    StatefulInteractiveElement, Styled, TextAlign, Timer, Window,
+    div, prelude::FluentBuilder as _, px, Action, AnyElement, App, AppContext, ClickEvent,
+    ClipboardItem, Context, Entity, Focusable, InteractiveElement, IntoElement, ParentElement,
+    Render, SharedString, StatefulInteractiveElement, Styled, TextAlign, Timer, Window,
 };
 use gpui_component::{
-    button::Button,
+    button::{Button, ButtonVariants as _},
     checkbox::Checkbox,
     h_flex,
     indicator::Indicator,
     input::{InputEvent, InputState, TextInput},
     label::Label,
     popup_menu::{PopupMenu, PopupMenuExt},
-    table::{Column, ColumnFixed, ColumnSort, Table, TableDelegate, TableEvent},
+    table::{Column, ColumnFixed, ColumnSort, SelectionMode, Table, TableDelegate, TableEvent},
     v_flex, ActiveTheme as _, Selectable, Sizable as _, Size, StyleSized as _, StyledExt,
 };
 use serde::{Deserialize, Serialize};
@@ -178,6 +178,9 @@ struct StockTableDelegate {
     loading: bool,
     full_loading: bool,
     eof: bool,
+    /// Simulate the server-paginated data.
+    paginated: bool,
+    page_size: usize,
     visible_rows: Range<usize>,
     visible_cols: Range<usize>,
 }
@@ -253,10 +256,17 @@ impl StockTableDelegate {
                 Column::new("day_30_ranking", "30d Ranking"),
                 Column::new("day_120_ranking", "120d Ranking"),
                 Column::new("day_250_ranking", "250d Ranking"),
+                Column::new("actions", "Actions")
+                    .width(px(80.))
+                    .fixed(ColumnFixed::Right)
+                    .resizable(false)
+                    .movable(false),
             ],
             loading: false,
             full_loading: false,
             eof: false,
+            paginated: false,
+            page_size: 20,
             visible_cols: Range::default(),
             visible_rows: Range::default(),
         }
@@ -373,6 +383,17 @@ impl TableDelegate for StockTableDelegate {
         .menu("Size XSmall", Box::new(ChangeSize(Size::XSmall)))
     }
 
+    fn row_accent(&self, row_ix: usize, cx: &App) -> Option<gpui::Hsla> {
+        let stock = self.stocks.get(row_ix)?;
+        if stock.year_change_percent > 0.8 {
+            Some(cx.theme().green)
+        } else if stock.year_change_percent < -0.8 {
+            Some(cx.theme().red)
+        } else {
+            None
+        }
+    }
+
     fn render_tr(
         &self,
         row_ix: usize,
@@ -389,6 +410,46 @@ impl TableDelegate for StockTableDelegate {
             }))
     }
 
+    fn cell_text(&self, row_ix: usize, col_ix: usize, _: &App) -> SharedString {
+        let Some(stock) = self.stocks.get(row_ix) else {
+            return SharedString::default();
+        };
+        let Some(col) = self.columns.get(col_ix) else {
+            return SharedString::default();
+        };
+
+        match col.key.as_ref() {
+            "id" => stock.id.to_string().into(),
+            "market" => stock.counter.market.clone(),
+            "symbol" => stock.counter.symbol_code(),
+            "name" => stock.counter.name.clone(),
+            "price" => format!("{:.3}", stock.price).into(),
+            "change" => format!("{:.3}", stock.change).into(),
+            "change_percent" => format!("{:.2}%", stock.change_percent * 100.).into(),
+            "volume" => format!("{:.3}", stock.volume).into(),
+            _ => SharedString::default(),
+        }
+    }
+
+    fn is_cell_editable(&self, _: usize, col_ix: usize, _: &App) -> bool {
+        self.columns
+            .get(col_ix)
+            .is_some_and(|col| col.key.as_ref() == "name")
+    }
+
+    fn edit_cell(
+        &mut self,
+        row_ix: usize,
+        _: usize,
+        new_value: SharedString,
+        _: &mut Window,
+        _: &mut Context<Table<Self>>,
+    ) {
+        if let Some(stock) = self.stocks.get_mut(row_ix) {
+            stock.counter.name = new_value;
+        }
+    }
+
     /// NOTE: Performance metrics
     ///
     /// last render 561 cells total: 232.745µs, avg: 414ns
@@ -491,6 +552,14 @@ impl TableDelegate for StockTableDelegate {
             "day_30_ranking" => stock.day_30_ranking.floor().to_string().into_any_element(),
             "day_120_ranking" => stock.day_120_ranking.floor().to_string().into_any_element(),
             "day_250_ranking" => stock.day_250_ranking.floor().to_string().into_any_element(),
+            "actions" => Button::new(("detail", row_ix))
+                .xsmall()
+                .ghost()
+                .label("Detail")
+                .on_click(move |_, window, cx| {
+                    window.dispatch_action(Box::new(OpenDetail(row_ix)), cx)
+                })
+                .into_any_element(),
             _ => "--".to_string().into_any_element(),
         }
     }
@@ -506,6 +575,21 @@ impl TableDelegate for StockTableDelegate {
         self.columns.insert(to_ix, col);
     }
 
+    fn can_move_row(&self, _: usize, _: &App) -> bool {
+        true
+    }
+
+    fn move_row(
+        &mut self,
+        row_ix: usize,
+        to_ix: usize,
+        _: &mut Window,
+        _: &mut Context<Table<Self>>,
+    ) {
+        let stock = self.stocks.remove(row_ix);
+        self.stocks.insert(to_ix, stock);
+    }
+
     fn perform_sort(
         &mut self,
         col_ix: usize,
@@ -535,6 +619,18 @@ impl TableDelegate for StockTableDelegate {
         }
     }
 
+    fn perform_sort_multi(
+        &mut self,
+        sorts: &[(usize, ColumnSort)],
+        window: &mut Window,
+        cx: &mut Context<Table<Self>>,
+    ) {
+        // The sort is stable, so sort by the lowest precedence first.
+        for (col_ix, sort) in sorts.iter().rev() {
+            self.perform_sort(*col_ix, *sort, window, cx);
+        }
+    }
+
     fn loading(&self, _: &App) -> bool {
         self.full_loading
     }
@@ -565,6 +661,23 @@ impl TableDelegate for StockTableDelegate {
         .detach();
     }
 
+    fn total_rows(&self, _: &App) -> Option<usize> {
+        self.paginated.then_some(1000)
+    }
+
+    fn page_size(&self, _: &App) -> usize {
+        self.page_size
+    }
+
+    fn set_page_size(&mut self, page_size: usize, _: &mut Window, _: &mut Context<Table<Self>>) {
+        self.page_size = page_size;
+    }
+
+    fn set_page(&mut self, _: usize, _: &mut Window, _: &mut Context<Table<Self>>) {
+        // Simulate to load the rows of the page from the server.
+        self.stocks = random_stocks(self.page_size);
+    }
+
     fn visible_rows_changed(
         &mut self,
         visible_range: Range<usize>,
@@ -745,6 +858,17 @@ impl TableStory {
         });
     }
 
+    fn toggle_multi_selection(&mut self, checked: &bool, _: &mut Window, cx: &mut Context<Self>) {
+        self.table.update(cx, |table, cx| {
+            table.selection_mode = if *checked {
+                SelectionMode::Multi
+            } else {
+                SelectionMode::Single
+            };
+            cx.notify();
+        });
+    }
+
     fn toggle_stripe(&mut self, checked: &bool, _: &mut Window, cx: &mut Context<Self>) {
         self.stripe = *checked;
         let stripe = self.stripe;
@@ -779,11 +903,19 @@ impl TableStory {
                 println!("Column widths changed: {:?}", col_widths)
             }
             TableEvent::SelectColumn(ix) => println!("Select col: {}", ix),
-            TableEvent::DoubleClickedRow(ix) => println!("Double clicked row: {}", ix),
+            TableEvent::ActivateRow(ix) => println!("Activate row: {}", ix),
             TableEvent::SelectRow(ix) => println!("Select row: {}", ix),
+            TableEvent::SelectRows(ixs) => println!("Select rows: {:?}", ixs),
             TableEvent::MoveColumn(origin_idx, target_idx) => {
                 println!("Move col index: {} -> {}", origin_idx, target_idx);
             }
+            TableEvent::MoveRow(origin_idx, target_idx) => {
+                println!("Move row index: {} -> {}", origin_idx, target_idx);
+            }
+            TableEvent::CellEdited { row, col } => {
+                println!("Edited cell: row {}, col {}", row, col);
+            }
+            TableEvent::PageChanged(page) => println!("Page changed: {}", page),
         }
     }
 }
@@ -841,6 +973,12 @@ impl Render for TableStory {
                             .selected(table.col_fixed)
                             .on_click(cx.listener(Self::toggle_col_fixed)),
                     )
+                    .child(
+                        Checkbox::new("multi-selection")
+                            .label("Multi Selection")
+                            .selected(table.selection_mode == SelectionMode::Multi)
+                            .on_click(cx.listener(Self::toggle_multi_selection)),
+                    )
                     .child(
                         Checkbox::new("stripe")
                             .label("Stripe")
@@ -858,6 +996,31 @@ impl Render for TableStory {
                                 })
                             })),
                     )
+                    .child(
+                        Checkbox::new("busy")
+                            .label("Busy")
+                            .checked(self.table.read(cx).is_busy())
+                            .on_click(cx.listener(|this, check: &bool, _, cx| {
+                                this.table
+                                    .update(cx, |table, cx| table.set_busy(*check, cx))
+                            })),
+                    )
+                    .child(
+                        Checkbox::new("pagination")
+                            .label("Pagination")
+                            .checked(self.table.read(cx).delegate().paginated)
+                            .on_click(cx.listener(|this, check: &bool, window, cx| {
+                                this.table.update(cx, |table, cx| {
+                                    table.delegate_mut().paginated = *check;
+                                    if *check {
+                                        table.set_page(0, window, cx);
+                                    } else {
+                                        table.delegate_mut().update_stocks(5000);
+                                        cx.notify();
+                                    }
+                                })
+                            })),
+                    )
                     .child(
                         Checkbox::new("refresh-data")
                             .label("Refresh Data")
@@ -901,9 +1064,9 @@ impl Render for TableStory {
                             .outline()
                             .small()
                             .child("Scroll to Top")
-                            .on_click(cx.listener(|this, _, _, cx| {
+                            .on_click(cx.listener(|this, _, window, cx| {
                                 this.table.update(cx, |table, cx| {
-                                    table.scroll_to_row(0, cx);
+                                    table.scroll_to_row_animated(0, window, cx);
                                 })
                             })),
                     )
@@ -917,6 +1080,16 @@ impl Render for TableStory {
                                     table.scroll_to_row(table.delegate().rows_count(cx) - 1, cx);
                                 })
                             })),
+                    )
+                    .child(
+                        Button::new("copy-csv")
+                            .outline()
+                            .small()
+                            .child("Copy as CSV")
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                let csv = this.table.read(cx).export_delimited(',', cx);
+                                cx.write_to_clipboard(ClipboardItem::new_string(csv));
+                            })),
                     ), // .child(
                        //     Button::new("scroll-first-col")
                        //         .child("Scroll to First Column")
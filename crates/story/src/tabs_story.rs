@@ -1,13 +1,13 @@
 use gpui::{
     App, AppContext, Context, Entity, FocusHandle, Focusable, IntoElement, ParentElement, Render,
-    Styled, Window,
+    SharedString, Styled, Window,
 };
 
 use gpui_component::{
     button::{Button, ButtonGroup, ButtonVariants},
     checkbox::Checkbox,
     h_flex,
-    tab::{Tab, TabBar},
+    tab::{Tab, TabBar, TabsEvent},
     v_flex, IconName, Selectable as _, Sizable, Size,
 };
 
@@ -18,6 +18,9 @@ pub struct TabsStory {
     active_tab_ix: usize,
     size: Size,
     menu: bool,
+    dynamic_tabs: Vec<SharedString>,
+    dynamic_active_ix: usize,
+    next_tab_id: usize,
 }
 
 impl super::Story for TabsStory {
@@ -45,9 +48,34 @@ impl TabsStory {
             active_tab_ix: 0,
             size: Size::default(),
             menu: false,
+            dynamic_tabs: (1..=3).map(|i| format!("File {}", i).into()).collect(),
+            dynamic_active_ix: 0,
+            next_tab_id: 4,
         }
     }
 
+    fn on_dynamic_tabs_event(&mut self, event: &TabsEvent, _: &mut Window, cx: &mut Context<Self>) {
+        match *event {
+            TabsEvent::CloseRequested(ix) => {
+                if ix < self.dynamic_tabs.len() {
+                    self.dynamic_tabs.remove(ix);
+                }
+                if self.dynamic_active_ix > ix || self.dynamic_active_ix >= self.dynamic_tabs.len()
+                {
+                    self.dynamic_active_ix = self.dynamic_active_ix.saturating_sub(1);
+                }
+            }
+            TabsEvent::Reorder(from, to) => {
+                if from < self.dynamic_tabs.len() && to < self.dynamic_tabs.len() {
+                    let tab = self.dynamic_tabs.remove(from);
+                    self.dynamic_tabs.insert(to, tab);
+                    self.dynamic_active_ix = to;
+                }
+            }
+        }
+        cx.notify();
+    }
+
     fn set_active_tab(&mut self, ix: usize, _: &mut Window, cx: &mut Context<Self>) {
         self.active_tab_ix = ix;
         cx.notify();
@@ -244,5 +272,31 @@ impl Render for TabsStory {
                         .children(vec!["Appearance", "Settings", "About", "License"]),
                 ),
             )
+            .child(
+                section("Closable and Reorderable Tabs").max_w_md().child(
+                    TabBar::new("dynamic")
+                        .w_full()
+                        .with_size(self.size)
+                        .reorderable(true)
+                        .selected_index(self.dynamic_active_ix)
+                        .on_click(cx.listener(|this, ix: &usize, _, cx| {
+                            this.dynamic_active_ix = *ix;
+                            cx.notify();
+                        }))
+                        .on_event(cx.listener(Self::on_dynamic_tabs_event))
+                        .on_add(cx.listener(|this, _, _, cx| {
+                            this.dynamic_tabs
+                                .push(format!("File {}", this.next_tab_id).into());
+                            this.next_tab_id += 1;
+                            this.dynamic_active_ix = this.dynamic_tabs.len() - 1;
+                            cx.notify();
+                        }))
+                        .children(
+                            self.dynamic_tabs
+                                .iter()
+                                .map(|label| Tab::new(label.clone()).closable(true)),
+                        ),
+                ),
+            )
     }
 }
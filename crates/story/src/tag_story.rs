@@ -1,14 +1,20 @@
 use gpui::{
-    px, App, AppContext, Context, Entity, FocusHandle, Focusable, IntoElement, ParentElement,
+    div, px, App, AppContext, Context, Entity, FocusHandle, Focusable, IntoElement, ParentElement,
     Render, Styled, Window,
 };
 
-use gpui_component::{h_flex, indigo_50, indigo_500, tag::Tag, v_flex, ColorName, Sizable};
+use gpui_component::{
+    h_flex, indigo_50, indigo_500,
+    tag::{Tag, TagGroup, TagGroupItem, TagGroupOverflow, TagVariant},
+    v_flex, ColorName, Sizable,
+};
 
 use crate::section;
 
 pub struct TagStory {
     focus_handle: FocusHandle,
+    tag_group: Entity<TagGroup>,
+    scroll_tag_group: Entity<TagGroup>,
 }
 
 impl super::Story for TagStory {
@@ -26,9 +32,29 @@ impl super::Story for TagStory {
 }
 
 impl TagStory {
-    pub(crate) fn new(_: &mut Window, cx: &mut App) -> Self {
+    pub(crate) fn new(window: &mut Window, cx: &mut App) -> Self {
+        let priorities = [
+            TagGroupItem::new("P0").variant(TagVariant::Danger),
+            TagGroupItem::new("P1").variant(TagVariant::Warning),
+            TagGroupItem::new("P2").variant(TagVariant::Info),
+            TagGroupItem::new("P3"),
+        ];
+        let tag_group = cx.new(|cx| TagGroup::new(priorities, window, cx));
+        let scroll_tag_group = cx.new(|cx| {
+            TagGroup::new(
+                ColorName::all().into_iter().map(|color| {
+                    TagGroupItem::new(color.to_string()).variant(TagVariant::Color(color))
+                }),
+                window,
+                cx,
+            )
+            .overflow(TagGroupOverflow::Scroll)
+        });
+
         Self {
             focus_handle: cx.focus_handle(),
+            tag_group,
+            scroll_tag_group,
         }
     }
 
@@ -125,6 +151,11 @@ impl Render for TagStory {
                         .child(Tag::info().small().rounded(px(0.)).child("Info")),
                 ),
             )
+            .child(section("TagGroup (drag to reorder)").child(self.tag_group.clone()))
+            .child(
+                section("TagGroup (scroll)")
+                    .child(div().w_full().child(self.scroll_tag_group.clone())),
+            )
             .child(
                 section("Color Tags").child(
                     v_flex().gap_4().child(
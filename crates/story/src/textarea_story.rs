@@ -1,13 +1,15 @@
 use gpui::{
     px, App, AppContext as _, ClickEvent, Context, Entity, FocusHandle, Focusable,
-    InteractiveElement, IntoElement, KeyBinding, ParentElement as _, Render, Styled, Window,
+    InteractiveElement, IntoElement, KeyBinding, ParentElement as _, Render, Styled, Subscription,
+    Window,
 };
 
 use crate::{section, Tab, TabPrev};
 use gpui_component::{
     button::Button,
+    checkbox::Checkbox,
     h_flex,
-    input::{InputState, TextInput},
+    input::{InputEvent, InputState, SearchEvent, SearchOptions, TextInput},
     v_flex, FocusableCycle, Sizable,
 };
 
@@ -24,6 +26,11 @@ pub struct TextareaStory {
     textarea: Entity<InputState>,
     textarea_auto_grow: Entity<InputState>,
     textarea_no_wrap: Entity<InputState>,
+    search_input: Entity<InputState>,
+    replace_input: Entity<InputState>,
+    search_options: SearchOptions,
+    search_status: SearchEvent,
+    _subscriptions: Vec<Subscription>,
 }
 
 impl super::Story for TextareaStory {
@@ -54,6 +61,8 @@ impl TextareaStory {
             InputState::new(window, cx)
                 .multi_line()
                 .rows(10)
+                // Normalize the line endings of the pasted text, `secondary-shift-v` to paste verbatim.
+                .on_paste(|text| text.replace("\r\n", "\n"))
                 .placeholder("Enter text here...").default_value(
                 unindent::unindent(
                     r#"Hello 世界，this is GPUI component.
@@ -93,13 +102,44 @@ impl TextareaStory {
                 .default_value("This is a very long line of text to test if the horizontal scrolling function is working properly, and it should not wrap automatically but display a horizontal scrollbar.\nThe second line is also very long text, used to test the horizontal scrolling effect under multiple lines, and you can input more content to test.\nThe third line: Here you can input other long text content that requires horizontal scrolling.\n")
         });
 
+        let search_input = cx.new(|cx| InputState::new(window, cx).placeholder("Search"));
+        let replace_input = cx.new(|cx| InputState::new(window, cx).placeholder("Replace"));
+
+        let _subscriptions = vec![
+            cx.subscribe_in(&search_input, window, |this, _, event, window, cx| {
+                if let InputEvent::Change(_) = event {
+                    this.update_search(window, cx);
+                }
+            }),
+            cx.subscribe(&textarea, |this, _, event: &SearchEvent, cx| {
+                this.search_status = *event;
+                cx.notify();
+            }),
+        ];
+
         Self {
             textarea,
             textarea_auto_grow,
             textarea_no_wrap,
+            search_input,
+            replace_input,
+            search_options: SearchOptions::default(),
+            search_status: SearchEvent {
+                current: None,
+                total: 0,
+            },
+            _subscriptions,
         }
     }
 
+    fn update_search(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let query = self.search_input.read(cx).value();
+        let options = self.search_options;
+        self.textarea.update(cx, |input, cx| {
+            input.search(query, options, window, cx);
+        });
+    }
+
     fn tab(&mut self, _: &Tab, window: &mut Window, cx: &mut Context<Self>) {
         self.cycle_focus(true, window, cx);
     }
@@ -119,6 +159,32 @@ impl TextareaStory {
         });
     }
 
+    fn on_find_prev(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        self.textarea.update(cx, |input, cx| {
+            input.find_prev(window, cx);
+        });
+    }
+
+    fn on_find_next(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        self.textarea.update(cx, |input, cx| {
+            input.find_next(window, cx);
+        });
+    }
+
+    fn on_replace_current(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let replacement = self.replace_input.read(cx).value();
+        self.textarea.update(cx, |input, cx| {
+            input.replace_current(&replacement, window, cx);
+        });
+    }
+
+    fn on_replace_all(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
+        let replacement = self.replace_input.read(cx).value();
+        self.textarea.update(cx, |input, cx| {
+            input.replace_all(&replacement, window, cx);
+        });
+    }
+
     fn on_replace_text_to_textarea(
         &mut self,
         _: &ClickEvent,
@@ -145,6 +211,10 @@ impl Focusable for TextareaStory {
 impl Render for TextareaStory {
     fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
         let loc = self.textarea.read(cx).line_column();
+        let search_status = match self.search_status.current {
+            Some(ix) => format!("{} of {}", ix + 1, self.search_status.total),
+            None => "No results".to_string(),
+        };
 
         v_flex()
             .key_context(CONTEXT)
@@ -157,7 +227,68 @@ impl Render for TextareaStory {
                     v_flex()
                         .gap_2()
                         .w_full()
-                        .child(TextInput::new(&self.textarea).h(px(320.)))
+                        .child(
+                            h_flex()
+                                .gap_2()
+                                .child(TextInput::new(&self.search_input).small().w(px(200.)))
+                                .child(
+                                    Checkbox::new("search-case-sensitive")
+                                        .label("Aa")
+                                        .checked(self.search_options.case_sensitive)
+                                        .on_click(cx.listener(|this, checked, window, cx| {
+                                            this.search_options.case_sensitive = *checked;
+                                            this.update_search(window, cx);
+                                        })),
+                                )
+                                .child(
+                                    Checkbox::new("search-whole-word")
+                                        .label("Whole Word")
+                                        .checked(self.search_options.whole_word)
+                                        .on_click(cx.listener(|this, checked, window, cx| {
+                                            this.search_options.whole_word = *checked;
+                                            this.update_search(window, cx);
+                                        })),
+                                )
+                                .child(search_status)
+                                .child(
+                                    Button::new("btn-find-prev")
+                                        .outline()
+                                        .xsmall()
+                                        .label("Prev")
+                                        .on_click(cx.listener(Self::on_find_prev)),
+                                )
+                                .child(
+                                    Button::new("btn-find-next")
+                                        .outline()
+                                        .xsmall()
+                                        .label("Next")
+                                        .on_click(cx.listener(Self::on_find_next)),
+                                ),
+                        )
+                        .child(
+                            h_flex()
+                                .gap_2()
+                                .child(TextInput::new(&self.replace_input).small().w(px(200.)))
+                                .child(
+                                    Button::new("btn-replace-current")
+                                        .outline()
+                                        .xsmall()
+                                        .label("Replace")
+                                        .on_click(cx.listener(Self::on_replace_current)),
+                                )
+                                .child(
+                                    Button::new("btn-replace-all")
+                                        .outline()
+                                        .xsmall()
+                                        .label("Replace All")
+                                        .on_click(cx.listener(Self::on_replace_all)),
+                                ),
+                        )
+                        .child(
+                            TextInput::new(&self.textarea)
+                                .h(px(320.))
+                                .line_numbers(true),
+                        )
                         .child(
                             h_flex()
                                 .justify_between()
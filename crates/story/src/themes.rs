@@ -5,9 +5,10 @@ use gpui::{
 };
 use gpui_component::{
     button::{Button, ButtonVariants},
+    notification::NotificationType,
     popup_menu::PopupMenuExt,
     scroll::ScrollbarShow,
-    ActiveTheme, IconName, Sizable, Theme, ThemeRegistry,
+    ActiveTheme, ContextModal as _, IconName, Sizable, Theme, ThemeRegistry,
 };
 use serde::{Deserialize, Serialize};
 
@@ -42,6 +43,21 @@ pub fn init(cx: &mut App) {
         cx.refresh_windows();
     }
 
+    // Hot reload the theme file for theme development, e.g.:
+    // `THEME_FILE=themes/my-theme.json cargo run`
+    if let Ok(path) = std::env::var("THEME_FILE") {
+        if let Err(err) = ThemeRegistry::watch_file(PathBuf::from(path), cx, |message, cx| {
+            for window in cx.windows() {
+                let message = message.clone();
+                _ = window.update(cx, |_, window, cx| {
+                    window.push_notification((NotificationType::Error, message), cx);
+                });
+            }
+        }) {
+            tracing::error!("Failed to watch theme file: {}", err);
+        }
+    }
+
     cx.observe_global::<Theme>(|cx| {
         let state = State {
             theme: cx.theme().theme_name().clone(),
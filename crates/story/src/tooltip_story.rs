@@ -1,6 +1,8 @@
+use std::time::Duration;
+
 use gpui::{
     actions, div, App, AppContext, Context, Entity, Focusable, InteractiveElement, KeyBinding,
-    ParentElement, Render, StatefulInteractiveElement, Styled, Window,
+    ParentElement, Render, SharedString, StatefulInteractiveElement, Styled, Window,
 };
 
 use gpui_component::{
@@ -10,8 +12,8 @@ use gpui_component::{
     h_flex,
     radio::Radio,
     switch::Switch,
-    tooltip::Tooltip,
-    v_flex, ActiveTheme, IconName,
+    tooltip::{Tooltip, TooltipExt, TooltipPlacement},
+    v_flex, ActiveTheme, IconName, Placement,
 };
 
 use crate::{section, Story};
@@ -61,8 +63,8 @@ impl Focusable for TooltipStory {
 impl Render for TooltipStory {
     fn render(
         &mut self,
-        _: &mut gpui::Window,
-        _cx: &mut gpui::Context<Self>,
+        window: &mut gpui::Window,
+        cx: &mut gpui::Context<Self>,
     ) -> impl gpui::IntoElement {
         v_flex()
             .p_4()
@@ -135,5 +137,70 @@ impl Render for TooltipStory {
                         .tooltip("This is a switch"),
                 ),
             )
+            .child(
+                section("Delay and Placement")
+                    .child(Button::new("btn-delay").label("Delay 1s").tooltip_with(
+                        "tooltip-delay",
+                        |_, _| {
+                            Tooltip::new("Shown after 1s, hidden after 500ms.")
+                                .delay(Duration::from_secs(1))
+                                .hide_delay(Duration::from_millis(500))
+                        },
+                        window,
+                        cx,
+                    ))
+                    .children(
+                        [
+                            ("Top", TooltipPlacement::Top),
+                            ("Bottom", TooltipPlacement::Bottom),
+                            ("Left", Placement::Left.into()),
+                            ("Right", Placement::Right.into()),
+                            ("Auto", TooltipPlacement::Auto),
+                        ]
+                        .into_iter()
+                        .map(|(label, placement)| {
+                            let id = SharedString::from(format!("tooltip-{}", label));
+                            div()
+                                .id(id.clone())
+                                .child(Button::new(label).label(label))
+                                .tooltip_with(
+                                    id,
+                                    move |_, _| {
+                                        Tooltip::new(format!("Placement {}", label))
+                                            .delay(Duration::from_millis(200))
+                                            .placement(placement)
+                                    },
+                                    window,
+                                    cx,
+                                )
+                        }),
+                    ),
+            )
+            .child(
+                section("Follow Cursor").child(
+                    div()
+                        .id("tooltip-follow-cursor")
+                        .flex()
+                        .items_center()
+                        .justify_center()
+                        .w_full()
+                        .h_32()
+                        .border_1()
+                        .border_color(cx.theme().border)
+                        .rounded(cx.theme().radius)
+                        .bg(cx.theme().muted)
+                        .child("Move the mouse here")
+                        .tooltip_with(
+                            "tooltip-follow-cursor",
+                            |_, _| {
+                                Tooltip::new("This tooltip follows the cursor.")
+                                    .delay(Duration::from_millis(100))
+                                    .follow_cursor(true)
+                            },
+                            window,
+                            cx,
+                        ),
+                ),
+            )
     }
 }
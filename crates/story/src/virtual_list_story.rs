@@ -10,7 +10,8 @@ use gpui_component::{
     divider::Divider,
     h_flex,
     scroll::{Scrollbar, ScrollbarAxis, ScrollbarState},
-    v_flex, v_virtual_list, ActiveTheme as _, Selectable, Sizable, VirtualListScrollHandle,
+    v_flex, v_virtual_list, ActiveTheme as _, ScrollAlign, Selectable, Sizable,
+    VirtualListScrollHandle, VirtualListState,
 };
 
 pub struct VirtualListStory {
@@ -23,6 +24,7 @@ pub struct VirtualListStory {
     axis: ScrollbarAxis,
     size_mode: usize,
     visible_range: Range<usize>,
+    saved_state: Option<VirtualListState>,
 }
 
 const ITEM_SIZE: Size<Pixels> = size(px(100.), px(30.));
@@ -42,6 +44,7 @@ impl VirtualListStory {
             axis: ScrollbarAxis::Both,
             size_mode: 0,
             visible_range: (0..0),
+            saved_state: None,
         }
     }
 
@@ -160,6 +163,7 @@ impl VirtualListStory {
             .child(
                 h_flex()
                     .gap_2()
+                    .flex_wrap()
                     .child(
                         Button::new("scroll-to0")
                             .small()
@@ -200,6 +204,51 @@ impl VirtualListStory {
                                 this.scroll_handle.scroll_to_bottom();
                                 cx.notify();
                             })),
+                    )
+                    .child(Divider::vertical().px_2())
+                    .child(
+                        Button::new("scroll-to-5000-start")
+                            .small()
+                            .outline()
+                            .label("Scroll to 5000 (start)")
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                this.scroll_handle.scroll_to(5000, ScrollAlign::Start);
+                                cx.notify();
+                            })),
+                    )
+                    .child(
+                        Button::new("scroll-to-5000-end")
+                            .small()
+                            .outline()
+                            .label("Scroll to 5000 (end)")
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                this.scroll_handle.scroll_to(5000, ScrollAlign::End);
+                                cx.notify();
+                            })),
+                    )
+                    .child(Divider::vertical().px_2())
+                    .child(
+                        Button::new("save-state")
+                            .small()
+                            .outline()
+                            .label("Save Position")
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                this.saved_state = Some(this.scroll_handle.state());
+                                cx.notify();
+                            })),
+                    )
+                    .child(
+                        Button::new("restore-state")
+                            .small()
+                            .outline()
+                            .label("Restore Position")
+                            .disabled(self.saved_state.is_none())
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                if let Some(state) = this.saved_state {
+                                    this.scroll_handle.restore_state(state);
+                                    cx.notify();
+                                }
+                            })),
                     ),
             )
     }
@@ -17,7 +17,9 @@ pub struct Accordion {
     bordered: bool,
     disabled: bool,
     children: Vec<AccordionItem>,
+    open_ixs: Option<Vec<usize>>,
     on_toggle_click: Option<Arc<dyn Fn(&[usize], &mut Window, &mut App) + Send + Sync>>,
+    on_toggle: Option<Rc<dyn Fn(&(usize, bool), &mut Window, &mut App)>>,
 }
 
 impl Accordion {
@@ -29,10 +31,13 @@ impl Accordion {
             bordered: true,
             children: Vec::new(),
             disabled: false,
+            open_ixs: None,
             on_toggle_click: None,
+            on_toggle: None,
         }
     }
 
+    /// Set true to allow multiple items to be open at the same time, default: false
     pub fn multiple(mut self, multiple: bool) -> Self {
         self.multiple = multiple;
         self
@@ -57,6 +62,23 @@ impl Accordion {
         self
     }
 
+    /// Set the indices of the open items, this overrides the `open` of the items.
+    ///
+    /// Use with [`Accordion::on_toggle`] to control the open state.
+    pub fn open(mut self, open_ixs: Vec<usize>) -> Self {
+        self.open_ixs = Some(open_ixs);
+        self
+    }
+
+    /// Sets the callback when an item is toggled, the argument is the `(index, open)` of the item.
+    pub fn on_toggle(
+        mut self,
+        on_toggle: impl Fn(&(usize, bool), &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_toggle = Some(Rc::new(on_toggle));
+        self
+    }
+
     /// Sets the on_toggle_click callback for the AccordionGroup.
     ///
     /// The first argument `Vec<usize>` is the indices of the open accordions.
@@ -90,6 +112,10 @@ impl RenderOnce for Accordion {
                     .into_iter()
                     .enumerate()
                     .map(|(ix, accordion)| {
+                        let accordion = match &self.open_ixs {
+                            Some(open_ixs) => accordion.open(open_ixs.contains(&ix)),
+                            None => accordion,
+                        };
                         if accordion.open {
                             open_ixs.borrow_mut().insert(ix);
                         }
@@ -101,15 +127,22 @@ impl RenderOnce for Accordion {
                             .disabled(self.disabled)
                             .on_toggle_click({
                                 let open_ixs = Rc::clone(&open_ixs);
-                                move |open, _, _| {
-                                    let mut open_ixs = open_ixs.borrow_mut();
-                                    if *open {
-                                        if !is_multiple {
-                                            open_ixs.clear();
+                                let on_toggle = self.on_toggle.clone();
+                                move |open, window, cx| {
+                                    {
+                                        let mut open_ixs = open_ixs.borrow_mut();
+                                        if *open {
+                                            if !is_multiple {
+                                                open_ixs.clear();
+                                            }
+                                            open_ixs.insert(ix);
+                                        } else {
+                                            open_ixs.remove(&ix);
                                         }
-                                        open_ixs.insert(ix);
-                                    } else {
-                                        open_ixs.remove(&ix);
+                                    }
+
+                                    if let Some(on_toggle) = &on_toggle {
+                                        on_toggle(&(ix, *open), window, cx);
                                     }
                                 }
                             })
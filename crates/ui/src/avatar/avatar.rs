@@ -1,7 +1,7 @@
 use gpui::{
-    div, img, prelude::FluentBuilder, App, Div, Hsla, ImageSource, InteractiveElement,
+    div, img, prelude::FluentBuilder, px, App, Div, Hsla, ImageSource, InteractiveElement,
     Interactivity, IntoElement, ParentElement as _, RenderOnce, SharedString, StyleRefinement,
-    Styled, Window,
+    Styled, StyledImage as _, Window,
 };
 
 use crate::{
@@ -9,6 +9,26 @@ use crate::{
     ActiveTheme, Colorize, Icon, IconName, Sizable, Size, StyledExt,
 };
 
+/// The presence status of the avatar user, see [`Avatar::status`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AvatarStatus {
+    Online,
+    Away,
+    Busy,
+    Offline,
+}
+
+impl AvatarStatus {
+    fn color(&self, cx: &App) -> Hsla {
+        match self {
+            Self::Online => cx.theme().success,
+            Self::Away => cx.theme().warning,
+            Self::Busy => cx.theme().danger,
+            Self::Offline => cx.theme().muted_foreground,
+        }
+    }
+}
+
 /// User avatar element.
 ///
 /// We can use [`Sizable`] trait to set the size of the avatar (see also: [`avatar_size`] about the size in pixels).
@@ -21,6 +41,7 @@ pub struct Avatar {
     short_name: SharedString,
     placeholder: Icon,
     size: Size,
+    status: Option<AvatarStatus>,
 }
 
 impl Avatar {
@@ -33,6 +54,7 @@ impl Avatar {
             short_name: SharedString::default(),
             placeholder: Icon::new(IconName::User),
             size: Size::Medium,
+            status: None,
         }
     }
 
@@ -42,7 +64,10 @@ impl Avatar {
         self
     }
 
-    /// Set name of the avatar user, if `src` is none, will use this name as placeholder.
+    /// Set name of the avatar user.
+    ///
+    /// If `src` is none or the image fails to load, up to two uppercase initials of the name
+    /// are shown on a background color derived from the name.
     pub fn name(mut self, name: impl Into<SharedString>) -> Self {
         let name: SharedString = name.into();
         let short: SharedString = extract_text_initials(&name).into();
@@ -57,6 +82,12 @@ impl Avatar {
         self.placeholder = icon.into();
         self
     }
+
+    /// Set the presence status of the user, shown as a dot at the bottom right corner.
+    pub fn status(mut self, status: AvatarStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
 }
 impl Sizable for Avatar {
     fn with_size(mut self, size: impl Into<Size>) -> Self {
@@ -97,30 +128,75 @@ impl RenderOnce for Avatar {
             .justify_center()
             .flex_shrink_0()
             .rounded_full()
-            .overflow_hidden()
+            .when(self.status.is_none(), |this| this.overflow_hidden())
+            .when(self.status.is_some(), |this| this.relative())
             .bg(cx.theme().secondary)
             .text_color(cx.theme().background)
             .border_1()
             .border_color(cx.theme().background)
-            .when(self.name.is_none() && self.src.is_none(), |this| {
-                this.text_size(avatar_size(self.size) * 0.6)
-                    .child(self.placeholder)
-            })
-            .map(|this| match self.src {
-                None => this.when(self.name.is_some(), |this| {
+            .map(|this| {
+                let initials = self.name.is_some().then(|| {
                     let color_ix = gpui::hash(&self.short_name) % COLOR_COUNT;
-                    let color = default_color(color_ix, cx);
-
-                    this.bg(color.opacity(BG_OPACITY))
-                        .text_color(color)
-                        .child(div().avatar_text_size(self.size).child(self.short_name))
-                }),
-                Some(src) => this.child(
-                    img(src)
-                        .avatar_size(self.size)
+                    (self.short_name.clone(), default_color(color_ix, cx))
+                });
+
+                match self.src {
+                    None => match initials {
+                        Some((short_name, color)) => this
+                            .bg(color.opacity(BG_OPACITY))
+                            .text_color(color)
+                            .child(div().avatar_text_size(self.size).child(short_name)),
+                        None => this
+                            .text_size(avatar_size(self.size) * 0.6)
+                            .child(self.placeholder),
+                    },
+                    Some(src) => {
+                        // Fallback to the initials or the placeholder if the image fails to load.
+                        let size = self.size;
+                        let placeholder = self.placeholder;
+
+                        this.child(
+                            img(src)
+                                .avatar_size(self.size)
+                                .rounded_full()
+                                .refine_style(&inner_style)
+                                .with_fallback(move || {
+                                    let base = div()
+                                        .size_full()
+                                        .flex()
+                                        .items_center()
+                                        .justify_center()
+                                        .rounded_full();
+
+                                    match initials.clone() {
+                                        Some((short_name, color)) => base
+                                            .bg(color.opacity(BG_OPACITY))
+                                            .text_color(color)
+                                            .child(div().avatar_text_size(size).child(short_name)),
+                                        None => base
+                                            .text_size(avatar_size(size) * 0.6)
+                                            .child(placeholder.clone()),
+                                    }
+                                    .into_any_element()
+                                }),
+                        )
+                    }
+                }
+            })
+            .when_some(self.status, |this, status| {
+                let dot_size = (avatar_size(self.size) * 0.25).max(px(8.));
+
+                this.child(
+                    div()
+                        .absolute()
+                        .right_0()
+                        .bottom_0()
+                        .size(dot_size)
                         .rounded_full()
-                        .refine_style(&inner_style),
-                ),
+                        .border_2()
+                        .border_color(cx.theme().background)
+                        .bg(status.color(cx)),
+                )
             })
             .refine_style(&self.style)
     }
@@ -150,5 +226,6 @@ mod tests {
         assert_eq!(extract_text_initials(&"Jason Lee"), "JL".to_string());
         assert_eq!(extract_text_initials(&"Foo Bar Dar"), "FB".to_string());
         assert_eq!(extract_text_initials(&"huacnlee"), "HU".to_string());
+        assert_eq!(extract_text_initials(&" jason  lee "), "JL".to_string());
     }
 }
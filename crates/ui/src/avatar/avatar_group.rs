@@ -3,7 +3,10 @@ use gpui::{
     ParentElement as _, RenderOnce, StyleRefinement, Styled,
 };
 
-use crate::{avatar::Avatar, ActiveTheme, Sizable, Size, StyledExt as _};
+use crate::{
+    avatar::{Avatar, AvatarSized as _},
+    ActiveTheme, Sizable, Size, StyledExt as _,
+};
 
 /// A grouped avatars to display in a compact layout.
 #[derive(IntoElement)]
@@ -14,6 +17,7 @@ pub struct AvatarGroup {
     size: Size,
     limit: usize,
     ellipsis: bool,
+    overflow_count: bool,
 }
 
 impl AvatarGroup {
@@ -25,6 +29,7 @@ impl AvatarGroup {
             size: Size::default(),
             limit: 3,
             ellipsis: false,
+            overflow_count: false,
         }
     }
 
@@ -51,6 +56,13 @@ impl AvatarGroup {
         self.ellipsis = true;
         self
     }
+
+    /// Show at most `max` avatars, and collapse the rest into a "+k" avatar.
+    pub fn max(mut self, max: usize) -> Self {
+        self.limit = max;
+        self.overflow_count = true;
+        self
+    }
 }
 
 impl Sizable for AvatarGroup {
@@ -81,18 +93,41 @@ impl RenderOnce for AvatarGroup {
             .h_flex()
             .flex_row_reverse()
             .refine_style(&self.style)
-            .children(if self.ellipsis && avatars_len > self.limit {
-                Some(
-                    Avatar::new()
-                        .name("⋯")
+            .when(self.overflow_count && avatars_len > self.limit, |this| {
+                this.child(
+                    div()
+                        .avatar_size(self.size)
+                        .flex()
+                        .items_center()
+                        .justify_center()
+                        .flex_shrink_0()
+                        .rounded_full()
                         .bg(cx.theme().secondary)
                         .text_color(cx.theme().muted_foreground)
-                        .with_size(self.size)
-                        .ml_1(),
+                        .border_1()
+                        .border_color(cx.theme().background)
+                        .ml(item_ml)
+                        .child(
+                            div()
+                                .avatar_text_size(self.size)
+                                .child(format!("+{}", avatars_len - self.limit)),
+                        ),
                 )
-            } else {
-                None
             })
+            .children(
+                if self.ellipsis && !self.overflow_count && avatars_len > self.limit {
+                    Some(
+                        Avatar::new()
+                            .name("⋯")
+                            .bg(cx.theme().secondary)
+                            .text_color(cx.theme().muted_foreground)
+                            .with_size(self.size)
+                            .ml_1(),
+                    )
+                } else {
+                    None
+                },
+            )
             .children(
                 self.avatars
                     .into_iter()
@@ -1,6 +1,6 @@
 use gpui::{
-    div, prelude::FluentBuilder, px, relative, AnyElement, App, Hsla, IntoElement, ParentElement,
-    RenderOnce, StyleRefinement, Styled, Window,
+    div, prelude::FluentBuilder, px, relative, AnyElement, App, Corner, Hsla, IntoElement,
+    ParentElement, RenderOnce, StyleRefinement, Styled, Window,
 };
 
 use crate::{h_flex, white, ActiveTheme, Icon, Sizable, Size, StyledExt};
@@ -36,6 +36,7 @@ pub struct Badge {
     children: Vec<AnyElement>,
     color: Option<Hsla>,
     size: Size,
+    position: Option<Corner>,
 }
 
 impl Badge {
@@ -49,6 +50,7 @@ impl Badge {
             color: None,
             children: Vec::new(),
             size: Size::default(),
+            position: None,
         }
     }
 
@@ -78,6 +80,14 @@ impl Badge {
         self
     }
 
+    /// Set the corner of the child to place the badge.
+    ///
+    /// Default is [`Corner::TopRight`] for the dot and count, [`Corner::BottomRight`] for the icon.
+    pub fn position(mut self, position: Corner) -> Self {
+        self.position = Some(position);
+        self
+    }
+
     /// Set the color (background) of the badge.
     pub fn color(mut self, color: impl Into<Hsla>) -> Self {
         self.color = Some(color.into());
@@ -125,39 +135,56 @@ impl RenderOnce for Badge {
                         .bg(self.color.unwrap_or(cx.theme().red))
                         .text_color(white())
                         .text_size(text_size)
-                        .map(|this| match self.variant {
-                            BadgeVariant::Dot => this.top_0().right_0().size(px(6.)),
-                            BadgeVariant::Number => {
-                                let count = if self.count > self.max {
-                                    format!("{}+", self.max)
-                                } else {
-                                    self.count.to_string()
-                                };
-
-                                let (top, left) = match self.size {
-                                    Size::Large => (px(2.), -px(count.len() as f32)),
-                                    Size::Medium | Size::Size(_) => {
-                                        (-px(3.), -px(3.) * count.len())
-                                    }
-                                    Size::Small | Size::XSmall => (-px(4.), -px(4.) * count.len()),
-                                };
-
-                                this.top(top)
-                                    .right(left)
-                                    .py_0p5()
-                                    .px_0p5()
-                                    .min_w_3p5()
-                                    .text_size(px(10.))
-                                    .line_height(relative(1.))
-                                    .child(count)
+                        .map(|this| {
+                            let (this, default_position, (top, right)) = match self.variant {
+                                BadgeVariant::Dot => {
+                                    (this.size(px(6.)), Corner::TopRight, (px(0.), px(0.)))
+                                }
+                                BadgeVariant::Number => {
+                                    let count = if self.count > self.max {
+                                        format!("{}+", self.max)
+                                    } else {
+                                        self.count.to_string()
+                                    };
+
+                                    let offset = match self.size {
+                                        Size::Large => (px(2.), -px(count.len() as f32)),
+                                        Size::Medium | Size::Size(_) => {
+                                            (-px(3.), -px(3.) * count.len())
+                                        }
+                                        Size::Small | Size::XSmall => {
+                                            (-px(4.), -px(4.) * count.len())
+                                        }
+                                    };
+
+                                    (
+                                        this.py_0p5()
+                                            .px_0p5()
+                                            .min_w_3p5()
+                                            .text_size(px(10.))
+                                            .line_height(relative(1.))
+                                            .child(count),
+                                        Corner::TopRight,
+                                        offset,
+                                    )
+                                }
+                                BadgeVariant::Icon(icon) => (
+                                    this.size(size)
+                                        .border_1()
+                                        .border_color(cx.theme().background)
+                                        .child(*icon),
+                                    Corner::BottomRight,
+                                    (px(0.), px(0.)),
+                                ),
+                            };
+
+                            // The offsets are for the top right corner, mirror them to others.
+                            match self.position.unwrap_or(default_position) {
+                                Corner::TopLeft => this.top(top).left(right),
+                                Corner::TopRight => this.top(top).right(right),
+                                Corner::BottomLeft => this.bottom(top).left(right),
+                                Corner::BottomRight => this.bottom(top).right(right),
                             }
-                            BadgeVariant::Icon(icon) => this
-                                .right_0()
-                                .bottom_0()
-                                .size(size)
-                                .border_1()
-                                .border_color(cx.theme().background)
-                                .child(*icon),
                         }),
                 )
             })
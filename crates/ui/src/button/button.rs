@@ -1,14 +1,17 @@
 use std::rc::Rc;
 
 use crate::{
-    h_flex, indicator::Indicator, tooltip::Tooltip, ActiveTheme, Colorize as _, Disableable, Icon,
-    Selectable, Sizable, Size, StyleSized, StyledExt,
+    h_flex,
+    indicator::Indicator,
+    tooltip::{FocusableTooltip as _, Tooltip},
+    ActiveTheme, Colorize as _, Disableable, Icon, Selectable, Sizable, Size, StyleSized,
+    StyledExt,
 };
 use gpui::{
     div, prelude::FluentBuilder as _, relative, Action, AnyElement, App, ClickEvent, Corners, Div,
-    Edges, ElementId, Hsla, InteractiveElement, Interactivity, IntoElement, MouseButton,
-    ParentElement, Pixels, RenderOnce, SharedString, Stateful, StatefulInteractiveElement as _,
-    StyleRefinement, Styled, Window,
+    Edges, ElementId, FocusHandle, Hsla, InteractiveElement, Interactivity, IntoElement,
+    MouseButton, ParentElement, Pixels, RenderOnce, SharedString, Stateful,
+    StatefulInteractiveElement as _, StyleRefinement, Styled, Window,
 };
 
 #[derive(Default, Clone, Copy)]
@@ -181,6 +184,7 @@ impl ButtonVariant {
 /// A Button element.
 #[derive(IntoElement)]
 pub struct Button {
+    id: ElementId,
     base: Stateful<Div>,
     style: StyleRefinement,
     icon: Option<Icon>,
@@ -203,6 +207,7 @@ pub struct Button {
     pub(crate) stop_propagation: bool,
     loading: bool,
     loading_icon: Option<Icon>,
+    focus_handle: Option<FocusHandle>,
 }
 
 impl From<Button> for AnyElement {
@@ -213,8 +218,10 @@ impl From<Button> for AnyElement {
 
 impl Button {
     pub fn new(id: impl Into<ElementId>) -> Self {
+        let id: ElementId = id.into();
         Self {
-            base: div().id(id.into()).flex_shrink_0(),
+            base: div().id(id.clone()).flex_shrink_0(),
+            id,
             style: StyleRefinement::default(),
             icon: None,
             label: None,
@@ -233,6 +240,7 @@ impl Button {
             outline: false,
             children: Vec::new(),
             loading_icon: None,
+            focus_handle: None,
         }
     }
 
@@ -373,10 +381,19 @@ impl InteractiveElement for Button {
     fn interactivity(&mut self) -> &mut Interactivity {
         self.base.interactivity()
     }
+
+    /// Track the focus of the button by the `focus_handle`.
+    ///
+    /// The tooltip of the button will be shown when the `focus_handle` is focused by keyboard.
+    fn track_focus(mut self, focus_handle: &FocusHandle) -> Self {
+        self.focus_handle = Some(focus_handle.clone());
+        self.base = self.base.track_focus(focus_handle);
+        self
+    }
 }
 
 impl RenderOnce for Button {
-    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
         let style: ButtonVariant = self.variant;
         let normal_style = style.normal(self.outline, cx);
         let icon_size = match self.size {
@@ -519,16 +536,22 @@ impl RenderOnce for Button {
                     .text_color(normal_style.fg.opacity(0.8))
             })
             .when_some(self.tooltip, |this, (tooltip, action)| {
-                this.tooltip(move |window, cx| {
-                    Tooltip::new(tooltip.clone())
-                        .when_some(action.clone(), |this, (action, context)| {
-                            this.action(
-                                action.boxed_clone().as_ref(),
-                                context.as_ref().map(|c| c.as_ref()),
-                            )
-                        })
-                        .build(window, cx)
-                })
+                this.focusable_tooltip(
+                    &self.id,
+                    self.focus_handle.as_ref(),
+                    move |window, cx| {
+                        Tooltip::new(tooltip.clone())
+                            .when_some(action.clone(), |this, (action, context)| {
+                                this.action(
+                                    action.boxed_clone().as_ref(),
+                                    context.as_ref().map(|c| c.as_ref()),
+                                )
+                            })
+                            .build(window, cx)
+                    },
+                    window,
+                    cx,
+                )
             })
     }
 }
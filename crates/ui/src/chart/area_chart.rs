@@ -8,7 +8,7 @@ use crate::{
     plot::{
         scale::{Scale, ScaleLinear, ScalePoint, Sealed},
         shape::Area,
-        Axis, AxisText, Grid, Plot, StrokeStyle, AXIS_GAP,
+        Axis, AxisText, Grid, Placeholder, Plot, StrokeStyle, AXIS_GAP,
     },
     ActiveTheme,
 };
@@ -21,6 +21,7 @@ where
     Y: Clone + Copy + PartialOrd + Num + ToPrimitive + Sealed + 'static,
 {
     data: Vec<T>,
+    loading: bool,
     x: Option<Rc<dyn Fn(&T) -> X>>,
     y: Vec<Rc<dyn Fn(&T) -> Y>>,
     stroke: Vec<Hsla>,
@@ -40,6 +41,7 @@ where
     {
         Self {
             data: data.into_iter().collect(),
+            loading: false,
             stroke_style: Default::default(),
             stroke: vec![],
             fill: vec![],
@@ -49,6 +51,12 @@ where
         }
     }
 
+    /// Set the loading state, a skeleton will be painted instead of the chart.
+    pub fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
     pub fn x(mut self, x: impl Fn(&T) -> X + 'static) -> Self {
         self.x = Some(Rc::new(x));
         self
@@ -86,6 +94,13 @@ where
     Y: Clone + Copy + PartialOrd + Num + ToPrimitive + Sealed + 'static,
 {
     fn paint(&mut self, bounds: Bounds<Pixels>, window: &mut Window, cx: &mut App) {
+        if self.loading || self.data.is_empty() {
+            Placeholder::new()
+                .loading(self.loading)
+                .paint(&bounds, window, cx);
+            return;
+        }
+
         let Some(x_fn) = self.x.as_ref() else {
             return;
         };
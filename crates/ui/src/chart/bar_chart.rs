@@ -1,15 +1,19 @@
 use std::rc::Rc;
 
-use gpui::{px, App, Bounds, Hsla, Pixels, SharedString, TextAlign, Window};
+use gpui::{
+    point, px, size, AnyElement, App, Bounds, FontWeight, Hsla, IntoElement, ParentElement, Pixels,
+    Point, SharedString, TextAlign, TextRun, Window,
+};
 use gpui_component_macros::IntoPlot;
 use num_traits::{Num, ToPrimitive};
 
 use crate::{
     plot::{
-        label::Text,
+        label::{Label, Text, TEXT_GAP, TEXT_HEIGHT, TEXT_SIZE},
         scale::{Scale, ScaleBand, ScaleLinear, Sealed},
         shape::Bar,
-        Axis, AxisText, Grid, Plot, AXIS_GAP,
+        tooltip::{Tooltip, TooltipItem, TooltipPosition},
+        Axis, AxisText, DataClickHandler, Grid, Placeholder, Plot, AXIS_GAP,
     },
     ActiveTheme,
 };
@@ -22,11 +26,15 @@ where
     Y: Copy + PartialOrd + Num + ToPrimitive + Sealed + 'static,
 {
     data: Vec<T>,
+    loading: bool,
     x: Option<Rc<dyn Fn(&T) -> X>>,
     y: Option<Rc<dyn Fn(&T) -> Y>>,
     fill: Option<Rc<dyn Fn(&T) -> Hsla>>,
     tick_margin: usize,
     label: Option<Rc<dyn Fn(&T) -> SharedString>>,
+    tooltip_formatter: Option<Rc<dyn Fn(&T) -> SharedString>>,
+    on_click: Option<DataClickHandler>,
+    horizontal: bool,
 }
 
 impl<T, X, Y> BarChart<T, X, Y>
@@ -40,14 +48,30 @@ where
     {
         Self {
             data: data.into_iter().collect(),
+            loading: false,
             x: None,
             y: None,
             fill: None,
             tick_margin: 1,
             label: None,
+            tooltip_formatter: None,
+            on_click: None,
+            horizontal: false,
         }
     }
 
+    /// Set the bars to grow from left to right, with the categories on the y-axis.
+    pub fn horizontal(mut self) -> Self {
+        self.horizontal = true;
+        self
+    }
+
+    /// Set the loading state, a skeleton will be painted instead of the chart.
+    pub fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
     pub fn x(mut self, x: impl Fn(&T) -> X + 'static) -> Self {
         self.x = Some(Rc::new(x));
         self
@@ -78,14 +102,94 @@ where
         self.label = Some(Rc::new(move |t| label(t).into()));
         self
     }
+
+    /// Set the text of the tooltip shown when hovering a bar,
+    /// default is the x label and the y value.
+    pub fn tooltip_formatter<S>(mut self, formatter: impl Fn(&T) -> S + 'static) -> Self
+    where
+        S: Into<SharedString> + 'static,
+    {
+        self.tooltip_formatter = Some(Rc::new(move |t| formatter(t).into()));
+        self
+    }
+
+    /// Set the handler of the click on a bar, with the index of the clicked data.
+    pub fn on_click(mut self, handler: impl Fn(usize, &mut Window, &mut App) + 'static) -> Self {
+        self.on_click = Some(Rc::new(handler));
+        self
+    }
 }
 
-impl<T, X, Y> Plot for BarChart<T, X, Y>
+/// Returns the domain of the value scale, always including zero.
+///
+/// If there are negative values, the domain is symmetric around zero,
+/// so the zero baseline stays in the middle of the chart.
+fn value_domain<Y>(values: impl Iterator<Item = Y>) -> Vec<Y>
+where
+    Y: Copy + PartialOrd + Num,
+{
+    let (min, max) = values.fold((Y::zero(), Y::zero()), |(min, max), v| {
+        (if v < min { v } else { min }, if v > max { v } else { max })
+    });
+
+    if min < Y::zero() {
+        let abs_min = Y::zero() - min;
+        let limit = if abs_min > max { abs_min } else { max };
+        vec![Y::zero() - limit, limit]
+    } else {
+        vec![Y::zero(), max]
+    }
+}
+
+impl<T, X, Y> BarChart<T, X, Y>
 where
     X: PartialEq + Into<SharedString> + 'static,
     Y: Copy + PartialOrd + Num + ToPrimitive + Sealed + 'static,
 {
-    fn paint(&mut self, bounds: Bounds<Pixels>, window: &mut Window, cx: &mut App) {
+    /// The band scale of the categories in the `range`.
+    fn band_scale(&self, x_fn: &dyn Fn(&T) -> X, range: f32) -> ScaleBand<X> {
+        ScaleBand::new(self.data.iter().map(x_fn).collect(), vec![0., range])
+            .padding_inner(0.4)
+            .padding_outer(0.2)
+    }
+
+    /// Returns the band of each bar across the chart, relative to the origin of the `bounds`.
+    fn bar_regions(&self, bounds: Bounds<Pixels>) -> Vec<(usize, Bounds<Pixels>)> {
+        let Some(x_fn) = self.x.as_ref() else {
+            return vec![];
+        };
+
+        let range = if self.horizontal {
+            bounds.size.height
+        } else {
+            bounds.size.width
+        };
+        let band = self.band_scale(x_fn.as_ref(), range.0);
+        let band_width = px(band.band_width());
+        self.data
+            .iter()
+            .enumerate()
+            .filter_map(|(ix, d)| {
+                let tick = px(band.tick(&x_fn(d))?);
+                let region = if self.horizontal {
+                    Bounds::new(point(px(0.), tick), size(bounds.size.width, band_width))
+                } else {
+                    Bounds::new(point(tick, px(0.)), size(band_width, bounds.size.height))
+                };
+                Some((ix, region))
+            })
+            .collect()
+    }
+
+    /// Returns the index of the bar whose band contains the `position`.
+    fn bar_at(&self, bounds: Bounds<Pixels>, position: Point<Pixels>) -> Option<usize> {
+        self.bar_regions(bounds)
+            .into_iter()
+            .find(|(_, region)| region.contains(&position))
+            .map(|(ix, _)| ix)
+    }
+
+    fn paint_vertical(&self, bounds: Bounds<Pixels>, window: &mut Window, cx: &mut App) {
         let (Some(x_fn), Some(y_fn)) = (self.x.as_ref(), self.y.as_ref()) else {
             return;
         };
@@ -94,20 +198,15 @@ where
         let height = bounds.size.height.0 - AXIS_GAP;
 
         // X scale
-        let x = ScaleBand::new(self.data.iter().map(|v| x_fn(v)).collect(), vec![0., width])
-            .padding_inner(0.4)
-            .padding_outer(0.2);
+        let x = self.band_scale(x_fn.as_ref(), width);
         let band_width = x.band_width();
 
         // Y scale, ensure start from 0.
         let y = ScaleLinear::new(
-            self.data
-                .iter()
-                .map(|v| y_fn(v))
-                .chain(Some(Y::zero()))
-                .collect(),
+            value_domain(self.data.iter().map(|v| y_fn(v))),
             vec![10., height],
         );
+        let zero = y.tick(&Y::zero()).unwrap_or(height);
 
         // Draw X axis
         let x_label = self.data.iter().enumerate().filter_map(|(i, d)| {
@@ -125,11 +224,19 @@ where
             }
         });
 
-        Axis::new()
+        let mut axis = Axis::new()
             .x(height)
             .x_label(x_label)
-            .stroke(cx.theme().border)
-            .paint(&bounds, window, cx);
+            .stroke(cx.theme().border);
+        if zero != height {
+            // The axis crosses at zero, keep the labels at the bottom.
+            axis = axis.hide_x_axis();
+            Axis::new()
+                .x(zero)
+                .stroke(cx.theme().border)
+                .paint(&bounds, window, cx);
+        }
+        axis.paint(&bounds, window, cx);
 
         // Draw grid
         Grid::new()
@@ -148,7 +255,7 @@ where
             .data(&self.data)
             .band_width(band_width)
             .x(move |d| x.tick(&x_fn(d)))
-            .y0(height)
+            .y0(zero)
             .y1(move |d| y.tick(&y_fn(d)))
             .fill(move |d| fill.as_ref().map(|f| f(d)).unwrap_or(default_fill));
 
@@ -160,4 +267,239 @@ where
 
         bar.paint(&bounds, window, cx);
     }
+
+    fn paint_horizontal(&self, bounds: Bounds<Pixels>, window: &mut Window, cx: &mut App) {
+        let (Some(x_fn), Some(y_fn)) = (self.x.as_ref(), self.y.as_ref()) else {
+            return;
+        };
+
+        let width = bounds.size.width.0;
+        let height = bounds.size.height.0;
+
+        // Measure the category labels to leave space on the left.
+        let font = window.text_style().highlight(FontWeight::NORMAL).font();
+        let label_width = self
+            .data
+            .iter()
+            .filter_map(|d| {
+                let text: SharedString = x_fn(d).into();
+                let run = TextRun {
+                    len: text.len(),
+                    font: font.clone(),
+                    color: cx.theme().muted_foreground,
+                    background_color: None,
+                    underline: None,
+                    strikethrough: None,
+                };
+                window
+                    .text_system()
+                    .shape_text(text, px(TEXT_SIZE), &[run], None, None)
+                    .ok()
+                    .and_then(|lines| {
+                        lines
+                            .iter()
+                            .map(|line| line.size(px(TEXT_SIZE)).width.0)
+                            .reduce(f32::max)
+                    })
+            })
+            .fold(0., f32::max);
+        let left = label_width + TEXT_GAP * 3.;
+        let right = width - 10.;
+
+        // Y scale for the categories.
+        let y = self.band_scale(x_fn.as_ref(), height);
+        let band_width = y.band_width();
+
+        // X scale for the values, the linear scale is from bottom to top, so flip it.
+        let x = ScaleLinear::new(
+            value_domain(self.data.iter().map(|v| y_fn(v))),
+            vec![left, right],
+        );
+        let x_tick = move |v: &Y| x.tick(v).map(|tick| left + right - tick);
+        let zero = x_tick(&Y::zero()).unwrap_or(left);
+
+        // Draw Y axis at zero
+        Axis::new()
+            .y(zero)
+            .stroke(cx.theme().border)
+            .paint(&bounds, window, cx);
+
+        // Draw the category labels
+        Label::new(
+            self.data
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| (i + 1) % self.tick_margin == 0)
+                .filter_map(|(_, d)| {
+                    y.tick(&x_fn(d)).map(|y_tick| {
+                        Text::new(
+                            x_fn(d),
+                            point(
+                                px(label_width),
+                                px(y_tick + band_width / 2. - TEXT_HEIGHT / 2.),
+                            ),
+                            cx.theme().muted_foreground,
+                        )
+                        .align(TextAlign::Right)
+                    })
+                })
+                .collect(),
+        )
+        .paint(&bounds, window, cx);
+
+        // Draw grid
+        Grid::new()
+            .x((1..=4)
+                .map(|i| left + (right - left) * i as f32 / 4.0)
+                .collect())
+            .stroke(cx.theme().border)
+            .dash_array(&[px(4.), px(2.)])
+            .paint(&bounds, window);
+
+        // Draw bars
+        let x_fn = x_fn.clone();
+        let y_fn = y_fn.clone();
+        let default_fill = cx.theme().chart_2;
+        let fill = self.fill.clone();
+        let label_color = cx.theme().foreground;
+        let mut bar = Bar::new()
+            .data(&self.data)
+            .horizontal(true)
+            .band_width(band_width)
+            .x(move |d| y.tick(&x_fn(d)))
+            .y0(zero)
+            .y1({
+                let y_fn = y_fn.clone();
+                move |d| x_tick(&y_fn(d))
+            })
+            .fill(move |d| fill.as_ref().map(|f| f(d)).unwrap_or(default_fill));
+
+        if let Some(label) = self.label.as_ref() {
+            let label = label.clone();
+            bar = bar.label(move |d, p| {
+                let align = if y_fn(d) < Y::zero() {
+                    TextAlign::Right
+                } else {
+                    TextAlign::Left
+                };
+                Text::new(label(d), p, label_color).align(align)
+            });
+        }
+
+        bar.paint(&bounds, window, cx);
+    }
+}
+
+impl<T, X, Y> Plot for BarChart<T, X, Y>
+where
+    X: PartialEq + Into<SharedString> + 'static,
+    Y: Copy + PartialOrd + Num + ToPrimitive + Sealed + 'static,
+{
+    fn paint(&mut self, bounds: Bounds<Pixels>, window: &mut Window, cx: &mut App) {
+        if self.loading || self.data.is_empty() {
+            Placeholder::new()
+                .loading(self.loading)
+                .paint(&bounds, window, cx);
+            return;
+        }
+
+        if self.horizontal {
+            self.paint_horizontal(bounds, window, cx);
+        } else {
+            self.paint_vertical(bounds, window, cx);
+        }
+    }
+
+    fn has_tooltip(&self) -> bool {
+        true
+    }
+
+    fn tooltip(
+        &self,
+        bounds: Bounds<Pixels>,
+        position: Point<Pixels>,
+        _: &mut Window,
+        cx: &mut App,
+    ) -> Option<AnyElement> {
+        if self.loading {
+            return None;
+        }
+
+        let (Some(x_fn), Some(y_fn)) = (self.x.as_ref(), self.y.as_ref()) else {
+            return None;
+        };
+        let d = &self.data[self.bar_at(bounds, position)?];
+
+        let (tooltip_position, gap) = TooltipPosition::for_point(position.x.0, bounds.size.width.0);
+        let tooltip = Tooltip::new()
+            .position(tooltip_position)
+            .gap(gap)
+            .top(position.y);
+        let tooltip = if let Some(formatter) = self.tooltip_formatter.as_ref() {
+            tooltip.child(formatter(d))
+        } else {
+            let fill = self
+                .fill
+                .as_ref()
+                .map(|f| f(d))
+                .unwrap_or(cx.theme().chart_2);
+            let value = y_fn(d).to_f64().unwrap_or_default().to_string();
+            tooltip.child(TooltipItem::new(x_fn(d), value, fill))
+        };
+
+        Some(tooltip.into_any_element())
+    }
+
+    fn click_regions(
+        &self,
+        bounds: Bounds<Pixels>,
+    ) -> Option<(DataClickHandler, Vec<(usize, Bounds<Pixels>)>)> {
+        if self.loading {
+            return None;
+        }
+
+        let handler = self.on_click.clone()?;
+        Some((handler, self.bar_regions(bounds)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::{point, px, size, Bounds};
+
+    use super::{value_domain, BarChart};
+    use crate::plot::scale::Scale;
+
+    #[test]
+    fn test_value_domain() {
+        assert_eq!(value_domain(vec![3., 5., 1.].into_iter()), vec![0., 5.]);
+        assert_eq!(value_domain(vec![3., -8., 1.].into_iter()), vec![-8., 8.]);
+        assert_eq!(value_domain(vec![-2., 6.].into_iter()), vec![-6., 6.]);
+        assert_eq!(value_domain(Vec::<f64>::new().into_iter()), vec![0., 0.]);
+    }
+
+    #[test]
+    fn test_bar_at() {
+        let bounds = Bounds::new(point(px(0.), px(0.)), size(px(100.), px(100.)));
+        let chart = BarChart::new(vec![("a", 1.), ("b", 2.)])
+            .x(|d| d.0)
+            .y(|d| d.1);
+        let (band_width, ticks) = {
+            let band = chart.band_scale(&|d: &(&str, f64)| d.0, 100.);
+            (band.band_width(), [band.tick(&"a"), band.tick(&"b")])
+        };
+        let a = ticks[0].unwrap();
+        let b = ticks[1].unwrap();
+
+        assert_eq!(chart.bar_at(bounds, point(px(a + 1.), px(50.))), Some(0));
+        assert_eq!(
+            chart.bar_at(bounds, point(px(b + band_width - 1.), px(50.))),
+            Some(1)
+        );
+        assert_eq!(chart.bar_at(bounds, point(px(a - 1.), px(50.))), None);
+
+        let chart = chart.horizontal();
+        assert_eq!(chart.bar_at(bounds, point(px(50.), px(b + 1.))), Some(1));
+        assert_eq!(chart.bar_at(bounds, point(px(b + 1.), px(a - 1.))), None);
+    }
 }
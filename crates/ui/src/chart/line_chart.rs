@@ -1,14 +1,19 @@
 use std::rc::Rc;
 
-use gpui::{px, App, Bounds, Hsla, Pixels, SharedString, TextAlign, Window};
+use gpui::{
+    point, px, size, AnyElement, App, Bounds, Hsla, IntoElement, ParentElement, Pixels, Point,
+    SharedString, TextAlign, Window,
+};
 use gpui_component_macros::IntoPlot;
 use num_traits::{Num, ToPrimitive};
 
 use crate::{
     plot::{
-        scale::{Scale, ScaleLinear, ScalePoint, Sealed},
+        label::TEXT_HEIGHT,
+        scale::{Scale, ScaleLinear, ScaleLog, ScalePoint, ScaleType, Sealed},
         shape::Line,
-        Axis, AxisText, Grid, Plot, StrokeStyle, AXIS_GAP,
+        tooltip::{nearest_index, CrossLine, Dot, Tooltip, TooltipItem, TooltipPosition},
+        Axis, AxisText, DataClickHandler, Grid, Placeholder, Plot, StrokeStyle, AXIS_GAP,
     },
     ActiveTheme,
 };
@@ -21,12 +26,16 @@ where
     Y: Copy + PartialOrd + Num + ToPrimitive + Sealed + 'static,
 {
     data: Vec<T>,
+    loading: bool,
     x: Option<Rc<dyn Fn(&T) -> X>>,
     y: Option<Rc<dyn Fn(&T) -> Y>>,
     stroke: Option<Hsla>,
     stroke_style: StrokeStyle,
     dot: bool,
     tick_margin: usize,
+    y_scale: ScaleType,
+    tooltip_formatter: Option<Rc<dyn Fn(&T) -> SharedString>>,
+    on_point_click: Option<DataClickHandler>,
 }
 
 impl<T, X, Y> LineChart<T, X, Y>
@@ -40,15 +49,25 @@ where
     {
         Self {
             data: data.into_iter().collect(),
+            loading: false,
             stroke: None,
             stroke_style: Default::default(),
             dot: false,
             x: None,
             y: None,
             tick_margin: 1,
+            y_scale: ScaleType::default(),
+            tooltip_formatter: None,
+            on_point_click: None,
         }
     }
 
+    /// Set the loading state, a skeleton will be painted instead of the chart.
+    pub fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
     pub fn x(mut self, x: impl Fn(&T) -> X + 'static) -> Self {
         self.x = Some(Rc::new(x));
         self
@@ -73,6 +92,92 @@ where
         self.tick_margin = tick_margin;
         self
     }
+
+    /// Set the type of the y scale, default: [`ScaleType::Linear`]
+    ///
+    /// With [`ScaleType::Log`], the ticks are placed at the powers of the base,
+    /// and the values <= 0 are skipped.
+    pub fn y_scale(mut self, y_scale: ScaleType) -> Self {
+        self.y_scale = y_scale;
+        self
+    }
+
+    /// Set the text of the tooltip shown when hovering the line,
+    /// default is the x label and the y value of the nearest point.
+    pub fn tooltip_formatter<S>(mut self, formatter: impl Fn(&T) -> S + 'static) -> Self
+    where
+        S: Into<SharedString> + 'static,
+    {
+        self.tooltip_formatter = Some(Rc::new(move |t| formatter(t).into()));
+        self
+    }
+
+    /// Set the handler of the click on a point, with the index of the clicked data.
+    ///
+    /// The nearest point by the x distance is clicked, like the tooltip.
+    pub fn on_point_click(
+        mut self,
+        handler: impl Fn(usize, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_point_click = Some(Rc::new(handler));
+        self
+    }
+
+    fn log_scale(&self, base: f64, height: f32) -> Option<ScaleLog<Y>> {
+        let y_fn = self.y.as_ref()?;
+        Some(
+            ScaleLog::new(
+                self.data.iter().map(|v| y_fn(v)).collect(),
+                vec![10., height],
+            )
+            .base(base),
+        )
+    }
+
+    fn scales(&self, bounds: &Bounds<Pixels>) -> Option<(ScalePoint<X>, Rc<dyn Scale<Y>>)> {
+        let (Some(x_fn), Some(y_fn)) = (self.x.as_ref(), self.y.as_ref()) else {
+            return None;
+        };
+
+        let width = bounds.size.width.0;
+        let height = bounds.size.height.0 - AXIS_GAP;
+
+        // X scale
+        let x = ScalePoint::new(self.data.iter().map(|v| x_fn(v)).collect(), vec![0., width]);
+
+        // Y scale, ensure start from 0 for the linear scale.
+        let y: Rc<dyn Scale<Y>> = match self.y_scale {
+            ScaleType::Linear => Rc::new(ScaleLinear::new(
+                self.data
+                    .iter()
+                    .map(|v| y_fn(v))
+                    .chain(Some(Y::zero()))
+                    .collect(),
+                vec![10., height],
+            )),
+            ScaleType::Log(base) => Rc::new(self.log_scale(base, height)?),
+        };
+
+        Some((x, y))
+    }
+}
+
+/// Returns the columns of the points at `xs` in the `bounds` size,
+/// split at the middle between the adjacent points, so the nearest point is hit.
+fn point_regions(xs: &[f32], bounds_size: gpui::Size<Pixels>) -> Vec<Bounds<Pixels>> {
+    xs.iter()
+        .enumerate()
+        .map(|(ix, x)| {
+            let left = if ix == 0 { 0. } else { (xs[ix - 1] + x) / 2. };
+            let right = xs
+                .get(ix + 1)
+                .map_or(bounds_size.width.0, |next| (x + next) / 2.);
+            Bounds::new(
+                point(px(left), px(0.)),
+                size(px(right - left), bounds_size.height),
+            )
+        })
+        .collect()
 }
 
 impl<T, X, Y> Plot for LineChart<T, X, Y>
@@ -81,26 +186,22 @@ where
     Y: Copy + PartialOrd + Num + ToPrimitive + Sealed + 'static,
 {
     fn paint(&mut self, bounds: Bounds<Pixels>, window: &mut Window, cx: &mut App) {
+        if self.loading || self.data.is_empty() {
+            Placeholder::new()
+                .loading(self.loading)
+                .paint(&bounds, window, cx);
+            return;
+        }
+
         let (Some(x_fn), Some(y_fn)) = (self.x.as_ref(), self.y.as_ref()) else {
             return;
         };
+        let Some((x, y)) = self.scales(&bounds) else {
+            return;
+        };
 
-        let width = bounds.size.width.0;
         let height = bounds.size.height.0 - AXIS_GAP;
 
-        // X scale
-        let x = ScalePoint::new(self.data.iter().map(|v| x_fn(v)).collect(), vec![0., width]);
-
-        // Y scale, ensure start from 0.
-        let y = ScaleLinear::new(
-            self.data
-                .iter()
-                .map(|v| y_fn(v))
-                .chain(Some(Y::zero()))
-                .collect(),
-            vec![10., height],
-        );
-
         // Draw X axis
         let data_len = self.data.len();
         let x_label = self.data.iter().enumerate().filter_map(|(i, d)| {
@@ -130,9 +231,34 @@ where
             .stroke(cx.theme().border)
             .paint(&bounds, window, cx);
 
-        // Draw grid
+        // Draw grid, at the powers of the base for the log scale.
+        let grid_y: Vec<f32> = match self.y_scale {
+            ScaleType::Linear => (0..=3).map(|i| height * i as f32 / 4.0).collect(),
+            ScaleType::Log(base) => {
+                let Some(log) = self.log_scale(base, height) else {
+                    return;
+                };
+                let major_ticks = log.major_ticks();
+
+                Axis::new()
+                    .y(px(0.))
+                    .y_ticks(major_ticks.iter().map(|(_, tick)| px(*tick)))
+                    .y_minor_ticks(log.minor_ticks().into_iter().map(px))
+                    .y_label(major_ticks.iter().map(|(value, tick)| {
+                        AxisText::new(
+                            value.to_string(),
+                            px((tick - TEXT_HEIGHT).max(0.)),
+                            cx.theme().muted_foreground,
+                        )
+                    }))
+                    .stroke(cx.theme().border)
+                    .paint(&bounds, window, cx);
+
+                major_ticks.into_iter().map(|(_, tick)| tick).collect()
+            }
+        };
         Grid::new()
-            .y((0..=3).map(|i| height * i as f32 / 4.0).collect())
+            .y(grid_y)
             .stroke(cx.theme().border)
             .dash_array(&[px(4.), px(2.)])
             .paint(&bounds, window);
@@ -155,4 +281,104 @@ where
 
         line.paint(&bounds, window);
     }
+
+    fn has_tooltip(&self) -> bool {
+        true
+    }
+
+    fn tooltip(
+        &self,
+        bounds: Bounds<Pixels>,
+        position: Point<Pixels>,
+        _: &mut Window,
+        cx: &mut App,
+    ) -> Option<AnyElement> {
+        if self.loading {
+            return None;
+        }
+
+        let (Some(x_fn), Some(y_fn)) = (self.x.as_ref(), self.y.as_ref()) else {
+            return None;
+        };
+        let (x, y) = self.scales(&bounds)?;
+
+        // Find the nearest point by the x distance.
+        let points = self
+            .data
+            .iter()
+            .enumerate()
+            .filter_map(|(ix, d)| Some((ix, x.tick(&x_fn(d))?, y.tick(&y_fn(d))?)))
+            .collect::<Vec<_>>();
+        let xs = points.iter().map(|(_, x, _)| *x).collect::<Vec<_>>();
+        let (ix, x_tick, y_tick) = points[nearest_index(&xs, position.x.0)?];
+        let d = &self.data[ix];
+
+        let stroke = self.stroke.unwrap_or(cx.theme().chart_2);
+        let point = point(px(x_tick), px(y_tick));
+        let (tooltip_position, gap) = TooltipPosition::for_point(x_tick, bounds.size.width.0);
+        let tooltip = Tooltip::new()
+            .position(tooltip_position)
+            .gap(gap)
+            .cross_line(CrossLine::new(point).height(bounds.size.height.0 - AXIS_GAP))
+            .dots([Dot::new(point)
+                .size(px(8.))
+                .stroke(stroke)
+                .fill(cx.theme().background)]);
+        let tooltip = if let Some(formatter) = self.tooltip_formatter.as_ref() {
+            tooltip.child(formatter(d))
+        } else {
+            let value = y_fn(d).to_f64().unwrap_or_default().to_string();
+            tooltip.child(TooltipItem::new(x_fn(d), value, stroke))
+        };
+
+        Some(tooltip.into_any_element())
+    }
+
+    fn click_regions(
+        &self,
+        bounds: Bounds<Pixels>,
+    ) -> Option<(DataClickHandler, Vec<(usize, Bounds<Pixels>)>)> {
+        if self.loading {
+            return None;
+        }
+
+        let handler = self.on_point_click.clone()?;
+        let x_fn = self.x.as_ref()?;
+        let (x, _) = self.scales(&bounds)?;
+        let points = self
+            .data
+            .iter()
+            .enumerate()
+            .filter_map(|(ix, d)| Some((ix, x.tick(&x_fn(d))?)))
+            .collect::<Vec<_>>();
+        let xs = points.iter().map(|(_, x)| *x).collect::<Vec<_>>();
+        let regions = points
+            .iter()
+            .map(|(ix, _)| *ix)
+            .zip(point_regions(&xs, bounds.size))
+            .collect();
+
+        Some((handler, regions))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::{point, px, size, Bounds};
+
+    use super::point_regions;
+
+    #[test]
+    fn test_point_regions() {
+        let bounds_size = size(px(100.), px(50.));
+        assert_eq!(point_regions(&[], bounds_size), vec![]);
+        assert_eq!(
+            point_regions(&[0., 40., 100.], bounds_size),
+            vec![
+                Bounds::new(point(px(0.), px(0.)), size(px(20.), px(50.))),
+                Bounds::new(point(px(20.), px(0.)), size(px(50.), px(50.))),
+                Bounds::new(point(px(70.), px(0.)), size(px(30.), px(50.))),
+            ]
+        );
+    }
 }
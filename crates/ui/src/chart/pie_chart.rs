@@ -1,25 +1,34 @@
 use std::rc::Rc;
 
-use gpui::{App, Bounds, Hsla, Pixels, Window};
-use gpui_component_macros::IntoPlot;
+use gpui::{
+    point, AlignItems, AnyElement, App, Bounds, CursorStyle, Display, Element, ElementId,
+    GlobalElementId, Hitbox, HitboxBehavior, Hsla, InspectorElementId, IntoElement, JustifyContent,
+    LayoutId, MouseButton, MouseDownEvent, MouseMoveEvent, Pixels, Point, Style, Window,
+};
 use num_traits::Zero;
 
 use crate::{
     plot::{
-        shape::{Arc, Pie},
-        Plot,
+        shape::{Arc, ArcData, Pie},
+        DataClickHandler, Placeholder, Plot,
     },
     ActiveTheme,
 };
 
-#[derive(IntoPlot)]
+/// The outer radius of the hovered slice is increased by this.
+const HOVER_OFFSET: f32 = 6.;
+
 pub struct PieChart<T: 'static> {
     data: Vec<T>,
+    loading: bool,
     inner_radius: f32,
     outer_radius: f32,
     pad_angle: f32,
     value: Option<Rc<dyn Fn(&T) -> f32>>,
     color: Option<Rc<dyn Fn(&T) -> Hsla>>,
+    center_content: Option<AnyElement>,
+    hovered_index: Option<usize>,
+    on_click: Option<DataClickHandler>,
 }
 
 impl<T> PieChart<T> {
@@ -29,19 +38,33 @@ impl<T> PieChart<T> {
     {
         Self {
             data: data.into_iter().collect(),
+            loading: false,
             inner_radius: 0.,
             outer_radius: 0.,
             pad_angle: 0.,
             value: None,
             color: None,
+            center_content: None,
+            hovered_index: None,
+            on_click: None,
         }
     }
 
+    /// Set the loading state, a skeleton will be painted instead of the chart.
+    pub fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
+    /// Set the inner radius as a fraction (0.0 - 1.0) of the outer radius, default: 0.0
+    ///
+    /// If greater than 0, a hole is cut in the center to draw a donut chart.
     pub fn inner_radius(mut self, inner_radius: f32) -> Self {
-        self.inner_radius = inner_radius;
+        self.inner_radius = inner_radius.clamp(0., 1.);
         self
     }
 
+    /// Set the outer radius in pixels, default is 40% of the chart height.
     pub fn outer_radius(mut self, outer_radius: f32) -> Self {
         self.outer_radius = outer_radius;
         self
@@ -64,39 +87,227 @@ impl<T> PieChart<T> {
         self.color = Some(Rc::new(move |t| color(t).into()));
         self
     }
+
+    /// Set the content in the center of the chart, e.g. the total of a donut chart.
+    pub fn center_content(mut self, content: impl IntoElement) -> Self {
+        self.center_content = Some(content.into_any_element());
+        self
+    }
+
+    /// Set the handler of the click on a slice, with the index of the clicked data.
+    pub fn on_click(mut self, handler: impl Fn(usize, &mut Window, &mut App) + 'static) -> Self {
+        self.on_click = Some(Rc::new(handler));
+        self
+    }
+
+    fn outer_radius_for(&self, bounds: &Bounds<Pixels>) -> f32 {
+        if self.outer_radius.is_zero() {
+            bounds.size.height.0 * 0.4
+        } else {
+            self.outer_radius
+        }
+    }
+
+    fn arcs(&self) -> Vec<ArcData<'_, T>> {
+        let Some(value_fn) = self.value.clone() else {
+            return vec![];
+        };
+
+        Pie::<T>::new()
+            .value(move |d| Some(value_fn(d)))
+            .pad_angle(self.pad_angle)
+            .arcs(&self.data)
+    }
+}
+
+/// Returns the index of the slice at the `position`.
+fn slice_at(
+    arc: &Arc,
+    angles: &[(usize, f32, f32)],
+    center: Point<Pixels>,
+    position: Point<Pixels>,
+) -> Option<usize> {
+    let point = point((position.x - center.x).0, (position.y - center.y).0);
+    angles.iter().find_map(|(index, start_angle, end_angle)| {
+        let arc_data = ArcData {
+            data: &(),
+            index: *index,
+            value: 0.,
+            start_angle: *start_angle,
+            end_angle: *end_angle,
+            pad_angle: 0.,
+        };
+        arc.contains(&arc_data, point).then_some(*index)
+    })
 }
 
 impl<T> Plot for PieChart<T> {
     fn paint(&mut self, bounds: Bounds<Pixels>, window: &mut Window, cx: &mut App) {
-        let Some(value_fn) = self.value.as_ref() else {
+        if self.loading || self.data.is_empty() {
+            Placeholder::new()
+                .loading(self.loading)
+                .paint(&bounds, window, cx);
             return;
-        };
+        }
 
-        let outer_radius = if self.outer_radius.is_zero() {
-            bounds.size.height.0 * 0.4
-        } else {
-            self.outer_radius
+        let outer_radius = self.outer_radius_for(&bounds);
+        let inner_radius = outer_radius * self.inner_radius;
+        let arc = Arc::new()
+            .inner_radius(inner_radius)
+            .outer_radius(outer_radius);
+        let hovered_arc = Arc::new()
+            .inner_radius(inner_radius)
+            .outer_radius(outer_radius + HOVER_OFFSET);
+
+        for a in &self.arcs() {
+            let color = if let Some(color_fn) = self.color.as_ref() {
+                color_fn(a.data)
+            } else {
+                cx.theme().chart_2
+            };
+
+            if self.hovered_index == Some(a.index) {
+                hovered_arc.paint(a, color, &bounds, window);
+            } else {
+                arc.paint(a, color, &bounds, window);
+            }
+        }
+    }
+}
+
+impl<T> IntoElement for PieChart<T> {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl<T> Element for PieChart<T> {
+    type RequestLayoutState = ();
+    type PrepaintState = Hitbox;
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn source_location(&self) -> Option<&'static std::panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _: Option<&GlobalElementId>,
+        _: Option<&InspectorElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        // Center the content by the flex layout.
+        let style = Style {
+            size: gpui::Size::full(),
+            display: Display::Flex,
+            align_items: Some(AlignItems::Center),
+            justify_content: Some(JustifyContent::Center),
+            ..Default::default()
         };
 
+        let children = self
+            .center_content
+            .as_mut()
+            .map(|content| content.request_layout(window, cx));
+
+        (window.request_layout(style, children, cx), ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _: Option<&GlobalElementId>,
+        _: Option<&InspectorElementId>,
+        bounds: Bounds<Pixels>,
+        _: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Self::PrepaintState {
+        if let Some(content) = self.center_content.as_mut() {
+            content.prepaint(window, cx);
+        }
+
+        window.insert_hitbox(bounds, HitboxBehavior::Normal)
+    }
+
+    fn paint(
+        &mut self,
+        _: Option<&GlobalElementId>,
+        _: Option<&InspectorElementId>,
+        bounds: Bounds<Pixels>,
+        _: &mut Self::RequestLayoutState,
+        hitbox: &mut Self::PrepaintState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        let outer_radius = self.outer_radius_for(&bounds);
         let arc = Arc::new()
-            .inner_radius(self.inner_radius)
+            .inner_radius(outer_radius * self.inner_radius)
             .outer_radius(outer_radius);
-        let value_fn = value_fn.clone();
-        let mut pie = Pie::<T>::new().value(move |d| Some(value_fn(d)));
-        pie = pie.pad_angle(self.pad_angle);
-        let arcs = pie.arcs(&self.data);
-
-        for a in &arcs {
-            arc.paint(
-                a,
-                if let Some(color_fn) = self.color.as_ref() {
-                    color_fn(a.data)
-                } else {
-                    cx.theme().chart_2
-                },
-                &bounds,
-                window,
-            );
+        let angles = if self.loading {
+            vec![]
+        } else {
+            self.arcs()
+                .iter()
+                .map(|a| (a.index, a.start_angle, a.end_angle))
+                .collect::<Vec<_>>()
+        };
+        let center = bounds.center();
+
+        self.hovered_index = if hitbox.is_hovered(window) {
+            slice_at(&arc, &angles, center, window.mouse_position())
+        } else {
+            None
+        };
+        <Self as Plot>::paint(self, bounds, window, cx);
+
+        if let Some(content) = self.center_content.as_mut() {
+            content.paint(window, cx);
+        }
+
+        if let Some(on_click) = self.on_click.clone() {
+            if self.hovered_index.is_some() {
+                window.set_cursor_style(CursorStyle::PointingHand, hitbox);
+            }
+
+            let angles = angles.clone();
+            let hitbox = hitbox.clone();
+            window.on_mouse_event(move |event: &MouseDownEvent, phase, window, cx| {
+                if !phase.bubble()
+                    || event.button != MouseButton::Left
+                    || !hitbox.is_hovered(window)
+                {
+                    return;
+                }
+
+                if let Some(ix) = slice_at(&arc, &angles, center, event.position) {
+                    on_click(ix, window, cx);
+                }
+            });
         }
+
+        // Repaint when the hovered slice changes.
+        let current_view = window.current_view();
+        let hovered_index = self.hovered_index;
+        let hitbox = hitbox.clone();
+        window.on_mouse_event(move |event: &MouseMoveEvent, phase, window, cx| {
+            if !phase.bubble() {
+                return;
+            }
+
+            let updated = if hitbox.is_hovered(window) {
+                slice_at(&arc, &angles, center, event.position)
+            } else {
+                None
+            };
+            if updated != hovered_index {
+                cx.notify(current_view);
+            }
+        });
     }
 }
@@ -1,10 +1,14 @@
+use std::rc::Rc;
+
 use gpui::{
-    anchored, canvas, deferred, div, prelude::FluentBuilder as _, px, relative, App, AppContext,
-    Bounds, Context, Corner, ElementId, Entity, EventEmitter, FocusHandle, Focusable, Hsla,
-    InteractiveElement as _, IntoElement, KeyBinding, MouseButton, ParentElement, Pixels, Point,
-    Render, RenderOnce, SharedString, StatefulInteractiveElement as _, StyleRefinement, Styled,
-    Subscription, Window,
+    anchored, black, canvas, deferred, div, fill, linear_color_stop, linear_gradient, point,
+    prelude::FluentBuilder as _, px, relative, white, App, AppContext, Axis, Bounds, Context,
+    Corner, DragMoveEvent, ElementId, Empty, Entity, EntityId, EventEmitter, FocusHandle,
+    Focusable, Hsla, InteractiveElement as _, IntoElement, KeyBinding, MouseButton, MouseDownEvent,
+    ParentElement, Pixels, Point, Render, RenderOnce, SharedString, Size,
+    StatefulInteractiveElement as _, StyleRefinement, Styled, Subscription, Window,
 };
+use rust_i18n::t;
 
 use crate::{
     actions::Cancel,
@@ -12,9 +16,10 @@ use crate::{
     divider::Divider,
     h_flex,
     input::{InputEvent, InputState, TextInput},
+    slider::{Slider, SliderEvent, SliderState},
     tooltip::Tooltip,
-    v_flex, ActiveTheme as _, Colorize as _, Icon, Selectable as _, Sizable, Size, StyleSized,
-    StyledExt,
+    v_flex, ActiveTheme as _, Colorize as _, Disableable as _, FillColor, Icon, IconName,
+    Selectable as _, Sizable, Size, StyleSized, StyledExt,
 };
 
 const CONTEXT: &'static str = "ColorPicker";
@@ -26,6 +31,297 @@ pub fn init(cx: &mut App) {
 #[derive(Clone)]
 pub enum ColorPickerEvent {
     Change(Option<Hsla>),
+    /// The gradient changed in [`ColorPickerMode::Gradient`].
+    ChangeGradient(Gradient),
+}
+
+/// The mode of the [`ColorPicker`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ColorPickerMode {
+    /// Pick a single color.
+    #[default]
+    Color,
+    /// Edit a linear gradient with multiple color stops.
+    Gradient,
+}
+
+/// A linear gradient value edited by the [`ColorPicker`] in [`ColorPickerMode::Gradient`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gradient {
+    /// The angle of the gradient in degrees, `0` is to top, `90` is to right.
+    pub angle: f32,
+    /// The color stops of the gradient, each stop is `(position, color)`,
+    /// sorted by the position from 0.0 to 1.0.
+    pub stops: Vec<(f32, Hsla)>,
+}
+
+impl Default for Gradient {
+    fn default() -> Self {
+        Self::new(90., [(0., black()), (1., white())])
+    }
+}
+
+impl From<Gradient> for FillColor {
+    fn from(gradient: Gradient) -> Self {
+        FillColor::Gradient(gradient.stops)
+    }
+}
+
+impl Gradient {
+    /// The minimum number of stops of a gradient.
+    const MIN_STOPS: usize = 2;
+
+    /// Create a new gradient with the angle in degrees and the color stops.
+    pub fn new(angle: f32, stops: impl IntoIterator<Item = (f32, Hsla)>) -> Self {
+        let mut stops: Vec<_> = stops
+            .into_iter()
+            .map(|(position, color)| (position.clamp(0., 1.), color))
+            .collect();
+        stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        Self {
+            angle: angle.rem_euclid(360.),
+            stops,
+        }
+    }
+
+    /// Returns the color at the given position (0.0 to 1.0) of the gradient.
+    pub fn color_at(&self, position: f32) -> Hsla {
+        FillColor::Gradient(self.stops.clone()).color_at(position)
+    }
+
+    /// Add a stop at the given position with the color at that position,
+    /// returns the index of the new stop.
+    pub fn add_stop(&mut self, position: f32) -> usize {
+        let position = position.clamp(0., 1.);
+        let color = self.color_at(position);
+        let ix = self.stops.partition_point(|(p, _)| *p <= position);
+        self.stops.insert(ix, (position, color));
+        ix
+    }
+
+    /// Remove the stop at the given index, returns `false` if the gradient must keep the stop.
+    pub fn remove_stop(&mut self, ix: usize) -> bool {
+        if ix >= self.stops.len() || self.stops.len() <= Self::MIN_STOPS {
+            return false;
+        }
+
+        self.stops.remove(ix);
+        true
+    }
+
+    /// Move the stop at the given index to the new position,
+    /// returns the new index of the stop after sorting.
+    pub fn move_stop(&mut self, ix: usize, position: f32) -> usize {
+        if ix >= self.stops.len() {
+            return ix;
+        }
+
+        let (_, color) = self.stops.remove(ix);
+        let position = position.clamp(0., 1.);
+        let ix = self.stops.partition_point(|(p, _)| *p <= position);
+        self.stops.insert(ix, (position, color));
+        ix
+    }
+
+    /// Set the color of the stop at the given index.
+    pub fn set_stop_color(&mut self, ix: usize, color: Hsla) {
+        if let Some(stop) = self.stops.get_mut(ix) {
+            stop.1 = color;
+        }
+    }
+
+    /// Returns the position (0.0 to 1.0) of the gradient at the `point` in the box of `size`,
+    /// the gradient line passes the center at the angle, like the CSS `linear-gradient`.
+    fn position_at(&self, point: Point<Pixels>, size: Size<Pixels>) -> f32 {
+        let (sin, cos) = self.angle.to_radians().sin_cos();
+        let length = size.width.0 * sin.abs() + size.height.0 * cos.abs();
+        if length <= 0. {
+            return 0.;
+        }
+
+        let x = point.x.0 - size.width.0 / 2.;
+        let y = point.y.0 - size.height.0 / 2.;
+        // The y axis is downward, and `0` degree is to top.
+        ((x * sin - y * cos) / length + 0.5).clamp(0., 1.)
+    }
+}
+
+/// Paint the `gradient` at its angle in the `bounds`.
+///
+/// The linear gradient of GPUI only has 2 stops, so each row of 1px is split between
+/// the stops, the color of each part changes linearly from the left to the right.
+fn paint_gradient(gradient: &Gradient, bounds: Bounds<Pixels>, window: &mut Window) {
+    let size = bounds.size;
+    let rows = size.height.0.ceil().max(0.) as usize;
+    for row in 0..rows {
+        let top = px(row as f32);
+        let bottom = (top + px(1.)).min(size.height);
+        let center_y = (top + bottom) / 2.;
+        let start = gradient.position_at(point(px(0.), center_y), size);
+        let end = gradient.position_at(point(size.width, center_y), size);
+        let row_bounds = |left: Pixels, right: Pixels| {
+            Bounds::from_corners(
+                bounds.origin + point(left, top),
+                bounds.origin + point(right, bottom),
+            )
+        };
+
+        if (end - start).abs() <= f32::EPSILON {
+            window.paint_quad(fill(
+                row_bounds(px(0.), size.width),
+                gradient.color_at(start),
+            ));
+            continue;
+        }
+
+        // The positions where the color changes, from the left to the right.
+        let mut positions: Vec<f32> = gradient
+            .stops
+            .iter()
+            .map(|(position, _)| *position)
+            .filter(|position| *position > start.min(end) && *position < start.max(end))
+            .collect();
+        if start > end {
+            positions.reverse();
+        }
+        positions.insert(0, start);
+        positions.push(end);
+
+        let x = |position: f32| size.width * ((position - start) / (end - start));
+        for pair in positions.windows(2) {
+            window.paint_quad(fill(
+                row_bounds(x(pair[0]), x(pair[1])),
+                linear_gradient(
+                    90.,
+                    linear_color_stop(gradient.color_at(pair[0]), 0.),
+                    linear_color_stop(gradient.color_at(pair[1]), 1.),
+                ),
+            ));
+        }
+    }
+}
+
+/// The preview of the `gradient` at its angle, fills the parent.
+fn gradient_preview(gradient: Gradient) -> impl IntoElement {
+    canvas(
+        |_, _, _| {},
+        move |bounds, _, window, _| paint_gradient(&gradient, bounds, window),
+    )
+    .absolute()
+    .size_full()
+}
+
+/// Format the angle of the gradient for the angle input.
+fn format_angle(angle: f32) -> SharedString {
+    format!("{}", angle.round()).into()
+}
+
+/// The editable fields of the [`ColorPicker`], each is synced with the current color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ColorField {
+    Hex,
+    Red,
+    Green,
+    Blue,
+    Hue,
+    Saturation,
+    Lightness,
+    Alpha,
+}
+
+impl ColorField {
+    /// The numeric fields rendered as the inputs, in the RGB and HSL groups.
+    const CHANNELS: [ColorField; 6] = [
+        ColorField::Red,
+        ColorField::Green,
+        ColorField::Blue,
+        ColorField::Hue,
+        ColorField::Saturation,
+        ColorField::Lightness,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            ColorField::Hex => "Hex",
+            ColorField::Red => "R",
+            ColorField::Green => "G",
+            ColorField::Blue => "B",
+            ColorField::Hue => "H",
+            ColorField::Saturation => "S",
+            ColorField::Lightness => "L",
+            ColorField::Alpha => "A",
+        }
+    }
+
+    /// Format the value of this field of the `color`.
+    ///
+    /// The RGB are in 0-255, the hue is in degrees, the saturation, lightness and alpha are in percent.
+    fn format(self, color: Hsla) -> String {
+        let rgb = color.to_rgb();
+        match self {
+            ColorField::Hex => color.to_hex(),
+            ColorField::Red => ((rgb.r * 255.).round() as u8).to_string(),
+            ColorField::Green => ((rgb.g * 255.).round() as u8).to_string(),
+            ColorField::Blue => ((rgb.b * 255.).round() as u8).to_string(),
+            ColorField::Hue => ((color.h * 360.).round() as u16).to_string(),
+            ColorField::Saturation => ((color.s * 100.).round() as u8).to_string(),
+            ColorField::Lightness => ((color.l * 100.).round() as u8).to_string(),
+            ColorField::Alpha => ((color.a * 100.).round() as u8).to_string(),
+        }
+    }
+
+    /// Parse the `text` of this field and apply it to the `color`,
+    /// returns `None` if the text is invalid or out of range.
+    fn parse(self, text: &str, color: Hsla) -> Option<Hsla> {
+        let text = text.trim();
+        let percent = |text: &str| {
+            let value = text.parse::<f32>().ok()?;
+            (0. ..=100.).contains(&value).then_some(value / 100.)
+        };
+
+        match self {
+            ColorField::Hex => Hsla::parse_hex(text).ok(),
+            ColorField::Red | ColorField::Green | ColorField::Blue => {
+                let value = text.parse::<u8>().ok()? as f32 / 255.;
+                let mut rgb = color.to_rgb();
+                match self {
+                    ColorField::Red => rgb.r = value,
+                    ColorField::Green => rgb.g = value,
+                    _ => rgb.b = value,
+                }
+                Some(rgb.into())
+            }
+            ColorField::Hue => {
+                let value = text.parse::<f32>().ok()?;
+                (0. ..=360.).contains(&value).then_some(Hsla {
+                    h: value / 360.,
+                    ..color
+                })
+            }
+            ColorField::Saturation => Some(Hsla {
+                s: percent(text)?,
+                ..color
+            }),
+            ColorField::Lightness => Some(Hsla {
+                l: percent(text)?,
+                ..color
+            }),
+            ColorField::Alpha => Some(Hsla {
+                a: percent(text)?,
+                ..color
+            }),
+        }
+    }
+}
+
+#[derive(Clone)]
+struct DragGradientStop(EntityId);
+
+impl Render for DragGradientStop {
+    fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+        Empty
+    }
 }
 
 fn color_palettes() -> Vec<Vec<Hsla>> {
@@ -59,11 +355,24 @@ fn color_palettes() -> Vec<Vec<Hsla>> {
 /// State of the [`ColorPicker`].
 pub struct ColorPickerState {
     focus_handle: FocusHandle,
+    mode: ColorPickerMode,
     value: Option<Hsla>,
+    gradient: Gradient,
+    /// The index of the gradient stop is being edited.
+    active_stop: usize,
     hovered_color: Option<Hsla>,
     state: Entity<InputState>,
+    /// The inputs of the [`ColorField::CHANNELS`].
+    channel_states: [Entity<InputState>; 6],
+    alpha_state: Entity<SliderState>,
+    /// The input of the gradient angle in degrees.
+    angle_state: Entity<InputState>,
     open: bool,
     bounds: Bounds<Pixels>,
+    gradient_bar_bounds: Bounds<Pixels>,
+    eyedropper_sampler: Option<Rc<dyn Fn(Point<Pixels>, &mut Window, &mut App) -> Option<Hsla>>>,
+    /// The eyedropper is picking a color from the window.
+    picking: bool,
     _subscriptions: Vec<Subscription>,
 }
 
@@ -71,34 +380,85 @@ impl ColorPickerState {
     pub fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
         let state = cx.new(|cx| InputState::new(window, cx));
 
-        let _subscriptions = vec![cx.subscribe_in(
-            &state,
-            window,
-            |this, _, ev: &InputEvent, window, cx| match ev {
-                InputEvent::Change(value) => {
-                    if let Ok(color) = Hsla::parse_hex(value) {
-                        this.value = Some(color);
-                        this.hovered_color = Some(color);
+        let channel_states = std::array::from_fn(|_| cx.new(|cx| InputState::new(window, cx)));
+        let alpha_state = cx.new(|_| SliderState::new().min(0.).max(1.).step(0.01));
+        let angle_state = cx.new(|cx| {
+            InputState::new(window, cx).default_value(format_angle(Gradient::default().angle))
+        });
+
+        let mut _subscriptions = vec![
+            cx.subscribe_in(
+                &state,
+                window,
+                |this, _, ev: &InputEvent, window, cx| match ev {
+                    InputEvent::Change(value) => {
+                        this.on_field_input(ColorField::Hex, value, window, cx)
                     }
-                }
-                InputEvent::PressEnter { .. } => {
-                    let val = this.state.read(cx).value();
-                    if let Ok(color) = Hsla::parse_hex(&val) {
-                        this.open = false;
-                        this.update_value(Some(color), true, window, cx);
+                    InputEvent::PressEnter { .. } => {
+                        let val = this.state.read(cx).value();
+                        if let Ok(color) = Hsla::parse_hex(&val) {
+                            match this.mode {
+                                ColorPickerMode::Color => {
+                                    this.open = false;
+                                    this.update_value(Some(color), true, window, cx);
+                                }
+                                ColorPickerMode::Gradient => {
+                                    this.update_stop_color(this.active_stop, color, cx)
+                                }
+                            }
+                        }
+                    }
+                    _ => {}
+                },
+            ),
+            cx.subscribe_in(
+                &alpha_state,
+                window,
+                |this, _, ev: &SliderEvent, window, cx| {
+                    if let SliderEvent::Change(value) = ev {
+                        let color = Hsla {
+                            a: value.start(),
+                            ..this.current_color()
+                        };
+                        this.apply_color(color, Some(ColorField::Alpha), window, cx);
                     }
+                },
+            ),
+            cx.subscribe_in(&angle_state, window, |this, _, ev: &InputEvent, _, cx| {
+                if let InputEvent::Change(value) = ev {
+                    this.on_angle_input(value, cx);
                 }
-                _ => {}
-            },
-        )];
+            }),
+        ];
+        for (field, input) in ColorField::CHANNELS.iter().zip(&channel_states) {
+            let field = *field;
+            _subscriptions.push(cx.subscribe_in(
+                input,
+                window,
+                move |this, _, ev: &InputEvent, window, cx| {
+                    if let InputEvent::Change(value) = ev {
+                        this.on_field_input(field, value, window, cx);
+                    }
+                },
+            ));
+        }
 
         Self {
             focus_handle: cx.focus_handle(),
+            mode: ColorPickerMode::default(),
             value: None,
+            gradient: Gradient::default(),
+            active_stop: 0,
             hovered_color: None,
             state,
+            channel_states,
+            alpha_state,
+            angle_state,
             open: false,
             bounds: Bounds::default(),
+            gradient_bar_bounds: Bounds::default(),
+            eyedropper_sampler: None,
+            picking: false,
             _subscriptions,
         }
     }
@@ -119,7 +479,247 @@ impl ColorPickerState {
         self.value
     }
 
+    /// Set the sampler to read the color at a position (in window coordinates) for the eyedropper,
+    /// see [`ColorPicker::eyedropper`].
+    ///
+    /// GPUI has no API to capture the screen or read back the rendered pixels of the window,
+    /// so the color can only be picked from the content the application knows the color of,
+    /// e.g.: the decoded image it displays or the canvas it draws.
+    /// The sampler returns `None` if there is no color at the position, then nothing is picked.
+    pub fn eyedropper_sampler(
+        mut self,
+        sampler: impl Fn(Point<Pixels>, &mut Window, &mut App) -> Option<Hsla> + 'static,
+    ) -> Self {
+        self.eyedropper_sampler = Some(Rc::new(sampler));
+        self
+    }
+
+    /// Set the mode of the color picker, default is [`ColorPickerMode::Color`].
+    pub fn mode(mut self, mode: ColorPickerMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Set default gradient value, used in [`ColorPickerMode::Gradient`].
+    pub fn default_gradient(mut self, gradient: Gradient) -> Self {
+        self.gradient = gradient;
+        self.active_stop = 0;
+        self
+    }
+
+    /// Set current gradient value, used in [`ColorPickerMode::Gradient`].
+    pub fn set_gradient(
+        &mut self,
+        gradient: Gradient,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.gradient = gradient;
+        self.sync_angle(window, cx);
+        self.set_active_stop(0, window, cx);
+    }
+
+    /// Get current gradient value.
+    pub fn gradient(&self) -> &Gradient {
+        &self.gradient
+    }
+
+    fn set_active_stop(&mut self, ix: usize, window: &mut Window, cx: &mut Context<Self>) {
+        self.active_stop = ix.min(self.gradient.stops.len().saturating_sub(1));
+        let color = self.gradient.stops.get(self.active_stop).map(|(_, c)| *c);
+        self.hovered_color = color;
+        self.state.update(cx, |view, cx| {
+            view.set_value(color.map(|c| c.to_hex()).unwrap_or_default(), window, cx);
+        });
+        if let Some(color) = color {
+            self.sync_fields(color, Some(ColorField::Hex), window, cx);
+        }
+        cx.notify();
+    }
+
+    /// The color is being edited, the value or the active gradient stop.
+    fn current_color(&self) -> Hsla {
+        match self.mode {
+            ColorPickerMode::Color => self.value.or(self.hovered_color),
+            ColorPickerMode::Gradient => self.gradient.stops.get(self.active_stop).map(|(_, c)| *c),
+        }
+        .unwrap_or(black())
+    }
+
+    fn on_field_input(
+        &mut self,
+        field: ColorField,
+        text: &str,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let color = self.current_color();
+        // The text is set by `sync_fields`, or the value is not changed.
+        if text == field.format(color) {
+            return;
+        }
+
+        // Ignore the invalid text until it is valid.
+        if let Some(color) = field.parse(text, color) {
+            self.apply_color(color, Some(field), window, cx);
+        }
+    }
+
+    /// Apply the `color` edited by the `source` field, and sync the other fields.
+    fn apply_color(
+        &mut self,
+        color: Hsla,
+        source: Option<ColorField>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.hovered_color = Some(color);
+        match self.mode {
+            ColorPickerMode::Color => {
+                self.value = Some(color);
+                cx.emit(ColorPickerEvent::Change(Some(color)));
+            }
+            ColorPickerMode::Gradient => self.update_stop_color(self.active_stop, color, cx),
+        }
+        self.sync_fields(color, source, window, cx);
+        cx.notify();
+    }
+
+    /// Update the fields to the `color`, except the `source` field that is being edited.
+    fn sync_fields(
+        &mut self,
+        color: Hsla,
+        source: Option<ColorField>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let inputs = std::iter::once((ColorField::Hex, &self.state)).chain(
+            ColorField::CHANNELS
+                .iter()
+                .copied()
+                .zip(&self.channel_states),
+        );
+        for (field, input) in inputs {
+            if source == Some(field) {
+                continue;
+            }
+
+            let text = field.format(color);
+            input.update(cx, |input, cx| {
+                if *input.value() != *text {
+                    input.set_value(text, window, cx);
+                }
+            });
+        }
+
+        if source != Some(ColorField::Alpha) {
+            self.alpha_state.update(cx, |slider, cx| {
+                slider.set_value(color.a, window, cx);
+            });
+        }
+    }
+
+    fn update_stop_color(&mut self, ix: usize, color: Hsla, cx: &mut Context<Self>) {
+        self.gradient.set_stop_color(ix, color);
+        cx.emit(ColorPickerEvent::ChangeGradient(self.gradient.clone()));
+        cx.notify();
+    }
+
+    /// Returns the position (0.0 to 1.0) of the mouse on the gradient bar.
+    fn gradient_position(&self, position: Point<Pixels>) -> f32 {
+        let bounds = self.gradient_bar_bounds;
+        if bounds.size.width <= px(0.) {
+            return 0.;
+        }
+
+        (position.x - bounds.left()).clamp(px(0.), bounds.size.width) / bounds.size.width
+    }
+
+    fn add_gradient_stop(&mut self, position: f32, window: &mut Window, cx: &mut Context<Self>) {
+        let ix = self.gradient.add_stop(position);
+        self.set_active_stop(ix, window, cx);
+        cx.emit(ColorPickerEvent::ChangeGradient(self.gradient.clone()));
+    }
+
+    fn move_gradient_stop(&mut self, position: f32, cx: &mut Context<Self>) {
+        self.active_stop = self.gradient.move_stop(self.active_stop, position);
+        cx.emit(ColorPickerEvent::ChangeGradient(self.gradient.clone()));
+        cx.notify();
+    }
+
+    fn remove_gradient_stop(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.gradient.remove_stop(self.active_stop) {
+            self.set_active_stop(self.active_stop, window, cx);
+            cx.emit(ColorPickerEvent::ChangeGradient(self.gradient.clone()));
+        }
+    }
+
+    fn rotate_gradient(&mut self, delta: f32, window: &mut Window, cx: &mut Context<Self>) {
+        self.gradient.angle = (self.gradient.angle + delta).rem_euclid(360.);
+        self.sync_angle(window, cx);
+        cx.emit(ColorPickerEvent::ChangeGradient(self.gradient.clone()));
+        cx.notify();
+    }
+
+    fn on_angle_input(&mut self, text: &str, cx: &mut Context<Self>) {
+        // The text is set by `sync_angle`, or the value is not changed.
+        if text == format_angle(self.gradient.angle).as_ref() {
+            return;
+        }
+
+        // Ignore the invalid text until it is valid.
+        let Some(angle) = text
+            .trim()
+            .trim_end_matches('°')
+            .parse::<f32>()
+            .ok()
+            .filter(|angle| angle.is_finite())
+        else {
+            return;
+        };
+
+        self.gradient.angle = angle.rem_euclid(360.);
+        cx.emit(ColorPickerEvent::ChangeGradient(self.gradient.clone()));
+        cx.notify();
+    }
+
+    /// Update the angle input to the angle of the gradient.
+    fn sync_angle(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let text = format_angle(self.gradient.angle);
+        self.angle_state.update(cx, |input, cx| {
+            if *input.value() != *text {
+                input.set_value(text, window, cx);
+            }
+        });
+    }
+
+    fn start_eyedropper(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.open = false;
+        self.picking = true;
+        // Focus to cancel the picking by the escape.
+        self.focus_handle.focus(window);
+        cx.notify();
+    }
+
+    fn pick_color(&mut self, position: Point<Pixels>, window: &mut Window, cx: &mut Context<Self>) {
+        self.picking = false;
+        let color = self
+            .eyedropper_sampler
+            .clone()
+            .and_then(|sampler| sampler(position, window, cx));
+        if let Some(color) = color {
+            self.apply_color(color, None, window, cx);
+        }
+        cx.notify();
+    }
+
     fn on_escape(&mut self, _: &Cancel, _: &mut Window, cx: &mut Context<Self>) {
+        if self.picking {
+            self.picking = false;
+            cx.notify();
+            return;
+        }
+
         if !self.open {
             cx.propagate();
         }
@@ -128,8 +728,12 @@ impl ColorPickerState {
         cx.notify();
     }
 
-    fn toggle_picker(&mut self, _: &gpui::ClickEvent, _: &mut Window, cx: &mut Context<Self>) {
+    fn toggle_picker(&mut self, _: &gpui::ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
         self.open = !self.open;
+        if self.open {
+            self.sync_fields(self.current_color(), None, window, cx);
+            self.sync_angle(window, cx);
+        }
         cx.notify();
     }
 
@@ -149,6 +753,9 @@ impl ColorPickerState {
                 view.set_value("", window, cx);
             }
         });
+        if let Some(value) = value {
+            self.sync_fields(value, Some(ColorField::Hex), window, cx);
+        }
         if emit {
             cx.emit(ColorPickerEvent::Change(value));
         }
@@ -177,6 +784,7 @@ pub struct ColorPicker {
     icon: Option<Icon>,
     size: Size,
     anchor: Corner,
+    eyedropper: bool,
 }
 
 impl ColorPicker {
@@ -190,6 +798,7 @@ impl ColorPicker {
             label: None,
             icon: None,
             anchor: Corner::TopLeft,
+            eyedropper: false,
         }
     }
 
@@ -233,6 +842,18 @@ impl ColorPicker {
         self
     }
 
+    /// Set true to show the eyedropper button to pick a color from the window, default: false
+    ///
+    /// The color is read by the [`ColorPickerState::eyedropper_sampler`],
+    /// the button is disabled if there is no sampler.
+    ///
+    /// NOTE: The color outside of the window can't be picked,
+    /// because GPUI doesn't expose the screen capture on any platform.
+    pub fn eyedropper(mut self, eyedropper: bool) -> Self {
+        self.eyedropper = eyedropper;
+        self
+    }
+
     fn render_item(
         &self,
         color: Hsla,
@@ -261,10 +882,16 @@ impl ColorPicker {
                 }))
                 .on_click(window.listener_for(
                     &state,
-                    move |state, _, window, cx| {
-                        state.update_value(Some(color), true, window, cx);
-                        state.open = false;
-                        cx.notify();
+                    move |state, _, window, cx| match state.mode {
+                        ColorPickerMode::Color => {
+                            state.update_value(Some(color), true, window, cx);
+                            state.open = false;
+                            cx.notify();
+                        }
+                        ColorPickerMode::Gradient => {
+                            state.update_stop_color(state.active_stop, color, cx);
+                            state.set_active_stop(state.active_stop, window, cx);
+                        }
                     },
                 ))
             })
@@ -310,22 +937,242 @@ impl ColorPicker {
                     })),
             )
             .when_some(state.read(cx).hovered_color, |this, hovered_color| {
-                this.child(Divider::horizontal()).child(
+                this.child(Divider::horizontal())
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .items_center()
+                            .child(
+                                div()
+                                    .bg(hovered_color)
+                                    .flex_shrink_0()
+                                    .border_1()
+                                    .border_color(hovered_color.darken(0.2))
+                                    .size_5()
+                                    .rounded(cx.theme().radius),
+                            )
+                            .child(TextInput::new(&state.read(cx).state))
+                            .when(self.eyedropper, |this| {
+                                this.child(
+                                    Button::new("eyedropper")
+                                        .ghost()
+                                        .small()
+                                        .icon(IconName::Pipette)
+                                        .tooltip(t!("ColorPicker.pick_color"))
+                                        .disabled(state.read(cx).eyedropper_sampler.is_none())
+                                        .on_click(window.listener_for(
+                                            &state,
+                                            |state, _, window, cx| {
+                                                state.start_eyedropper(window, cx)
+                                            },
+                                        )),
+                                )
+                            }),
+                    )
+                    .child(self.render_fields(cx))
+            })
+    }
+
+    fn render_fields(&self, cx: &App) -> impl IntoElement {
+        let state = self.state.read(cx);
+        let alpha = ColorField::Alpha.format(state.current_color());
+        let label = |field: ColorField| {
+            div()
+                .w_4()
+                .flex_shrink_0()
+                .text_xs()
+                .text_color(cx.theme().muted_foreground)
+                .child(field.label())
+        };
+        let channels = |fields: &[ColorField], states: &[Entity<InputState>]| {
+            h_flex()
+                .gap_2()
+                .children(fields.iter().zip(states).map(|(field, input)| {
                     h_flex()
-                        .gap_2()
+                        .flex_1()
+                        .gap_1()
                         .items_center()
-                        .child(
-                            div()
-                                .bg(hovered_color)
-                                .flex_shrink_0()
-                                .border_1()
-                                .border_color(hovered_color.darken(0.2))
-                                .size_5()
-                                .rounded(cx.theme().radius),
+                        .child(label(*field))
+                        .child(TextInput::new(input).xsmall())
+                }))
+        };
+
+        v_flex()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_1()
+                    .items_center()
+                    .child(label(ColorField::Alpha))
+                    .child(Slider::new(&state.alpha_state).flex_1())
+                    .child(
+                        div()
+                            .w_10()
+                            .flex_shrink_0()
+                            .text_right()
+                            .text_xs()
+                            .child(format!("{}%", alpha)),
+                    ),
+            )
+            .child(channels(
+                &ColorField::CHANNELS[..3],
+                &state.channel_states[..3],
+            ))
+            .child(channels(
+                &ColorField::CHANNELS[3..],
+                &state.channel_states[3..],
+            ))
+    }
+
+    fn render_gradient_editor(&self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let state = self.state.read(cx);
+        let entity_id = self.state.entity_id();
+        let gradient = state.gradient.clone();
+        let active_stop = state.active_stop;
+        let can_remove = gradient.stops.len() > Gradient::MIN_STOPS;
+
+        v_flex()
+            .gap_3()
+            .child(
+                div()
+                    .id("gradient-bar")
+                    .relative()
+                    .h_6()
+                    .w_full()
+                    .border_1()
+                    .border_color(cx.theme().border)
+                    .rounded(cx.theme().radius)
+                    .child(FillColor::from(gradient.clone()).apply(
+                        div().absolute().size_full().rounded(cx.theme().radius),
+                        Axis::Horizontal,
+                        0.,
+                        1.,
+                    ))
+                    .children(
+                        gradient
+                            .stops
+                            .iter()
+                            .enumerate()
+                            .map(|(ix, (position, color))| {
+                                let is_active = ix == active_stop;
+                                div()
+                                    .id(("gradient-stop", ix))
+                                    .absolute()
+                                    .top(px(-3.))
+                                    .bottom(px(-3.))
+                                    .left(relative(*position))
+                                    .ml(px(-5.))
+                                    .w(px(10.))
+                                    .rounded(px(2.))
+                                    .bg(*color)
+                                    .border_2()
+                                    .border_color(if is_active {
+                                        cx.theme().foreground
+                                    } else {
+                                        cx.theme().background
+                                    })
+                                    .shadow_sm()
+                                    .on_mouse_down(
+                                        MouseButton::Left,
+                                        window.listener_for(
+                                            &self.state,
+                                            move |state, _, window, cx| {
+                                                cx.stop_propagation();
+                                                state.set_active_stop(ix, window, cx);
+                                            },
+                                        ),
+                                    )
+                                    .on_drag(DragGradientStop(entity_id), |drag, _, _, cx| {
+                                        cx.stop_propagation();
+                                        cx.new(|_| drag.clone())
+                                    })
+                            }),
+                    )
+                    .on_mouse_down(
+                        MouseButton::Left,
+                        window.listener_for(
+                            &self.state,
+                            |state, e: &MouseDownEvent, window, cx| {
+                                let position = state.gradient_position(e.position);
+                                state.add_gradient_stop(position, window, cx);
+                            },
+                        ),
+                    )
+                    .on_drag_move(window.listener_for(
+                        &self.state,
+                        move |state, e: &DragMoveEvent<DragGradientStop>, _, cx| {
+                            if e.drag(cx).0 != entity_id {
+                                return;
+                            }
+
+                            let position = state.gradient_position(e.event.position);
+                            state.move_gradient_stop(position, cx);
+                        },
+                    ))
+                    .child(
+                        canvas(
+                            {
+                                let state = self.state.clone();
+                                move |bounds, _, cx| {
+                                    state.update(cx, |r, _| r.gradient_bar_bounds = bounds)
+                                }
+                            },
+                            |_, _, _, _| {},
                         )
-                        .child(TextInput::new(&state.read(cx).state)),
-                )
-            })
+                        .absolute()
+                        .size_full(),
+                    ),
+            )
+            .child(
+                h_flex()
+                    .gap_1()
+                    .items_center()
+                    .child(
+                        Button::new("rotate-left")
+                            .ghost()
+                            .xsmall()
+                            .icon(IconName::Minus)
+                            .on_click(window.listener_for(&self.state, |state, _, window, cx| {
+                                state.rotate_gradient(-15., window, cx)
+                            })),
+                    )
+                    .child(
+                        div()
+                            .w_16()
+                            .child(TextInput::new(&state.angle_state).xsmall().suffix("°")),
+                    )
+                    .child(
+                        Button::new("rotate-right")
+                            .ghost()
+                            .xsmall()
+                            .icon(IconName::Plus)
+                            .on_click(window.listener_for(&self.state, |state, _, window, cx| {
+                                state.rotate_gradient(15., window, cx)
+                            })),
+                    )
+                    .child(div().flex_1())
+                    .child(
+                        div()
+                            .relative()
+                            .size_6()
+                            .border_1()
+                            .border_color(cx.theme().border)
+                            .rounded(cx.theme().radius)
+                            .overflow_hidden()
+                            .child(gradient_preview(gradient.clone())),
+                    )
+                    .child(
+                        Button::new("remove-stop")
+                            .ghost()
+                            .xsmall()
+                            .icon(IconName::Delete)
+                            .disabled(!can_remove)
+                            .on_click(window.listener_for(&self.state, |state, _, window, cx| {
+                                state.remove_gradient_stop(window, cx)
+                            })),
+                    ),
+            )
+            .child(Divider::horizontal())
     }
 
     fn resolved_corner(&self, bounds: Bounds<Pixels>) -> Point<Pixels> {
@@ -406,6 +1253,10 @@ impl RenderOnce for ColorPicker {
                                         .border_color(value.darken(0.3))
                                         .when(state.open, |this| this.border_2())
                                 })
+                                .when(state.mode == ColorPickerMode::Gradient, |this| {
+                                    this.relative()
+                                        .child(gradient_preview(state.gradient.clone()))
+                                })
                                 .when(!display_title.is_empty(), |this| {
                                     this.tooltip(move |_, cx| {
                                         cx.new(|_| Tooltip::new(display_title.clone())).into()
@@ -427,6 +1278,33 @@ impl RenderOnce for ColorPicker {
                         .size_full(),
                     ),
             )
+            .when(state.picking, |this| {
+                let viewport_size = window.viewport_size();
+                this.child(
+                    deferred(
+                        anchored()
+                            .position(Point::default())
+                            .snap_to_window()
+                            .child(
+                                div()
+                                    .occlude()
+                                    .w(viewport_size.width)
+                                    .h(viewport_size.height)
+                                    .cursor_crosshair()
+                                    .on_mouse_down(
+                                        MouseButton::Left,
+                                        window.listener_for(
+                                            &self.state,
+                                            |state, e: &MouseDownEvent, window, cx| {
+                                                state.pick_color(e.position, window, cx)
+                                            },
+                                        ),
+                                    ),
+                            ),
+                    )
+                    .with_priority(2),
+                )
+            })
             .when(state.open, |this| {
                 this.child(
                     deferred(
@@ -450,6 +1328,9 @@ impl RenderOnce for ColorPicker {
                                     .shadow_lg()
                                     .rounded(cx.theme().radius)
                                     .bg(cx.theme().background)
+                                    .when(state.mode == ColorPickerMode::Gradient, |this| {
+                                        this.child(self.render_gradient_editor(window, cx))
+                                    })
                                     .child(self.render_colors(window, cx))
                                     .on_mouse_up_out(
                                         MouseButton::Left,
@@ -464,3 +1345,99 @@ impl RenderOnce for ColorPicker {
             })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use gpui::{blue, green, point, px, red, size};
+
+    use super::{ColorField, Gradient};
+
+    #[test]
+    fn test_gradient_stops() {
+        let mut gradient = Gradient::new(450., [(1.2, red()), (0., green())]);
+        assert_eq!(gradient.angle, 90.);
+        assert_eq!(gradient.stops, vec![(0., green()), (1., red())]);
+
+        assert_eq!(gradient.add_stop(0.5), 1);
+        assert_eq!(gradient.stops.len(), 3);
+        assert_eq!(gradient.stops[1].1, gradient.color_at(0.5));
+
+        gradient.set_stop_color(1, blue());
+        assert_eq!(gradient.stops[1], (0.5, blue()));
+
+        // Move the middle stop after the last stop.
+        assert_eq!(gradient.move_stop(1, 2.), 2);
+        assert_eq!(
+            gradient.stops,
+            vec![(0., green()), (1., red()), (1., blue())]
+        );
+        assert_eq!(gradient.move_stop(2, 0.25), 1);
+        assert_eq!(gradient.stops[1], (0.25, blue()));
+
+        assert!(gradient.remove_stop(1));
+        assert!(!gradient.remove_stop(5));
+        // Keep at least 2 stops.
+        assert!(!gradient.remove_stop(0));
+        assert_eq!(gradient.stops, vec![(0., green()), (1., red())]);
+    }
+
+    #[test]
+    fn test_color_field() {
+        let color = red();
+        assert_eq!(ColorField::Hex.format(color), "#FF0000");
+        assert_eq!(ColorField::Red.format(color), "255");
+        assert_eq!(ColorField::Green.format(color), "0");
+        assert_eq!(ColorField::Hue.format(color), "0");
+        assert_eq!(ColorField::Saturation.format(color), "100");
+        assert_eq!(ColorField::Lightness.format(color), "50");
+        assert_eq!(ColorField::Alpha.format(color), "100");
+
+        let color = ColorField::Alpha.parse("50", color).unwrap();
+        assert_eq!(color.a, 0.5);
+        assert_eq!(ColorField::Hex.format(color), "#FF00007F");
+        assert_eq!(
+            ColorField::Hex
+                .parse("#00FF0080", color)
+                .map(|c| c.to_rgb().g),
+            Some(1.)
+        );
+
+        let color = ColorField::Blue.parse(" 255 ", color).unwrap();
+        assert_eq!(ColorField::Hue.format(color), "300");
+        assert_eq!(color.a, 0.5);
+        let color = ColorField::Hue.parse("120", color).unwrap();
+        assert_eq!(ColorField::Green.format(color), "255");
+
+        // Invalid or out of range
+        assert_eq!(ColorField::Hex.parse("#FF00", color), None);
+        assert_eq!(ColorField::Hex.parse("#中中", color), None);
+        assert_eq!(ColorField::Red.parse("256", color), None);
+        assert_eq!(ColorField::Hue.parse("361", color), None);
+        assert_eq!(ColorField::Saturation.parse("-1", color), None);
+        assert_eq!(ColorField::Alpha.parse("NaN", color), None);
+        assert_eq!(ColorField::Lightness.parse("", color), None);
+    }
+
+    #[test]
+    fn test_gradient_position_at() {
+        let box_size = size(px(100.), px(100.));
+        let position = |angle: f32, x: f32, y: f32| {
+            let position = Gradient::new(angle, []).position_at(point(px(x), px(y)), box_size);
+            (position * 100.).round() / 100.
+        };
+
+        // To right.
+        assert_eq!(position(90., 0., 50.), 0.);
+        assert_eq!(position(90., 100., 0.), 1.);
+        assert_eq!(position(90., 25., 80.), 0.25);
+        // To top.
+        assert_eq!(position(0., 50., 100.), 0.);
+        assert_eq!(position(0., 0., 0.), 1.);
+        // To bottom.
+        assert_eq!(position(180., 20., 25.), 0.25);
+        // To the top right corner.
+        assert_eq!(position(45., 0., 100.), 0.);
+        assert_eq!(position(45., 100., 0.), 1.);
+        assert_eq!(position(45., 100., 100.), 0.5);
+    }
+}
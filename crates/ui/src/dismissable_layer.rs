@@ -0,0 +1,128 @@
+use std::{cell::Cell, rc::Rc};
+
+use gpui::{
+    canvas, App, Bounds, InteractiveElement, IntoElement, MouseDownEvent, Pixels, Point,
+    Styled as _, Window,
+};
+
+/// A region that clicks inside it will not dismiss the [`DismissableLayer`].
+///
+/// Render the [`EscapeHatch::track`] element into the region (e.g. a linked toolbar)
+/// to keep the bounds of the region updated.
+#[derive(Debug, Clone, Default)]
+pub struct EscapeHatch(Rc<Cell<Bounds<Pixels>>>);
+
+impl EscapeHatch {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the bounds of the region after last paint.
+    pub fn bounds(&self) -> Bounds<Pixels> {
+        self.0.get()
+    }
+
+    /// Set the bounds of the region.
+    pub fn set_bounds(&self, bounds: Bounds<Pixels>) {
+        self.0.set(bounds);
+    }
+
+    /// Returns an element to track the bounds of the region.
+    ///
+    /// The element is absolute positioned with full size, so the parent should be `relative`.
+    pub fn track(&self) -> impl IntoElement {
+        let hatch = self.clone();
+        canvas(
+            move |bounds, _, _| hatch.set_bounds(bounds),
+            |_, _, _, _| {},
+        )
+        .absolute()
+        .size_full()
+    }
+}
+
+/// A helper to dismiss the overlays (Popover, Dropdown, PopupMenu) when click outside of them.
+///
+/// The clicks inside the escape-hatch regions (e.g. the trigger) will not dismiss the layer.
+#[derive(Debug, Clone, Default)]
+pub struct DismissableLayer {
+    escape_hatches: Vec<EscapeHatch>,
+    regions: Vec<Bounds<Pixels>>,
+}
+
+impl DismissableLayer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an [`EscapeHatch`] that clicks inside it will not dismiss the layer.
+    pub fn escape_hatch(mut self, hatch: &EscapeHatch) -> Self {
+        self.escape_hatches.push(hatch.clone());
+        self
+    }
+
+    /// Add multiple [`EscapeHatch`]es.
+    pub fn escape_hatches<'a>(
+        mut self,
+        hatches: impl IntoIterator<Item = &'a EscapeHatch>,
+    ) -> Self {
+        self.escape_hatches.extend(hatches.into_iter().cloned());
+        self
+    }
+
+    /// Add a fixed region that clicks inside it will not dismiss the layer.
+    pub fn escape_region(mut self, bounds: Bounds<Pixels>) -> Self {
+        self.regions.push(bounds);
+        self
+    }
+
+    /// Returns true if the position is inside any of the escape-hatch regions.
+    pub fn contains(&self, position: &Point<Pixels>) -> bool {
+        self.regions.iter().any(|bounds| bounds.contains(position))
+            || self
+                .escape_hatches
+                .iter()
+                .any(|hatch| hatch.bounds().contains(position))
+    }
+
+    /// Bind the layer to the `element` (the overlay content), the `on_dismiss` will be called
+    /// when the mouse down outside of the element and the escape-hatch regions.
+    pub fn apply<E>(
+        self,
+        element: E,
+        on_dismiss: impl Fn(&MouseDownEvent, &mut Window, &mut App) + 'static,
+    ) -> E
+    where
+        E: InteractiveElement,
+    {
+        element.on_mouse_down_out(move |event, window, cx| {
+            if self.contains(&event.position) {
+                return;
+            }
+
+            on_dismiss(event, window, cx);
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::{point, px, size, Bounds};
+
+    use super::{DismissableLayer, EscapeHatch};
+
+    #[test]
+    fn test_contains() {
+        let hatch = EscapeHatch::new();
+        let layer = DismissableLayer::new()
+            .escape_region(Bounds::new(point(px(0.), px(0.)), size(px(10.), px(10.))))
+            .escape_hatch(&hatch);
+
+        assert!(layer.contains(&point(px(5.), px(5.))));
+        assert!(!layer.contains(&point(px(50.), px(50.))));
+
+        // The hatch bounds is updated after the layer is created.
+        hatch.set_bounds(Bounds::new(point(px(40.), px(40.)), size(px(20.), px(20.))));
+        assert!(layer.contains(&point(px(50.), px(50.))));
+    }
+}
@@ -8,11 +8,14 @@ mod tiles;
 
 use anyhow::Result;
 use gpui::{
-    actions, canvas, div, prelude::FluentBuilder, AnyElement, AnyView, App, AppContext, Axis,
-    Bounds, Context, Edges, Entity, EntityId, EventEmitter, InteractiveElement as _, IntoElement,
-    ParentElement as _, Pixels, Render, SharedString, Styled, Subscription, WeakEntity, Window,
+    actions, canvas, div, prelude::FluentBuilder, px, size, AnyElement, AnyView, AnyWindowHandle,
+    App, AppContext, Axis, Bounds, Context, DragMoveEvent, Edges, Entity, EntityId, EventEmitter,
+    InteractiveElement as _, IntoElement, MouseButton, ParentElement as _, Pixels, Render,
+    SharedString, Styled, Subscription, Task, WeakEntity, Window, WindowBounds, WindowOptions,
 };
-use std::sync::Arc;
+use std::{cell::RefCell, rc::Rc, sync::Arc, time::Duration};
+
+use crate::Root;
 
 pub use dock::*;
 pub use panel::*;
@@ -25,17 +28,30 @@ pub fn init(cx: &mut App) {
     PanelRegistry::init(cx);
 }
 
-actions!(dock, [ToggleZoom, ClosePanel]);
+actions!(dock, [ToggleZoom, ClosePanel, MaximizePanel]);
 
 pub enum DockEvent {
     /// The layout of the dock has changed, subscribers this to save the layout.
     ///
     /// This event is emitted when every time the layout of the dock has changed,
-    /// So it emits may be too frequently, you may want to debounce the event.
+    /// So it emits may be too frequently, you may want to debounce the event,
+    /// or use [`DockArea::auto_persist`] instead.
     LayoutChanged,
 
     /// The drag item drop event.
     DragDrop(AnyDrag),
+
+    /// The active panel has changed, by clicking a tab, dragging a panel or keyboard navigation.
+    ///
+    /// Subscribe this to reflect the current panel outside the dock, e.g. status bar or window title.
+    ActivePanelChanged {
+        /// The [`Panel::panel_name`] of the active panel.
+        panel_name: &'static str,
+        /// The entity id of the active panel.
+        panel_id: EntityId,
+        /// The placement of the dock that contains the active panel.
+        placement: DockPlacement,
+    },
 }
 
 /// The main area of the dock.
@@ -61,6 +77,8 @@ pub struct DockArea {
     right_dock: Option<Entity<Dock>>,
     /// The top zoom view of the dock_area, if any.
     zoom_view: Option<AnyView>,
+    /// The TabPanel that is maximized in the center, see [`DockArea::toggle_maximize_panel`].
+    maximized_panel: Option<Entity<TabPanel>>,
 
     /// Lock panels layout, but allow to resize.
     locked: bool,
@@ -68,9 +86,37 @@ pub struct DockArea {
     /// The panel style, default is [`PanelStyle::Default`](PanelStyle::Default).
     pub(crate) panel_style: PanelStyle,
 
+    /// The entity_id of the last active panel, used to avoid emitting duplicate events.
+    active_panel_id: Option<EntityId>,
+
+    /// The state to save the layout automatically, see [`DockArea::auto_persist`].
+    auto_persist: Option<AutoPersist>,
+
+    /// The panels detached into separate windows, see [`DockArea::detach_panel`].
+    detached_panels: Vec<DetachedPanel>,
+    /// The dragging panel to detach when the drag is released outside the window.
+    pending_detach: Option<Arc<dyn PanelView>>,
+
     _subscriptions: Vec<Subscription>,
 }
 
+/// The distance the drag must leave the window bounds to detach a panel.
+const DETACH_THRESHOLD: Pixels = px(20.);
+
+struct DetachedPanel {
+    /// The panel_id of the panel in the detached window.
+    panel_id: EntityId,
+    window: AnyWindowHandle,
+}
+
+struct AutoPersist {
+    callback: Rc<dyn Fn(DockAreaState, &mut App)>,
+    debounce: Duration,
+    /// Whether the layout has changed since the last save.
+    dirty: bool,
+    _task: Task<()>,
+}
+
 /// DockItem is a tree structure that represents the layout of the dock.
 #[derive(Clone)]
 pub enum DockItem {
@@ -457,6 +503,7 @@ impl DockArea {
             bounds: Bounds::default(),
             items: dock_item,
             zoom_view: None,
+            maximized_panel: None,
             toggle_button_panels: Edges::default(),
             toggle_button_visible: true,
             left_dock: None,
@@ -464,6 +511,10 @@ impl DockArea {
             bottom_dock: None,
             locked: false,
             panel_style: PanelStyle::Default,
+            active_panel_id: None,
+            auto_persist: None,
+            detached_panels: vec![],
+            pending_detach: None,
             _subscriptions: vec![],
         };
 
@@ -520,6 +571,7 @@ impl DockArea {
     pub fn set_center(&mut self, item: DockItem, window: &mut Window, cx: &mut Context<Self>) {
         self.subscribe_item(&item, window, cx);
         self.items = item;
+        self.maximized_panel = None;
         self.update_toggle_button_tab_panels(window, cx);
         cx.notify();
     }
@@ -816,6 +868,170 @@ impl DockArea {
         self.remove_panel(panel.clone(), DockPlacement::Bottom, window, cx);
     }
 
+    /// Detach a panel into a separate window.
+    ///
+    /// The panel is recreated from [`Panel::dump`] by the [`register_panel`] builder in a
+    /// new window, and removed from this DockArea once the window is opened. The panel must
+    /// be registered, otherwise an invalid placeholder panel is shown instead of it.
+    ///
+    /// Dragging a panel tab out of the window will also detach it.
+    ///
+    /// Closing the detached window will attach the panel back to this DockArea,
+    /// see also [`DockArea::attach_panel`].
+    pub fn detach_panel(
+        &mut self,
+        panel: Arc<dyn PanelView>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Result<Entity<Root>> {
+        let state = panel.dump(cx);
+        // Each detached window has its own DockArea, so make the id unique per panel.
+        let id = SharedString::from(format!(
+            "{}/detached/{}",
+            self.id,
+            panel.panel_id(cx).as_u64()
+        ));
+        let version = self.version;
+        let source = cx.entity().downgrade();
+        let source_window = window.window_handle();
+        let detached = Rc::new(RefCell::new(None));
+
+        let window_bounds = Bounds::centered(None, size(px(800.), px(600.)), cx);
+        let options = WindowOptions {
+            window_bounds: Some(WindowBounds::Windowed(window_bounds)),
+            ..Default::default()
+        };
+        let window_handle = cx.open_window(options, {
+            let detached = detached.clone();
+            move |window, cx| {
+                let dock_area = cx.new(|cx| DockArea::new(id, version, window, cx));
+                let weak_dock_area = dock_area.downgrade();
+                let panel: Arc<dyn PanelView> = PanelRegistry::build_panel(
+                    &state.panel_name,
+                    weak_dock_area.clone(),
+                    &state,
+                    &state.info,
+                    window,
+                    cx,
+                )
+                .into();
+                dock_area.update(cx, |dock_area, cx| {
+                    let item =
+                        DockItem::tabs(vec![panel.clone()], None, &weak_dock_area, window, cx);
+                    dock_area.set_center(item, window, cx);
+                });
+
+                window.on_window_should_close(cx, {
+                    let panel = panel.clone();
+                    move |_, cx| {
+                        // Attach the panel back, unless it has been closed in the detached window.
+                        let Ok(contains) = weak_dock_area.read_with(cx, |dock_area, cx| {
+                            panel_contains(&dock_area.items.view(), panel.panel_id(cx), cx)
+                        }) else {
+                            return true;
+                        };
+
+                        _ = source_window.update(cx, |_, window, cx| {
+                            _ = source.update(cx, |this, cx| {
+                                if contains {
+                                    this.attach_panel(panel.clone(), window, cx);
+                                } else {
+                                    let panel_id = panel.panel_id(cx);
+                                    this.detached_panels.retain(|p| p.panel_id != panel_id);
+                                }
+                            });
+                        });
+                        true
+                    }
+                });
+
+                let root = cx.new(|cx| Root::new(dock_area.into(), window, cx));
+                *detached.borrow_mut() = Some((root.clone(), panel.panel_id(cx)));
+                root
+            }
+        })?;
+
+        let Some((root, panel_id)) = detached.borrow_mut().take() else {
+            return Err(anyhow::anyhow!("Failed to open the detached window."));
+        };
+        self.remove_panel_from_all_docks(panel, window, cx);
+        self.detached_panels.push(DetachedPanel {
+            panel_id,
+            window: window_handle.into(),
+        });
+
+        cx.emit(DockEvent::LayoutChanged);
+        cx.notify();
+        Ok(root)
+    }
+
+    /// Attach a panel back into the center of this DockArea.
+    ///
+    /// The panel is recreated from [`Panel::dump`] in this DockArea, and if it was detached
+    /// by [`DockArea::detach_panel`], the detached window will be closed.
+    pub fn attach_panel(
+        &mut self,
+        panel: Arc<dyn PanelView>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let panel_id = panel.panel_id(cx);
+        let state = panel.dump(cx);
+
+        if let Some(ix) = self
+            .detached_panels
+            .iter()
+            .position(|detached| detached.panel_id == panel_id)
+        {
+            let detached = self.detached_panels.remove(ix);
+            // This will fail if the window is closing itself, that's fine.
+            _ = detached
+                .window
+                .update(cx, |_, window, _| window.remove_window());
+        }
+
+        let panel: Arc<dyn PanelView> = PanelRegistry::build_panel(
+            &state.panel_name,
+            cx.entity().downgrade(),
+            &state,
+            &state.info,
+            window,
+            cx,
+        )
+        .into();
+        self.add_panel(panel, DockPlacement::Center, None, window, cx);
+        cx.emit(DockEvent::LayoutChanged);
+        cx.notify();
+    }
+
+    fn on_panel_drag_move(
+        &mut self,
+        event: &DragMoveEvent<DragPanel>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.locked {
+            return;
+        }
+
+        let position = event.event.position;
+        let viewport = window.viewport_size();
+        let outside = position.x < -DETACH_THRESHOLD
+            || position.y < -DETACH_THRESHOLD
+            || position.x > viewport.width + DETACH_THRESHOLD
+            || position.y > viewport.height + DETACH_THRESHOLD;
+
+        self.pending_detach = outside.then(|| event.drag(cx).panel.clone());
+    }
+
+    fn on_panel_drag_end(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(panel) = self.pending_detach.take() {
+            if let Err(err) = self.detach_panel(panel, window, cx) {
+                tracing::error!("failed to detach panel: {:?}", err);
+            }
+        }
+    }
+
     /// Load the state of the DockArea from the DockAreaState.
     ///
     /// See also [DockeArea::dump].
@@ -841,6 +1057,14 @@ impl DockArea {
         }
 
         self.items = state.center.to_item(weak_self, window, cx);
+        self.maximized_panel = None;
+        if let Some(path) = state.maximized_panel {
+            let root = self.items.view();
+            if let Some(tab_panel) = tab_panel_at_path(&root, &path, cx) {
+                set_maximized_path(&root, Some(&path), cx);
+                self.maximized_panel = Some(tab_panel);
+            }
+        }
         self.update_toggle_button_tab_panels(window, cx);
         Ok(())
     }
@@ -851,6 +1075,11 @@ impl DockArea {
     pub fn dump(&self, cx: &App) -> DockAreaState {
         let root = self.items.view();
         let center = root.dump(cx);
+        let maximized_panel = self
+            .maximized_panel
+            .as_ref()
+            .and_then(|tab_panel| tab_panel_path(&root, tab_panel.entity_id(), cx))
+            .map(|(path, _)| path);
 
         let left_dock = self
             .left_dock
@@ -871,7 +1100,100 @@ impl DockArea {
             left_dock,
             right_dock,
             bottom_dock,
+            maximized_panel,
+        }
+    }
+
+    /// Dump the layout to a JSON string, e.g. to save the layout to disk.
+    ///
+    /// See also [`DockArea::load_layout`].
+    pub fn save_layout(&self, cx: &App) -> Result<String> {
+        self.dump(cx).to_json()
+    }
+
+    /// Load the layout from a JSON string that is saved by [`DockArea::save_layout`].
+    pub fn load_layout(
+        &mut self,
+        json: &str,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Result<()> {
+        let state = DockAreaState::from_json(json)?;
+        self.load(state, window, cx)
+    }
+
+    /// Listen the layout changes with debounce, the frequent [`DockEvent::LayoutChanged`]
+    /// emissions within the `debounce` duration are coalesced into one `callback` call.
+    ///
+    /// This is a wrapper of [`DockArea::auto_persist`] and replaces its callback.
+    pub fn on_layout_changed(
+        &mut self,
+        debounce: Duration,
+        callback: impl Fn(DockAreaState, &mut App) + 'static,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.auto_persist(callback, debounce, window, cx);
+    }
+
+    /// Save the layout automatically when it changed.
+    ///
+    /// The frequent [`DockEvent::LayoutChanged`] emissions within the `debounce` duration
+    /// are coalesced, the `callback` is called with the dumped [`DockAreaState`] after the
+    /// layout has not changed for the `debounce` duration. The pending changes will be flushed
+    /// when the dock area is released, e.g. the window is closed.
+    pub fn auto_persist(
+        &mut self,
+        callback: impl Fn(DockAreaState, &mut App) + 'static,
+        debounce: Duration,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.auto_persist.is_none() {
+            self._subscriptions.push(cx.subscribe(
+                &cx.entity(),
+                |this, _, event: &DockEvent, cx| {
+                    if let DockEvent::LayoutChanged = event {
+                        this.schedule_persist(cx);
+                    }
+                },
+            ));
+            cx.on_release(|this, cx| this.flush_persist(cx)).detach();
         }
+
+        self.auto_persist = Some(AutoPersist {
+            callback: Rc::new(callback),
+            debounce,
+            dirty: false,
+            _task: Task::ready(()),
+        });
+    }
+
+    fn schedule_persist(&mut self, cx: &mut Context<Self>) {
+        let Some(auto_persist) = self.auto_persist.as_mut() else {
+            return;
+        };
+
+        let debounce = auto_persist.debounce;
+        auto_persist.dirty = true;
+        auto_persist._task = cx.spawn(async move |this, cx| {
+            cx.background_executor().timer(debounce).await;
+            _ = this.update(cx, |this, cx| this.flush_persist(cx));
+        });
+    }
+
+    /// Call the auto persist callback if the layout has changed.
+    fn flush_persist(&mut self, cx: &mut App) {
+        let callback = match self.auto_persist.as_mut() {
+            Some(auto_persist) if auto_persist.dirty => {
+                auto_persist.dirty = false;
+                auto_persist.callback.clone()
+            }
+            _ => return,
+        };
+
+        let state = self.dump(cx);
+        callback(state, cx);
     }
 
     /// Subscribe event on the panels
@@ -944,6 +1266,7 @@ impl DockArea {
                     PanelEvent::LayoutChanged => {
                         cx.spawn_in(window, async move |view, window| {
                             _ = view.update_in(window, |view, window, cx| {
+                                view.sync_maximized_panel(cx);
                                 view.update_toggle_button_tab_panels(window, cx)
                             });
                         })
@@ -961,6 +1284,65 @@ impl DockArea {
         self.id.clone()
     }
 
+    /// Returns the entity id of the last active panel, if any.
+    pub fn active_panel_id(&self) -> Option<EntityId> {
+        self.active_panel_id
+    }
+
+    /// Mark the `panel` in the `tab_panel` as active.
+    ///
+    /// Emits [`DockEvent::ActivePanelChanged`] when the active panel has changed.
+    pub(crate) fn set_active_panel(
+        &mut self,
+        panel: Arc<dyn PanelView>,
+        tab_panel: &Entity<TabPanel>,
+        cx: &mut Context<Self>,
+    ) {
+        let panel_id = panel.panel_id(cx);
+        if self.active_panel_id == Some(panel_id) {
+            return;
+        }
+
+        self.active_panel_id = Some(panel_id);
+        if self
+            .maximized_panel
+            .as_ref()
+            .is_some_and(|maximized| maximized != tab_panel)
+        {
+            self.restore_maximized_panel(cx);
+        }
+
+        let placement = self.placement_of(tab_panel.entity_id(), cx);
+        cx.emit(DockEvent::ActivePanelChanged {
+            panel_name: panel.panel_name(cx),
+            panel_id,
+            placement,
+        });
+    }
+
+    /// Returns the [`DockPlacement`] of the dock that contains the panel with `panel_id`.
+    ///
+    /// Returns [`DockPlacement::Center`] if the panel is not in the left, bottom or right dock.
+    pub fn placement_of(&self, panel_id: EntityId, cx: &App) -> DockPlacement {
+        let docks = [
+            (DockPlacement::Left, &self.left_dock),
+            (DockPlacement::Bottom, &self.bottom_dock),
+            (DockPlacement::Right, &self.right_dock),
+        ];
+
+        for (placement, dock) in docks {
+            let Some(dock) = dock else {
+                continue;
+            };
+
+            if panel_contains(&dock.read(cx).panel.view(), panel_id, cx) {
+                return placement;
+            }
+        }
+
+        DockPlacement::Center
+    }
+
     pub fn set_zoomed_in<P: Panel>(
         &mut self,
         panel: Entity<P>,
@@ -976,6 +1358,68 @@ impl DockArea {
         cx.notify();
     }
 
+    /// Toggle maximize the [`TabPanel`] that contains the `panel` in the center of the DockArea.
+    ///
+    /// Unlike zoom, this keeps the docks and collapses the sibling splits of the panel,
+    /// they are restored when toggled again or a panel in another TabPanel is activated.
+    pub fn toggle_maximize_panel(
+        &mut self,
+        panel: Arc<dyn PanelView>,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let panel_id = panel.panel_id(cx);
+        let is_maximized = self.maximized_panel.as_ref().is_some_and(|tab_panel| {
+            panel_contains(
+                &(Arc::new(tab_panel.clone()) as Arc<dyn PanelView>),
+                panel_id,
+                cx,
+            )
+        });
+
+        if is_maximized {
+            self.restore_maximized_panel(cx);
+            return;
+        }
+
+        let root = self.items.view();
+        let Some((path, tab_panel)) = tab_panel_path(&root, panel_id, cx) else {
+            return;
+        };
+
+        set_maximized_path(&root, Some(&path), cx);
+        self.maximized_panel = Some(tab_panel);
+        cx.emit(DockEvent::LayoutChanged);
+        cx.notify();
+    }
+
+    /// Returns the [`TabPanel`] that is maximized by [`DockArea::toggle_maximize_panel`].
+    pub fn maximized_panel(&self) -> Option<&Entity<TabPanel>> {
+        self.maximized_panel.as_ref()
+    }
+
+    fn restore_maximized_panel(&mut self, cx: &mut Context<Self>) {
+        if self.maximized_panel.take().is_none() {
+            return;
+        }
+
+        set_maximized_path(&self.items.view(), None, cx);
+        cx.emit(DockEvent::LayoutChanged);
+        cx.notify();
+    }
+
+    /// Restore the whole maximized path if a StackPanel along it has been changed,
+    /// e.g. a panel is added or removed.
+    fn sync_maximized_panel(&mut self, cx: &mut Context<Self>) {
+        let Some(tab_panel) = self.maximized_panel.as_ref() else {
+            return;
+        };
+
+        if maximized_tab_panel(&self.items.view(), cx).as_ref() != Some(tab_panel) {
+            self.restore_maximized_panel(cx);
+        }
+    }
+
     fn render_items(&self, _window: &mut Window, _cx: &mut Context<Self>) -> AnyElement {
         match &self.items {
             DockItem::Split { view, .. } => view.clone().into_any_element(),
@@ -1006,6 +1450,103 @@ impl DockArea {
             .map(|view| view.entity_id());
     }
 }
+
+/// Returns true if the `view` is, or recursively contains, the panel with `panel_id`.
+fn panel_contains(view: &Arc<dyn PanelView>, panel_id: EntityId, cx: &App) -> bool {
+    if view.panel_id(cx) == panel_id {
+        return true;
+    }
+
+    let view = view.view();
+    if let Ok(stack_panel) = view.clone().downcast::<StackPanel>() {
+        return stack_panel
+            .read(cx)
+            .panels
+            .iter()
+            .any(|panel| panel_contains(panel, panel_id, cx));
+    }
+    if let Ok(tab_panel) = view.downcast::<TabPanel>() {
+        return tab_panel
+            .read(cx)
+            .panels
+            .iter()
+            .any(|panel| panel_contains(panel, panel_id, cx));
+    }
+
+    false
+}
+
+/// Returns the child indices from the `view` to the [`TabPanel`] that contains the panel with `panel_id`.
+fn tab_panel_path(
+    view: &Arc<dyn PanelView>,
+    panel_id: EntityId,
+    cx: &App,
+) -> Option<(Vec<usize>, Entity<TabPanel>)> {
+    if let Ok(tab_panel) = view.view().downcast::<TabPanel>() {
+        return panel_contains(view, panel_id, cx).then(|| (vec![], tab_panel));
+    }
+
+    if let Ok(stack_panel) = view.view().downcast::<StackPanel>() {
+        for (ix, panel) in stack_panel.read(cx).panels.iter().enumerate() {
+            if let Some((mut path, tab_panel)) = tab_panel_path(panel, panel_id, cx) {
+                path.insert(0, ix);
+                return Some((path, tab_panel));
+            }
+        }
+    }
+
+    None
+}
+
+/// Returns the [`TabPanel`] at the child indices `path` from the `view`.
+fn tab_panel_at_path(
+    view: &Arc<dyn PanelView>,
+    path: &[usize],
+    cx: &App,
+) -> Option<Entity<TabPanel>> {
+    match path.split_first() {
+        None => view.view().downcast::<TabPanel>().ok(),
+        Some((ix, rest)) => {
+            let stack_panel = view.view().downcast::<StackPanel>().ok()?;
+            let panel = stack_panel.read(cx).panels.get(*ix)?.clone();
+            tab_panel_at_path(&panel, rest, cx)
+        }
+    }
+}
+
+/// Returns the [`TabPanel`] reached by following the maximized child of the StackPanels from the `view`.
+fn maximized_tab_panel(view: &Arc<dyn PanelView>, cx: &App) -> Option<Entity<TabPanel>> {
+    if let Ok(tab_panel) = view.view().downcast::<TabPanel>() {
+        return Some(tab_panel);
+    }
+
+    let stack_panel = view.view().downcast::<StackPanel>().ok()?.read(cx);
+    let panel = stack_panel.panels.get(stack_panel.maximized_ix?)?;
+    maximized_tab_panel(panel, cx)
+}
+
+/// Collapse the siblings of the StackPanels along the `path`, or restore all if `path` is `None`.
+fn set_maximized_path(view: &Arc<dyn PanelView>, path: Option<&[usize]>, cx: &mut App) {
+    let Ok(stack_panel) = view.view().downcast::<StackPanel>() else {
+        return;
+    };
+
+    let (maximized_ix, rest) = match path.and_then(|path| path.split_first()) {
+        Some((ix, rest)) => (Some(*ix), Some(rest)),
+        None => (None, None),
+    };
+    let panels = stack_panel.update(cx, |stack_panel, cx| {
+        stack_panel.maximized_ix = maximized_ix;
+        cx.notify();
+        stack_panel.panels.clone()
+    });
+
+    for (ix, panel) in panels.iter().enumerate() {
+        let path = if maximized_ix == Some(ix) { rest } else { None };
+        set_maximized_path(panel, path, cx);
+    }
+}
+
 impl EventEmitter<DockEvent> for DockArea {}
 impl Render for DockArea {
     fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
@@ -1016,6 +1557,11 @@ impl Render for DockArea {
             .relative()
             .size_full()
             .overflow_hidden()
+            .on_drag_move(cx.listener(Self::on_panel_drag_move))
+            .on_mouse_up_out(
+                MouseButton::Left,
+                cx.listener(|this, _, window, cx| this.on_panel_drag_end(window, cx)),
+            )
             .child(
                 canvas(
                     move |bounds, _, cx| view.update(cx, |r, _| r.bounds = bounds),
@@ -22,6 +22,8 @@ pub struct StackPanel {
     pub(super) axis: Axis,
     focus_handle: FocusHandle,
     pub(crate) panels: SmallVec<[Arc<dyn PanelView>; 2]>,
+    /// The index of the maximized panel, other panels are collapsed.
+    pub(super) maximized_ix: Option<usize>,
     state: Entity<ResizableState>,
     _subscriptions: Vec<Subscription>,
 }
@@ -65,6 +67,7 @@ impl StackPanel {
             parent: None,
             focus_handle: cx.focus_handle(),
             panels: SmallVec::new(),
+            maximized_ix: None,
             state,
             _subscriptions,
         }
@@ -257,6 +260,8 @@ impl StackPanel {
         };
 
         self.panels.insert(ix, panel.clone());
+        // The DockArea will restore the whole maximized path on the layout changed.
+        self.maximized_ix = None;
         self.state.update(cx, |state, cx| {
             state.insert_panel(Some(size), Some(ix), cx);
         });
@@ -278,6 +283,8 @@ impl StackPanel {
         };
 
         self.panels.remove(ix);
+        // The DockArea will restore the whole maximized path on the layout changed.
+        self.maximized_ix = None;
         self.state.update(cx, |state, cx| {
             state.remove_panel(ix, cx);
         });
@@ -419,11 +426,20 @@ impl Render for StackPanel {
             .child(
                 ResizablePanelGroup::new("stack-panel-group", self.state.clone())
                     .axis(self.axis)
-                    .children(self.panels.clone().into_iter().map(|panel| {
-                        resizable_panel()
-                            .child(panel.view())
-                            .visible(panel.visible(cx))
-                    })),
+                    .children(
+                        self.panels
+                            .clone()
+                            .into_iter()
+                            .enumerate()
+                            .map(|(ix, panel)| {
+                                let collapsed = self
+                                    .maximized_ix
+                                    .is_some_and(|maximized_ix| maximized_ix != ix);
+                                resizable_panel()
+                                    .child(panel.view())
+                                    .visible(panel.visible(cx) && !collapsed)
+                            }),
+                    ),
             )
     }
 }
@@ -1,3 +1,4 @@
+use anyhow::Result;
 use gpui::{point, px, size, App, AppContext, Axis, Bounds, Entity, Pixels, WeakEntity, Window};
 use itertools::Itertools as _;
 use serde::{Deserialize, Serialize};
@@ -19,6 +20,21 @@ pub struct DockAreaState {
     pub right_dock: Option<DockState>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bottom_dock: Option<DockState>,
+    /// The child indices from the center to the maximized TabPanel, see [`DockArea::toggle_maximize_panel`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub maximized_panel: Option<Vec<usize>>,
+}
+
+impl DockAreaState {
+    /// Serialize the state to a JSON string, e.g. to save the layout to disk.
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Deserialize the state from a JSON string.
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
 }
 
 /// Used to serialize and deserialize the Dock
@@ -256,6 +272,7 @@ mod tests {
             "StoryContainer"
         );
         assert_eq!(state.center.children[1].panel_name, "TabPanel");
+        assert_eq!(state.maximized_panel, None);
 
         let left_dock = state.left_dock.unwrap();
         assert_eq!(left_dock.open, true);
@@ -279,4 +296,14 @@ mod tests {
         assert_eq!(right_dock.panel.children.len(), 1);
         assert_eq!(right_dock.panel.children[0].panel_name, "StoryContainer");
     }
+
+    #[test]
+    fn test_json_round_trip() {
+        let json = include_str!("../../tests/fixtures/layout.json");
+        let state = DockAreaState::from_json(json).unwrap();
+        let restored = DockAreaState::from_json(&state.to_json().unwrap()).unwrap();
+        assert_eq!(restored, state);
+
+        assert!(DockAreaState::from_json("{ invalid json").is_err());
+    }
 }
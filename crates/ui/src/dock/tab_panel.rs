@@ -1,10 +1,11 @@
 use std::sync::Arc;
 
 use gpui::{
-    div, prelude::FluentBuilder, px, relative, rems, App, AppContext, Context, Corner,
+    div, prelude::FluentBuilder, px, relative, rems, Action, App, AppContext, Context, Corner,
     DismissEvent, Div, DragMoveEvent, Empty, Entity, EventEmitter, FocusHandle, Focusable,
-    InteractiveElement as _, IntoElement, ParentElement, Pixels, Render, ScrollHandle,
-    SharedString, StatefulInteractiveElement, StyleRefinement, Styled, WeakEntity, Window,
+    InteractiveElement as _, IntoElement, MouseButton, ParentElement, Pixels, Render, ScrollHandle,
+    ScrollWheelEvent, SharedString, StatefulInteractiveElement, StyleRefinement, Styled,
+    WeakEntity, Window,
 };
 use rust_i18n::t;
 
@@ -13,15 +14,33 @@ use crate::{
     dock::PanelInfo,
     h_flex,
     popup_menu::{PopupMenu, PopupMenuExt},
+    scroll::{self, Scrollbar, ScrollbarState},
     tab::{Tab, TabBar},
     v_flex, ActiveTheme, AxisExt, IconName, Placement, Selectable, Sizable,
 };
 
 use super::{
-    ClosePanel, DockArea, DockPlacement, Panel, PanelControl, PanelEvent, PanelState, PanelStyle,
-    PanelView, StackPanel, ToggleZoom,
+    ClosePanel, DockArea, DockPlacement, MaximizePanel, Panel, PanelControl, PanelEvent,
+    PanelState, PanelStyle, PanelView, StackPanel, ToggleZoom,
 };
 
+/// Activate the panel at the index, used by the overflow dropdown menu.
+#[derive(Action, Debug, Clone, Copy, PartialEq, Eq)]
+#[action(namespace = dock, no_json)]
+struct ActivateTab(usize);
+
+/// How the [`TabPanel`] handles the tabs that don't fit in the tab bar.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TabOverflow {
+    /// Clip the overflowing tabs.
+    #[default]
+    Clip,
+    /// Show a horizontal scrollbar to scroll the tabs.
+    Scroll,
+    /// Show a dropdown button to list the hidden tabs.
+    Dropdown,
+}
+
 #[derive(Clone)]
 struct TabState {
     closable: bool,
@@ -77,6 +96,8 @@ pub struct TabPanel {
     pub(crate) closable: bool,
 
     tab_bar_scroll_handle: ScrollHandle,
+    tab_bar_scroll_state: ScrollbarState,
+    overflow: TabOverflow,
     zoomed: bool,
     collapsed: bool,
     /// When drag move, will get the placement of the panel to be split
@@ -156,6 +177,8 @@ impl TabPanel {
             panels: Vec::new(),
             active_ix: 0,
             tab_bar_scroll_handle: ScrollHandle::new(),
+            tab_bar_scroll_state: ScrollbarState::default(),
+            overflow: TabOverflow::default(),
             will_split_placement: None,
             zoomed: false,
             collapsed: false,
@@ -164,6 +187,18 @@ impl TabPanel {
         }
     }
 
+    /// Set how to handle the tabs that don't fit in the tab bar, default is [`TabOverflow::Clip`].
+    pub fn overflow(mut self, overflow: TabOverflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Set how to handle the tabs that don't fit in the tab bar.
+    pub fn set_overflow(&mut self, overflow: TabOverflow, cx: &mut Context<Self>) {
+        self.overflow = overflow;
+        cx.notify();
+    }
+
     /// Mark the TabPanel as being used in Tiles.
     pub(super) fn set_in_tiles(&mut self, in_tiles: bool) {
         self.in_tiles = in_tiles;
@@ -197,7 +232,7 @@ impl TabPanel {
         let last_active_ix = self.active_ix;
 
         self.active_ix = ix;
-        self.tab_bar_scroll_handle.scroll_to_item(ix);
+        self.scroll_to_tab(ix, cx);
         self.focus_active_panel(window, cx);
 
         // Sync the active state to all panels
@@ -207,8 +242,9 @@ impl TabPanel {
                     if let Some(last_active) = view.panels.get(last_active_ix) {
                         last_active.set_active(false, window, cx);
                     }
-                    if let Some(active) = view.panels.get(view.active_ix) {
+                    if let Some(active) = view.panels.get(view.active_ix).cloned() {
                         active.set_active(true, window, cx);
+                        view.report_active_panel(active, cx);
                     }
                 });
             });
@@ -219,6 +255,14 @@ impl TabPanel {
         cx.notify();
     }
 
+    /// Report the `panel` to the [`DockArea`] as the active panel.
+    fn report_active_panel(&self, panel: Arc<dyn PanelView>, cx: &mut Context<Self>) {
+        let tab_panel = cx.entity();
+        _ = self.dock_area.update(cx, |dock_area, cx| {
+            dock_area.set_active_panel(panel, &tab_panel, cx);
+        });
+    }
+
     /// Add a panel to the end of the tabs
     pub fn add_panel(
         &mut self,
@@ -464,6 +508,7 @@ impl TabPanel {
                     .popup_menu({
                         let zoomable = state.zoomable.map_or(false, |v| v.menu_visible());
                         let closable = state.closable;
+                        let maximized = self.is_maximized(cx);
 
                         move |this, window, cx| {
                             view.read(cx)
@@ -478,6 +523,14 @@ impl TabPanel {
                                     Box::new(ToggleZoom),
                                     !zoomable,
                                 )
+                                .menu(
+                                    if maximized {
+                                        t!("Dock.Restore")
+                                    } else {
+                                        t!("Dock.Maximize")
+                                    },
+                                    Box::new(MaximizePanel),
+                                )
                                 .when(closable, |this| {
                                     this.separator()
                                         .menu(t!("Dock.Close"), Box::new(ClosePanel))
@@ -661,8 +714,15 @@ impl TabPanel {
         }
 
         let tabs_count = self.panels.len();
+        let hidden_tabs = if self.overflow == TabOverflow::Dropdown {
+            self.hidden_tabs(cx)
+        } else {
+            vec![]
+        };
+        let scrollable = self.overflow == TabOverflow::Scroll
+            && self.tab_bar_scroll_handle.max_offset().width > px(0.);
 
-        TabBar::new("tab-bar")
+        let tab_bar = TabBar::new("tab-bar")
             .tab_item_top_offset(-px(1.))
             .track_scroll(&self.tab_bar_scroll_handle)
             .when(
@@ -713,6 +773,9 @@ impl TabPanel {
                             let dock_area = self.dock_area.clone();
                             move |view, _, window, cx| {
                                 view.set_active_ix(ix, window, cx);
+                                if let Some(panel) = view.panels.get(ix).cloned() {
+                                    view.report_active_panel(panel, cx);
+                                }
 
                                 // Open dock if clicked on the collapsed bottom dock
                                 if is_bottom_dock && is_collapsed {
@@ -788,6 +851,37 @@ impl TabPanel {
                         .bg(cx.theme().tab_bar)
                         .px_2()
                         .gap_1()
+                        .when(!hidden_tabs.is_empty(), |this| {
+                            let panels = self.panels.clone();
+                            let active_ix = self.active_ix;
+                            this.child(
+                                Button::new("more-tabs")
+                                    .icon(IconName::ChevronDown)
+                                    .xsmall()
+                                    .ghost()
+                                    .popup_menu(move |mut this, _, _| {
+                                        this = this.scrollable();
+                                        for ix in hidden_tabs.iter().copied() {
+                                            let Some(panel) = panels.get(ix).cloned() else {
+                                                continue;
+                                            };
+                                            this = this.menu_element_with_check(
+                                                ix == active_ix,
+                                                Box::new(ActivateTab(ix)),
+                                                move |window, cx| {
+                                                    if let Some(tab_name) = panel.tab_name(cx) {
+                                                        tab_name.into_any_element()
+                                                    } else {
+                                                        panel.title(window, cx)
+                                                    }
+                                                },
+                                            );
+                                        }
+                                        this
+                                    })
+                                    .anchor(Corner::TopRight),
+                            )
+                        })
                         .children(
                             self.active_panel(cx)
                                 .and_then(|panel| panel.title_suffix(window, cx)),
@@ -795,6 +889,25 @@ impl TabPanel {
                         .child(self.render_toolbar(state, window, cx))
                         .when_some(right_dock_button, |this, btn| this.child(btn)),
                 )
+            });
+
+        div()
+            .relative()
+            .on_scroll_wheel(cx.listener(|_, _: &ScrollWheelEvent, _, cx| cx.notify()))
+            .child(tab_bar)
+            .when(scrollable, |this| {
+                this.child(
+                    div()
+                        .absolute()
+                        .left_0()
+                        .right_0()
+                        .bottom_0()
+                        .h(scroll::WIDTH)
+                        .child(Scrollbar::horizontal(
+                            &self.tab_bar_scroll_state,
+                            &self.tab_bar_scroll_handle,
+                        )),
+                )
             })
             .into_any_element()
     }
@@ -820,6 +933,13 @@ impl TabPanel {
             .group("")
             .flex_1()
             .when(is_render_in_tabs, |this| this.pt_2())
+            .on_mouse_down(
+                MouseButton::Left,
+                cx.listener({
+                    let active_panel = active_panel.clone();
+                    move |view, _, _, cx| view.report_active_panel(active_panel.clone(), cx)
+                }),
+            )
             .child(
                 div()
                     .id("tab-content")
@@ -1098,6 +1218,87 @@ impl TabPanel {
         .detach();
     }
 
+    /// Scroll the tab bar to the tab of the panel at `ix`.
+    ///
+    /// The tab bar only has the tabs of the visible panels, so the panel index is mapped
+    /// to the index of its tab.
+    fn scroll_to_tab(&self, ix: usize, cx: &App) {
+        let Some(item_ix) = tab_item_ix(self.panels.iter().map(|panel| panel.visible(cx)), ix)
+        else {
+            return;
+        };
+
+        self.tab_bar_scroll_handle.scroll_to_item(item_ix);
+    }
+
+    /// Returns the indexes of the panels that the tabs are out of the visible area of the tab bar.
+    fn hidden_tabs(&self, cx: &App) -> Vec<usize> {
+        let scroll_handle = &self.tab_bar_scroll_handle;
+        if scroll_handle.max_offset().width <= px(0.) {
+            return vec![];
+        }
+
+        let bounds = scroll_handle.bounds();
+        let offset_x = scroll_handle.offset().x;
+        self.panels
+            .iter()
+            .enumerate()
+            .filter(|(_, panel)| panel.visible(cx))
+            .enumerate()
+            .filter_map(|(item_ix, (ix, _))| {
+                let item_bounds = scroll_handle.bounds_for_item(item_ix)?;
+                let hidden = item_bounds.left() + offset_x < bounds.left()
+                    || item_bounds.right() + offset_x > bounds.right();
+                hidden.then_some(ix)
+            })
+            .collect()
+    }
+
+    fn on_action_activate_tab(
+        &mut self,
+        action: &ActivateTab,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let ix = action.0;
+        let Some(panel) = self.panels.get(ix).cloned() else {
+            return;
+        };
+
+        self.set_active_ix(ix, window, cx);
+        self.scroll_to_tab(ix, cx);
+        self.report_active_panel(panel, cx);
+        cx.notify();
+    }
+
+    /// Returns true if this TabPanel is maximized in the DockArea.
+    fn is_maximized(&self, cx: &Context<Self>) -> bool {
+        let entity_id = cx.entity_id();
+        self.dock_area.upgrade().is_some_and(|dock_area| {
+            dock_area
+                .read(cx)
+                .maximized_panel()
+                .is_some_and(|tab_panel| tab_panel.entity_id() == entity_id)
+        })
+    }
+
+    fn on_action_maximize_panel(
+        &mut self,
+        _: &MaximizePanel,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let tab_panel = Arc::new(cx.entity());
+        window.defer(cx, {
+            let dock_area = self.dock_area.clone();
+            move |window, cx| {
+                _ = dock_area.update(cx, |this, cx| {
+                    this.toggle_maximize_panel(tab_panel, window, cx);
+                });
+            }
+        });
+    }
+
     fn on_action_close_panel(
         &mut self,
         _: &ClosePanel,
@@ -1128,6 +1329,8 @@ impl TabPanel {
         v_flex().when(!self.collapsed, |this| {
             this.on_action(cx.listener(Self::on_action_toggle_zoom))
                 .on_action(cx.listener(Self::on_action_close_panel))
+                .on_action(cx.listener(Self::on_action_maximize_panel))
+                .on_action(cx.listener(Self::on_action_activate_tab))
         })
     }
 }
@@ -1171,3 +1374,34 @@ impl Render for TabPanel {
             .child(self.render_active_panel(&state, window, cx))
     }
 }
+
+/// Returns the index of the tab of the panel at `ix` by the visibility of the panels,
+/// or `None` if the panel is not visible.
+fn tab_item_ix(visible: impl IntoIterator<Item = bool>, ix: usize) -> Option<usize> {
+    let mut item_ix = 0;
+    for (panel_ix, visible) in visible.into_iter().enumerate() {
+        if panel_ix == ix {
+            return visible.then_some(item_ix);
+        }
+        if visible {
+            item_ix += 1;
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::tab_item_ix;
+
+    #[test]
+    fn test_tab_item_ix() {
+        let visible = [true, false, true, true];
+        assert_eq!(tab_item_ix(visible, 0), Some(0));
+        assert_eq!(tab_item_ix(visible, 1), None);
+        assert_eq!(tab_item_ix(visible, 2), Some(1));
+        assert_eq!(tab_item_ix(visible, 3), Some(2));
+        assert_eq!(tab_item_ix(visible, 4), None);
+    }
+}
@@ -12,8 +12,8 @@ use crate::{
     h_flex,
     input::clear_button,
     list::{List, ListDelegate},
-    v_flex, ActiveTheme, Disableable, Icon, IconName, IndexPath, Selectable, Sizable, Size,
-    StyleSized, StyledExt,
+    v_flex, ActiveTheme, Disableable, DismissableLayer, EscapeHatch, Icon, IconName, IndexPath,
+    Selectable, Sizable, Size, StyleSized, StyledExt,
 };
 
 #[derive(Clone)]
@@ -114,6 +114,12 @@ pub trait DropdownDelegate: Sized {
     fn perform_search(&mut self, _query: &str, _window: &mut Window, _: &mut App) -> Task<()> {
         Task::ready(())
     }
+
+    /// Returns true if the item at the given index path matches the query of the
+    /// [`Dropdown::searchable`] filter, default is to match the item's title by substring.
+    fn match_query(&self, ix: IndexPath, query: &str) -> bool {
+        self.item(ix).map_or(false, |item| item.matches(query))
+    }
 }
 
 impl<T: DropdownItem> DropdownDelegate for Vec<T> {
@@ -142,6 +148,57 @@ struct DropdownListDelegate<D: DropdownDelegate + 'static> {
     delegate: D,
     dropdown: WeakEntity<DropdownState<D>>,
     selected_index: Option<IndexPath>,
+    /// The matched rows of each section by the search query, `None` to show all items.
+    matched_rows: Option<Vec<Vec<usize>>>,
+}
+
+impl<D> DropdownListDelegate<D>
+where
+    D: DropdownDelegate + 'static,
+{
+    /// Convert the index path of the list to the index path of the delegate.
+    fn item_ix(&self, ix: IndexPath) -> IndexPath {
+        match &self.matched_rows {
+            Some(matched_rows) => matched_rows
+                .get(ix.section)
+                .and_then(|rows| rows.get(ix.row))
+                .map_or(ix, |row| ix.row(*row)),
+            None => ix,
+        }
+    }
+
+    /// Convert the index path of the delegate to the index path of the list,
+    /// returns `None` if the item is filtered out.
+    fn list_ix(&self, ix: IndexPath) -> Option<IndexPath> {
+        match &self.matched_rows {
+            Some(matched_rows) => matched_rows
+                .get(ix.section)?
+                .iter()
+                .position(|row| *row == ix.row)
+                .map(|row| ix.row(row)),
+            None => Some(ix),
+        }
+    }
+
+    fn update_matched_rows(&mut self, query: &str, cx: &App) {
+        if query.is_empty() {
+            self.matched_rows = None;
+            return;
+        }
+
+        self.matched_rows = Some(
+            (0..self.delegate.sections_count(cx))
+                .map(|section| {
+                    (0..self.delegate.items_count(section))
+                        .filter(|row| {
+                            let ix = IndexPath::default().section(section).row(*row);
+                            self.delegate.match_query(ix, query)
+                        })
+                        .collect()
+                })
+                .collect(),
+        );
+    }
 }
 
 impl<D> ListDelegate for DropdownListDelegate<D>
@@ -155,7 +212,10 @@ where
     }
 
     fn items_count(&self, section: usize, _: &App) -> usize {
-        self.delegate.items_count(section)
+        match &self.matched_rows {
+            Some(matched_rows) => matched_rows.get(section).map_or(0, |rows| rows.len()),
+            None => self.delegate.items_count(section),
+        }
     }
 
     fn render_section_header(
@@ -194,7 +254,7 @@ where
             .upgrade()
             .map_or(Size::Medium, |dropdown| dropdown.read(cx).size);
 
-        if let Some(item) = self.delegate.item(ix) {
+        if let Some(item) = self.delegate.item(self.item_ix(ix)) {
             let list_item = DropdownListItem::new(ix.row)
                 .selected(selected)
                 .with_size(size)
@@ -216,9 +276,14 @@ where
     }
 
     fn confirm(&mut self, _secondary: bool, window: &mut Window, cx: &mut Context<List<Self>>) {
+        // The items can't be selected while loading.
+        if self.loading(cx) {
+            return;
+        }
+
         let selected_value = self
             .selected_index
-            .and_then(|ix| self.delegate.item(ix))
+            .and_then(|ix| self.delegate.item(self.item_ix(ix)))
             .map(|item| item.value().clone());
         let dropdown = self.dropdown.clone();
 
@@ -238,8 +303,19 @@ where
         window: &mut Window,
         cx: &mut Context<List<Self>>,
     ) -> Task<()> {
-        self.dropdown.upgrade().map_or(Task::ready(()), |dropdown| {
+        let search = self.dropdown.upgrade().map_or(Task::ready(()), |dropdown| {
             dropdown.update(cx, |_, cx| self.delegate.perform_search(query, window, cx))
+        });
+        self.update_matched_rows(query, cx);
+
+        // Match again after the delegate's search is done, the items may have changed.
+        let query = query.to_string();
+        cx.spawn_in(window, async move |list, window| {
+            search.await;
+            _ = list.update_in(window, |list, _, cx| {
+                list.delegate_mut().update_matched_rows(&query, cx);
+                cx.notify();
+            });
         })
     }
 
@@ -252,6 +328,12 @@ where
         self.selected_index = ix;
     }
 
+    fn loading(&self, cx: &App) -> bool {
+        self.dropdown
+            .upgrade()
+            .map_or(false, |dropdown| dropdown.read(cx).loading)
+    }
+
     fn render_empty(&self, window: &mut Window, cx: &mut Context<List<Self>>) -> impl IntoElement {
         if let Some(empty) = self
             .dropdown
@@ -259,6 +341,14 @@ where
             .and_then(|dropdown| dropdown.read(cx).empty.as_ref())
         {
             empty(window, cx).into_any_element()
+        } else if self.matched_rows.is_some() {
+            h_flex()
+                .px_2()
+                .py_1p5()
+                .text_sm()
+                .text_color(cx.theme().muted_foreground)
+                .child(t!("Dropdown.no_matches").to_string())
+                .into_any_element()
         } else {
             h_flex()
                 .justify_center()
@@ -272,6 +362,8 @@ where
 
 pub enum DropdownEvent<D: DropdownDelegate + 'static> {
     Confirm(Option<<D::Item as DropdownItem>::Value>),
+    /// Emitted every time the menu is opened, e.g. to start loading the items.
+    OpenRequested,
 }
 
 /// State of the [`Dropdown`].
@@ -283,6 +375,10 @@ pub struct DropdownState<D: DropdownDelegate + 'static> {
     /// Store the bounds of the input
     bounds: Bounds<Pixels>,
     open: bool,
+    /// Whether the query input is shown, see [`Dropdown::searchable`].
+    searchable: bool,
+    /// Whether the items are loading, see [`Dropdown::loading`].
+    loading: bool,
     selected_value: Option<<D::Item as DropdownItem>::Value>,
     _subscriptions: Vec<Subscription>,
 }
@@ -302,6 +398,9 @@ pub struct Dropdown<D: DropdownDelegate + 'static> {
     menu_width: Length,
     disabled: bool,
     appearance: bool,
+    searchable: Option<bool>,
+    loading: bool,
+    escape_hatches: Vec<EscapeHatch>,
 }
 
 #[derive(Debug, Clone)]
@@ -506,6 +605,7 @@ where
             delegate,
             dropdown: cx.entity().downgrade(),
             selected_index,
+            matched_rows: None,
         };
 
         let searchable = delegate.delegate.searchable();
@@ -532,6 +632,8 @@ where
             size: Size::Medium,
             selected_value: None,
             open: false,
+            searchable,
+            loading: false,
             bounds: Bounds::default(),
             empty: None,
             _subscriptions,
@@ -556,6 +658,7 @@ where
         cx: &mut Context<Self>,
     ) {
         self.list.update(cx, |list, cx| {
+            let selected_index = selected_index.and_then(|ix| list.delegate().list_ix(ix));
             list._set_selected_index(selected_index, window, cx);
         });
         self.update_selected_value(window, cx);
@@ -575,7 +678,8 @@ where
     }
 
     pub fn selected_index(&self, cx: &App) -> Option<IndexPath> {
-        self.list.read(cx).selected_index()
+        let list = self.list.read(cx);
+        list.selected_index().map(|ix| list.delegate().item_ix(ix))
     }
 
     fn update_selected_value(&mut self, _: &Window, cx: &App) {
@@ -593,6 +697,11 @@ where
         self.focus_handle.focus(window);
     }
 
+    fn open_menu(&mut self, cx: &mut Context<Self>) {
+        self.open = true;
+        cx.emit(DropdownEvent::OpenRequested);
+    }
+
     fn on_blur(&mut self, window: &mut Window, cx: &mut Context<Self>) {
         // When the dropdown and dropdown menu are both not focused, close the dropdown menu.
         if self.list.focus_handle(cx).is_focused(window) || self.focus_handle.is_focused(window) {
@@ -614,7 +723,7 @@ where
 
     fn down(&mut self, _: &SelectNext, window: &mut Window, cx: &mut Context<Self>) {
         if !self.open {
-            self.open = true;
+            self.open_menu(cx);
         }
 
         self.list.focus_handle(cx).focus(window);
@@ -626,7 +735,7 @@ where
         cx.propagate();
 
         if !self.open {
-            self.open = true;
+            self.open_menu(cx);
             cx.notify();
         } else {
             self.list.focus_handle(cx).focus(window);
@@ -636,8 +745,10 @@ where
     fn toggle_menu(&mut self, _: &ClickEvent, window: &mut Window, cx: &mut Context<Self>) {
         cx.stop_propagation();
 
-        self.open = !self.open;
         if self.open {
+            self.open = false;
+        } else {
+            self.open_menu(cx);
             self.list.focus_handle(cx).focus(window);
         }
         cx.notify();
@@ -657,14 +768,24 @@ where
         cx.emit(DropdownEvent::Confirm(None));
     }
 
-    /// Set the items for the dropdown.
+    /// Set the items for the dropdown, e.g. to push the items once they are loaded.
+    ///
+    /// The items are filtered again by the current search query.
     pub fn set_items(&mut self, items: D, _: &mut Window, cx: &mut Context<Self>)
     where
         D: DropdownDelegate + 'static,
     {
-        self.list.update(cx, |list, _| {
-            list.delegate_mut().delegate = items;
+        self.list.update(cx, |list, cx| {
+            let query = list
+                .query_input()
+                .map(|input| input.read(cx).value().to_string())
+                .unwrap_or_default();
+            let delegate = list.delegate_mut();
+            delegate.delegate = items;
+            delegate.update_matched_rows(&query, cx);
+            cx.notify();
         });
+        cx.notify();
     }
 }
 
@@ -695,9 +816,20 @@ where
             menu_width: Length::Auto,
             disabled: false,
             appearance: true,
+            searchable: None,
+            loading: false,
+            escape_hatches: vec![],
         }
     }
 
+    /// Add an [`EscapeHatch`] region, the click inside it will not close the dropdown menu.
+    ///
+    /// The dropdown input is always an escape-hatch, click it again to close the menu.
+    pub fn escape_hatch(mut self, hatch: &EscapeHatch) -> Self {
+        self.escape_hatches.push(hatch.clone());
+        self
+    }
+
     /// Set the width of the dropdown menu, default: Length::Auto
     pub fn menu_width(mut self, width: impl Into<Length>) -> Self {
         self.menu_width = width.into();
@@ -749,6 +881,24 @@ where
         self
     }
 
+    /// Set to show a query input at the top of the menu to filter the items,
+    /// default is [`DropdownDelegate::searchable`].
+    ///
+    /// The items are filtered by [`DropdownDelegate::match_query`].
+    pub fn searchable(mut self, searchable: bool) -> Self {
+        self.searchable = Some(searchable);
+        self
+    }
+
+    /// Set to show a loading skeleton in the menu instead of the items, default: false
+    ///
+    /// The items can't be selected while loading,
+    /// use [`DropdownEvent::OpenRequested`] to start loading the items when the menu is opened.
+    pub fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
     /// Returns the title element for the dropdown input.
     fn display_title(&self, _: &Window, cx: &App) -> impl IntoElement {
         let default_title = div()
@@ -858,10 +1008,28 @@ where
             });
         }
 
+        if let Some(searchable) = self.searchable {
+            if searchable != self.state.read(cx).searchable {
+                self.state.update(cx, |this, cx| {
+                    this.searchable = searchable;
+                    this.list.update(cx, |list, cx| {
+                        list.set_query_visible(searchable, window, cx);
+                        list.delegate_mut().matched_rows = None;
+                    });
+                });
+            }
+        }
+
+        if self.loading != self.state.read(cx).loading {
+            self.state.update(cx, |this, cx| {
+                this.loading = self.loading;
+                this.list.update(cx, |_, cx| cx.notify());
+            });
+        }
+
         let state = self.state.read(cx);
         let show_clean = self.cleanable && state.selected_index(cx).is_some();
         let bounds = state.bounds;
-        let allow_open = !(state.open || self.disabled);
         let outline_visible = state.open || is_focused && !self.disabled;
         let popup_radius = cx.theme().radius.min(px(8.));
 
@@ -901,7 +1069,7 @@ where
                     .input_text_size(self.size)
                     .refine_style(&self.style)
                     .when(outline_visible, |this| this.focused_border(cx))
-                    .when(allow_open, |this| {
+                    .when(!self.disabled, |this| {
                         this.on_click(window.listener_for(&self.state, DropdownState::toggle_menu))
                     })
                     .child(
@@ -982,12 +1150,17 @@ where
                                         .shadow_md()
                                         .child(state.list.clone()),
                                 )
-                                .on_mouse_down_out(window.listener_for(
-                                    &self.state,
-                                    |this, _, window, cx| {
-                                        this.escape(&Cancel, window, cx);
-                                    },
-                                )),
+                                .map(|this| {
+                                    let state = self.state.clone();
+                                    DismissableLayer::new()
+                                        .escape_region(bounds)
+                                        .escape_hatches(&self.escape_hatches)
+                                        .apply(this, move |_, window, cx| {
+                                            state.update(cx, |this, cx| {
+                                                this.escape(&Cancel, window, cx);
+                                            })
+                                        })
+                                }),
                         ),
                     )
                     .with_priority(1),
@@ -0,0 +1,215 @@
+use gpui::{
+    div, linear_color_stop, linear_gradient, prelude::FluentBuilder as _, relative,
+    transparent_black, Axis, Background, Div, Hsla, ParentElement as _, Rgba, Styled as _,
+};
+
+use crate::AxisExt as _;
+
+/// The fill color of a track, used by [`crate::slider::Slider`] and [`crate::progress::Progress`].
+///
+/// The positions of the stops are the percentage of the track, from 0.0 to 1.0.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FillColor {
+    /// Fill with a single color.
+    Solid(Hsla),
+    /// Fill with a gradient along the track, each stop is `(position, color)`.
+    Gradient(Vec<(f32, Hsla)>),
+    /// Fill with the color of the last threshold that the value reached, each threshold is `(position, color)`.
+    ///
+    /// For example `[(0.0, green), (0.6, yellow), (0.85, red)]`.
+    Thresholds(Vec<(f32, Hsla)>),
+}
+
+impl From<Hsla> for FillColor {
+    fn from(color: Hsla) -> Self {
+        Self::Solid(color)
+    }
+}
+
+/// A part of the fill, the `start` and `end` are the percentage of the track.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct FillSegment {
+    start: f32,
+    end: f32,
+    from: Hsla,
+    to: Hsla,
+}
+
+impl FillColor {
+    /// Returns the color at the given position (0.0 to 1.0) of the track.
+    pub fn color_at(&self, position: f32) -> Hsla {
+        match self {
+            Self::Solid(color) => *color,
+            Self::Thresholds(thresholds) => {
+                let thresholds = sorted_stops(thresholds);
+                thresholds
+                    .iter()
+                    .rev()
+                    .find(|(threshold, _)| *threshold <= position)
+                    .or(thresholds.first())
+                    .map(|(_, color)| *color)
+                    .unwrap_or(transparent_black())
+            }
+            Self::Gradient(stops) => {
+                let stops = sorted_stops(stops);
+                let Some(first) = stops.first() else {
+                    return transparent_black();
+                };
+                if position <= first.0 {
+                    return first.1;
+                }
+
+                for pair in stops.windows(2) {
+                    let (start, from) = pair[0];
+                    let (end, to) = pair[1];
+                    if position <= end {
+                        if end <= start {
+                            return to;
+                        }
+                        return mix(from, to, (position - start) / (end - start));
+                    }
+                }
+
+                stops.last().map(|(_, color)| *color).unwrap_or(first.1)
+            }
+        }
+    }
+
+    /// Split the fill of the range `start..end` into segments,
+    /// each segment is a linear gradient between two colors.
+    fn segments(&self, start: f32, end: f32) -> Vec<FillSegment> {
+        if end <= start {
+            return vec![];
+        }
+
+        match self {
+            Self::Solid(_) | Self::Thresholds(_) => {
+                let color = self.color_at(end);
+                vec![FillSegment {
+                    start,
+                    end,
+                    from: color,
+                    to: color,
+                }]
+            }
+            Self::Gradient(stops) => {
+                let mut points = vec![start];
+                points.extend(
+                    sorted_stops(stops)
+                        .into_iter()
+                        .map(|(position, _)| position)
+                        .filter(|position| *position > start && *position < end),
+                );
+                points.push(end);
+                points.dedup();
+
+                points
+                    .windows(2)
+                    .map(|pair| FillSegment {
+                        start: pair[0],
+                        end: pair[1],
+                        from: self.color_at(pair[0]),
+                        to: self.color_at(pair[1]),
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    /// Apply the fill to the `fill` element, which is the element covering the range `start..end` of the track.
+    pub(crate) fn apply(&self, fill: Div, axis: Axis, start: f32, end: f32) -> Div {
+        let segments = self.segments(start, end);
+        match segments.as_slice() {
+            [] => return fill,
+            [segment] if segment.from == segment.to => return fill.bg(segment.to),
+            [segment] => return fill.bg(gradient(axis, segment.from, segment.to)),
+            _ => {}
+        }
+
+        let size = end - start;
+        fill.overflow_hidden()
+            .children(segments.into_iter().map(|segment| {
+                let offset = relative((segment.start - start) / size);
+                let length = relative((segment.end - segment.start) / size);
+
+                div()
+                    .absolute()
+                    .map(|this| {
+                        if axis.is_horizontal() {
+                            this.top_0().bottom_0().left(offset).w(length)
+                        } else {
+                            this.left_0().right_0().bottom(offset).h(length)
+                        }
+                    })
+                    .bg(gradient(axis, segment.from, segment.to))
+            }))
+    }
+}
+
+fn gradient(axis: Axis, from: Hsla, to: Hsla) -> Background {
+    // The value increases from left to right, or from bottom to top.
+    let angle = if axis.is_horizontal() { 90. } else { 0. };
+    linear_gradient(
+        angle,
+        linear_color_stop(from, 0.),
+        linear_color_stop(to, 1.),
+    )
+}
+
+fn sorted_stops(stops: &[(f32, Hsla)]) -> Vec<(f32, Hsla)> {
+    let mut stops: Vec<_> = stops
+        .iter()
+        .map(|(position, color)| (position.clamp(0., 1.), *color))
+        .collect();
+    stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+    stops
+}
+
+fn mix(from: Hsla, to: Hsla, t: f32) -> Hsla {
+    let from = from.to_rgb();
+    let to = to.to_rgb();
+    let t = t.clamp(0., 1.);
+
+    Rgba {
+        r: from.r + (to.r - from.r) * t,
+        g: from.g + (to.g - from.g) * t,
+        b: from.b + (to.b - from.b) * t,
+        a: from.a + (to.a - from.a) * t,
+    }
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::{blue, green, red};
+
+    use super::FillColor;
+
+    #[test]
+    fn test_thresholds_color_at() {
+        let fill = FillColor::Thresholds(vec![(0.85, red()), (0., green()), (0.6, blue())]);
+        assert_eq!(fill.color_at(0.), green());
+        assert_eq!(fill.color_at(0.59), green());
+        assert_eq!(fill.color_at(0.6), blue());
+        assert_eq!(fill.color_at(0.9), red());
+        assert_eq!(fill.color_at(1.), red());
+    }
+
+    #[test]
+    fn test_gradient_segments() {
+        let fill = FillColor::Gradient(vec![(0., green()), (0.5, blue()), (1., red())]);
+        assert_eq!(fill.color_at(0.), green());
+        assert_eq!(fill.color_at(0.5), blue());
+        assert_eq!(fill.color_at(1.), red());
+
+        let segments = fill.segments(0., 0.8);
+        assert_eq!(segments.len(), 2);
+        assert_eq!((segments[0].start, segments[0].end), (0., 0.5));
+        assert_eq!((segments[1].start, segments[1].end), (0.5, 0.8));
+        assert_eq!(segments[0].from, green());
+        assert_eq!(segments[1].from, blue());
+
+        assert_eq!(fill.segments(0.2, 0.4).len(), 1);
+        assert!(fill.segments(0.5, 0.5).is_empty());
+    }
+}
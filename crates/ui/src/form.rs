@@ -1,12 +1,24 @@
-use std::rc::{Rc, Weak};
+use std::{
+    any::Any,
+    collections::HashMap,
+    rc::{Rc, Weak},
+    time::Duration,
+};
 
 use gpui::{
-    div, prelude::FluentBuilder as _, px, AlignItems, AnyElement, AnyView, App, Axis, Div, Element,
-    ElementId, FocusHandle, InteractiveElement as _, IntoElement, ParentElement, Pixels, Rems,
-    RenderOnce, SharedString, Styled, Window,
+    canvas, div, prelude::FluentBuilder as _, px, AlignItems, AnyElement, AnyView, App, Axis,
+    Bounds, Context, Div, Element, ElementId, Entity, FocusHandle, InteractiveElement as _,
+    IntoElement, ParentElement, Pixels, Rems, RenderOnce, ScrollHandle, SharedString, Styled,
+    Subscription, Task, Window,
+};
+
+use crate::{
+    h_flex, indicator::Indicator, v_flex, ActiveTheme as _, AxisExt, FocusableCycle, Icon,
+    IconName, Sizable, Size, StyledExt,
 };
 
-use crate::{h_flex, v_flex, ActiveTheme as _, AxisExt, FocusableCycle, Sizable, Size, StyledExt};
+/// The debounce delay to run the async validator after the value of the field is changed.
+const ASYNC_VALIDATE_DEBOUNCE: Duration = Duration::from_millis(300);
 
 /// Create a new form with a vertical layout.
 pub fn v_form() -> Form {
@@ -23,10 +35,343 @@ pub fn form_field() -> FormField {
     FormField::new()
 }
 
+/// When the fields of the [`Form`] are validated, see [`FormState::validate_mode`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ValidateMode {
+    /// Validate all the fields on submit.
+    #[default]
+    OnSubmit,
+    /// Validate a field when it loses the focus (set by [`FormField::track_focus`]),
+    /// and all the fields on submit.
+    OnBlur,
+}
+
+type Validator = Rc<dyn Fn(&App) -> Result<(), SharedString>>;
+
+#[derive(Clone)]
+struct AsyncValidator {
+    /// Update the last value of the field, returns true if the value is changed.
+    changed: Rc<dyn Fn(&mut Option<Box<dyn Any>>, &App) -> bool>,
+    validate: Rc<dyn Fn(&mut App) -> Task<Result<(), SharedString>>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AsyncStatus {
+    Pending,
+    Valid,
+}
+
+/// The validation state of a [`Form`], keeps the error messages of the fields.
+pub struct FormState {
+    mode: ValidateMode,
+    errors: HashMap<usize, SharedString>,
+    /// The validators and the focus handles of the fields, synced from the [`Form`] on render.
+    validators: HashMap<usize, Validator>,
+    async_validators: HashMap<usize, AsyncValidator>,
+    focus_handles: HashMap<usize, FocusHandle>,
+    field_bounds: HashMap<usize, Bounds<Pixels>>,
+    /// The last values of the fields with async validator, to revalidate on change.
+    async_values: HashMap<usize, Option<Box<dyn Any>>>,
+    async_status: HashMap<usize, AsyncStatus>,
+    on_submit: Option<Rc<dyn Fn(&mut Window, &mut App)>>,
+    scroll_handle: Option<ScrollHandle>,
+    _blur_subscriptions: HashMap<usize, Subscription>,
+    /// The in-flight async validations, replace it to cancel the previous one.
+    _async_tasks: HashMap<usize, Task<()>>,
+    _submit_task: Task<()>,
+}
+
+impl FormState {
+    pub fn new(_: &mut Window, _: &mut Context<Self>) -> Self {
+        Self {
+            mode: ValidateMode::default(),
+            errors: HashMap::new(),
+            validators: HashMap::new(),
+            async_validators: HashMap::new(),
+            focus_handles: HashMap::new(),
+            field_bounds: HashMap::new(),
+            async_values: HashMap::new(),
+            async_status: HashMap::new(),
+            on_submit: None,
+            scroll_handle: None,
+            _blur_subscriptions: HashMap::new(),
+            _async_tasks: HashMap::new(),
+            _submit_task: Task::ready(()),
+        }
+    }
+
+    /// Set when to validate the fields, default is [`ValidateMode::OnSubmit`].
+    pub fn validate_mode(mut self, mode: ValidateMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Set the scroll handle of the scrollable container of the form,
+    /// to scroll the first invalid field into view on submit.
+    pub fn scroll_handle(mut self, scroll_handle: &ScrollHandle) -> Self {
+        self.scroll_handle = Some(scroll_handle.clone());
+        self
+    }
+
+    /// Returns the error message of the field at the index.
+    pub fn error(&self, ix: usize) -> Option<&SharedString> {
+        self.errors.get(&ix)
+    }
+
+    /// Returns true if there is no error in the form.
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Returns true if the async validator of the field at the index is running.
+    pub fn is_validating(&self, ix: usize) -> bool {
+        self.async_status.get(&ix) == Some(&AsyncStatus::Pending)
+    }
+
+    /// Clear all the error messages.
+    pub fn clear_errors(&mut self, cx: &mut Context<Self>) {
+        self.errors.clear();
+        cx.notify();
+    }
+
+    /// Run all the sync validators of the fields, returns true if all the fields are valid.
+    ///
+    /// The async validators are not awaited, the last resolved errors of them are kept,
+    /// use [`FormState::validate_async`] to wait for them.
+    ///
+    /// The first invalid field will be focused and scrolled into view.
+    pub fn validate(&mut self, window: &mut Window, cx: &mut Context<Self>) -> bool {
+        self.run_validators(cx);
+        self.focus_first_error(window)
+    }
+
+    /// Run all the validators of the fields, include the async validators.
+    ///
+    /// The returned task resolves to true if all the fields are valid.
+    pub fn validate_async(&mut self, window: &mut Window, cx: &mut Context<Self>) -> Task<bool> {
+        self.run_validators(cx);
+
+        let mut ixs = self.async_validators.keys().copied().collect::<Vec<_>>();
+        ixs.sort();
+        let mut tasks = vec![];
+        for ix in ixs {
+            // Cancel the debounced validation, it will be replaced by this one.
+            self._async_tasks.remove(&ix);
+            if self.errors.contains_key(&ix) {
+                self.async_status.remove(&ix);
+                continue;
+            }
+
+            let validator = self.async_validators[&ix].clone();
+            self.async_status.insert(ix, AsyncStatus::Pending);
+            tasks.push((ix, (validator.validate)(cx)));
+        }
+        cx.notify();
+
+        cx.spawn_in(window, async move |this, cx| {
+            let mut results = vec![];
+            for (ix, task) in tasks {
+                results.push((ix, task.await));
+            }
+
+            this.update_in(cx, |this, window, cx| {
+                for (ix, result) in results {
+                    this.apply_async_result(ix, result, cx);
+                }
+                this.focus_first_error(window)
+            })
+            .unwrap_or(false)
+        })
+    }
+
+    /// Validate the form, then call the [`Form::on_submit`] if all the fields are valid.
+    ///
+    /// The async validators are awaited before deciding to submit.
+    pub fn submit(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let validate = self.validate_async(window, cx);
+        self._submit_task = cx.spawn_in(window, async move |this, cx| {
+            if !validate.await {
+                return;
+            }
+
+            _ = this.update_in(cx, |this, window, cx| {
+                if let Some(on_submit) = this.on_submit.clone() {
+                    on_submit(window, cx);
+                }
+            });
+        });
+    }
+
+    fn run_validators(&mut self, cx: &mut Context<Self>) {
+        let mut ixs = self.validators.keys().copied().collect::<Vec<_>>();
+        ixs.sort();
+        for ix in ixs {
+            self.validate_field(ix, cx);
+        }
+        cx.notify();
+    }
+
+    /// Focus and scroll to the first invalid field, returns true if there is no error.
+    fn focus_first_error(&self, window: &mut Window) -> bool {
+        let Some(first_ix) = self.errors.keys().min().copied() else {
+            return true;
+        };
+        if let Some(focus_handle) = self.focus_handles.get(&first_ix) {
+            focus_handle.focus(window);
+        }
+        self.scroll_to_field(first_ix);
+        false
+    }
+
+    fn validate_field(&mut self, ix: usize, cx: &mut Context<Self>) {
+        let Some(validator) = self.validators.get(&ix).cloned() else {
+            return;
+        };
+
+        match validator(cx) {
+            Ok(()) => self.errors.remove(&ix),
+            Err(err) => self.errors.insert(ix, err),
+        };
+        cx.notify();
+    }
+
+    /// Run the async validator of the field after the debounce delay,
+    /// the previous in-flight validation of the field will be cancelled.
+    fn validate_field_async(&mut self, ix: usize, cx: &mut Context<Self>) {
+        let Some(validator) = self.async_validators.get(&ix).cloned() else {
+            return;
+        };
+
+        // The error is stale after the value changed.
+        self.errors.remove(&ix);
+        if let Some(Err(_)) = self.validators.get(&ix).map(|validate| validate(cx)) {
+            self._async_tasks.remove(&ix);
+            self.async_status.remove(&ix);
+            cx.notify();
+            return;
+        }
+
+        self.async_status.insert(ix, AsyncStatus::Pending);
+        cx.notify();
+
+        let task = cx.spawn(async move |this, cx| {
+            cx.background_executor()
+                .timer(ASYNC_VALIDATE_DEBOUNCE)
+                .await;
+
+            let Ok(task) = this.update(cx, |_, cx| (validator.validate)(cx)) else {
+                return;
+            };
+            let result = task.await;
+            _ = this.update(cx, |this, cx| this.apply_async_result(ix, result, cx));
+        });
+        self._async_tasks.insert(ix, task);
+    }
+
+    fn apply_async_result(
+        &mut self,
+        ix: usize,
+        result: Result<(), SharedString>,
+        cx: &mut Context<Self>,
+    ) {
+        match result {
+            Ok(()) => {
+                self.errors.remove(&ix);
+                self.async_status.insert(ix, AsyncStatus::Valid);
+            }
+            Err(err) => {
+                self.errors.insert(ix, err);
+                self.async_status.remove(&ix);
+            }
+        }
+        cx.notify();
+    }
+
+    fn scroll_to_field(&self, ix: usize) {
+        let (Some(scroll_handle), Some(field_bounds)) =
+            (self.scroll_handle.as_ref(), self.field_bounds.get(&ix))
+        else {
+            return;
+        };
+
+        let viewport = scroll_handle.bounds();
+        let mut offset = scroll_handle.offset();
+        if field_bounds.top() < viewport.top() {
+            offset.y += viewport.top() - field_bounds.top();
+        } else if field_bounds.bottom() > viewport.bottom() {
+            offset.y -= field_bounds.bottom() - viewport.bottom();
+        }
+        scroll_handle.set_offset(offset);
+    }
+
+    /// Sync the validators and the focus handles of the fields from the [`Form`].
+    fn sync(&mut self, form: &Form, window: &mut Window, cx: &mut Context<Self>) {
+        self.on_submit = form.on_submit.clone();
+        self.validators.clear();
+        self.async_validators.clear();
+        self.focus_handles.clear();
+        for (ix, field) in form.fields.iter().enumerate() {
+            if let Some(validator) = field.validator.clone() {
+                self.validators.insert(ix, validator);
+            }
+            if let Some(validator) = field.async_validator.clone() {
+                self.async_validators.insert(ix, validator);
+            }
+            if let Some(focus_handle) = field.focus_handle.clone() {
+                self.focus_handles.insert(ix, focus_handle);
+            }
+        }
+        self.errors.retain(|ix, _| {
+            self.validators.contains_key(ix) || self.async_validators.contains_key(ix)
+        });
+        self.async_values
+            .retain(|ix, _| self.async_validators.contains_key(ix));
+        self.async_status
+            .retain(|ix, _| self.async_validators.contains_key(ix));
+        self._async_tasks
+            .retain(|ix, _| self.async_validators.contains_key(ix));
+
+        // Revalidate the fields with async validator when the value is changed.
+        let mut changed_ixs = vec![];
+        for (ix, validator) in self.async_validators.iter() {
+            let last_value = self.async_values.entry(*ix).or_default();
+            if (validator.changed)(last_value, cx) {
+                changed_ixs.push(*ix);
+            }
+        }
+        for ix in changed_ixs {
+            self.validate_field_async(ix, cx);
+        }
+
+        if self.mode != ValidateMode::OnBlur {
+            self._blur_subscriptions.clear();
+            return;
+        }
+
+        self._blur_subscriptions
+            .retain(|ix, _| self.validators.contains_key(ix));
+        for (ix, focus_handle) in self.focus_handles.iter() {
+            if !self.validators.contains_key(ix) || self._blur_subscriptions.contains_key(ix) {
+                continue;
+            }
+
+            let ix = *ix;
+            self._blur_subscriptions.insert(
+                ix,
+                cx.on_focus_out(focus_handle, window, move |this, _, _, cx| {
+                    this.validate_field(ix, cx);
+                }),
+            );
+        }
+    }
+}
+
 #[derive(IntoElement)]
 pub struct Form {
     fields: Vec<FormField>,
     props: FieldProps,
+    state: Option<Entity<FormState>>,
+    on_submit: Option<Rc<dyn Fn(&mut Window, &mut App)>>,
 }
 
 #[derive(Clone, Copy)]
@@ -56,6 +401,8 @@ impl Form {
         Self {
             props: FieldProps::default(),
             fields: Vec::new(),
+            state: None,
+            on_submit: None,
         }
     }
 
@@ -93,6 +440,19 @@ impl Form {
         self
     }
 
+    /// Bind the [`FormState`] to validate the fields and show the error messages.
+    pub fn state(mut self, state: &Entity<FormState>) -> Self {
+        self.state = Some(state.clone());
+        self
+    }
+
+    /// Set the callback when the form is submitted by [`FormState::submit`],
+    /// it is only called when all the fields are valid.
+    pub fn on_submit(mut self, on_submit: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.on_submit = Some(Rc::new(on_submit));
+        self
+    }
+
     /// Add a child to the form.
     pub fn child(mut self, field: impl Into<FormField>) -> Self {
         self.fields.push(field.into());
@@ -174,6 +534,7 @@ impl From<SharedString> for FieldBuilder {
 #[derive(IntoElement)]
 pub struct FormField {
     id: ElementId,
+    ix: usize,
     form: Weak<Form>,
     label: Option<FieldBuilder>,
     no_label_indent: bool,
@@ -186,12 +547,19 @@ pub struct FormField {
     /// Alignment of the form field.
     align_items: Option<AlignItems>,
     props: FieldProps,
+    validator: Option<Validator>,
+    async_validator: Option<AsyncValidator>,
+    /// The state and the validation status, synced from the [`Form`].
+    state: Option<Entity<FormState>>,
+    error: Option<SharedString>,
+    async_status: Option<AsyncStatus>,
 }
 
 impl FormField {
     pub fn new() -> Self {
         Self {
             id: 0.into(),
+            ix: 0,
             form: Weak::new(),
             label: None,
             description: None,
@@ -202,6 +570,11 @@ impl FormField {
             focus_handle: None,
             align_items: None,
             props: FieldProps::default(),
+            validator: None,
+            async_validator: None,
+            state: None,
+            error: None,
+            async_status: None,
         }
     }
 
@@ -271,6 +644,77 @@ impl FormField {
         self
     }
 
+    /// Set the validator of the form field, see [`FormState::validate`].
+    ///
+    /// The `value` reads the value of the field, e.g.: the text of an `InputState`,
+    /// then the `validate` returns the error message if the value is invalid.
+    ///
+    /// ```ignore
+    /// form_field()
+    ///     .label("Email")
+    ///     .child(TextInput::new(&email_input))
+    ///     .validator(
+    ///         move |cx| email_input.read(cx).value(),
+    ///         |value| match value.contains('@') {
+    ///             true => Ok(()),
+    ///             false => Err("Please enter a valid email.".into()),
+    ///         },
+    ///     )
+    /// ```
+    pub fn validator<V: 'static>(
+        mut self,
+        value: impl Fn(&App) -> V + 'static,
+        validate: impl Fn(&V) -> Result<(), SharedString> + 'static,
+    ) -> Self {
+        self.validator = Some(Rc::new(move |cx| validate(&value(cx))));
+        self
+    }
+
+    /// Set the async validator of the form field, e.g.: check the username is available on the server.
+    ///
+    /// The `validate` runs after the `value` changed (debounced), and the in-flight
+    /// validation is cancelled by the new one, a spinner is shown in the field while pending.
+    ///
+    /// The [`FormState::submit`] waits for all the async validators before submitting.
+    ///
+    /// ```ignore
+    /// form_field()
+    ///     .label("Username")
+    ///     .child(TextInput::new(&username_input))
+    ///     .async_validator(
+    ///         move |cx| username_input.read(cx).value(),
+    ///         |value, cx| cx.spawn(async move |_| check_username(value).await),
+    ///     )
+    /// ```
+    pub fn async_validator<V: PartialEq + 'static>(
+        mut self,
+        value: impl Fn(&App) -> V + 'static,
+        validate: impl Fn(V, &mut App) -> Task<Result<(), SharedString>> + 'static,
+    ) -> Self {
+        let value = Rc::new(value);
+        self.async_validator = Some(AsyncValidator {
+            changed: Rc::new({
+                let value = value.clone();
+                move |last_value, cx| {
+                    let value = value(cx);
+                    let Some(last) = last_value.as_ref() else {
+                        // Keep the initial value, not to validate before any edit.
+                        *last_value = Some(Box::new(value));
+                        return false;
+                    };
+                    if last.downcast_ref::<V>() == Some(&value) {
+                        return false;
+                    }
+
+                    *last_value = Some(Box::new(value));
+                    true
+                }
+            }),
+            validate: Rc::new(move |cx| validate(value(cx), cx)),
+        });
+        self
+    }
+
     pub fn parent(mut self, form: &Rc<Form>) -> Self {
         self.form = Rc::downgrade(form);
         self
@@ -281,10 +725,21 @@ impl FormField {
     /// This is internal API for sync props from From.
     fn props(mut self, ix: usize, props: FieldProps) -> Self {
         self.id = ix.into();
+        self.ix = ix;
         self.props = props;
         self
     }
 
+    /// Set the form state and the error message of the field.
+    fn state(mut self, state: Option<Entity<FormState>>, cx: &App) -> Self {
+        if let Some(state) = state.as_ref().map(|state| state.read(cx)) {
+            self.error = state.error(self.ix).cloned();
+            self.async_status = state.async_status.get(&self.ix).copied();
+        }
+        self.state = state;
+        self
+    }
+
     /// Align the form field items to the start, this is the default.
     pub fn items_start(mut self) -> Self {
         self.align_items = Some(AlignItems::Start);
@@ -394,13 +849,32 @@ impl RenderOnce for FormField {
                                 }),
                         )
                     })
-                    .child(
-                        div()
-                            .w_full()
-                            .flex_1()
-                            .overflow_x_hidden()
-                            .child(self.child),
-                    ),
+                    .child(div().w_full().flex_1().overflow_x_hidden().map(|this| {
+                        if self.async_validator.is_none() {
+                            return this.child(self.child);
+                        }
+
+                        this.child(
+                            h_flex()
+                                .gap_2()
+                                .child(div().flex_1().child(self.child))
+                                .child(div().flex_shrink_0().size_4().map(|this| {
+                                    match self.async_status {
+                                        Some(AsyncStatus::Pending) => this.child(
+                                            Indicator::new()
+                                                .small()
+                                                .color(cx.theme().muted_foreground),
+                                        ),
+                                        Some(AsyncStatus::Valid) => this.child(
+                                            Icon::new(IconName::Check)
+                                                .small()
+                                                .text_color(cx.theme().success),
+                                        ),
+                                        None => this,
+                                    }
+                                })),
+                        )
+                    })),
             )
             .child(
                 // Other
@@ -421,11 +895,41 @@ impl RenderOnce for FormField {
                         )
                     }),
             )
+            .when_some(self.error, |this, error| {
+                this.child(
+                    wrap_div(layout)
+                        .gap(inner_gap)
+                        .when(has_label && layout.is_horizontal(), |this| {
+                            this.child(wrap_label(label_width))
+                        })
+                        .child(div().text_xs().text_color(cx.theme().danger).child(error)),
+                )
+            })
+            .when_some(self.state, |this, state| {
+                // Save the bounds to scroll into view when the field is invalid.
+                let ix = self.ix;
+                this.relative().child(
+                    canvas(
+                        move |bounds, _, cx| {
+                            state.update(cx, |state, _| {
+                                state.field_bounds.insert(ix, bounds);
+                            })
+                        },
+                        |_, _, _, _| {},
+                    )
+                    .absolute()
+                    .size_full(),
+                )
+            })
     }
 }
 impl RenderOnce for Form {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
         let props = self.props;
+        let state = self.state.clone();
+        if let Some(state) = &state {
+            state.update(cx, |state, cx| state.sync(&self, window, cx));
+        }
 
         let gap = match props.size {
             Size::XSmall | Size::Small => px(6.),
@@ -437,7 +941,189 @@ impl RenderOnce for Form {
             self.fields
                 .into_iter()
                 .enumerate()
-                .map(|(ix, field)| field.props(ix, props)),
+                .map(|(ix, field)| field.props(ix, props).state(state.clone(), cx)),
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        cell::{Cell, RefCell},
+        rc::Rc,
+        time::Duration,
+    };
+
+    use gpui::{AppContext as _, SharedString, TestAppContext};
+
+    use super::{form_field, v_form, FormState};
+
+    #[gpui::test]
+    fn test_validate_in_order(cx: &mut TestAppContext) {
+        let cx = cx.add_empty_window();
+        let state = cx.update(|window, cx| cx.new(|cx| FormState::new(window, cx)));
+        let focus_handles =
+            cx.update(|_, cx| (0..3).map(|_| cx.focus_handle()).collect::<Vec<_>>());
+
+        let validated = Rc::new(RefCell::new(vec![]));
+        let name = Rc::new(RefCell::new(SharedString::default()));
+        let email = Rc::new(RefCell::new(SharedString::default()));
+        let form = v_form()
+            .child(form_field().track_focus(&focus_handles[0]).validator(
+                {
+                    let validated = validated.clone();
+                    move |_| validated.borrow_mut().push(0)
+                },
+                |_| Ok(()),
+            ))
+            .child(form_field().track_focus(&focus_handles[1]).validator(
+                {
+                    let validated = validated.clone();
+                    let name = name.clone();
+                    move |_| {
+                        validated.borrow_mut().push(1);
+                        name.borrow().clone()
+                    }
+                },
+                |name| {
+                    if name.is_empty() {
+                        return Err("Name is required".into());
+                    }
+                    Ok(())
+                },
+            ))
+            .child(form_field().track_focus(&focus_handles[2]).validator(
+                {
+                    let validated = validated.clone();
+                    let email = email.clone();
+                    move |_| {
+                        validated.borrow_mut().push(2);
+                        email.borrow().clone()
+                    }
+                },
+                |email| {
+                    if !email.contains('@') {
+                        return Err("Invalid email".into());
+                    }
+                    Ok(())
+                },
+            ));
+
+        let validate = |cx: &mut gpui::VisualTestContext| {
+            state.update_in(cx, |state, window, cx| {
+                state.sync(&form, window, cx);
+                state.validate(window, cx)
+            })
+        };
+
+        // All the fields are validated in order, and the first invalid one is focused.
+        assert!(!validate(cx));
+        assert_eq!(*validated.borrow(), vec![0, 1, 2]);
+        state.read_with(cx, |state, _| {
+            assert_eq!(state.error(0), None);
+            assert_eq!(state.error(1).map(|e| e.as_ref()), Some("Name is required"));
+            assert_eq!(state.error(2).map(|e| e.as_ref()), Some("Invalid email"));
+            assert!(!state.is_valid());
+        });
+        cx.update(|window, _| assert!(focus_handles[1].is_focused(window)));
+
+        // Fix the first invalid field, the next invalid one is focused.
+        *name.borrow_mut() = "Jason".into();
+        assert!(!validate(cx));
+        state.read_with(cx, |state, _| assert_eq!(state.error(1), None));
+        cx.update(|window, _| assert!(focus_handles[2].is_focused(window)));
+
+        *email.borrow_mut() = "jason@example.com".into();
+        assert!(validate(cx));
+        state.read_with(cx, |state, _| assert!(state.is_valid()));
+    }
+    #[gpui::test]
+    fn test_submit_gating(cx: &mut TestAppContext) {
+        let cx = cx.add_empty_window();
+        let state = cx.update(|window, cx| cx.new(|cx| FormState::new(window, cx)));
+
+        let submitted = Rc::new(Cell::new(0));
+        let name = Rc::new(RefCell::new(SharedString::default()));
+        let username = Rc::new(RefCell::new(SharedString::from("taken")));
+        let form = v_form()
+            .on_submit({
+                let submitted = submitted.clone();
+                move |_, _| submitted.set(submitted.get() + 1)
+            })
+            .child(form_field().validator(
+                {
+                    let name = name.clone();
+                    move |_| name.borrow().clone()
+                },
+                |name| {
+                    if name.is_empty() {
+                        return Err("Name is required".into());
+                    }
+                    Ok(())
+                },
+            ))
+            .child(form_field().async_validator(
+                {
+                    let username = username.clone();
+                    move |_| username.borrow().clone()
+                },
+                |username, cx| {
+                    cx.spawn(async move |cx| {
+                        cx.background_executor().timer(Duration::from_secs(1)).await;
+                        if username == "taken" {
+                            return Err("Username is taken".into());
+                        }
+                        Ok(())
+                    })
+                },
+            ));
+
+        let submit = |cx: &mut gpui::VisualTestContext| {
+            state.update_in(cx, |state, window, cx| {
+                state.sync(&form, window, cx);
+                state.submit(window, cx);
+            });
+        };
+        let resolve = |cx: &mut gpui::VisualTestContext| {
+            cx.executor().advance_clock(Duration::from_secs(1));
+            cx.run_until_parked();
+        };
+
+        // Blocked by the sync error, even if the async validator passes.
+        *username.borrow_mut() = "free".into();
+        submit(cx);
+        resolve(cx);
+        state.read_with(cx, |state, _| {
+            assert_eq!(state.error(0).map(|e| e.as_ref()), Some("Name is required"));
+            assert_eq!(state.error(1), None);
+        });
+        assert_eq!(submitted.get(), 0);
+
+        // Blocked while the async validator is pending.
+        *name.borrow_mut() = "Jason".into();
+        *username.borrow_mut() = "taken".into();
+        submit(cx);
+        cx.run_until_parked();
+        state.read_with(cx, |state, _| assert!(state.is_validating(1)));
+        assert_eq!(submitted.get(), 0);
+
+        // Blocked by the failed async validator.
+        resolve(cx);
+        state.read_with(cx, |state, _| {
+            assert!(!state.is_validating(1));
+            assert_eq!(state.error(0), None);
+            assert_eq!(
+                state.error(1).map(|e| e.as_ref()),
+                Some("Username is taken")
+            );
+        });
+        assert_eq!(submitted.get(), 0);
+
+        // Submitted once all the validators passed.
+        *username.borrow_mut() = "free".into();
+        submit(cx);
+        resolve(cx);
+        state.read_with(cx, |state, _| assert!(state.is_valid()));
+        assert_eq!(submitted.get(), 1);
+    }
+}
@@ -1,12 +1,26 @@
 use crate::go_board::core::*;
 use gpui::{px, Pixels, Point, Size};
 
+/// A move played by [`Board::play`], with the state to restore on undo
+#[derive(Debug, Clone)]
+struct Move {
+    pos: Pos,
+    stone: Stone,
+    captured: Vec<Pos>,
+    /// The ko point before the move.
+    ko: Option<Pos>,
+    /// The last move before the move.
+    last_move: Option<Pos>,
+}
+
 /// Simplified Go board component with ergonomic API
 #[derive(Clone)]
 pub struct Board {
     data: BoardData,
     pub theme: Theme,
     show_coordinates: bool,
+    history: Vec<Move>,
+    redo_stack: Vec<Move>,
 }
 
 impl Board {
@@ -16,6 +30,8 @@ impl Board {
             data: BoardData::standard(),
             theme: Theme::default(),
             show_coordinates: true,
+            history: Vec::new(),
+            redo_stack: Vec::new(),
         }
     }
 
@@ -25,6 +41,8 @@ impl Board {
             data: BoardData::new(width, height),
             theme: Theme::default(),
             show_coordinates: true,
+            history: Vec::new(),
+            redo_stack: Vec::new(),
         }
     }
 
@@ -108,6 +126,18 @@ impl Board {
         self
     }
 
+    /// Set the point that is forbidden to play by the ko rule
+    pub fn ko(mut self, pos: Option<Pos>) -> Self {
+        self.data.ko = pos;
+        self
+    }
+
+    /// Set the side to move
+    pub fn to_play(mut self, stone: Stone) -> Self {
+        self.data.to_play = stone;
+        self
+    }
+
     /// Add territory marking
     pub fn territory(mut self, pos: Pos, territory: Territory) -> Self {
         self.data.set_territory(pos, Some(territory));
@@ -120,6 +150,90 @@ impl Board {
         self
     }
 
+    // =============================================================================
+    // MOVE HISTORY
+    // =============================================================================
+
+    /// Play a stone for the side to move and record it in the history, see [`BoardData::play`]
+    ///
+    /// The undone moves are discarded, returns the captured positions, or `None` if the move is illegal.
+    pub fn play(&mut self, pos: Pos) -> Option<Vec<Pos>> {
+        let captured = self.play_move(pos, self.data.to_play)?;
+        self.redo_stack.clear();
+        Some(captured)
+    }
+
+    fn play_move(&mut self, pos: Pos, stone: Stone) -> Option<Vec<Pos>> {
+        let ko = self.data.ko;
+        let last_move = self.last_move_pos();
+        let captured = self.data.play(pos, stone)?;
+        self.history.push(Move {
+            pos,
+            stone,
+            captured: captured.clone(),
+            ko,
+            last_move,
+        });
+        Some(captured)
+    }
+
+    fn last_move_pos(&self) -> Option<Pos> {
+        self.data
+            .selections
+            .iter()
+            .find(|(_, selection)| selection.is_last_move)
+            .map(|(pos, _)| *pos)
+    }
+
+    /// Take back the last move played by [`Board::play`], the captured stones are restored.
+    ///
+    /// Returns the position of the undone move, or `None` if there is no move to undo.
+    pub fn undo(&mut self) -> Option<Pos> {
+        let mv = self.history.pop()?;
+
+        self.data.set_stone(mv.pos, EMPTY);
+        for pos in &mv.captured {
+            self.data.set_stone(*pos, -mv.stone);
+        }
+        self.data.ko = mv.ko;
+        self.data.to_play = mv.stone;
+        self.data
+            .selections
+            .retain(|_, selection| !selection.is_last_move);
+        if let Some(pos) = mv.last_move {
+            self.data
+                .set_selection(pos, Some(Selection::last_move(pos)));
+        }
+
+        let pos = mv.pos;
+        self.redo_stack.push(mv);
+        Some(pos)
+    }
+
+    /// Replay the last move taken back by [`Board::undo`].
+    ///
+    /// Returns the position of the replayed move, or `None` if there is no move to redo.
+    pub fn redo(&mut self) -> Option<Pos> {
+        let mv = self.redo_stack.pop()?;
+        self.play_move(mv.pos, mv.stone)?;
+        Some(mv.pos)
+    }
+
+    /// Returns true if there is a move to undo.
+    pub fn can_undo(&self) -> bool {
+        !self.history.is_empty()
+    }
+
+    /// Returns true if there is a move to redo.
+    pub fn can_redo(&self) -> bool {
+        !self.redo_stack.is_empty()
+    }
+
+    /// Returns the number of the moves played in the history, 0 at the start.
+    pub fn move_number(&self) -> usize {
+        self.history.len()
+    }
+
     // =============================================================================
     // CLEAR OPERATIONS
     // =============================================================================
@@ -269,4 +383,44 @@ mod tests {
         assert!(board.marker_at(Pos::new(1, 1)).is_some());
         assert!(board.data().ghosts.contains_key(&Pos::new(5, 5)));
     }
+
+    #[test]
+    fn test_undo_redo() {
+        let mut board = Board::with_size(9, 9);
+        assert_eq!(board.undo(), None);
+        assert_eq!(board.redo(), None);
+
+        // Black captures the white stone at (1, 0).
+        for pos in [(0, 0), (1, 0), (2, 0), (8, 8), (1, 1)] {
+            assert!(board.play(Pos::new(pos.0, pos.1)).is_some());
+        }
+        assert_eq!(board.stone_at(Pos::new(1, 0)), EMPTY);
+        assert_eq!(board.move_number(), 5);
+
+        assert_eq!(board.undo(), Some(Pos::new(1, 1)));
+        assert_eq!(board.stone_at(Pos::new(1, 1)), EMPTY);
+        assert_eq!(board.stone_at(Pos::new(1, 0)), WHITE);
+        assert_eq!(board.data().to_play, BLACK);
+        assert_eq!(board.move_number(), 4);
+        assert!(board.data().selections[&Pos::new(8, 8)].is_last_move);
+        assert!(board.can_redo());
+
+        assert_eq!(board.redo(), Some(Pos::new(1, 1)));
+        assert_eq!(board.stone_at(Pos::new(1, 0)), EMPTY);
+        assert_eq!(board.data().to_play, WHITE);
+        assert_eq!(board.move_number(), 5);
+        assert!(!board.can_redo());
+
+        // A new move discards the undone moves.
+        board.undo();
+        board.undo();
+        assert!(board.play(Pos::new(4, 4)).is_some());
+        assert!(!board.can_redo());
+        assert_eq!(board.move_number(), 4);
+
+        while board.undo().is_some() {}
+        assert!(board.data().stones.is_empty());
+        assert!(board.data().selections.is_empty());
+        assert_eq!(board.move_number(), 0);
+    }
 }
@@ -1,5 +1,5 @@
 use gpui::{rgb, Hsla, Modifiers, MouseButton, Rgba, SharedString};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 // =============================================================================
 // CORE TYPES - Simplified and consolidated
@@ -318,6 +318,10 @@ pub struct BoardData {
     pub lines: Vec<Line>,
     pub size: (usize, usize),
     pub range: Range,
+    /// The point that is forbidden to play by the ko rule.
+    pub ko: Option<Pos>,
+    /// The side to move.
+    pub to_play: Stone,
 }
 
 impl BoardData {
@@ -325,6 +329,7 @@ impl BoardData {
         Self {
             size: (width, height),
             range: Range::full(width, height),
+            to_play: BLACK,
             ..Default::default()
         }
     }
@@ -406,6 +411,7 @@ impl BoardData {
         self.territory.clear();
         self.selections.clear();
         self.lines.clear();
+        self.ko = None;
     }
 
     pub fn clear_selections(&mut self) {
@@ -439,6 +445,149 @@ impl BoardData {
             .retain(|&pos, _| pos.x < new_size.0 && pos.y < new_size.1);
         self.selections
             .retain(|&pos, _| pos.x < new_size.0 && pos.y < new_size.1);
+        self.ko = self
+            .ko
+            .filter(|pos| pos.x < new_size.0 && pos.y < new_size.1);
+    }
+
+    // Rules
+
+    fn neighbors(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        let (x, y) = (pos.x as isize, pos.y as isize);
+        [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)]
+            .into_iter()
+            .filter(|(x, y)| *x >= 0 && *y >= 0)
+            .map(|(x, y)| Pos::new(x as usize, y as usize))
+            .filter(|pos| self.is_valid_pos(*pos))
+    }
+
+    /// Returns the stones of the group at `pos` and the liberties of the group.
+    ///
+    /// Both are empty if there is no stone at `pos`.
+    pub fn group(&self, pos: Pos) -> (HashSet<Pos>, HashSet<Pos>) {
+        let mut stones = HashSet::new();
+        let mut liberties = HashSet::new();
+        let stone = self.get_stone(pos);
+        if stone == EMPTY {
+            return (stones, liberties);
+        }
+
+        let mut stack = vec![pos];
+        stones.insert(pos);
+        while let Some(current) = stack.pop() {
+            for neighbor in self.neighbors(current) {
+                match self.get_stone(neighbor) {
+                    EMPTY => {
+                        liberties.insert(neighbor);
+                    }
+                    other if other == stone && stones.insert(neighbor) => stack.push(neighbor),
+                    _ => {}
+                }
+            }
+        }
+
+        (stones, liberties)
+    }
+
+    /// Returns true if `stone` can be played at `pos`.
+    ///
+    /// The point must be empty, not the ko point, and the move must not be a suicide.
+    pub fn is_legal_move(&self, pos: Pos, stone: Stone) -> bool {
+        if stone == EMPTY
+            || !self.is_valid_pos(pos)
+            || self.get_stone(pos) != EMPTY
+            || self.ko == Some(pos)
+        {
+            return false;
+        }
+
+        self.neighbors(pos).any(|neighbor| {
+            let neighbor_stone = self.get_stone(neighbor);
+            if neighbor_stone == EMPTY {
+                return true;
+            }
+
+            let (_, liberties) = self.group(neighbor);
+            if neighbor_stone == stone {
+                // Connect to a group that still has other liberties.
+                liberties.len() > 1
+            } else {
+                // Capture the opponent group.
+                liberties.len() == 1
+            }
+        })
+    }
+
+    /// Returns all the legal moves of `stone`.
+    pub fn legal_moves(&self, stone: Stone) -> Vec<Pos> {
+        let (width, height) = self.size;
+        (0..height)
+            .flat_map(|y| (0..width).map(move |x| Pos::new(x, y)))
+            .filter(|pos| self.is_legal_move(*pos, stone))
+            .collect()
+    }
+
+    /// Play `stone` at `pos`, remove the captured stones, and update the ko point,
+    /// the last move and the side to move.
+    ///
+    /// Returns the captured positions, or `None` if the move is illegal.
+    pub fn play(&mut self, pos: Pos, stone: Stone) -> Option<Vec<Pos>> {
+        if !self.is_legal_move(pos, stone) {
+            return None;
+        }
+
+        self.set_stone(pos, stone);
+
+        let mut captured = vec![];
+        for neighbor in self.neighbors(pos).collect::<Vec<_>>() {
+            if self.get_stone(neighbor) != -stone {
+                continue;
+            }
+
+            let (stones, liberties) = self.group(neighbor);
+            if liberties.is_empty() {
+                for stone_pos in stones {
+                    self.stones.remove(&stone_pos);
+                    captured.push(stone_pos);
+                }
+            }
+        }
+
+        // A single stone capturing a single stone, and left with only one liberty is a ko.
+        let (stones, liberties) = self.group(pos);
+        self.ko = if captured.len() == 1 && stones.len() == 1 && liberties.len() == 1 {
+            Some(captured[0])
+        } else {
+            None
+        };
+
+        self.selections
+            .retain(|_, selection| !selection.is_last_move);
+        self.set_selection(pos, Some(Selection::last_move(pos)));
+        self.to_play = -stone;
+
+        Some(captured)
+    }
+}
+
+/// The overlays derived from the game state, each one can be toggled independently.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overlays {
+    /// Mark the last move, default: true
+    pub last_move: bool,
+    /// Mark the ko-forbidden point, default: true
+    pub ko: bool,
+    /// Show faint dots on the legal points of the side to move, default: false
+    pub legal_moves: bool,
+}
+
+impl Default for Overlays {
+    fn default() -> Self {
+        Self {
+            last_move: true,
+            ko: true,
+            legal_moves: false,
+        }
     }
 }
 
@@ -478,6 +627,10 @@ pub enum NavEvent {
     MoveFocus(Pos),
     Select(Pos),
     ClearSelection,
+    /// Take back the last move in the history.
+    Undo,
+    /// Replay the last undone move in the history.
+    Redo,
 }
 
 // =============================================================================
@@ -765,4 +918,55 @@ mod tests {
         // Grid lines should be visible
         assert!(theme.grid_lines.l < 0.5 || theme.grid_lines.l > 0.5);
     }
+
+    #[test]
+    fn test_capture_and_ko() {
+        let mut data = BoardData::new(9, 9);
+        // . B W .
+        // B W . W
+        // . B W .
+        for (x, y, stone) in [
+            (1, 0, BLACK),
+            (0, 1, BLACK),
+            (1, 2, BLACK),
+            (2, 0, WHITE),
+            (1, 1, WHITE),
+            (3, 1, WHITE),
+            (2, 2, WHITE),
+        ] {
+            data.set_stone(Pos::new(x, y), stone);
+        }
+
+        let captured = data.play(Pos::new(2, 1), BLACK).unwrap();
+        assert_eq!(captured, vec![Pos::new(1, 1)]);
+        assert_eq!(data.get_stone(Pos::new(1, 1)), EMPTY);
+        assert_eq!(data.ko, Some(Pos::new(1, 1)));
+        assert_eq!(data.to_play, WHITE);
+        assert!(data.selections[&Pos::new(2, 1)].is_last_move);
+
+        // White can not retake the ko immediately.
+        assert!(!data.is_legal_move(Pos::new(1, 1), WHITE));
+        assert!(data.play(Pos::new(1, 1), WHITE).is_none());
+
+        // After a move elsewhere, the ko is resolved.
+        data.play(Pos::new(8, 8), WHITE).unwrap();
+        assert_eq!(data.ko, None);
+        assert!(!data.selections.contains_key(&Pos::new(2, 1)));
+    }
+
+    #[test]
+    fn test_legal_moves() {
+        let mut data = BoardData::new(3, 3);
+        data.set_stone(Pos::new(1, 0), BLACK);
+        data.set_stone(Pos::new(0, 1), BLACK);
+
+        // Suicide is illegal.
+        assert!(!data.is_legal_move(Pos::new(0, 0), WHITE));
+        assert!(data.is_legal_move(Pos::new(0, 0), BLACK));
+        // Occupied point is illegal.
+        assert!(!data.is_legal_move(Pos::new(1, 0), WHITE));
+
+        assert_eq!(data.legal_moves(WHITE).len(), 6);
+        assert_eq!(data.legal_moves(BLACK).len(), 7);
+    }
 }
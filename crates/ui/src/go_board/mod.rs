@@ -5,6 +5,7 @@
 pub mod board;
 pub mod core;
 pub mod render;
+mod sgf;
 pub mod view;
 
 // =============================================================================
@@ -16,8 +17,8 @@ pub use render::Renderer;
 pub use view::BoardView;
 
 pub use core::{
-    BoardData, Ghost, Heat, Line, Marker, NavEvent, Pos, PosEvent, Range, Selection, Stone,
-    Territory, Theme, BLACK, EMPTY, WHITE,
+    BoardData, Ghost, Heat, Line, Marker, NavEvent, Overlays, Pos, PosEvent, Range, Selection,
+    Stone, Territory, Theme, BLACK, EMPTY, WHITE,
 };
 
 // =============================================================================
@@ -109,12 +110,13 @@ where
 ///     .stone(Pos::new(9, 9), BLACK);
 /// ```
 ///
-/// ## Loading from SGF-like format
+/// ## Loading and saving SGF
 ///
 /// ```rust
-/// use crate::go_board::{board::from_position_string, *};
+/// use crate::go_board::*;
 ///
-/// let board = from_position_string((19, 19), "B[dd],W[pp],B[pd]");
+/// let board = Board::from_sgf("(;SZ[19]AB[dd][pd];W[pp])").unwrap();
+/// let sgf = board.to_sgf();
 /// let view = BoardView::new(board);
 /// ```
 
@@ -17,6 +17,9 @@ const SELECTION_SIZE_RATIO: f32 = 0.9;
 const GREEN_HUE: f32 = 120.0 / 360.0;
 const RED_HUE: f32 = 0.0;
 const GHOST_SATURATION: f32 = 0.6;
+const KO_SIZE_RATIO: f32 = 0.5;
+const LEGAL_HINT_SIZE_RATIO: f32 = 0.25;
+const LEGAL_HINT_ALPHA: f32 = 0.25;
 
 #[derive(Debug, Clone)]
 pub struct ResponsiveSpacing {
@@ -46,6 +49,7 @@ pub struct Renderer {
     theme: Theme,
     coord_offset: Point<Pixels>,
     spacing: ResponsiveSpacing,
+    overlays: Overlays,
 }
 
 impl Renderer {
@@ -56,6 +60,7 @@ impl Renderer {
             theme,
             coord_offset: point(px(0.0), px(0.0)),
             spacing,
+            overlays: Overlays::default(),
         }
     }
 
@@ -71,6 +76,11 @@ impl Renderer {
         self
     }
 
+    pub fn with_overlays(mut self, overlays: Overlays) -> Self {
+        self.overlays = overlays;
+        self
+    }
+
     pub fn render(&self, data: &BoardData, show_coordinates: bool) -> impl IntoElement {
         let range = &data.range;
         let grid_width = range.width() as f32 * self.vertex_size;
@@ -114,6 +124,7 @@ impl Renderer {
             .child(self.render_ghosts(data))
             .child(self.render_lines(data))
             .child(self.render_markers(data))
+            .child(self.render_hints(data))
             .child(self.render_selections(data));
 
         board
@@ -537,6 +548,10 @@ impl Renderer {
         let range = &data.range;
 
         for (&pos, selection) in &data.selections {
+            if selection.is_last_move && !self.overlays.last_move {
+                continue;
+            }
+
             if range.contains(pos) {
                 let pixel_pos = self.pos_to_pixel_grid(pos, range);
                 let selection_size = self.vertex_size * SELECTION_SIZE_RATIO;
@@ -586,6 +601,56 @@ impl Renderer {
         selections
     }
 
+    fn render_hints(&self, data: &BoardData) -> impl IntoElement {
+        let mut hints = div().absolute().inset_0();
+        let range = &data.range;
+
+        if self.overlays.legal_moves {
+            let color = match data.to_play {
+                BLACK => self.theme.black_stone,
+                _ => self.theme.white_stone,
+            };
+            let hint_size = self.vertex_size * LEGAL_HINT_SIZE_RATIO;
+
+            for pos in data.legal_moves(data.to_play) {
+                if range.contains(pos) {
+                    let pixel_pos = self.pos_to_pixel_grid(pos, range);
+                    hints = hints.child(
+                        div()
+                            .absolute()
+                            .left(pixel_pos.x - px(hint_size / 2.0))
+                            .top(pixel_pos.y - px(hint_size / 2.0))
+                            .w(px(hint_size))
+                            .h(px(hint_size))
+                            .rounded_full()
+                            .bg(color)
+                            .opacity(LEGAL_HINT_ALPHA),
+                    );
+                }
+            }
+        }
+
+        if self.overlays.ko {
+            if let Some(pos) = data.ko.filter(|pos| range.contains(*pos)) {
+                let pixel_pos = self.pos_to_pixel_grid(pos, range);
+                let ko_size = self.vertex_size * KO_SIZE_RATIO;
+                hints = hints.child(
+                    div()
+                        .absolute()
+                        .left(pixel_pos.x - px(ko_size / 2.0))
+                        .top(pixel_pos.y - px(ko_size / 2.0))
+                        .w(px(ko_size))
+                        .h(px(ko_size))
+                        .bg(self.theme.background)
+                        .border_2()
+                        .border_color(rgb(0xd03030)),
+                );
+            }
+        }
+
+        hints
+    }
+
     fn render_lines(&self, data: &BoardData) -> impl IntoElement {
         let mut lines = div().absolute().inset_0();
         let range = &data.range;
@@ -793,57 +858,37 @@ impl Renderer {
         )
     }
 
+    /// Returns the star points (hoshi) of the board, works for any `width × height`.
+    ///
+    /// The corner points are placed on the 3rd line (4th line for the side >= 12),
+    /// the center point only when both sides are odd, and the side points only
+    /// on the odd side longer than 13. No star points when any side is less than 7.
     fn calculate_star_points(&self, data: &BoardData) -> Vec<Pos> {
         let (width, height) = data.size;
-        let mut points = Vec::new();
-
-        match (width, height) {
-            (19, 19) => {
-                points.extend([
-                    Pos::new(3, 3),
-                    Pos::new(9, 3),
-                    Pos::new(15, 3),
-                    Pos::new(3, 9),
-                    Pos::new(9, 9),
-                    Pos::new(15, 9),
-                    Pos::new(3, 15),
-                    Pos::new(9, 15),
-                    Pos::new(15, 15),
-                ]);
-            }
-            (13, 13) => {
-                points.extend([
-                    Pos::new(3, 3),
-                    Pos::new(9, 3),
-                    Pos::new(6, 6),
-                    Pos::new(3, 9),
-                    Pos::new(9, 9),
-                ]);
-            }
-            (9, 9) => {
-                points.extend([
-                    Pos::new(2, 2),
-                    Pos::new(6, 2),
-                    Pos::new(4, 4),
-                    Pos::new(2, 6),
-                    Pos::new(6, 6),
-                ]);
-            }
-            _ => {
-                if width >= 7 && height >= 7 {
-                    let offset = if width <= 11 { 2 } else { 3 };
-                    points.extend([
-                        Pos::new(offset, offset),
-                        Pos::new(width - 1 - offset, offset),
-                        Pos::new(offset, height - 1 - offset),
-                        Pos::new(width - 1 - offset, height - 1 - offset),
-                    ]);
-
-                    if width % 2 == 1 && height % 2 == 1 {
-                        points.push(Pos::new(width / 2, height / 2));
-                    }
-                }
-            }
+        if width.min(height) < 7 {
+            return Vec::new();
+        }
+
+        let near = |size: usize| if size <= 11 { 2 } else { 3 };
+        let (near_x, near_y) = (near(width), near(height));
+        let (far_x, far_y) = (width - 1 - near_x, height - 1 - near_y);
+        let (middle_x, middle_y) = (width / 2, height / 2);
+
+        let mut points = vec![
+            Pos::new(near_x, near_y),
+            Pos::new(far_x, near_y),
+            Pos::new(near_x, far_y),
+            Pos::new(far_x, far_y),
+        ];
+
+        if width % 2 == 1 && height % 2 == 1 && width != 7 && height != 7 {
+            points.push(Pos::new(middle_x, middle_y));
+        }
+        if width % 2 == 1 && width > 13 {
+            points.extend([Pos::new(middle_x, near_y), Pos::new(middle_x, far_y)]);
+        }
+        if height % 2 == 1 && height > 13 {
+            points.extend([Pos::new(near_x, middle_y), Pos::new(far_x, middle_y)]);
         }
 
         points
@@ -874,16 +919,23 @@ impl Renderer {
         }
     }
 
+    /// The column label skips the `I` by convention, and the columns after `Z`
+    /// are labeled with two letters: `AA`, `AB`, ...
     fn x_coordinate_label(&self, x: usize) -> String {
-        if x >= 25 {
-            return "?".to_string();
-        }
-        let letter = if x < 8 {
-            (b'A' + x as u8) as char
+        const LETTERS: &[u8] = b"ABCDEFGHJKLMNOPQRSTUVWXYZ";
+        let count = LETTERS.len();
+
+        if x < count {
+            (LETTERS[x] as char).to_string()
+        } else if x < count * (count + 1) {
+            format!(
+                "{}{}",
+                LETTERS[x / count - 1] as char,
+                LETTERS[x % count] as char
+            )
         } else {
-            (b'A' + x as u8 + 1) as char
-        };
-        letter.to_string()
+            "?".to_string()
+        }
     }
 
     fn y_coordinate_label(&self, y: usize, board_height: usize) -> String {
@@ -1008,8 +1060,11 @@ mod tests {
         let renderer = Renderer::new(20.0, Theme::default());
 
         // Test coordinate labels for very large boards
-        assert_eq!(renderer.x_coordinate_label(25), "?");
-        assert_eq!(renderer.x_coordinate_label(26), "?");
+        assert_eq!(renderer.x_coordinate_label(25), "AA");
+        assert_eq!(renderer.x_coordinate_label(26), "AB");
+        assert_eq!(renderer.x_coordinate_label(49), "AZ");
+        assert_eq!(renderer.x_coordinate_label(50), "BA");
+        assert_eq!(renderer.x_coordinate_label(25 * 26), "?");
 
         // Test coordinate labels for standard positions
         assert_eq!(renderer.x_coordinate_label(0), "A");
@@ -1042,6 +1097,71 @@ mod tests {
         assert!(even_stars.contains(&Pos::new(16, 16)));
     }
 
+    fn sorted_star_points(renderer: &Renderer, width: usize, height: usize) -> Vec<Pos> {
+        let mut stars = renderer.calculate_star_points(&BoardData::new(width, height));
+        stars.sort_by_key(|pos| (pos.y, pos.x));
+        stars
+    }
+
+    #[test]
+    fn test_star_points_by_size() {
+        let renderer = Renderer::new(20.0, Theme::default());
+
+        assert_eq!(
+            sorted_star_points(&renderer, 9, 9),
+            vec![
+                Pos::new(2, 2),
+                Pos::new(6, 2),
+                Pos::new(4, 4),
+                Pos::new(2, 6),
+                Pos::new(6, 6),
+            ]
+        );
+        assert_eq!(
+            sorted_star_points(&renderer, 13, 13),
+            vec![
+                Pos::new(3, 3),
+                Pos::new(9, 3),
+                Pos::new(6, 6),
+                Pos::new(3, 9),
+                Pos::new(9, 9),
+            ]
+        );
+        assert_eq!(
+            sorted_star_points(&renderer, 19, 19),
+            vec![
+                Pos::new(3, 3),
+                Pos::new(9, 3),
+                Pos::new(15, 3),
+                Pos::new(3, 9),
+                Pos::new(9, 9),
+                Pos::new(15, 9),
+                Pos::new(3, 15),
+                Pos::new(9, 15),
+                Pos::new(15, 15),
+            ]
+        );
+
+        // Rectangular board, the side points only on the long odd side.
+        assert_eq!(
+            sorted_star_points(&renderer, 19, 9),
+            vec![
+                Pos::new(3, 2),
+                Pos::new(9, 2),
+                Pos::new(15, 2),
+                Pos::new(9, 4),
+                Pos::new(3, 6),
+                Pos::new(9, 6),
+                Pos::new(15, 6),
+            ]
+        );
+
+        // Too narrow for star points.
+        assert!(sorted_star_points(&renderer, 19, 3).is_empty());
+        // No center point on 7x7.
+        assert_eq!(sorted_star_points(&renderer, 7, 7).len(), 4);
+    }
+
     #[test]
     fn test_responsive_spacing_calculation() {
         let small_spacing = ResponsiveSpacing::for_vertex_size(15.0);
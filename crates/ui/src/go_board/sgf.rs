@@ -0,0 +1,458 @@
+use crate::go_board::{board::Board, core::*};
+
+// =============================================================================
+// SGF IMPORT / EXPORT
+// =============================================================================
+
+/// A property of an SGF node, e.g. `AB[dd][pp]`.
+type Property = (String, Vec<String>);
+
+/// A game tree of an SGF collection, the nodes are the main sequence.
+#[derive(Debug, Default)]
+struct GameTree {
+    nodes: Vec<Vec<Property>>,
+    variations: Vec<GameTree>,
+}
+
+impl Board {
+    /// Serialize the current position to SGF (FF[4]).
+    ///
+    /// The stones are written as setup stones (`AB`/`AW`) in the root node, except the
+    /// last move which is written as a `B`/`W` move node, so it's restored by [`Board::from_sgf`].
+    /// The markers are written as `CR`, `MA`, `TR`, `SQ` and `LB` properties,
+    /// dots have no SGF equivalent and are skipped.
+    pub fn to_sgf(&self) -> String {
+        let data = self.data();
+        let (width, height) = data.size;
+
+        let last_move = data
+            .selections
+            .values()
+            .find(|selection| selection.is_last_move)
+            .map(|selection| selection.pos)
+            .filter(|pos| data.get_stone(*pos) != EMPTY);
+
+        let mut sgf = String::from("(;GM[1]FF[4]");
+        if width == height {
+            sgf.push_str(&format!("SZ[{}]", width));
+        } else {
+            sgf.push_str(&format!("SZ[{}:{}]", width, height));
+        }
+
+        for (ident, color) in [("AB", BLACK), ("AW", WHITE)] {
+            let points = sorted_positions(data.stones.iter().filter_map(|(pos, stone)| {
+                (*stone == color && Some(*pos) != last_move).then_some(*pos)
+            }));
+            write_property(&mut sgf, ident, points.into_iter().map(point_to_sgf));
+        }
+
+        match last_move {
+            Some(pos) => {
+                let ident = if data.get_stone(pos) == BLACK {
+                    "B"
+                } else {
+                    "W"
+                };
+                sgf.push(';');
+                write_property(&mut sgf, ident, [point_to_sgf(pos)]);
+            }
+            None if data.to_play == WHITE => sgf.push_str("PL[W]"),
+            None => {}
+        }
+
+        for ident in ["CR", "MA", "TR", "SQ", "LB"] {
+            let points =
+                sorted_positions(data.markers.iter().filter_map(|(pos, marker)| {
+                    (marker_ident(marker) == Some(ident)).then_some(*pos)
+                }));
+            write_property(
+                &mut sgf,
+                ident,
+                points.into_iter().map(|pos| match data.get_marker(pos) {
+                    Some(Marker::Label { text, .. }) => {
+                        format!("{}:{}", point_to_sgf(pos), escape(text))
+                    }
+                    _ => point_to_sgf(pos),
+                }),
+            );
+        }
+
+        sgf.push(')');
+        sgf
+    }
+
+    /// Load the position at the end of the main line of the first game in an SGF collection.
+    ///
+    /// Setup stones (`AB`/`AW`/`AE`) are placed as-is, moves (`B`/`W`) are played with the
+    /// captures, and the markers of the last node are restored.
+    /// Returns `None` if the SGF can't be parsed.
+    pub fn from_sgf(sgf: &str) -> Option<Self> {
+        let mut parser = Parser {
+            chars: sgf.chars().collect(),
+            ix: 0,
+        };
+        let tree = parser.parse_game_tree()?;
+
+        let root = tree.nodes.first()?;
+        let (width, height) = match find_property(root, "SZ") {
+            Some(value) => match value.split_once(':') {
+                Some((width, height)) => (width.trim().parse().ok()?, height.trim().parse().ok()?),
+                None => {
+                    let size = value.trim().parse().ok()?;
+                    (size, size)
+                }
+            },
+            None => (19, 19),
+        };
+        if width == 0 || height == 0 || width > 52 || height > 52 {
+            return None;
+        }
+
+        let mut board = Board::with_size(width, height);
+        let mut tree = &tree;
+        loop {
+            for node in &tree.nodes {
+                apply_node(board.data_mut(), node);
+            }
+            // Follow the main line, the other variations are ignored.
+            match tree.variations.first() {
+                Some(variation) => tree = variation,
+                None => break,
+            }
+        }
+
+        Some(board)
+    }
+}
+
+fn apply_node(data: &mut BoardData, node: &[Property]) {
+    // Markup is only valid for the node it's defined in.
+    data.markers.clear();
+
+    for (ident, values) in node {
+        match ident.as_str() {
+            "AB" | "AW" | "AE" => {
+                let stone = match ident.as_str() {
+                    "AB" => BLACK,
+                    "AW" => WHITE,
+                    _ => EMPTY,
+                };
+                for pos in values.iter().flat_map(|value| parse_points(value)) {
+                    data.set_stone(pos, stone);
+                }
+            }
+            "B" | "W" => {
+                let stone = if ident == "B" { BLACK } else { WHITE };
+                let pos = values
+                    .first()
+                    .and_then(|value| sgf_to_point(value))
+                    .filter(|pos| data.is_valid_pos(*pos));
+                match pos {
+                    Some(pos) => {
+                        if data.play(pos, stone).is_none() {
+                            // Keep illegal moves of the record, without the captures.
+                            data.set_stone(pos, stone);
+                            data.selections
+                                .retain(|_, selection| !selection.is_last_move);
+                            data.set_selection(pos, Some(Selection::last_move(pos)));
+                            data.ko = None;
+                            data.to_play = -stone;
+                        }
+                    }
+                    // A pass, `B[]` or `B[tt]`.
+                    None => {
+                        data.selections
+                            .retain(|_, selection| !selection.is_last_move);
+                        data.ko = None;
+                        data.to_play = -stone;
+                    }
+                }
+            }
+            "PL" => match values.first().map(|value| value.trim()) {
+                Some("B") => data.to_play = BLACK,
+                Some("W") => data.to_play = WHITE,
+                _ => {}
+            },
+            "CR" | "MA" | "TR" | "SQ" => {
+                for pos in values.iter().flat_map(|value| parse_points(value)) {
+                    let marker = match ident.as_str() {
+                        "CR" => Marker::circle(),
+                        "MA" => Marker::cross(),
+                        "TR" => Marker::triangle(),
+                        _ => Marker::square(),
+                    };
+                    data.set_marker(pos, Some(marker));
+                }
+            }
+            "LB" => {
+                for value in values {
+                    let Some((point, text)) = value.split_once(':') else {
+                        continue;
+                    };
+                    if let Some(pos) = sgf_to_point(point) {
+                        data.set_marker(pos, Some(Marker::label(text)));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Returns the SGF property of the marker, dots have no SGF equivalent.
+fn marker_ident(marker: &Marker) -> Option<&'static str> {
+    match marker {
+        Marker::Circle { .. } => Some("CR"),
+        Marker::Cross { .. } => Some("MA"),
+        Marker::Triangle { .. } => Some("TR"),
+        Marker::Square { .. } => Some("SQ"),
+        Marker::Label { .. } => Some("LB"),
+        Marker::Dot { .. } => None,
+    }
+}
+
+fn find_property<'a>(node: &'a [Property], ident: &str) -> Option<&'a str> {
+    node.iter()
+        .find(|(id, _)| id == ident)
+        .and_then(|(_, values)| values.first())
+        .map(|value| value.as_str())
+}
+
+fn write_property(sgf: &mut String, ident: &str, values: impl IntoIterator<Item = String>) {
+    let mut values = values.into_iter().peekable();
+    if values.peek().is_none() {
+        return;
+    }
+
+    sgf.push_str(ident);
+    for value in values {
+        sgf.push('[');
+        sgf.push_str(&value);
+        sgf.push(']');
+    }
+}
+
+fn sorted_positions(positions: impl Iterator<Item = Pos>) -> Vec<Pos> {
+    let mut positions = positions.collect::<Vec<_>>();
+    positions.sort_by_key(|pos| (pos.y, pos.x));
+    positions
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace(']', "\\]")
+}
+
+/// Convert a coordinate to a SGF letter, `a-z` then `A-Z` for boards larger than 26.
+fn coord_to_sgf(coord: usize) -> char {
+    match coord {
+        0..=25 => (b'a' + coord as u8) as char,
+        _ => (b'A' + (coord - 26) as u8) as char,
+    }
+}
+
+fn sgf_to_coord(c: char) -> Option<usize> {
+    match c {
+        'a'..='z' => Some(c as usize - 'a' as usize),
+        'A'..='Z' => Some(c as usize - 'A' as usize + 26),
+        _ => None,
+    }
+}
+
+fn point_to_sgf(pos: Pos) -> String {
+    [coord_to_sgf(pos.x), coord_to_sgf(pos.y)].iter().collect()
+}
+
+fn sgf_to_point(value: &str) -> Option<Pos> {
+    let mut chars = value.trim().chars();
+    let x = sgf_to_coord(chars.next()?)?;
+    let y = sgf_to_coord(chars.next()?)?;
+    if chars.next().is_some() {
+        return None;
+    }
+    Some(Pos::new(x, y))
+}
+
+/// Parse a point or a compressed rectangle of points, e.g. `aa:cc`.
+fn parse_points(value: &str) -> Vec<Pos> {
+    match value.split_once(':') {
+        Some((from, to)) => {
+            let (Some(from), Some(to)) = (sgf_to_point(from), sgf_to_point(to)) else {
+                return vec![];
+            };
+            (from.y.min(to.y)..=from.y.max(to.y))
+                .flat_map(|y| (from.x.min(to.x)..=from.x.max(to.x)).map(move |x| Pos::new(x, y)))
+                .collect()
+        }
+        None => sgf_to_point(value).into_iter().collect(),
+    }
+}
+
+struct Parser {
+    chars: Vec<char>,
+    ix: usize,
+}
+
+impl Parser {
+    fn skip_whitespace(&mut self) {
+        while self.chars.get(self.ix).map_or(false, |c| c.is_whitespace()) {
+            self.ix += 1;
+        }
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.chars.get(self.ix).copied()
+    }
+
+    fn expect(&mut self, c: char) -> Option<()> {
+        if self.peek()? != c {
+            return None;
+        }
+        self.ix += 1;
+        Some(())
+    }
+
+    fn parse_game_tree(&mut self) -> Option<GameTree> {
+        self.expect('(')?;
+        let mut tree = GameTree::default();
+        while self.peek()? == ';' {
+            self.ix += 1;
+            tree.nodes.push(self.parse_node()?);
+        }
+        while self.peek()? == '(' {
+            tree.variations.push(self.parse_game_tree()?);
+        }
+        self.expect(')')?;
+        Some(tree)
+    }
+
+    fn parse_node(&mut self) -> Option<Vec<Property>> {
+        let mut node = vec![];
+        while self.peek().map_or(false, |c| c.is_ascii_alphabetic()) {
+            let start = self.ix;
+            while self
+                .chars
+                .get(self.ix)
+                .map_or(false, |c| c.is_ascii_alphabetic())
+            {
+                self.ix += 1;
+            }
+            // FF[3] allows lowercase letters in the identifiers, e.g. `AddBlack`.
+            let ident = self.chars[start..self.ix]
+                .iter()
+                .filter(|c| c.is_ascii_uppercase())
+                .collect::<String>();
+
+            let mut values = vec![];
+            while self.peek() == Some('[') {
+                self.ix += 1;
+                values.push(self.parse_value()?);
+            }
+            if values.is_empty() {
+                return None;
+            }
+            node.push((ident, values));
+        }
+        Some(node)
+    }
+
+    fn parse_value(&mut self) -> Option<String> {
+        let mut value = String::new();
+        loop {
+            let c = *self.chars.get(self.ix)?;
+            self.ix += 1;
+            match c {
+                ']' => return Some(value),
+                '\\' => {
+                    let escaped = *self.chars.get(self.ix)?;
+                    self.ix += 1;
+                    // An escaped line break is a soft line break and is removed.
+                    if escaped != '\n' && escaped != '\r' {
+                        value.push(escaped);
+                    }
+                }
+                _ => value.push(c),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_sgf() {
+        let board = Board::with_size(9, 9)
+            .stone(Pos::new(2, 2), BLACK)
+            .stone(Pos::new(6, 2), WHITE)
+            .stone(Pos::new(3, 3), BLACK)
+            .last_move(Pos::new(6, 2))
+            .marker(Pos::new(4, 4), Marker::triangle())
+            .marker(Pos::new(5, 5), Marker::label("A]"))
+            .marker(Pos::new(6, 6), Marker::dot());
+
+        assert_eq!(
+            board.to_sgf(),
+            "(;GM[1]FF[4]SZ[9]AB[cc][dd];W[gc]TR[ee]LB[ff:A\\]])"
+        );
+
+        let board = Board::with_size(13, 9).to_play(WHITE);
+        assert_eq!(board.to_sgf(), "(;GM[1]FF[4]SZ[13:9]PL[W])");
+    }
+
+    #[test]
+    fn test_sgf_round_trip() {
+        let board = Board::with_size(9, 9)
+            .stone(Pos::new(2, 2), BLACK)
+            .stone(Pos::new(3, 2), WHITE)
+            .stone(Pos::new(6, 6), BLACK)
+            .last_move(Pos::new(6, 6))
+            .marker(Pos::new(0, 0), Marker::circle())
+            .marker(Pos::new(1, 0), Marker::cross())
+            .marker(Pos::new(2, 0), Marker::square())
+            .marker(Pos::new(3, 0), Marker::label("1"));
+
+        let loaded = Board::from_sgf(&board.to_sgf()).unwrap();
+        assert_eq!(loaded.dimensions(), (9, 9));
+        assert_eq!(loaded.data().stones, board.data().stones);
+        assert_eq!(loaded.data().to_play, WHITE);
+        assert!(loaded
+            .data()
+            .selections
+            .get(&Pos::new(6, 6))
+            .map_or(false, |selection| selection.is_last_move));
+        assert_eq!(loaded.marker_at(Pos::new(0, 0)), Some(&Marker::circle()));
+        assert_eq!(loaded.marker_at(Pos::new(1, 0)), Some(&Marker::cross()));
+        assert_eq!(loaded.marker_at(Pos::new(2, 0)), Some(&Marker::square()));
+        assert_eq!(loaded.marker_at(Pos::new(3, 0)), Some(&Marker::label("1")));
+        assert_eq!(loaded.to_sgf(), board.to_sgf());
+    }
+
+    #[test]
+    fn test_from_sgf_main_line() {
+        // White captures the black stone at `ba`, the variation is ignored.
+        let sgf = "(;GM[1]SZ[5]AB[ba]AW[aa:ab][bb]\n;W[ca](;B[dd]CR[ee])(;B[cc]))";
+        let board = Board::from_sgf(sgf).unwrap();
+        assert_eq!(board.dimensions(), (5, 5));
+        assert_eq!(board.stone_at(Pos::new(1, 0)), EMPTY);
+        assert_eq!(board.stone_at(Pos::new(0, 1)), WHITE);
+        assert_eq!(board.stone_at(Pos::new(2, 0)), WHITE);
+        assert_eq!(board.stone_at(Pos::new(3, 3)), BLACK);
+        assert_eq!(board.stone_at(Pos::new(2, 2)), EMPTY);
+        assert_eq!(board.data().to_play, WHITE);
+        assert!(board.marker_at(Pos::new(4, 4)).is_some());
+
+        assert!(Board::from_sgf("(;SZ[5]AB[aa]").is_none());
+        assert!(Board::from_sgf("not sgf").is_none());
+    }
+
+    #[test]
+    fn test_sgf_points() {
+        assert_eq!(point_to_sgf(Pos::new(0, 18)), "as");
+        assert_eq!(point_to_sgf(Pos::new(26, 30)), "AE");
+        assert_eq!(sgf_to_point("AE"), Some(Pos::new(26, 30)));
+        assert_eq!(sgf_to_point("a"), None);
+        assert_eq!(parse_points("aa:bb").len(), 4);
+    }
+}
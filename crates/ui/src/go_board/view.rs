@@ -10,6 +10,7 @@ pub struct BoardView {
     board: Board,
     renderer: Renderer,
     show_coordinates: bool,
+    overlays: Overlays,
     focus: Option<Pos>,
     on_click: Option<Rc<dyn Fn(PosEvent)>>,
     on_hover: Option<Rc<dyn Fn(Option<Pos>)>>,
@@ -26,6 +27,7 @@ impl BoardView {
             board,
             renderer: Renderer::new(vertex_size, theme),
             show_coordinates: false,
+            overlays: Overlays::default(),
             focus: None,
             on_click: None,
             on_hover: None,
@@ -43,6 +45,7 @@ impl BoardView {
             board,
             renderer: Renderer::new(vertex_size, theme),
             show_coordinates: false,
+            overlays: Overlays::default(),
             focus: None,
             on_click: None,
             on_hover: None,
@@ -55,6 +58,24 @@ impl BoardView {
         self
     }
 
+    /// Set whether to mark the last move, default: true
+    pub fn show_last_move(mut self, show: bool) -> Self {
+        self.overlays.last_move = show;
+        self
+    }
+
+    /// Set whether to mark the ko-forbidden point, default: true
+    pub fn show_ko(mut self, show: bool) -> Self {
+        self.overlays.ko = show;
+        self
+    }
+
+    /// Set whether to show the legal-move hints for the side to move, default: false
+    pub fn show_legal_moves(mut self, show: bool) -> Self {
+        self.overlays.legal_moves = show;
+        self
+    }
+
     /// Calculate a default vertex size based on board dimensions
     fn calculate_default_vertex_size((width, height): (usize, usize)) -> f32 {
         // Use a reasonable default size that scales with board size
@@ -167,6 +188,18 @@ impl BoardView {
         self
     }
 
+    /// Play a stone for the side to move, see [`Board::play`]
+    ///
+    /// Returns the captured positions, or `None` if the move is illegal.
+    pub fn play(&mut self, pos: Pos) -> Option<Vec<Pos>> {
+        self.board.play(pos)
+    }
+
+    /// Returns the number of the moves played in the history, see [`Board::move_number`]
+    pub fn current_move_number(&self) -> usize {
+        self.board.move_number()
+    }
+
     /// Clear all selections
     pub fn clear_selections(mut self) -> Self {
         self.board.data_mut().clear_selections();
@@ -209,8 +242,21 @@ impl BoardView {
         }
     }
 
+    /// Map the key to the [`NavEvent`], the arrow keys move the focus,
+    /// and with the platform modifier (`cmd` on macOS, `ctrl` on others) the left and right
+    /// arrow keys navigate the move history.
     pub fn handle_key_input(&mut self, event: &KeyDownEvent) -> Option<NavEvent> {
-        let nav_event = match event.keystroke.key.as_str() {
+        let history_nav = if event.keystroke.modifiers.secondary() {
+            match event.keystroke.key.as_str() {
+                "ArrowLeft" => Some(NavEvent::Undo),
+                "ArrowRight" => Some(NavEvent::Redo),
+                _ => None,
+            }
+        } else {
+            None
+        };
+
+        let nav_event = history_nav.or_else(|| match event.keystroke.key.as_str() {
             "ArrowLeft" => self.move_focus(-1, 0).map(NavEvent::MoveFocus),
             "ArrowRight" => self.move_focus(1, 0).map(NavEvent::MoveFocus),
             "ArrowUp" => self.move_focus(0, -1).map(NavEvent::MoveFocus),
@@ -218,7 +264,7 @@ impl BoardView {
             "Enter" | "Space" => self.focus.map(NavEvent::Select),
             "Escape" => Some(NavEvent::ClearSelection),
             _ => None,
-        };
+        });
 
         if let Some(ref handler) = self.on_key {
             handler(event.clone()).or(nav_event)
@@ -227,6 +273,31 @@ impl BoardView {
         }
     }
 
+    /// Apply the [`NavEvent`] to the view, returns true if the board is changed.
+    pub fn on_nav(&mut self, event: NavEvent) -> bool {
+        match event {
+            NavEvent::MoveFocus(pos) => {
+                self.focus = Some(pos);
+                true
+            }
+            NavEvent::Select(pos) => {
+                // Call the on_click callback for keyboard selection
+                if let Some(ref handler) = self.on_click {
+                    let event =
+                        PosEvent::with_mouse_button(pos, Modifiers::default(), MouseButton::Left);
+                    handler(event);
+                }
+                false
+            }
+            NavEvent::ClearSelection => {
+                self.board.data_mut().clear_selections();
+                true
+            }
+            NavEvent::Undo => self.board.undo().is_some(),
+            NavEvent::Redo => self.board.redo().is_some(),
+        }
+    }
+
     pub fn pos_from_mouse(
         &self,
         mouse_pos: Point<Pixels>,
@@ -289,7 +360,8 @@ impl Render for BoardView {
         // Render the board content first
         let container = container.child({
             let renderer = Renderer::new(vertex_size, self.board.theme.clone())
-                .with_coordinates(self.show_coordinates);
+                .with_coordinates(self.show_coordinates)
+                .with_overlays(self.overlays);
             renderer.render(self.board.data(), self.show_coordinates)
         });
 
@@ -298,26 +370,10 @@ impl Render for BoardView {
 
         container
             .key_context("go-board")
-            .on_key_down(cx.listener(|view, event, _cx, _phase| {
+            .on_key_down(cx.listener(|view, event, _, cx| {
                 if let Some(nav_event) = view.handle_key_input(event) {
-                    match nav_event {
-                        NavEvent::MoveFocus(pos) => {
-                            println!("Focus moved to {:?}", pos);
-                        }
-                        NavEvent::Select(pos) => {
-                            // Call the on_click callback for keyboard selection
-                            if let Some(ref handler) = view.on_click {
-                                let event = PosEvent::with_mouse_button(
-                                    pos,
-                                    Modifiers::default(),
-                                    MouseButton::Left,
-                                );
-                                handler(event);
-                            }
-                        }
-                        NavEvent::ClearSelection => {
-                            view.board.data_mut().clear_selections();
-                        }
+                    if view.on_nav(nav_event) {
+                        cx.notify();
                     }
                 }
             }))
@@ -524,6 +580,21 @@ mod tests {
         assert!(board.data().selections.contains_key(&Pos::new(4, 4)));
     }
 
+    #[test]
+    fn test_overlay_toggles() {
+        let mut view = BoardView::new(Board::with_size(9, 9))
+            .show_last_move(false)
+            .show_legal_moves(true);
+        assert!(!view.overlays.last_move);
+        assert!(view.overlays.ko);
+        assert!(view.overlays.legal_moves);
+
+        assert_eq!(view.play(Pos::new(4, 4)), Some(vec![]));
+        assert_eq!(view.board().stone_at(Pos::new(4, 4)), BLACK);
+        assert_eq!(view.board().data().to_play, WHITE);
+        assert_eq!(view.play(Pos::new(4, 4)), None);
+    }
+
     #[test]
     fn test_empty_board_rendering() {
         let view = BoardView::new(Board::new());
@@ -541,4 +612,23 @@ mod tests {
         assert_eq!(board.visible_range().width(), 19);
         assert_eq!(board.visible_range().height(), 19);
     }
+
+    #[test]
+    fn test_nav_history() {
+        let mut view = BoardView::new(Board::with_size(9, 9));
+        assert!(!view.on_nav(NavEvent::Undo));
+
+        view.play(Pos::new(2, 2));
+        view.play(Pos::new(3, 3));
+        assert_eq!(view.current_move_number(), 2);
+
+        assert!(view.on_nav(NavEvent::Undo));
+        assert_eq!(view.current_move_number(), 1);
+        assert_eq!(view.board().stone_at(Pos::new(3, 3)), EMPTY);
+
+        assert!(view.on_nav(NavEvent::Redo));
+        assert_eq!(view.current_move_number(), 2);
+        assert_eq!(view.board().stone_at(Pos::new(3, 3)), WHITE);
+        assert!(!view.on_nav(NavEvent::Redo));
+    }
 }
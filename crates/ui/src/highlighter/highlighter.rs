@@ -5,7 +5,9 @@ use anyhow::{anyhow, Context, Result};
 use gpui::{App, HighlightStyle, SharedString};
 use ropey::Rope;
 use std::{
-    collections::{BTreeSet, HashMap},
+    cell::RefCell,
+    collections::{hash_map::DefaultHasher, BTreeSet, HashMap},
+    hash::{Hash, Hasher},
     ops::Range,
     slice::Chunks,
     usize,
@@ -630,6 +632,121 @@ impl SyntaxHighlighter {
     }
 }
 
+/// A styled range of the highlighted source, can be used in [`gpui::StyledText::with_highlights`].
+pub type StyledSpan = (Range<usize>, HighlightStyle);
+
+/// The max number of parsed sources to keep in the [`highlight`] cache.
+const MAX_CACHED_SOURCES: usize = 64;
+
+thread_local! {
+    /// The parsed sources, the parse result is independent of the theme.
+    static HIGHLIGHT_CACHE: RefCell<HighlightCache<SyntaxHighlighter>> =
+        RefCell::new(HighlightCache::new(MAX_CACHED_SOURCES));
+}
+
+/// A LRU cache of the values built from the sources, keyed by `(language, source hash)`.
+struct HighlightCache<T> {
+    entries: HashMap<(SharedString, u64), HighlightCacheEntry<T>>,
+    capacity: usize,
+    /// Increased on each access, to find the least recently used entry.
+    tick: u64,
+}
+
+struct HighlightCacheEntry<T> {
+    /// The source to check on hit, the hash may collide.
+    source: String,
+    value: T,
+    last_used: u64,
+}
+
+impl<T> HighlightCache<T> {
+    fn new(capacity: usize) -> Self {
+        Self {
+            entries: HashMap::new(),
+            capacity: capacity.max(1),
+            tick: 0,
+        }
+    }
+
+    /// Returns the cached value of the `source`, or insert the one built by `build`.
+    ///
+    /// The least recently used entry is evicted when the cache is full.
+    fn get_or_insert_with(
+        &mut self,
+        language: &str,
+        source: &str,
+        build: impl FnOnce() -> T,
+    ) -> &T {
+        let mut hasher = DefaultHasher::new();
+        source.hash(&mut hasher);
+        let key = (SharedString::from(language.to_string()), hasher.finish());
+
+        match self.entries.get(&key) {
+            Some(entry) if entry.source == source => {}
+            Some(_) => {
+                // The hash is collided, replace it.
+                self.entries.remove(&key);
+            }
+            None => {}
+        }
+
+        if !self.entries.contains_key(&key) {
+            if self.entries.len() >= self.capacity {
+                let lru = self
+                    .entries
+                    .iter()
+                    .min_by_key(|(_, entry)| entry.last_used)
+                    .map(|(key, _)| key.clone());
+                if let Some(lru) = lru {
+                    self.entries.remove(&lru);
+                }
+            }
+
+            self.entries.insert(
+                key.clone(),
+                HighlightCacheEntry {
+                    source: source.to_string(),
+                    value: build(),
+                    last_used: 0,
+                },
+            );
+        }
+
+        self.tick += 1;
+        let entry = self.entries.get_mut(&key).expect("the entry is inserted");
+        entry.last_used = self.tick;
+        &entry.value
+    }
+}
+
+/// Highlight the `source` by the language (registered in [`LanguageRegistry`]) and the `theme`.
+///
+/// Returns the connected styled spans covering the whole `source`, this is useful to
+/// highlight the code out of the [`crate::input::InputState`], e.g.: a read-only code block.
+///
+/// The parse results of the recently highlighted sources are cached for repeated renders.
+///
+/// ```ignore
+/// let spans = highlight("rust", code, &cx.theme().highlight_theme, cx);
+/// StyledText::new(code).with_highlights(spans)
+/// ```
+pub fn highlight(
+    language: &str,
+    source: &str,
+    theme: &HighlightTheme,
+    cx: &App,
+) -> Vec<StyledSpan> {
+    HIGHLIGHT_CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        let highlighter = cache.get_or_insert_with(language, source, || {
+            let mut highlighter = SyntaxHighlighter::new(language, cx);
+            highlighter.update(None, &Rope::from_str(source), cx);
+            highlighter
+        });
+        highlighter.styles(&(0..source.len()), theme)
+    })
+}
+
 /// To merge intersection ranges, let the subsequent range cover
 /// the previous overlapping range and split the previous range.
 ///
@@ -792,4 +909,30 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn test_highlight_cache() {
+        let mut cache = HighlightCache::new(2);
+        let mut builds = 0;
+        let mut get = |cache: &mut HighlightCache<usize>, language: &str, source: &str| {
+            *cache.get_or_insert_with(language, source, || {
+                builds += 1;
+                builds
+            })
+        };
+
+        // Miss and hit.
+        assert_eq!(get(&mut cache, "rust", "fn a() {}"), 1);
+        assert_eq!(get(&mut cache, "rust", "fn a() {}"), 1);
+        // Miss by the language or the source.
+        assert_eq!(get(&mut cache, "go", "fn a() {}"), 2);
+        assert_eq!(cache.entries.len(), 2);
+
+        // Evict the least recently used one, "go" is older than "rust" after the hit.
+        assert_eq!(get(&mut cache, "rust", "fn a() {}"), 1);
+        assert_eq!(get(&mut cache, "rust", "fn b() {}"), 3);
+        assert_eq!(cache.entries.len(), 2);
+        assert_eq!(get(&mut cache, "rust", "fn a() {}"), 1);
+        assert_eq!(get(&mut cache, "go", "fn a() {}"), 4);
+    }
 }
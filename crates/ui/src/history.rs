@@ -28,6 +28,8 @@ pub struct History<I: HistoryItem> {
     max_undo: usize,
     group_interval: Option<Duration>,
     unique: bool,
+    /// Whether in a group started by [`History::start_group`], and a change has been pushed.
+    grouping: Option<bool>,
 }
 
 impl<I> History<I>
@@ -44,6 +46,7 @@ where
             max_undo: 1000,
             group_interval: None,
             unique: false,
+            grouping: None,
         }
     }
 
@@ -69,14 +72,30 @@ where
     /// Increment the version number if the last change was made more than `GROUP_INTERVAL` milliseconds ago.
     fn inc_version(&mut self) -> usize {
         let t = Instant::now();
-        if Some(self.last_changed_at.elapsed()) > self.group_interval {
+        if self.grouping == Some(true) {
+            // Keep the version of the first change in the group.
+        } else if Some(self.last_changed_at.elapsed()) > self.group_interval {
             self.version += 1;
         }
+        if self.grouping.is_some() {
+            self.grouping = Some(true);
+        }
 
         self.last_changed_at = t;
         self.version
     }
 
+    /// Start a group of changes, all the changes pushed until [`History::end_group`]
+    /// are undone and redone together, e.g.: an edit at multiple cursors.
+    pub fn start_group(&mut self) {
+        self.grouping = Some(false);
+    }
+
+    /// End the group of changes started by [`History::start_group`].
+    pub fn end_group(&mut self) {
+        self.grouping = None;
+    }
+
     /// Get the current version number.
     pub fn version(&self) -> usize {
         self.version
@@ -285,4 +304,35 @@ mod tests {
         assert_eq!(history.undos().len(), 0);
         assert_eq!(history.redos().len(), 4);
     }
+
+    #[test]
+    fn test_group_history() {
+        let mut history: History<TabIndex> = History::new();
+        history.push(0.into());
+
+        history.start_group();
+        history.push(1.into());
+        history.push(2.into());
+        history.push(3.into());
+        history.end_group();
+
+        history.push(4.into());
+        assert_eq!(history.version(), 3);
+
+        let changes = history.undo().unwrap();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].tab_index, 4);
+
+        let changes = history.undo().unwrap();
+        assert_eq!(
+            changes.iter().map(|c| c.tab_index).collect::<Vec<_>>(),
+            vec![3, 2, 1]
+        );
+
+        let changes = history.redo().unwrap();
+        assert_eq!(
+            changes.iter().map(|c| c.tab_index).collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+    }
 }
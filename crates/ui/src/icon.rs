@@ -67,6 +67,7 @@ pub enum IconName {
     PanelRight,
     PanelRightClose,
     PanelRightOpen,
+    Pipette,
     Plus,
     ResizeCorner,
     Search,
@@ -151,6 +152,7 @@ impl IconName {
             Self::PanelRight => "icons/panel-right.svg",
             Self::PanelRightClose => "icons/panel-right-close.svg",
             Self::PanelRightOpen => "icons/panel-right-open.svg",
+            Self::Pipette => "icons/pipette.svg",
             Self::Plus => "icons/plus.svg",
             Self::ResizeCorner => "icons/resize-corner.svg",
             Self::Search => "icons/search.svg",
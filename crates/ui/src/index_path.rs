@@ -68,6 +68,19 @@ impl IndexPath {
     }
 }
 
+/// Returns the new index of the item (e.g. a row or column) at `ix` after the item at `from_ix` moved to `to_ix`.
+pub(crate) fn moved_ix(ix: usize, from_ix: usize, to_ix: usize) -> usize {
+    if ix == from_ix {
+        to_ix
+    } else if from_ix < ix && ix <= to_ix {
+        ix - 1
+    } else if to_ix <= ix && ix < from_ix {
+        ix + 1
+    } else {
+        ix
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -99,4 +112,15 @@ mod tests {
         assert_eq!(index_path.row, 2);
         assert_eq!(index_path.column, 3);
     }
+
+    #[test]
+    fn test_moved_ix() {
+        // Move 1 to 3: [0, 1, 2, 3, 4] -> [0, 2, 3, 1, 4]
+        let moved: Vec<_> = (0..5).map(|ix| moved_ix(ix, 1, 3)).collect();
+        assert_eq!(moved, vec![0, 3, 1, 2, 4]);
+
+        // Move 3 to 1: [0, 1, 2, 3, 4] -> [0, 3, 1, 2, 4]
+        let moved: Vec<_> = (0..5).map(|ix| moved_ix(ix, 3, 1)).collect();
+        assert_eq!(moved, vec![0, 2, 3, 1, 4]);
+    }
 }
@@ -0,0 +1,153 @@
+use std::ops::Range;
+
+use ropey::Rope;
+
+/// The pairs to auto close on typing the opening char, see [`super::InputState::auto_pairs`].
+const AUTO_PAIRS: [(char, char); 6] = [
+    ('(', ')'),
+    ('[', ']'),
+    ('{', '}'),
+    ('"', '"'),
+    ('\'', '\''),
+    ('`', '`'),
+];
+
+/// The brackets to highlight the matching partner.
+const BRACKETS: [(char, char); 3] = [('(', ')'), ('[', ']'), ('{', '}')];
+
+/// The max chars to scan for the matching bracket, to avoid the cost on the huge text.
+const MAX_BRACKET_SCAN_CHARS: usize = 10_000;
+
+/// Returns the closing char if the `c` is an opening char of the [`AUTO_PAIRS`].
+pub(super) fn closing_pair(c: char) -> Option<char> {
+    AUTO_PAIRS
+        .iter()
+        .find(|(open, _)| *open == c)
+        .map(|(_, close)| *close)
+}
+
+/// Returns true if the `c` is a closing char of the [`AUTO_PAIRS`].
+pub(super) fn is_closing_pair(c: char) -> bool {
+    AUTO_PAIRS.iter().any(|(_, close)| *close == c)
+}
+
+/// Returns true if the `c` is a quote, which is both the opening and the closing char.
+pub(super) fn is_quote(c: char) -> bool {
+    AUTO_PAIRS
+        .iter()
+        .any(|(open, close)| *open == c && *close == c)
+}
+
+/// Find the bracket next to the `offset` (after first, then before), and its matching partner.
+///
+/// Returns the byte ranges of the bracket and the matching one,
+/// `None` if there is no bracket or the partner is not found within [`MAX_BRACKET_SCAN_CHARS`].
+pub(super) fn find_matching_bracket(
+    text: &Rope,
+    offset: usize,
+) -> Option<(Range<usize>, Range<usize>)> {
+    let offset = offset.min(text.len_bytes());
+    let char_ix = text.byte_to_char(offset);
+
+    let candidates = [Some(char_ix), char_ix.checked_sub(1)];
+    for ix in candidates.into_iter().flatten() {
+        let Some(c) = text.get_char(ix) else {
+            continue;
+        };
+
+        let matched_ix = if let Some((open, close)) = BRACKETS.iter().find(|(o, _)| *o == c) {
+            scan_bracket(
+                text.chars_at(ix + 1)
+                    .enumerate()
+                    .map(|(i, c)| (ix + 1 + i, c)),
+                *open,
+                *close,
+            )
+        } else if let Some((open, close)) = BRACKETS.iter().find(|(_, cl)| *cl == c) {
+            scan_bracket(
+                text.chars_at(ix)
+                    .reversed()
+                    .enumerate()
+                    .map(|(i, c)| (ix - 1 - i, c)),
+                *close,
+                *open,
+            )
+        } else {
+            continue;
+        };
+
+        let Some(matched_ix) = matched_ix else {
+            continue;
+        };
+        let char_range = |ix: usize| text.char_to_byte(ix)..text.char_to_byte(ix + 1);
+        return Some((char_range(ix), char_range(matched_ix)));
+    }
+
+    None
+}
+
+/// Scan the `chars` for the `target` that balances the already seen `this` bracket.
+fn scan_bracket(
+    chars: impl Iterator<Item = (usize, char)>,
+    this: char,
+    target: char,
+) -> Option<usize> {
+    let mut depth = 0;
+    for (ix, c) in chars.take(MAX_BRACKET_SCAN_CHARS) {
+        if c == this {
+            depth += 1;
+        } else if c == target {
+            if depth == 0 {
+                return Some(ix);
+            }
+            depth -= 1;
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use ropey::Rope;
+
+    use super::*;
+
+    #[test]
+    fn test_closing_pair() {
+        assert_eq!(closing_pair('('), Some(')'));
+        assert_eq!(closing_pair('{'), Some('}'));
+        assert_eq!(closing_pair('"'), Some('"'));
+        assert_eq!(closing_pair(')'), None);
+        assert_eq!(closing_pair('a'), None);
+        assert!(is_closing_pair(']'));
+        assert!(is_closing_pair('\''));
+        assert!(!is_closing_pair('['));
+        assert!(is_quote('`'));
+        assert!(!is_quote('('));
+    }
+
+    #[test]
+    fn test_find_matching_bracket() {
+        let text = Rope::from_str("fn foo(a: [u8; 2]) {\n    bar(a)\n}");
+
+        // The bracket after the offset is preferred.
+        assert_eq!(find_matching_bracket(&text, 6), Some((6..7, 17..18)));
+        assert_eq!(find_matching_bracket(&text, 7), Some((6..7, 17..18)));
+        assert_eq!(find_matching_bracket(&text, 10), Some((10..11, 16..17)));
+        assert_eq!(find_matching_bracket(&text, 18), Some((17..18, 6..7)));
+        assert_eq!(find_matching_bracket(&text, 19), Some((19..20, 32..33)));
+        assert_eq!(find_matching_bracket(&text, 33), Some((32..33, 19..20)));
+        assert_eq!(find_matching_bracket(&text, 28), Some((28..29, 30..31)));
+        assert_eq!(find_matching_bracket(&text, 2), None);
+
+        // Unbalanced
+        let text = Rope::from_str("(a");
+        assert_eq!(find_matching_bracket(&text, 0), None);
+
+        // Multi-bytes chars
+        let text = Rope::from_str("(中文)");
+        assert_eq!(find_matching_bracket(&text, 0), Some((0..1, 7..8)));
+        assert_eq!(find_matching_bracket(&text, 8), Some((7..8, 0..1)));
+    }
+}
@@ -0,0 +1,216 @@
+use std::{ops::Range, time::Duration};
+
+use gpui::{
+    deferred, div, prelude::FluentBuilder as _, px, App, Context, InteractiveElement as _,
+    IntoElement, MouseButton, ParentElement as _, ScrollHandle, SharedString,
+    StatefulInteractiveElement as _, Styled as _, Window,
+};
+use ropey::Rope;
+
+use crate::{h_flex, input::InputState, v_flex, ActiveTheme as _, StyledExt as _};
+
+/// The delay after typing before the [`CompletionProvider`] is queried.
+pub(super) const COMPLETION_DEBOUNCE: Duration = Duration::from_millis(100);
+
+/// A completion item to show in the completion menu of the [`InputState`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Completion {
+    /// The label to show in the menu.
+    pub label: SharedString,
+    /// The secondary text to show after the label, e.g.: the type or the kind of the item.
+    pub detail: Option<SharedString>,
+    /// The text to replace the current word with, default is the `label`.
+    pub insert_text: Option<SharedString>,
+}
+
+impl Completion {
+    /// Create a new completion item with the label.
+    pub fn new(label: impl Into<SharedString>) -> Self {
+        Self {
+            label: label.into(),
+            detail: None,
+            insert_text: None,
+        }
+    }
+
+    /// Set the secondary text to show after the label.
+    pub fn detail(mut self, detail: impl Into<SharedString>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+
+    /// Set the text to insert when the item is accepted, if it is different from the label.
+    pub fn insert_text(mut self, insert_text: impl Into<SharedString>) -> Self {
+        self.insert_text = Some(insert_text.into());
+        self
+    }
+
+    fn text_to_insert(&self) -> &SharedString {
+        self.insert_text.as_ref().unwrap_or(&self.label)
+    }
+}
+
+/// Provide the completions for the [`InputState`], see [`InputState::set_completion_provider`].
+pub trait CompletionProvider {
+    /// Returns the completions for the word under the cursor.
+    ///
+    /// The `offset` is the UTF-8 offset of the cursor in the `text`.
+    fn completions(&self, text: &Rope, offset: usize, cx: &App) -> Vec<Completion>;
+}
+
+/// Returns the range of the word under the cursor to complete,
+/// `None` if there is no word char before the cursor.
+pub(super) fn completion_word_range(text: &Rope, offset: usize) -> Option<Range<usize>> {
+    let offset = offset.min(text.len_bytes());
+    let is_word_char = |c: char| c.is_alphanumeric() || c == '_';
+
+    let char_ix = text.byte_to_char(offset);
+    let start = text
+        .chars_at(char_ix)
+        .reversed()
+        .take_while(|c| is_word_char(*c))
+        .map(char::len_utf8)
+        .sum::<usize>();
+    if start == 0 {
+        return None;
+    }
+    let end = text
+        .chars_at(char_ix)
+        .take_while(|c| is_word_char(*c))
+        .map(char::len_utf8)
+        .sum::<usize>();
+
+    Some(offset - start..offset + end)
+}
+
+/// The completion menu state of the [`InputState`].
+pub(super) struct CompletionMenu {
+    pub(super) items: Vec<Completion>,
+    pub(super) selected_ix: usize,
+    /// The start offset of the word to complete, the menu is anchored to it.
+    word_start: usize,
+    scroll_handle: ScrollHandle,
+}
+
+impl CompletionMenu {
+    pub(super) fn new(items: Vec<Completion>, word_start: usize) -> Self {
+        Self {
+            items,
+            selected_ix: 0,
+            word_start,
+            scroll_handle: ScrollHandle::new(),
+        }
+    }
+
+    /// Move the selection by the `delta`, wrapping around at the both ends.
+    pub(super) fn select(&mut self, delta: isize) {
+        if self.items.is_empty() {
+            return;
+        }
+
+        let len = self.items.len() as isize;
+        self.selected_ix = (self.selected_ix as isize + delta).rem_euclid(len) as usize;
+        self.scroll_handle.scroll_to_item(self.selected_ix);
+    }
+
+    /// Returns the text of the selected item to insert.
+    pub(super) fn selected_text(&self) -> Option<SharedString> {
+        self.items
+            .get(self.selected_ix)
+            .map(|item| item.text_to_insert().clone())
+    }
+
+    pub(super) fn render(
+        &self,
+        state: &InputState,
+        _: &mut Window,
+        cx: &mut Context<InputState>,
+    ) -> Option<impl IntoElement> {
+        let last_layout = state.last_layout.as_ref()?;
+        let (_, _, pos) = state.line_and_position_for_offset(self.word_start);
+        let pos = pos?;
+
+        let scroll_origin = state.scroll_handle.offset();
+        let x = pos.x + last_layout.line_number_width + scroll_origin.x;
+        let y = pos.y + last_layout.line_height + scroll_origin.y;
+
+        Some(deferred(
+            v_flex()
+                .id("completion-menu")
+                .absolute()
+                .left(x)
+                .top(y)
+                .min_w(px(160.))
+                .max_w(px(400.))
+                .max_h(px(240.))
+                .p_1()
+                .occlude()
+                .overflow_y_scroll()
+                .track_scroll(&self.scroll_handle)
+                .popover_style(cx)
+                .text_sm()
+                .children(self.items.iter().enumerate().map(|(ix, item)| {
+                    let selected = ix == self.selected_ix;
+
+                    h_flex()
+                        .id(ix)
+                        .flex_shrink_0()
+                        .gap_4()
+                        .justify_between()
+                        .px_2()
+                        .py_0p5()
+                        .rounded(cx.theme().radius)
+                        .when(selected, |this| {
+                            this.bg(cx.theme().accent)
+                                .text_color(cx.theme().accent_foreground)
+                        })
+                        .child(item.label.clone())
+                        .when_some(item.detail.clone(), |this, detail| {
+                            this.child(
+                                div()
+                                    .text_xs()
+                                    .text_color(cx.theme().muted_foreground)
+                                    .child(detail),
+                            )
+                        })
+                        .on_mouse_down(
+                            MouseButton::Left,
+                            cx.listener(move |this, _, window, cx| {
+                                if let Some(menu) = this.completion_menu.as_mut() {
+                                    menu.selected_ix = ix;
+                                }
+                                this.confirm_completion(window, cx);
+                                cx.stop_propagation();
+                            }),
+                        )
+                }))
+                .on_mouse_down_out(cx.listener(|this, _, _, cx| {
+                    this.hide_completion_menu(cx);
+                })),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ropey::Rope;
+
+    use super::completion_word_range;
+
+    #[test]
+    fn test_completion_word_range() {
+        let text = Rope::from_str("let foo_bar = baz.qux;\n中文 abc");
+
+        assert_eq!(completion_word_range(&text, 0), None);
+        assert_eq!(completion_word_range(&text, 3), Some(0..3));
+        // The word after the cursor is included to be replaced.
+        assert_eq!(completion_word_range(&text, 6), Some(4..11));
+        assert_eq!(completion_word_range(&text, 11), Some(4..11));
+        assert_eq!(completion_word_range(&text, 12), None);
+        assert_eq!(completion_word_range(&text, 18), None);
+        assert_eq!(completion_word_range(&text, 19), Some(18..21));
+        assert_eq!(completion_word_range(&text, 29), Some(23..29));
+        assert_eq!(completion_word_range(&text, 34), Some(30..33));
+        assert_eq!(completion_word_range(&text, 100), Some(30..33));
+    }
+}
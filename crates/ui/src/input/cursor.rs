@@ -157,6 +157,25 @@ impl From<&Selection> for Range<usize> {
     }
 }
 
+/// Sort the selections by offset, and merge the overlapping (or touching) ones.
+pub(super) fn merge_selections(mut selections: Vec<Selection>) -> Vec<Selection> {
+    selections.sort_by_key(|selection| selection.start.offset);
+
+    let mut merged: Vec<Selection> = Vec::with_capacity(selections.len());
+    for selection in selections {
+        if let Some(last) = merged.last_mut() {
+            if selection.start <= last.end {
+                if selection.end > last.end {
+                    last.end = selection.end;
+                }
+                continue;
+            }
+        }
+        merged.push(selection);
+    }
+    merged
+}
+
 /// Line and column position (1-based) in the source code.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct LineColumn {
@@ -192,6 +211,7 @@ impl fmt::Display for LineColumn {
 
 #[cfg(test)]
 mod tests {
+    use super::{merge_selections, Selection};
     use crate::input::LineColumn;
 
     #[test]
@@ -200,4 +220,25 @@ mod tests {
         assert_eq!(LineColumn::from((10, 10)).to_string(), "10:10");
         assert_eq!(LineColumn::from((0, 0)).to_string(), "1:1");
     }
+
+    #[test]
+    fn test_merge_selections() {
+        let selections: Vec<Selection> = vec![(10..12).into(), (0..0).into(), (5..5).into()];
+        assert_eq!(
+            merge_selections(selections),
+            vec![(0..0).into(), (5..5).into(), (10..12).into()]
+        );
+
+        let selections: Vec<Selection> = vec![
+            (5..5).into(),
+            (5..5).into(),
+            (3..6).into(),
+            (6..8).into(),
+            (10..10).into(),
+        ];
+        assert_eq!(
+            merge_selections(selections),
+            vec![(3..8).into(), (10..10).into()]
+        );
+    }
 }
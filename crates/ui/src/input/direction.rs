@@ -0,0 +1,94 @@
+/// The text direction of the [`super::InputState`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextDirection {
+    /// Left to right, the default.
+    #[default]
+    Ltr,
+    /// Right to left, for Arabic, Hebrew, etc.
+    Rtl,
+    /// Infer the direction from the first strong character of the text,
+    /// fallback to [`TextDirection::Ltr`] if there is no strong character.
+    Auto,
+}
+
+impl TextDirection {
+    /// Resolve the direction for the given text, the [`TextDirection::Auto`] will be
+    /// resolved to [`TextDirection::Ltr`] or [`TextDirection::Rtl`].
+    pub fn resolve(&self, text: &str) -> Self {
+        self.resolve_chars(text.chars())
+    }
+
+    /// Resolve the direction by the chars of the text, the chars are consumed lazily
+    /// until the first strong character.
+    pub(super) fn resolve_chars(&self, chars: impl IntoIterator<Item = char>) -> Self {
+        match self {
+            Self::Auto => chars
+                .into_iter()
+                .find_map(strong_direction)
+                .unwrap_or(Self::Ltr),
+            direction => *direction,
+        }
+    }
+
+    /// Returns true if the direction is [`TextDirection::Rtl`].
+    ///
+    /// The [`TextDirection::Auto`] should be resolved by [`TextDirection::resolve`] first.
+    pub fn is_rtl(&self) -> bool {
+        matches!(self, Self::Rtl)
+    }
+}
+
+/// Returns the direction of the char if it is a strong directional character.
+///
+/// Digits, punctuation and whitespaces are weak or neutral, so they return `None`.
+fn strong_direction(c: char) -> Option<TextDirection> {
+    if !c.is_alphabetic() {
+        return None;
+    }
+
+    match c as u32 {
+        // Hebrew, Arabic, Syriac, Thaana, NKo, Samaritan, Mandaic, Arabic Extended
+        0x0590..=0x08FF
+        // Hebrew and Arabic Presentation Forms
+        | 0xFB1D..=0xFDFF
+        | 0xFE70..=0xFEFF
+        // Other historic RTL scripts and Arabic Mathematical Symbols
+        | 0x10800..=0x10FFF
+        | 0x1E800..=0x1EFFF => Some(TextDirection::Rtl),
+        _ => Some(TextDirection::Ltr),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TextDirection;
+
+    #[test]
+    fn test_resolve_direction() {
+        assert_eq!(TextDirection::Auto.resolve(""), TextDirection::Ltr);
+        assert_eq!(TextDirection::Auto.resolve("Hello"), TextDirection::Ltr);
+        assert_eq!(TextDirection::Auto.resolve("שלום"), TextDirection::Rtl);
+        assert_eq!(
+            TextDirection::Auto.resolve("مرحبا Hello"),
+            TextDirection::Rtl
+        );
+        assert_eq!(
+            TextDirection::Auto.resolve("123, مرحبا"),
+            TextDirection::Rtl
+        );
+        assert_eq!(
+            TextDirection::Auto.resolve("123 Hello مرحبا"),
+            TextDirection::Ltr
+        );
+        assert_eq!(TextDirection::Auto.resolve("123"), TextDirection::Ltr);
+
+        // Stop at the first strong character.
+        assert_eq!(
+            TextDirection::Auto.resolve_chars("12 שלום".chars().chain(std::iter::repeat('a'))),
+            TextDirection::Rtl
+        );
+
+        assert_eq!(TextDirection::Ltr.resolve("שלום"), TextDirection::Ltr);
+        assert_eq!(TextDirection::Rtl.resolve("Hello"), TextDirection::Rtl);
+    }
+}
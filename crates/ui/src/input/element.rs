@@ -2,9 +2,9 @@ use std::{ops::Range, rc::Rc};
 
 use gpui::{
     fill, point, px, relative, size, App, Bounds, Corners, Element, ElementId, ElementInputHandler,
-    Entity, GlobalElementId, HighlightStyle, IntoElement, LayoutId, MouseButton, MouseMoveEvent,
-    Path, Pixels, Point, SharedString, Size, Style, TextAlign, TextRun, UnderlineStyle, Window,
-    WrappedLine,
+    Entity, GlobalElementId, HighlightStyle, Hsla, IntoElement, LayoutId, MouseButton,
+    MouseMoveEvent, Path, Pixels, Point, SharedString, Size, Style, TextAlign, TextRun,
+    UnderlineStyle, Window, WrappedLine,
 };
 use smallvec::SmallVec;
 
@@ -12,11 +12,16 @@ use crate::{
     highlighter::SyntaxHighlighter, input::blink_cursor::CURSOR_WIDTH, ActiveTheme as _, Root,
 };
 
-use super::{mode::InputMode, InputState, LastLayout};
+use super::{
+    bracket::find_matching_bracket, mode::InputMode, state::line_offset_x, InputState, LastLayout,
+    Selection,
+};
 
 pub(super) const RIGHT_MARGIN: Pixels = px(10.);
 const BOTTOM_MARGIN_ROWS: usize = 1;
 pub(super) const LINE_NUMBER_RIGHT_MARGIN: Pixels = px(10.);
+/// The minimum digits reserved for the line numbers, to avoid the gutter jumps on the first lines.
+const MIN_LINE_NUMBER_DIGITS: usize = 2;
 
 pub(super) struct TextElement {
     state: Entity<InputState>,
@@ -72,6 +77,8 @@ impl TextElement {
         }
 
         let cursor = state.cursor();
+        let rtl = state.text_direction().is_rtl();
+        let text_width = bounds.size.width - line_number_width;
         let mut current_line_index = None;
         let mut scroll_offset = state.scroll_handle.offset();
         let mut cursor_bounds = None;
@@ -95,7 +102,7 @@ impl TextElement {
                 break;
             }
 
-            let line_origin = point(px(0.), offset_y);
+            let line_origin = point(line_offset_x(line, text_width, rtl), offset_y);
             if cursor_pos.is_none() {
                 let offset = cursor.offset.saturating_sub(prev_lines_offset);
 
@@ -207,6 +214,172 @@ impl TextElement {
                 selected_range = (marked_range.end..marked_range.end).into();
             }
         }
+
+        self.layout_selection_path(
+            selected_range,
+            lines,
+            line_height,
+            bounds,
+            line_number_width,
+            cx,
+        )
+    }
+
+    /// Returns the paths of the secondary selections (multi-cursor).
+    fn layout_secondary_selections(
+        &self,
+        lines: &[WrappedLine],
+        line_height: Pixels,
+        bounds: &mut Bounds<Pixels>,
+        line_number_width: Pixels,
+        _: &mut Window,
+        cx: &mut App,
+    ) -> Vec<Path<Pixels>> {
+        let state = self.state.read(cx);
+        state
+            .secondary_selections
+            .iter()
+            .filter_map(|selection| {
+                self.layout_selection_path(
+                    *selection,
+                    lines,
+                    line_height,
+                    bounds,
+                    line_number_width,
+                    cx,
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the paths of the search matches in the visible lines.
+    fn layout_search_matches(
+        &self,
+        lines: &[WrappedLine],
+        line_height: Pixels,
+        bounds: &Bounds<Pixels>,
+        line_number_width: Pixels,
+        visible_range: &Range<usize>,
+        cx: &App,
+    ) -> Vec<Path<Pixels>> {
+        let state = self.state.read(cx);
+        if state.search_matches.is_empty() {
+            return vec![];
+        }
+
+        let total_lines = state.text.len_lines();
+        let visible_start = state
+            .text
+            .line_to_byte(visible_range.start.min(total_lines));
+        let visible_end = state.text.line_to_byte(visible_range.end.min(total_lines));
+        state
+            .search_matches
+            .iter()
+            .filter(|range| range.end >= visible_start && range.start <= visible_end)
+            .filter_map(|range| {
+                self.layout_selection_path(
+                    range.clone().into(),
+                    lines,
+                    line_height,
+                    bounds,
+                    line_number_width,
+                    cx,
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the paths of the bracket next to the cursor and its matching one,
+    /// only for the [`InputState::auto_pairs`] mode.
+    fn layout_bracket_match(
+        &self,
+        lines: &[WrappedLine],
+        line_height: Pixels,
+        bounds: &Bounds<Pixels>,
+        line_number_width: Pixels,
+        cx: &App,
+    ) -> Vec<Path<Pixels>> {
+        let state = self.state.read(cx);
+        if !state.auto_pairs || !state.selected_range.is_empty() {
+            return vec![];
+        }
+
+        let Some((bracket, matched)) = find_matching_bracket(&state.text, state.cursor().offset)
+        else {
+            return vec![];
+        };
+
+        [bracket, matched]
+            .into_iter()
+            .filter_map(|range| {
+                self.layout_selection_path(
+                    range.into(),
+                    lines,
+                    line_height,
+                    bounds,
+                    line_number_width,
+                    cx,
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the bounds of the carets of the secondary selections (multi-cursor).
+    ///
+    /// The `bounds` must be already offset by the scroll offset.
+    fn layout_secondary_cursors(
+        &self,
+        lines: &[WrappedLine],
+        line_height: Pixels,
+        bounds: &Bounds<Pixels>,
+        line_number_width: Pixels,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Vec<Bounds<Pixels>> {
+        let state = self.state.read(cx);
+        if state.secondary_selections.is_empty() || !state.show_cursor(window, cx) {
+            return vec![];
+        }
+
+        let rtl = state.text_direction().is_rtl();
+        let text_width = bounds.size.width - line_number_width;
+        let mut cursor_bounds = vec![];
+        for selection in &state.secondary_selections {
+            let mut prev_lines_offset = 0;
+            let mut offset_y = px(0.);
+            for line in lines.iter() {
+                let offset = selection.end.offset.saturating_sub(prev_lines_offset);
+                if let Some(pos) = line.position_for_index(offset, line_height) {
+                    let line_origin = point(line_offset_x(line, text_width, rtl), offset_y);
+                    cursor_bounds.push(Bounds::new(
+                        point(
+                            bounds.left() + line_origin.x + pos.x + line_number_width,
+                            bounds.top() + line_origin.y + pos.y,
+                        ),
+                        size(CURSOR_WIDTH, line_height),
+                    ));
+                    break;
+                }
+
+                offset_y += line.size(line_height).height;
+                // +1 for skip the last `\n`
+                prev_lines_offset += line.len() + 1;
+            }
+        }
+
+        cursor_bounds
+    }
+
+    fn layout_selection_path(
+        &self,
+        selected_range: Selection,
+        lines: &[WrappedLine],
+        line_height: Pixels,
+        bounds: &Bounds<Pixels>,
+        line_number_width: Pixels,
+        cx: &App,
+    ) -> Option<Path<Pixels>> {
+        let state = self.state.read(cx);
         if selected_range.is_empty() {
             return None;
         }
@@ -217,6 +390,8 @@ impl TextElement {
             (selected_range.end, selected_range.start)
         };
 
+        let rtl = state.text_direction().is_rtl();
+        let text_width = bounds.size.width - line_number_width;
         let mut prev_lines_offset = 0;
         let mut line_corners = vec![];
 
@@ -225,7 +400,7 @@ impl TextElement {
             let line_size = line.size(line_height);
             let line_wrap_width = line_size.width;
 
-            let line_origin = point(px(0.), offset_y);
+            let line_origin = point(line_offset_x(line, text_width, rtl), offset_y);
 
             let line_cursor_start =
                 line.position_for_index(start_ix.saturating_sub(prev_lines_offset), line_height);
@@ -307,7 +482,8 @@ impl TextElement {
         while let Some(corners) = rev_line_corners.next() {
             points.push(corners.top_left);
             if let Some(next) = rev_line_corners.peek() {
-                if next.top_left.x > corners.top_left.x {
+                // The lines may have different x offset in RTL.
+                if next.top_left.x != corners.top_left.x {
                     points.push(point(next.top_left.x, corners.top_left.y));
                 }
             }
@@ -448,6 +624,12 @@ pub(super) struct PrepaintState {
     /// line index (zero based), no wrap, same line as the cursor.
     current_line_index: Option<usize>,
     selection_path: Option<Path<Pixels>>,
+    /// The highlights of the search matches.
+    search_match_paths: Vec<Path<Pixels>>,
+    bracket_match_paths: Vec<Path<Pixels>>,
+    /// The selections and carets of the secondary cursors (multi-cursor).
+    secondary_selection_paths: Vec<Path<Pixels>>,
+    secondary_cursor_bounds: Vec<Bounds<Pixels>>,
     bounds: Bounds<Pixels>,
 }
 
@@ -563,25 +745,30 @@ impl Element for TextElement {
 
         let text_style = window.text_style();
 
-        // Calculate the width of the line numbers
-        let empty_line_number = window
-            .text_system()
-            .shape_text(
-                "++++".into(),
-                font_size,
-                &[TextRun {
-                    len: 4,
-                    font: style.font(),
-                    color: gpui::black(),
-                    background_color: None,
-                    underline: None,
-                    strikethrough: None,
-                }],
-                None,
-                None,
-            )
-            .unwrap();
-        let line_number_width = if state.mode.line_number() {
+        // Calculate the width of the line numbers by the digits of the last line number.
+        let line_number_digits = text
+            .len_lines()
+            .to_string()
+            .len()
+            .max(MIN_LINE_NUMBER_DIGITS);
+        let line_number_width = if state.show_line_numbers() {
+            let empty_line_number = window
+                .text_system()
+                .shape_text(
+                    "+".repeat(line_number_digits).into(),
+                    font_size,
+                    &[TextRun {
+                        len: line_number_digits,
+                        font: style.font(),
+                        color: gpui::black(),
+                        background_color: None,
+                        underline: None,
+                        strikethrough: None,
+                    }],
+                    None,
+                    None,
+                )
+                .unwrap();
             empty_line_number.last().unwrap().width() + LINE_NUMBER_RIGHT_MARGIN
         } else {
             px(0.)
@@ -739,29 +926,46 @@ impl Element for TextElement {
             window,
             cx,
         );
+        let secondary_selection_paths = self.layout_secondary_selections(
+            &lines,
+            line_height,
+            &mut bounds,
+            line_number_width,
+            window,
+            cx,
+        );
+        let search_match_paths = self.layout_search_matches(
+            &lines,
+            line_height,
+            &bounds,
+            line_number_width,
+            &visible_range,
+            cx,
+        );
+        let bracket_match_paths =
+            self.layout_bracket_match(&lines, line_height, &bounds, line_number_width, cx);
+        let secondary_cursor_bounds = self.layout_secondary_cursors(
+            &lines,
+            line_height,
+            &bounds,
+            line_number_width,
+            window,
+            cx,
+        );
 
         let state = self.state.read(cx);
-        let line_numbers = if state.mode.line_number() {
+        let line_numbers = if state.show_line_numbers() {
             let mut line_numbers = vec![];
-            let run_len = 4;
-            let other_line_runs = vec![TextRun {
-                len: run_len,
-                font: style.font(),
-                color: cx.theme().muted_foreground,
-                background_color: None,
-                underline: None,
-                strikethrough: None,
-            }];
-            let current_line_runs = vec![TextRun {
-                len: run_len,
+            let text_run = |len: usize, color: Hsla| TextRun {
+                len,
                 font: style.font(),
-                color: cx.theme().foreground,
+                color,
                 background_color: None,
                 underline: None,
                 strikethrough: None,
-            }];
+            };
 
-            // build line numbers
+            // build line numbers, the soft wrapped lines are continued with the empty lines.
             for (ix, line) in lines
                 .iter()
                 .skip(visible_range.start)
@@ -771,16 +975,20 @@ impl Element for TextElement {
                 let ix = ix + visible_range.start;
                 let line_no = ix + 1;
 
-                let mut line_no_text = format!("{:>4}", line_no);
+                let mut line_no_text = format!("{:>1$}", line_no, line_number_digits);
                 if !line.wrap_boundaries.is_empty() {
-                    line_no_text.push_str(&"\n    ".repeat(line.wrap_boundaries.len()));
+                    line_no_text.push_str(
+                        &format!("\n{}", " ".repeat(line_number_digits))
+                            .repeat(line.wrap_boundaries.len()),
+                    );
                 }
 
-                let runs = if current_line_index == Some(ix) {
-                    &current_line_runs
+                let color = if current_line_index == Some(ix) {
+                    cx.theme().foreground
                 } else {
-                    &other_line_runs
+                    cx.theme().muted_foreground
                 };
+                let runs = [text_run(line_no_text.len(), color)];
 
                 let shape_line = window
                     .text_system()
@@ -801,6 +1009,8 @@ impl Element for TextElement {
                 visible_range,
                 line_number_width,
                 wrap_width,
+                text_width: bounds.size.width - line_number_width,
+                rtl: state.text_direction().is_rtl(),
             },
             scroll_size,
             line_numbers,
@@ -808,6 +1018,10 @@ impl Element for TextElement {
             cursor_scroll_offset,
             current_line_index,
             selection_path,
+            search_match_paths,
+            bracket_match_paths,
+            secondary_selection_paths,
+            secondary_cursor_bounds,
         }
     }
 
@@ -876,6 +1090,37 @@ impl Element for TextElement {
             }
         }
 
+        // Paint highlighted lines
+        let highlighted_lines = &self.state.read(cx).highlighted_lines;
+        if !highlighted_lines.is_empty() {
+            let mut offset_y = invisible_top_padding;
+            for (ix, line) in prepaint
+                .last_layout
+                .lines
+                .iter()
+                .enumerate()
+                .skip(visible_range.start)
+                .take(visible_range.len())
+            {
+                let line_size = line.size(line_height);
+                // The later range wins if the ranges are overlapped.
+                if let Some((_, bg_color)) = highlighted_lines
+                    .iter()
+                    .rev()
+                    .find(|(range, _)| range.contains(&ix))
+                {
+                    window.paint_quad(fill(
+                        Bounds::new(
+                            point(input_bounds.origin.x, origin.y + offset_y),
+                            size(bounds.size.width, line_size.height),
+                        ),
+                        *bg_color,
+                    ));
+                }
+                offset_y += line_size.height;
+            }
+        }
+
         let active_line_color = cx.theme().highlight_theme.style.active_line;
 
         // Paint active line
@@ -903,11 +1148,26 @@ impl Element for TextElement {
             }
         }
 
+        // Paint search matches
+        for path in prepaint.search_match_paths.drain(..) {
+            window.paint_path(path, cx.theme().warning.opacity(0.3));
+        }
+
+        // Paint the matching brackets
+        if focused {
+            for path in prepaint.bracket_match_paths.drain(..) {
+                window.paint_path(path, cx.theme().muted_foreground.opacity(0.25));
+            }
+        }
+
         // Paint selections
         if window.is_window_active() {
             if let Some(path) = prepaint.selection_path.take() {
                 window.paint_path(path, cx.theme().selection);
             }
+            for path in prepaint.secondary_selection_paths.drain(..) {
+                window.paint_path(path, cx.theme().selection);
+            }
         }
 
         // Paint text
@@ -919,7 +1179,9 @@ impl Element for TextElement {
             .take(visible_range.len())
         {
             let p = point(
-                origin.x + prepaint.last_layout.line_number_width,
+                origin.x
+                    + prepaint.last_layout.line_number_width
+                    + prepaint.last_layout.line_offset_x(line),
                 origin.y + offset_y,
             );
             _ = line.paint(p, line_height, TextAlign::Left, None, window, cx);
@@ -932,6 +1194,9 @@ impl Element for TextElement {
                 cursor_bounds.origin.y += prepaint.cursor_scroll_offset.y;
                 window.paint_quad(fill(cursor_bounds, cx.theme().caret));
             }
+            for cursor_bounds in prepaint.secondary_cursor_bounds.drain(..) {
+                window.paint_quad(fill(cursor_bounds, cx.theme().caret));
+            }
         }
 
         // Paint line numbers
@@ -976,6 +1241,13 @@ impl Element for TextElement {
                         }
                     }
 
+                    // Right align the number, the digits may have different widths.
+                    let p = point(
+                        p.x + prepaint.last_layout.line_number_width
+                            - LINE_NUMBER_RIGHT_MARGIN
+                            - line.width(),
+                        p.y,
+                    );
                     _ = line.paint(p, line_height, TextAlign::Left, None, window, cx);
                     offset_y += line_size.height;
                 }
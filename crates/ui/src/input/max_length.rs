@@ -0,0 +1,43 @@
+use unicode_segmentation::UnicodeSegmentation as _;
+
+/// Truncate the `text` to at most `max_chars` Unicode scalar values,
+/// without splitting a grapheme cluster (e.g.: an emoji with the modifiers).
+pub(super) fn truncate_to_chars(text: &str, max_chars: usize) -> &str {
+    let mut chars = 0;
+    let mut end = 0;
+    for grapheme in text.graphemes(true) {
+        chars += grapheme.chars().count();
+        if chars > max_chars {
+            break;
+        }
+        end += grapheme.len();
+    }
+
+    &text[..end]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::truncate_to_chars;
+
+    #[test]
+    fn test_truncate_to_chars() {
+        assert_eq!(truncate_to_chars("hello", 10), "hello");
+        assert_eq!(truncate_to_chars("hello", 5), "hello");
+        assert_eq!(truncate_to_chars("hello", 3), "hel");
+        assert_eq!(truncate_to_chars("hello", 0), "");
+        assert_eq!(truncate_to_chars("", 3), "");
+
+        // Multi-bytes chars are counted by the Unicode scalar values.
+        assert_eq!("你好世界".chars().count(), 4);
+        assert_eq!(truncate_to_chars("你好世界", 2), "你好");
+        assert_eq!(truncate_to_chars("café", 4), "café");
+
+        // The grapheme cluster is not split, "e" + U+0301 is 2 chars.
+        assert_eq!(truncate_to_chars("cafe\u{301}", 4), "caf");
+        assert_eq!(truncate_to_chars("cafe\u{301}", 5), "cafe\u{301}");
+        // The family emoji is 5 chars joined by the ZWJ.
+        assert_eq!(truncate_to_chars("hi👨‍👩‍👧", 6), "hi");
+        assert_eq!(truncate_to_chars("hi👨‍👩‍👧", 7), "hi👨‍👩‍👧");
+    }
+}
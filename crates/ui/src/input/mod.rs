@@ -1,26 +1,36 @@
 mod blink_cursor;
+mod bracket;
 mod change;
 mod clear_button;
+mod completion;
 mod cursor;
+mod direction;
 mod element;
 mod hover_popover;
 mod marker;
 mod mask_pattern;
+mod max_length;
 mod mode;
 mod number_input;
 mod otp_input;
 mod rope_ext;
+mod search;
 mod state;
 mod text_input;
 mod text_wrapper;
 
 pub(crate) use clear_button::*;
+pub use completion::{Completion, CompletionProvider};
 pub(super) use cursor::*;
+pub use direction::TextDirection;
 pub use marker::*;
 pub use mask_pattern::MaskPattern;
 pub use mode::TabSize;
 pub use number_input::{NumberInput, NumberInputEvent, StepAction};
 pub use otp_input::*;
 pub(crate) use rope_ext::*;
+/// Re-export the text buffer used by the [`CompletionProvider`].
+pub use ropey::Rope;
+pub use search::{SearchEvent, SearchOptions};
 pub use state::*;
 pub use text_input::*;
@@ -1,3 +1,7 @@
+use std::ops::Range;
+
+use unicode_segmentation::UnicodeSegmentation as _;
+
 pub(crate) trait RopeExt {
     /// Get the index of (line, column) (0-based) from the byte offset (0-based).
     /// If the offset is out of bounds, return the last line and column.
@@ -9,6 +13,26 @@ pub(crate) trait RopeExt {
     /// Return the last line, if line is out of bounds.
     /// Return the end column of line, if the column is out of bounds.
     fn line_column_to_byte(&self, line_ix: usize, column_ix: usize) -> usize;
+    /// Get the previous grapheme boundary before the byte offset.
+    ///
+    /// The line breaks are stepped by char, so the `\r` of `\r\n` is still reachable.
+    fn prev_grapheme_boundary(&self, offset: usize) -> usize;
+    /// Get the next grapheme boundary after the byte offset.
+    ///
+    /// The line breaks are stepped by char, so the `\r` of `\r\n` is still reachable.
+    fn next_grapheme_boundary(&self, offset: usize) -> usize;
+    /// Get the start byte offset of the previous word before the byte offset.
+    ///
+    /// The words are split by the Unicode word boundaries (UAX #29), whitespaces are skipped.
+    fn prev_word_start(&self, offset: usize) -> usize;
+    /// Get the end byte offset of the next word after the byte offset.
+    ///
+    /// The words are split by the Unicode word boundaries (UAX #29), whitespaces are skipped.
+    fn next_word_end(&self, offset: usize) -> usize;
+    /// Get the byte range of the word at the byte offset, split by the Unicode word boundaries.
+    ///
+    /// Return `None` if the offset is on a line break.
+    fn word_range(&self, offset: usize) -> Option<Range<usize>>;
 }
 
 impl RopeExt for ropey::Rope {
@@ -37,4 +61,192 @@ impl RopeExt for ropey::Rope {
                 .try_char_to_byte(column_ix)
                 .unwrap_or(line.len_bytes().saturating_sub(1))
     }
+
+    fn prev_grapheme_boundary(&self, offset: usize) -> usize {
+        let offset = clip_offset(self, offset);
+        if offset == 0 {
+            return 0;
+        }
+
+        let line_start = self.line_to_byte(self.byte_to_line(offset));
+        if offset == line_start {
+            return self.char_to_byte(self.byte_to_char(offset) - 1);
+        }
+
+        self.byte_slice(line_start..offset)
+            .to_string()
+            .grapheme_indices(true)
+            .next_back()
+            .map(|(ix, _)| line_start + ix)
+            .unwrap_or(line_start)
+    }
+
+    fn next_grapheme_boundary(&self, offset: usize) -> usize {
+        let offset = clip_offset(self, offset);
+        let len = self.len_bytes();
+        if offset >= len {
+            return len;
+        }
+
+        let line_end = self.line_to_byte(self.byte_to_line(offset) + 1);
+        let text = self.byte_slice(offset..line_end).to_string();
+        match text.chars().next() {
+            Some('\r' | '\n') | None => self.char_to_byte(self.byte_to_char(offset) + 1).min(len),
+            Some(_) => text
+                .graphemes(true)
+                .next()
+                .map(|g| offset + g.len())
+                .unwrap_or(len),
+        }
+    }
+
+    fn prev_word_start(&self, offset: usize) -> usize {
+        let mut end = clip_offset(self, offset);
+        let mut line_ix = self.byte_to_line(end);
+        loop {
+            let line_start = self.line_to_byte(line_ix);
+            let text = self.byte_slice(line_start..end).to_string();
+            if let Some(ix) = text
+                .split_word_bound_indices()
+                .filter(|(_, s)| !s.trim_start().is_empty())
+                .next_back()
+                .map(|(ix, _)| ix)
+            {
+                return line_start + ix;
+            }
+
+            if line_ix == 0 {
+                return 0;
+            }
+            line_ix -= 1;
+            end = line_start;
+        }
+    }
+
+    fn next_word_end(&self, offset: usize) -> usize {
+        let len = self.len_bytes();
+        let mut start = clip_offset(self, offset);
+        let mut line_ix = self.byte_to_line(start);
+        loop {
+            let line_end = self.line_to_byte(line_ix + 1);
+            let text = self.byte_slice(start..line_end).to_string();
+            if let Some(end) = text
+                .split_word_bound_indices()
+                .find(|(_, s)| !s.trim_start().is_empty())
+                .map(|(ix, s)| start + ix + s.len())
+            {
+                return end;
+            }
+
+            if line_end >= len {
+                return len;
+            }
+            line_ix += 1;
+            start = line_end;
+        }
+    }
+
+    fn word_range(&self, offset: usize) -> Option<Range<usize>> {
+        let offset = clip_offset(self, offset);
+        let line_ix = self.byte_to_line(offset);
+        let line_start = self.line_to_byte(line_ix);
+        let line_end = self.line_to_byte(line_ix + 1);
+        let text = self.byte_slice(line_start..line_end).to_string();
+        let local_offset = offset - line_start;
+
+        let words = text
+            .split_word_bound_indices()
+            .filter(|(_, s)| !s.starts_with(['\r', '\n']))
+            .map(|(ix, s)| ix..ix + s.len());
+
+        // Prefer the word contains the offset, then the word ends at the offset.
+        words
+            .clone()
+            .find(|range| range.contains(&local_offset))
+            .or_else(|| words.clone().find(|range| range.end == local_offset))
+            .map(|range| line_start + range.start..line_start + range.end)
+    }
+}
+
+/// Clip the byte offset to the text length and the start of the char boundary.
+fn clip_offset(rope: &ropey::Rope, offset: usize) -> usize {
+    let offset = offset.min(rope.len_bytes());
+    rope.char_to_byte(rope.byte_to_char(offset))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RopeExt as _;
+
+    #[test]
+    fn test_word_navigation_mixed_script() {
+        // "Hello" 0..5, "," 5..6, " " 6..7, "世" 7..10, "界" 10..13, " " 13..14,
+        // "café" 14..19, " " 19..20, "👨‍👩‍👧" 20..38, " " 38..39, "über_all" 39..48
+        let text = ropey::Rope::from("Hello, 世界 café 👨‍👩‍👧 über_all");
+        let len = text.len_bytes();
+        assert_eq!(len, 48);
+
+        assert_eq!(text.next_word_end(0), 5);
+        assert_eq!(text.next_word_end(5), 6);
+        assert_eq!(text.next_word_end(6), 10);
+        assert_eq!(text.next_word_end(10), 13);
+        assert_eq!(text.next_word_end(13), 19);
+        assert_eq!(text.next_word_end(19), 38);
+        assert_eq!(text.next_word_end(38), 48);
+        assert_eq!(text.next_word_end(48), 48);
+
+        assert_eq!(text.prev_word_start(48), 39);
+        assert_eq!(text.prev_word_start(39), 20);
+        assert_eq!(text.prev_word_start(20), 14);
+        assert_eq!(text.prev_word_start(14), 10);
+        assert_eq!(text.prev_word_start(10), 7);
+        assert_eq!(text.prev_word_start(7), 5);
+        assert_eq!(text.prev_word_start(5), 0);
+        assert_eq!(text.prev_word_start(0), 0);
+        // Inside a multi-byte char
+        assert_eq!(text.prev_word_start(18), 14);
+    }
+
+    #[test]
+    fn test_word_navigation_multiline() {
+        let text = ropey::Rope::from("foo bar\r\n  baz\n");
+        assert_eq!(text.next_word_end(7), 14);
+        assert_eq!(text.prev_word_start(11), 4);
+        assert_eq!(text.prev_word_start(14), 11);
+        assert_eq!(text.next_word_end(14), text.len_bytes());
+    }
+
+    #[test]
+    fn test_word_range() {
+        let text = ropey::Rope::from("Hello, 世界 café 👨‍👩‍👧 über_all\nnext");
+        assert_eq!(text.word_range(0), Some(0..5));
+        assert_eq!(text.word_range(3), Some(0..5));
+        assert_eq!(text.word_range(5), Some(5..6));
+        assert_eq!(text.word_range(8), Some(7..10));
+        assert_eq!(text.word_range(16), Some(14..19));
+        assert_eq!(text.word_range(25), Some(20..38));
+        assert_eq!(text.word_range(40), Some(39..48));
+        // At the end of the line
+        assert_eq!(text.word_range(48), Some(39..48));
+        assert_eq!(text.word_range(49), Some(49..53));
+        assert_eq!(ropey::Rope::from("\n").word_range(0), None);
+    }
+
+    #[test]
+    fn test_grapheme_boundary() {
+        let text = ropey::Rope::from("e\u{301}👨‍👩‍👧\r\nok");
+        // "é" 0..3, "👨‍👩‍👧" 3..21, "\r" 21, "\n" 22, "ok" 23..25
+        assert_eq!(text.next_grapheme_boundary(0), 3);
+        assert_eq!(text.next_grapheme_boundary(3), 21);
+        assert_eq!(text.next_grapheme_boundary(21), 22);
+        assert_eq!(text.next_grapheme_boundary(22), 23);
+        assert_eq!(text.next_grapheme_boundary(25), 25);
+
+        assert_eq!(text.prev_grapheme_boundary(25), 24);
+        assert_eq!(text.prev_grapheme_boundary(23), 22);
+        assert_eq!(text.prev_grapheme_boundary(22), 21);
+        assert_eq!(text.prev_grapheme_boundary(21), 3);
+        assert_eq!(text.prev_grapheme_boundary(3), 0);
+        assert_eq!(text.prev_grapheme_boundary(0), 0);
+    }
 }
@@ -0,0 +1,143 @@
+use std::ops::Range;
+
+/// Options for searching the text in the input, see [`super::InputState::search`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SearchOptions {
+    /// Match with case sensitivity, default: false
+    pub case_sensitive: bool,
+    /// Only match the whole words, default: false
+    pub whole_word: bool,
+}
+
+impl SearchOptions {
+    /// Set the case sensitivity.
+    pub fn case_sensitive(mut self, case_sensitive: bool) -> Self {
+        self.case_sensitive = case_sensitive;
+        self
+    }
+
+    /// Set to only match the whole words.
+    pub fn whole_word(mut self, whole_word: bool) -> Self {
+        self.whole_word = whole_word;
+        self
+    }
+}
+
+/// Emitted when the search matches of the input are changed, use to show the "n of m" counts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SearchEvent {
+    /// The index (zero-based) of the current match, `None` if there is no current match.
+    pub current: Option<usize>,
+    /// The total number of the matches.
+    pub total: usize,
+}
+
+/// Returns the byte ranges of all the (non-overlapping) matches of the `query` in the `text`.
+pub(super) fn find_matches(text: &str, query: &str, options: SearchOptions) -> Vec<Range<usize>> {
+    if query.is_empty() {
+        return vec![];
+    }
+
+    let matches: Vec<Range<usize>> = if options.case_sensitive {
+        text.match_indices(query)
+            .map(|(ix, matched)| ix..ix + matched.len())
+            .collect()
+    } else {
+        // The lowercase may change the byte length of some chars, so compare char by char.
+        let query: Vec<char> = query.chars().flat_map(char::to_lowercase).collect();
+        let mut matches = vec![];
+        let mut ix = 0;
+        while ix < text.len() {
+            let mut chars = text[ix..]
+                .char_indices()
+                .flat_map(|(i, c)| c.to_lowercase().map(move |c| (i, c)));
+            let mut end = None;
+            for (n, q) in query.iter().enumerate() {
+                match chars.next() {
+                    Some((i, c)) if c == *q => {
+                        if n == query.len() - 1 {
+                            let len = text[ix + i..].chars().next().map_or(0, char::len_utf8);
+                            end = Some(ix + i + len);
+                        }
+                    }
+                    _ => break,
+                }
+            }
+
+            match end {
+                Some(end) => {
+                    matches.push(ix..end);
+                    ix = end;
+                }
+                None => {
+                    ix += text[ix..].chars().next().map_or(1, char::len_utf8);
+                }
+            }
+        }
+        matches
+    };
+
+    if !options.whole_word {
+        return matches;
+    }
+
+    let is_word_char = |c: char| c.is_alphanumeric() || c == '_';
+    matches
+        .into_iter()
+        .filter(|range| {
+            let before = text[..range.start].chars().next_back();
+            let after = text[range.end..].chars().next();
+            !before.map_or(false, is_word_char) && !after.map_or(false, is_word_char)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_matches() {
+        let text = "Hello world, hello World! helloworld";
+        assert_eq!(
+            find_matches(text, "hello", SearchOptions::default()),
+            vec![0..5, 13..18, 26..31]
+        );
+        assert_eq!(
+            find_matches(text, "hello", SearchOptions::default().case_sensitive(true)),
+            vec![13..18, 26..31]
+        );
+        assert_eq!(
+            find_matches(text, "world", SearchOptions::default().whole_word(true)),
+            vec![6..11, 19..24]
+        );
+        assert_eq!(
+            find_matches(
+                text,
+                "World",
+                SearchOptions::default()
+                    .case_sensitive(true)
+                    .whole_word(true)
+            ),
+            vec![19..24]
+        );
+        assert!(find_matches(text, "", SearchOptions::default()).is_empty());
+    }
+
+    #[test]
+    fn test_find_matches_unicode() {
+        let text = "你好 Straße STRASSE 你好";
+        assert_eq!(
+            find_matches(text, "你好", SearchOptions::default()),
+            vec![0..6, 23..29]
+        );
+        assert_eq!(
+            find_matches(text, "straße", SearchOptions::default()),
+            vec![7..14]
+        );
+        assert_eq!(
+            find_matches("aaa", "aa", SearchOptions::default()),
+            vec![0..2]
+        );
+    }
+}
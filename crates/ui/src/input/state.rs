@@ -9,14 +9,14 @@ use smallvec::SmallVec;
 use std::cell::RefCell;
 use std::ops::{Deref, Range};
 use std::rc::Rc;
-use unicode_segmentation::*;
 
 use gpui::{
     actions, div, point, prelude::FluentBuilder as _, px, App, AppContext, Bounds, ClipboardItem,
-    Context, Entity, EntityInputHandler, EventEmitter, FocusHandle, Focusable,
+    Context, Entity, EntityInputHandler, EventEmitter, FocusHandle, Focusable, Hsla,
     InteractiveElement as _, IntoElement, KeyBinding, KeyDownEvent, MouseButton, MouseDownEvent,
     MouseMoveEvent, MouseUpEvent, ParentElement as _, Pixels, Point, Render, ScrollHandle,
-    ScrollWheelEvent, SharedString, Styled as _, Subscription, UTF16Selection, Window, WrappedLine,
+    ScrollWheelEvent, SharedString, Styled as _, Subscription, Task, UTF16Selection, Window,
+    WrappedLine,
 };
 
 // TODO:
@@ -24,16 +24,21 @@ use gpui::{
 
 use super::{
     blink_cursor::BlinkCursor,
+    bracket::{closing_pair, is_closing_pair, is_quote},
     change::Change,
+    completion::{completion_word_range, CompletionMenu, CompletionProvider, COMPLETION_DEBOUNCE},
+    cursor::merge_selections,
     element::TextElement,
     mask_pattern::MaskPattern,
+    max_length::truncate_to_chars,
     mode::{InputMode, TabSize},
     number_input,
+    search::{find_matches, SearchEvent, SearchOptions},
     text_wrapper::TextWrapper,
 };
 use crate::input::hover_popover::DiagnosticPopover;
 use crate::input::marker::Marker;
-use crate::input::{Cursor, LineColumn, RopeExt, Selection};
+use crate::input::{Cursor, LineColumn, RopeExt, Selection, TextDirection};
 use crate::{history::History, scroll::ScrollbarState, Root};
 
 #[derive(Action, Clone, PartialEq, Eq, Deserialize)]
@@ -79,6 +84,7 @@ actions!(
         Copy,
         Cut,
         Paste,
+        PastePlain,
         Undo,
         Redo,
         MoveToStartOfLine,
@@ -87,6 +93,7 @@ actions!(
         MoveToEnd,
         MoveToPreviousWord,
         MoveToNextWord,
+        SelectNextOccurrence,
         Escape
     ]
 );
@@ -179,6 +186,10 @@ pub fn init(cx: &mut App) {
         #[cfg(not(target_os = "macos"))]
         KeyBinding::new("ctrl-v", Paste, Some(CONTEXT)),
         #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-shift-v", PastePlain, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-shift-v", PastePlain, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
         KeyBinding::new("ctrl-a", MoveHome, Some(CONTEXT)),
         #[cfg(target_os = "macos")]
         KeyBinding::new("cmd-left", MoveHome, Some(CONTEXT)),
@@ -210,6 +221,10 @@ pub fn init(cx: &mut App) {
         KeyBinding::new("ctrl-z", Undo, Some(CONTEXT)),
         #[cfg(not(target_os = "macos"))]
         KeyBinding::new("ctrl-y", Redo, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-d", SelectNextOccurrence, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-d", SelectNextOccurrence, Some(CONTEXT)),
     ]);
 
     number_input::init(cx);
@@ -227,6 +242,28 @@ pub(super) struct LastLayout {
     pub(super) wrap_width: Option<Pixels>,
     /// The line number area width of text layout, if not line number, this will be 0px.
     pub(super) line_number_width: Pixels,
+    /// The width of the text area (excluding the line number area), used to align the lines for RTL.
+    pub(super) text_width: Pixels,
+    /// Whether the lines are laid out from right to left.
+    pub(super) rtl: bool,
+}
+
+impl LastLayout {
+    /// Returns the x offset of the line to align it.
+    pub(super) fn line_offset_x(&self, line: &WrappedLine) -> Pixels {
+        line_offset_x(line, self.text_width, self.rtl)
+    }
+}
+
+/// Returns the x offset of the line, the RTL line is aligned to the right of the `text_width`.
+///
+/// The soft wrapped lines are already fill the `text_width`, so they are not offset.
+pub(super) fn line_offset_x(line: &WrappedLine, text_width: Pixels, rtl: bool) -> Pixels {
+    if !rtl || !line.wrap_boundaries.is_empty() {
+        return px(0.);
+    }
+
+    (text_width - line.width()).max(px(0.))
 }
 
 impl Deref for LastLayout {
@@ -251,6 +288,18 @@ pub struct InputState {
     /// - "Hello 世界💝" = 16
     /// - "💝" = 4
     pub(super) selected_range: Selection,
+    /// The other selections of the multi-cursor editing, the `selected_range` is the primary one.
+    ///
+    /// Use `secondary` + click to add a cursor, `secondary-d` to add the next occurrence.
+    pub(super) secondary_selections: Vec<Selection>,
+    /// The query and options of the search, see [`InputState::search`].
+    search: Option<(SharedString, SearchOptions)>,
+    /// The byte ranges of the search matches.
+    pub(super) search_matches: Rc<Vec<Range<usize>>>,
+    /// The index of the current search match.
+    search_index: Option<usize>,
+    /// The line ranges (zero based) to paint a background color, see [`InputState::highlight_lines`].
+    pub(super) highlighted_lines: Vec<(Range<usize>, Hsla)>,
     /// Range for save the selected word, use to keep word range when drag move.
     pub(super) selected_word_range: Option<Selection>,
     pub(super) selection_reversed: bool,
@@ -266,10 +315,22 @@ pub struct InputState {
     pub(super) selecting: bool,
     pub(super) disabled: bool,
     pub(super) masked: bool,
+    pub(super) direction: TextDirection,
+    /// Whether the `tab` key inserts the indent in the multi-line modes,
+    /// otherwise the `tab` is propagated to move the focus.
+    pub(super) tab_inserts: bool,
+    /// The max length of the text in Unicode scalar values, set by [`super::TextInput::max_length`].
+    pub(super) max_length: Option<usize>,
+    /// Override to show the line numbers in the multi-line modes, set by [`super::TextInput::line_numbers`].
+    pub(super) line_numbers: Option<bool>,
     pub(super) clean_on_escape: bool,
     pub(super) soft_wrap: bool,
+    /// Auto close the brackets and quotes, and highlight the matching bracket.
+    pub(super) auto_pairs: bool,
     pub(super) pattern: Option<regex::Regex>,
     pub(super) validate: Option<Box<dyn Fn(&str, &mut Context<Self>) -> bool + 'static>>,
+    /// Transform the pasting text before insertion, see [`InputState::on_paste`].
+    on_paste: Option<Box<dyn Fn(String) -> String + 'static>>,
     pub(crate) scroll_handle: ScrollHandle,
     pub(super) scroll_state: ScrollbarState,
     /// The size of the scrollable content.
@@ -278,9 +339,20 @@ pub struct InputState {
     /// The mask pattern for formatting the input text
     pub(crate) mask_pattern: MaskPattern,
     pub(super) placeholder: SharedString,
+    /// The placeholder to show instead of the `placeholder` when the input is focused.
+    pub(super) focused_placeholder: Option<SharedString>,
+    /// Set by the [`TextInput::floating_label`] to hide the placeholder while the label is in its place.
+    ///
+    /// [`TextInput::floating_label`]: super::TextInput::floating_label
+    pub(super) placeholder_hidden: bool,
 
     /// Popover
     diagnostic_popover: Option<Entity<DiagnosticPopover>>,
+    completion_provider: Option<Rc<dyn CompletionProvider>>,
+    pub(super) completion_menu: Option<CompletionMenu>,
+    /// The start offset and the text before the cursor of the last queried word.
+    completion_query: Option<(usize, String)>,
+    _completion_task: Task<()>,
 
     /// To remember the horizontal column (x-coordinate) of the cursor position for keep column for move up/down.
     preferred_column: Option<usize>,
@@ -288,6 +360,7 @@ pub struct InputState {
 }
 
 impl EventEmitter<InputEvent> for InputState {}
+impl EventEmitter<SearchEvent> for InputState {}
 
 impl InputState {
     /// Create a Input state with default [`InputMode::SingleLine`] mode.
@@ -329,6 +402,11 @@ impl InputState {
             blink_cursor,
             history,
             selected_range: (Cursor::new(0)..Cursor::new(0)).into(),
+            secondary_selections: vec![],
+            search: None,
+            search_matches: Rc::new(vec![]),
+            search_index: None,
+            highlighted_lines: vec![],
             selected_word_range: None,
             selection_reversed: false,
             marked_range: None,
@@ -336,11 +414,17 @@ impl InputState {
             selecting: false,
             disabled: false,
             masked: false,
+            direction: TextDirection::default(),
+            tab_inserts: true,
+            line_numbers: None,
+            max_length: None,
             clean_on_escape: false,
             soft_wrap: true,
+            auto_pairs: false,
             loading: false,
             pattern: None,
             validate: None,
+            on_paste: None,
             mode: InputMode::SingleLine,
             last_layout: None,
             last_bounds: None,
@@ -351,8 +435,14 @@ impl InputState {
             scroll_size: gpui::size(px(0.), px(0.)),
             preferred_column: None,
             placeholder: SharedString::default(),
+            focused_placeholder: None,
+            placeholder_hidden: false,
             mask_pattern: MaskPattern::default(),
             diagnostic_popover: None,
+            completion_provider: None,
+            completion_menu: None,
+            completion_query: None,
+            _completion_task: Task::ready(()),
             _subscriptions,
         }
     }
@@ -378,6 +468,28 @@ impl InputState {
         self
     }
 
+    /// Switch the multi-line input to [`InputMode::AutoGrow`] mode with the rows limit.
+    ///
+    /// The single-line input and code editor are not affected.
+    pub(super) fn set_auto_height(&mut self, min_rows: usize, max_rows: usize) {
+        match self.mode {
+            InputMode::AutoGrow {
+                min_rows: current_min_rows,
+                max_rows: current_max_rows,
+                ..
+            } if current_min_rows == min_rows && current_max_rows == max_rows => return,
+            InputMode::MultiLine { .. } | InputMode::AutoGrow { .. } => {}
+            _ => return,
+        }
+
+        self.mode = InputMode::AutoGrow {
+            rows: min_rows,
+            min_rows,
+            max_rows,
+        };
+        self.mode.update_auto_grow(&self.text_wrapper);
+    }
+
     /// Set Input to use [`InputMode::CodeEditor`] mode.
     ///
     /// Default options:
@@ -415,6 +527,12 @@ impl InputState {
         self
     }
 
+    /// Set the placeholder to show when the input is focused and empty, default is the `placeholder`.
+    pub fn focused_placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.focused_placeholder = Some(placeholder.into());
+        self
+    }
+
     /// Set enable/disable line number, only for [`InputMode::CodeEditor`] mode.
     pub fn line_number(mut self, line_number: bool) -> Self {
         if let InputMode::CodeEditor { line_number: l, .. } = &mut self.mode {
@@ -431,6 +549,14 @@ impl InputState {
         cx.notify();
     }
 
+    /// Returns true if the line numbers gutter should be shown.
+    ///
+    /// The [`InputMode::CodeEditor`] shows by the `line_number` option,
+    /// this can be overridden for any multi-line mode by [`super::TextInput::line_numbers`].
+    pub(super) fn show_line_numbers(&self) -> bool {
+        self.mode.is_multi_line() && self.line_numbers.unwrap_or(self.mode.line_number())
+    }
+
     /// Set the tab size for the input.
     ///
     /// Only for [`InputMode::MultiLine`] and [`InputMode::CodeEditor`] mode.
@@ -505,6 +631,17 @@ impl InputState {
         self.mode.set_markers(markers);
     }
 
+    /// Set the line ranges (zero based, end exclusive) to paint a full width background color.
+    ///
+    /// The background is painted beneath the current line, selections and the text,
+    /// for example to show the compiler errors or the search results by line.
+    ///
+    /// Pass an empty vec to clear.
+    pub fn highlight_lines(&mut self, ranges: Vec<(Range<usize>, Hsla)>, cx: &mut Context<Self>) {
+        self.highlighted_lines = ranges;
+        cx.notify();
+    }
+
     /// Set placeholder
     pub fn set_placeholder(
         &mut self,
@@ -516,6 +653,17 @@ impl InputState {
         cx.notify();
     }
 
+    /// Set the placeholder to show when the input is focused and empty.
+    pub fn set_focused_placeholder(
+        &mut self,
+        placeholder: Option<impl Into<SharedString>>,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.focused_placeholder = placeholder.map(Into::into);
+        cx.notify();
+    }
+
     /// Called after moving the cursor. Updates preferred_column if we know where the cursor now is.
     fn update_preferred_column(&mut self) {
         let column_ix = self.text.line_column(self.cursor().offset).1;
@@ -544,7 +692,7 @@ impl InputState {
             let local_offset = offset.saturating_sub(prev_lines_offset);
             if let Some(pos) = line.position_for_index(local_offset, line_height) {
                 let sub_line_index = (pos.y.0 / line_height.0) as usize;
-                let adjusted_pos = point(pos.x, pos.y + y_offset);
+                let adjusted_pos = point(pos.x + last_layout.line_offset_x(line), pos.y + y_offset);
                 return (line_index, sub_line_index, Some(adjusted_pos));
             }
 
@@ -679,6 +827,35 @@ impl InputState {
         cx.notify();
     }
 
+    /// Set the text direction of the input field, default is [`TextDirection::Ltr`].
+    ///
+    /// In RTL, the lines are aligned to the right, and the left/right arrow keys
+    /// still move the cursor to the previous/next character in the logical order.
+    pub fn direction(mut self, direction: TextDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Set the text direction of the input field.
+    pub fn set_direction(
+        &mut self,
+        direction: TextDirection,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.direction = direction;
+        cx.notify();
+    }
+
+    /// Returns the resolved text direction, the [`TextDirection::Auto`] is inferred from the text.
+    pub fn text_direction(&self) -> TextDirection {
+        match self.direction {
+            // Only the beginning of the text is needed to find the first strong character.
+            TextDirection::Auto => TextDirection::Auto.resolve_chars(self.text.chars().take(256)),
+            direction => direction,
+        }
+    }
+
     /// Set true to clear the input by pressing Escape key.
     pub fn clean_on_escape(mut self) -> Self {
         self.clean_on_escape = true;
@@ -697,6 +874,21 @@ impl InputState {
         cx.notify();
     }
 
+    /// Set true to auto close the brackets and quotes on typing, default is false.
+    ///
+    /// Typing the closing char next to the same one skips over it,
+    /// and the matching bracket of the one next to the cursor is highlighted.
+    pub fn auto_pairs(mut self, auto_pairs: bool) -> Self {
+        self.auto_pairs = auto_pairs;
+        self
+    }
+
+    /// Update the auto pairs mode, see [`InputState::auto_pairs`].
+    pub fn set_auto_pairs(&mut self, auto_pairs: bool, _: &mut Window, cx: &mut Context<Self>) {
+        self.auto_pairs = auto_pairs;
+        cx.notify();
+    }
+
     /// Set the regular expression pattern of the input field.
     pub fn pattern(mut self, pattern: regex::Regex) -> Self {
         self.pattern = Some(pattern);
@@ -719,6 +911,15 @@ impl InputState {
         self
     }
 
+    /// Set a function to transform the pasting text before insertion,
+    /// e.g.: trim, collapse the whitespace, or convert the CRLF to LF.
+    ///
+    /// The [`PastePlain`] action inserts the clipboard text verbatim without this transform.
+    pub fn on_paste(mut self, f: impl Fn(String) -> String + 'static) -> Self {
+        self.on_paste = Some(Box::new(f));
+        self
+    }
+
     /// Set true to show indicator at the input right.
     pub fn set_loading(&mut self, loading: bool, _: &mut Window, cx: &mut Context<Self>) {
         self.loading = loading;
@@ -778,11 +979,257 @@ impl InputState {
         self.move_to(Cursor::new(offset), window, cx);
     }
 
+    /// Search the `query` in the text, the matches are highlighted and kept updated when the text changes.
+    ///
+    /// The first match after the cursor is selected, use [`Self::find_next`] and [`Self::find_prev`]
+    /// to move between the matches. A [`SearchEvent`] is emitted to show the "n of m" counts.
+    ///
+    /// An empty `query` clears the search.
+    pub fn search(
+        &mut self,
+        query: impl Into<SharedString>,
+        options: SearchOptions,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let query: SharedString = query.into();
+        if query.is_empty() {
+            return self.clear_search(window, cx);
+        }
+
+        self.search = Some((query, options));
+        self.update_search_matches(cx);
+        self.search_index = self.search_match_after(self.selected_range.start.offset);
+        self.select_search_match(cx);
+    }
+
+    /// Clear the search and the highlighted matches.
+    pub fn clear_search(&mut self, _: &mut Window, cx: &mut Context<Self>) {
+        self.search = None;
+        self.search_matches = Rc::new(vec![]);
+        self.search_index = None;
+        cx.emit(SearchEvent {
+            current: None,
+            total: 0,
+        });
+        cx.notify();
+    }
+
+    /// Select the next search match, wrap around to the first one at the end.
+    pub fn find_next(&mut self, _: &mut Window, cx: &mut Context<Self>) {
+        let total = self.search_matches.len();
+        if total == 0 {
+            return;
+        }
+
+        self.search_index = match self.search_index {
+            Some(ix) => Some((ix + 1) % total),
+            None => self.search_match_after(self.cursor().offset),
+        };
+        self.select_search_match(cx);
+    }
+
+    /// Select the previous search match, wrap around to the last one at the start.
+    pub fn find_prev(&mut self, _: &mut Window, cx: &mut Context<Self>) {
+        let total = self.search_matches.len();
+        if total == 0 {
+            return;
+        }
+
+        self.search_index = match self.search_index {
+            Some(ix) => Some((ix + total - 1) % total),
+            None => self
+                .search_match_after(self.cursor().offset)
+                .map(|ix| (ix + total - 1) % total),
+        };
+        self.select_search_match(cx);
+    }
+
+    /// Replace the current search match with the `replacement`, and select the next match.
+    pub fn replace_current(
+        &mut self,
+        replacement: &str,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(range) = self
+            .search_index
+            .and_then(|ix| self.search_matches.get(ix).cloned())
+        else {
+            return;
+        };
+
+        self.replace_text_in_range(Some(self.range_to_utf16(&range)), replacement, window, cx);
+        self.search_index = self.search_match_after(range.start + replacement.len());
+        self.select_search_match(cx);
+    }
+
+    /// Replace all the search matches with the `replacement`, as one history entry.
+    pub fn replace_all(&mut self, replacement: &str, window: &mut Window, cx: &mut Context<Self>) {
+        let ranges = self
+            .search_matches
+            .iter()
+            .map(|range| range.clone().into())
+            .collect();
+        let Some(cursors) = self.replace_text_in_ranges(ranges, replacement, window, cx) else {
+            return;
+        };
+
+        let offset = cursors.last().copied().unwrap_or_default();
+        self.selected_range = (offset..offset).into();
+        self.secondary_selections.clear();
+        self.update_preferred_column();
+        cx.notify();
+    }
+
+    /// Returns the index of the first search match starting at or after the `offset`,
+    /// wrap around to the first match.
+    fn search_match_after(&self, offset: usize) -> Option<usize> {
+        if self.search_matches.is_empty() {
+            return None;
+        }
+
+        Some(
+            self.search_matches
+                .iter()
+                .position(|range| range.start >= offset)
+                .unwrap_or(0),
+        )
+    }
+
+    fn select_search_match(&mut self, cx: &mut Context<Self>) {
+        if let Some(range) = self
+            .search_index
+            .and_then(|ix| self.search_matches.get(ix).cloned())
+        {
+            self.selected_range = range.into();
+            self.secondary_selections.clear();
+            self.selection_reversed = false;
+            self.update_preferred_column();
+        }
+
+        cx.emit(SearchEvent {
+            current: self.search_index,
+            total: self.search_matches.len(),
+        });
+        cx.notify();
+    }
+
+    /// Update the search matches after the text changed.
+    fn update_search_matches(&mut self, cx: &mut Context<Self>) {
+        let Some((query, options)) = &self.search else {
+            return;
+        };
+
+        self.search_matches = Rc::new(find_matches(&self.text.to_string(), query, *options));
+        let total = self.search_matches.len();
+        self.search_index = self
+            .search_index
+            .filter(|_| total > 0)
+            .map(|ix| ix.min(total - 1));
+        cx.emit(SearchEvent {
+            current: self.search_index,
+            total,
+        });
+    }
+
+    /// Set the provider to show the completion menu at the cursor while typing.
+    ///
+    /// The menu is navigated by `up` and `down`, accepted by `tab` or `enter` to replace
+    /// the word under the cursor, and dismissed by `escape`.
+    pub fn set_completion_provider(
+        &mut self,
+        provider: Rc<dyn CompletionProvider>,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.completion_provider = Some(provider);
+        self.hide_completion_menu(cx);
+    }
+
+    /// Schedule to query the completions with debounce, if the word under the cursor is changed.
+    fn update_completion(&mut self, cx: &mut Context<Self>) {
+        if self.completion_provider.is_none() {
+            return;
+        }
+
+        let offset = self.cursor().offset;
+        let Some(word_range) = completion_word_range(&self.text, offset) else {
+            self.hide_completion_menu(cx);
+            return;
+        };
+
+        let query = (
+            word_range.start,
+            self.text_for_range_utf8(word_range.start..offset)
+                .to_string(),
+        );
+        if self.completion_query.as_ref() == Some(&query) {
+            return;
+        }
+
+        self.completion_query = Some(query);
+        self._completion_task = cx.spawn(async move |this, cx| {
+            cx.background_executor().timer(COMPLETION_DEBOUNCE).await;
+            _ = this.update(cx, |this, cx| this.query_completions(cx));
+        });
+    }
+
+    fn query_completions(&mut self, cx: &mut Context<Self>) {
+        let Some(provider) = self.completion_provider.clone() else {
+            return;
+        };
+
+        let offset = self.cursor().offset;
+        let Some(word_range) = completion_word_range(&self.text, offset) else {
+            self.hide_completion_menu(cx);
+            return;
+        };
+
+        let items = provider.completions(&self.text, offset, cx);
+        self.completion_menu =
+            (!items.is_empty()).then(|| CompletionMenu::new(items, word_range.start));
+        cx.notify();
+    }
+
+    pub(super) fn hide_completion_menu(&mut self, cx: &mut Context<Self>) {
+        self.completion_query = None;
+        self._completion_task = Task::ready(());
+        if self.completion_menu.take().is_some() {
+            cx.notify();
+        }
+    }
+
+    /// Replace the word under the cursor with the selected completion.
+    ///
+    /// Returns false if the completion menu is not open.
+    pub(super) fn confirm_completion(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> bool {
+        let Some(new_text) = self
+            .completion_menu
+            .as_ref()
+            .and_then(|menu| menu.selected_text())
+        else {
+            return false;
+        };
+        self.hide_completion_menu(cx);
+
+        let offset = self.cursor().offset;
+        let range = completion_word_range(&self.text, offset).unwrap_or(offset..offset);
+        let range_utf16 = self.range_to_utf16(&range);
+        self.replace_text_in_range(Some(range_utf16), &new_text, window, cx);
+        true
+    }
+
     /// Focus the input field.
     pub fn focus(&self, window: &mut Window, _: &mut Context<Self>) {
         self.focus_handle.focus(window);
     }
 
+    /// Move the cursor to the previous character in the logical order, also in RTL.
     pub(super) fn left(&mut self, _: &MoveLeft, window: &mut Window, cx: &mut Context<Self>) {
         self.pause_blink_cursor(cx);
         if self.selected_range.is_empty() {
@@ -796,6 +1243,7 @@ impl InputState {
         }
     }
 
+    /// Move the cursor to the next character in the logical order, also in RTL.
     pub(super) fn right(&mut self, _: &MoveRight, window: &mut Window, cx: &mut Context<Self>) {
         self.pause_blink_cursor(cx);
         if self.selected_range.is_empty() {
@@ -810,6 +1258,12 @@ impl InputState {
     }
 
     pub(super) fn up(&mut self, _: &MoveUp, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(menu) = self.completion_menu.as_mut() {
+            menu.select(-1);
+            cx.notify();
+            return;
+        }
+
         if self.mode.is_single_line() {
             return;
         }
@@ -826,6 +1280,12 @@ impl InputState {
     }
 
     pub(super) fn down(&mut self, _: &MoveDown, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(menu) = self.completion_menu.as_mut() {
+            menu.select(1);
+            cx.notify();
+            return;
+        }
+
         if self.mode.is_single_line() {
             return;
         }
@@ -1042,29 +1502,12 @@ impl InputState {
 
     /// Return the start offset of the previous word.
     fn previous_start_of_word(&mut self) -> usize {
-        let offset = self.selected_range.start.offset;
-        let prev_str = self
-            .text_for_range_utf8(0..offset)
-            .as_str()
-            .unwrap_or_default();
-        UnicodeSegmentation::split_word_bound_indices(prev_str)
-            .filter(|(_, s)| !s.trim_start().is_empty())
-            .next_back()
-            .map(|(i, _)| i)
-            .unwrap_or(0)
+        self.text.prev_word_start(self.selected_range.start.offset)
     }
 
     /// Return the next end offset of the next word.
     fn next_end_of_word(&mut self) -> usize {
-        let offset = self.cursor().offset;
-        let next_str = self
-            .text_for_range_utf8(offset..self.text.len_bytes())
-            .as_str()
-            .unwrap_or_default();
-        UnicodeSegmentation::split_word_bound_indices(next_str)
-            .find(|(_, s)| !s.trim_start().is_empty())
-            .map(|(i, s)| offset + i + s.len())
-            .unwrap_or(self.text.len_bytes())
+        self.text.next_word_end(self.cursor().offset)
     }
 
     /// Get start of line
@@ -1191,6 +1634,7 @@ impl InputState {
     }
 
     pub(super) fn backspace(&mut self, _: &Backspace, window: &mut Window, cx: &mut Context<Self>) {
+        self.extend_secondary_selections(false);
         if self.selected_range.is_empty() {
             self.select_to(
                 Cursor::new(self.previous_boundary(self.cursor().offset)),
@@ -1203,6 +1647,7 @@ impl InputState {
     }
 
     pub(super) fn delete(&mut self, _: &Delete, window: &mut Window, cx: &mut Context<Self>) {
+        self.extend_secondary_selections(true);
         if self.selected_range.is_empty() {
             self.select_to(
                 Cursor::new(self.next_boundary(self.cursor().offset)),
@@ -1286,6 +1731,10 @@ impl InputState {
     }
 
     pub(super) fn enter(&mut self, action: &Enter, window: &mut Window, cx: &mut Context<Self>) {
+        if self.confirm_completion(window, cx) {
+            return;
+        }
+
         if self.mode.is_multi_line() {
             // Get current line indent
             let indent = if self.mode.is_code_editor() {
@@ -1313,6 +1762,15 @@ impl InputState {
         window: &mut Window,
         cx: &mut Context<Self>,
     ) {
+        if self.confirm_completion(window, cx) {
+            return;
+        }
+
+        if !self.is_tab_inserts() {
+            cx.propagate();
+            return;
+        }
+
         self.indent(false, window, cx);
     }
 
@@ -1326,6 +1784,11 @@ impl InputState {
         window: &mut Window,
         cx: &mut Context<Self>,
     ) {
+        if !self.is_tab_inserts() {
+            cx.propagate();
+            return;
+        }
+
         self.outdent(false, window, cx);
     }
 
@@ -1338,6 +1801,14 @@ impl InputState {
         self.outdent(true, window, cx);
     }
 
+    /// The multi-line modes insert the indent by `tab`, unless it is turned off
+    /// by [`TextInput::tab_inserts`].
+    ///
+    /// [`TextInput::tab_inserts`]: super::TextInput::tab_inserts
+    fn is_tab_inserts(&self) -> bool {
+        self.mode.is_multi_line() && self.tab_inserts
+    }
+
     pub(super) fn indent(&mut self, block: bool, window: &mut Window, cx: &mut Context<Self>) {
         let Some(tab_size) = self.mode.tab_size() else {
             return;
@@ -1478,6 +1949,17 @@ impl InputState {
             self.unmark_text(window, cx);
         }
 
+        if self.completion_menu.is_some() {
+            self.hide_completion_menu(cx);
+            return;
+        }
+
+        if !self.secondary_selections.is_empty() {
+            self.secondary_selections.clear();
+            cx.notify();
+            return;
+        }
+
         if self.clean_on_escape {
             return self.clean(window, cx);
         }
@@ -1499,8 +1981,17 @@ impl InputState {
             }
         }
 
-        self.selecting = true;
         let offset = self.index_for_mouse_position(event.position, window, cx);
+        // Secondary click to add a cursor
+        if self.mode.is_multi_line()
+            && event.button == MouseButton::Left
+            && event.modifiers.secondary()
+        {
+            self.add_cursor(offset, cx);
+            return;
+        }
+
+        self.selecting = true;
         // Double click to select word
         if event.button == MouseButton::Left && event.click_count == 2 {
             self.select_word(offset, window, cx);
@@ -1600,7 +2091,7 @@ impl InputState {
             return;
         }
 
-        let selected_text = self.text_for_range_utf8(self.selected_range).to_string();
+        let selected_text = self.selected_text();
         cx.write_to_clipboard(ClipboardItem::new_string(selected_text));
     }
 
@@ -1609,14 +2100,31 @@ impl InputState {
             return;
         }
 
-        let selected_text = self.text_for_range_utf8(self.selected_range).to_string();
+        let selected_text = self.selected_text();
         cx.write_to_clipboard(ClipboardItem::new_string(selected_text));
         self.replace_text_in_range(None, "", window, cx);
     }
 
     pub(super) fn paste(&mut self, _: &Paste, window: &mut Window, cx: &mut Context<Self>) {
+        self.paste_text(true, window, cx);
+    }
+
+    pub(super) fn paste_plain(
+        &mut self,
+        _: &PastePlain,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.paste_text(false, window, cx);
+    }
+
+    /// Paste the clipboard text, the `transform` is to apply the [`InputState::on_paste`] first.
+    fn paste_text(&mut self, transform: bool, window: &mut Window, cx: &mut Context<Self>) {
         if let Some(clipboard) = cx.read_from_clipboard() {
             let mut new_text = clipboard.text().unwrap_or_default();
+            if let Some(on_paste) = self.on_paste.as_ref().filter(|_| transform) {
+                new_text = on_paste(new_text);
+            }
             if !self.mode.is_multi_line() {
                 new_text = new_text.replace('\n', "");
             }
@@ -1676,6 +2184,8 @@ impl InputState {
     fn move_to(&mut self, cursor: Cursor, _: &mut Window, cx: &mut Context<Self>) {
         let cursor = Cursor::new(cursor.offset.clamp(0, self.text.len_bytes()));
         self.selected_range = (cursor..cursor).into();
+        self.secondary_selections.clear();
+        self.hide_completion_menu(cx);
         self.pause_blink_cursor(cx);
         self.update_preferred_column();
         cx.notify()
@@ -1732,7 +2242,8 @@ impl InputState {
         let mut y_offset = px(0.);
 
         for (_, line) in last_layout.lines.iter().enumerate() {
-            let line_origin = self.line_origin_with_y_offset(&mut y_offset, &line, line_height);
+            let line_origin = self.line_origin_with_y_offset(&mut y_offset, &line, line_height)
+                + point(last_layout.line_offset_x(line), px(0.));
             let pos = inner_position - line_origin;
 
             // Return offset by use closest_index_for_x if is single line mode.
@@ -1829,58 +2340,273 @@ impl InputState {
         cx.notify()
     }
 
-    /// Select the word at the given offset.
+    /// Returns all the selections (including the secondary selections of multi-cursor editing),
+    /// sorted by the offset.
+    pub fn selections(&self) -> Vec<Selection> {
+        let mut selections = self.secondary_selections.clone();
+        selections.push(self.selected_range);
+        selections.sort_by_key(|selection| selection.start.offset);
+        selections
+    }
+
+    /// Add a cursor at the given offset for multi-cursor editing, or remove the cursor if it already exists.
     ///
-    /// The offset is the UTF-8 offset.
+    /// The current selection is kept as a secondary selection.
+    fn add_cursor(&mut self, offset: usize, cx: &mut Context<Self>) {
+        let cursor = Cursor::new(offset.min(self.text.len_bytes()));
+        let contains = |selection: &Selection| selection.start <= cursor && cursor <= selection.end;
+
+        if let Some(ix) = self.secondary_selections.iter().position(contains) {
+            self.secondary_selections.remove(ix);
+        } else if contains(&self.selected_range) {
+            // Remove the primary cursor, the last secondary one becomes primary.
+            if let Some(selection) = self.secondary_selections.pop() {
+                self.selected_range = selection;
+            }
+        } else {
+            self.secondary_selections.push(self.selected_range);
+            self.selected_range = (cursor..cursor).into();
+        }
+        self.selection_reversed = false;
+        self.pause_blink_cursor(cx);
+        cx.notify();
+    }
+
+    /// Select the next occurrence of the selected text as a new cursor.
     ///
-    /// FIXME: When click on a non-word character, the word is not selected.
-    fn select_word(&mut self, offset: usize, window: &mut Window, cx: &mut Context<Self>) {
-        #[inline(always)]
-        fn is_word(c: char) -> bool {
-            c.is_alphanumeric() || matches!(c, '_')
-        }
-
-        let mut start = offset;
-        let mut end = start;
-        let prev_text = self
-            .text_for_range(self.range_to_utf16(&(0..start)), &mut None, window, cx)
-            .unwrap_or_default();
-        let next_text = self
-            .text_for_range(
-                self.range_to_utf16(&(end..self.text.len_bytes())),
-                &mut None,
-                window,
-                cx,
-            )
-            .unwrap_or_default();
+    /// If nothing is selected, the word under the cursor will be selected first.
+    pub(super) fn select_next_occurrence(
+        &mut self,
+        _: &SelectNextOccurrence,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.selected_range.is_empty() {
+            self.select_word(self.cursor().offset, window, cx);
+            self.selected_word_range = None;
+            return;
+        }
 
-        let prev_chars = prev_text.chars().rev();
-        let next_chars = next_text.chars();
+        let query = self.text_for_range_utf8(self.selected_range).to_string();
+        let text = self.text.to_string();
+        let selections = self.selections();
+        // Search after the primary selection, and wrap around.
+        let from = self.selected_range.end.offset;
+        let Some(start) = text[from..]
+            .match_indices(&query)
+            .map(|(ix, _)| from + ix)
+            .chain(text[..from].match_indices(&query).map(|(ix, _)| ix))
+            .find(|start| {
+                !selections
+                    .iter()
+                    .any(|selection| selection.start.offset == *start)
+            })
+        else {
+            return;
+        };
 
-        let pre_chars_count = prev_chars.clone().count();
-        for (ix, c) in prev_chars.enumerate() {
-            if !is_word(c) {
-                break;
-            }
+        self.secondary_selections.push(self.selected_range);
+        self.selected_range = (start..start + query.len()).into();
+        self.selection_reversed = false;
+        self.update_preferred_column();
+        self.pause_blink_cursor(cx);
+        cx.notify();
+    }
 
-            if ix < pre_chars_count {
-                start = start.saturating_sub(c.len_utf8());
-            }
-        }
+    /// Extend the empty secondary selections by one grapheme, to delete at every cursor.
+    fn extend_secondary_selections(&mut self, forward: bool) {
+        self.secondary_selections = self
+            .secondary_selections
+            .iter()
+            .map(|selection| {
+                if !selection.is_empty() {
+                    return *selection;
+                }
 
-        for (_, c) in next_chars.enumerate() {
-            if !is_word(c) {
-                break;
-            }
+                let offset = selection.start.offset;
+                if forward {
+                    (offset..self.next_boundary(offset)).into()
+                } else {
+                    (self.previous_boundary(offset)..offset).into()
+                }
+            })
+            .collect();
+    }
 
-            end += c.len_utf8();
+    /// Returns the selected text, the text of multiple selections are joined by newline.
+    fn selected_text(&self) -> String {
+        if self.secondary_selections.is_empty() {
+            return self.text_for_range_utf8(self.selected_range).to_string();
         }
 
-        if start == end {
+        self.selections()
+            .into_iter()
+            .map(|selection| self.text_for_range_utf8(selection).to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Replace the text of all the selections with the `new_text`, as one history entry.
+    fn replace_text_in_selections(
+        &mut self,
+        new_text: &str,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let primary = self.selected_range;
+        let selections = merge_selections(self.selections());
+        let Some(cursors) = self.replace_text_in_ranges(selections.clone(), new_text, window, cx)
+        else {
             return;
+        };
+
+        let primary_ix = selections
+            .iter()
+            .position(|selection| selection.start <= primary.start && primary.end <= selection.end)
+            .unwrap_or(selections.len() - 1);
+        let mut new_selections: Vec<Selection> = cursors
+            .into_iter()
+            .map(|offset| (offset..offset).into())
+            .collect();
+        self.selected_range = new_selections.remove(primary_ix);
+        self.secondary_selections = new_selections;
+        self.selection_reversed = false;
+        self.update_preferred_column();
+        cx.notify();
+    }
+
+    /// Replace the text in the `ranges` with the `new_text`, as one history entry.
+    ///
+    /// Returns the offsets after each replaced text (sorted), or `None` if the new text is invalid.
+    fn replace_text_in_ranges(
+        &mut self,
+        ranges: Vec<Selection>,
+        new_text: &str,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Option<Vec<usize>> {
+        let ranges = merge_selections(ranges);
+        if ranges.is_empty() {
+            return None;
+        }
+
+        let mut pending_text = String::with_capacity(self.text.len_bytes());
+        let mut cursors = Vec::with_capacity(ranges.len());
+        let mut last_end = 0;
+        for range in &ranges {
+            pending_text.push_str(
+                &self
+                    .text_for_range_utf8(last_end..range.start.offset)
+                    .to_string(),
+            );
+            pending_text.push_str(new_text);
+            cursors.push(pending_text.len());
+            last_end = range.end.offset;
+        }
+        pending_text.push_str(
+            &self
+                .text_for_range_utf8(last_end..self.text.len_bytes())
+                .to_string(),
+        );
+        if !self.is_valid_input(&pending_text, cx) {
+            return None;
+        }
+
+        // Push from the last one, so that the ranges of each change are still valid when undo.
+        self.history.start_group();
+        for range in ranges.iter().rev() {
+            self.push_history(&range.into(), new_text, window, cx);
         }
+        self.history.end_group();
 
-        self.selected_range = (start..end).into();
+        // The whole edited range, from the first range to the last one.
+        let changed_range = ranges[0].start.offset..last_end;
+        let changed_text = &pending_text[ranges[0].start.offset..*cursors.last().unwrap()];
+        self.text = Rope::from_str(&pending_text);
+        self.mode.clear_markers();
+        self.text_wrapper.update(&self.text, false, cx);
+        self.mode
+            .update_highlighter(&changed_range, &self.text, changed_text, true, cx);
+        self.marked_range.take();
+        self.update_scroll_offset(None, cx);
+        self.mode.update_auto_grow(&self.text_wrapper);
+        self.update_search_matches(cx);
+        cx.emit(InputEvent::Change(self.unmask_value()));
+        cx.notify();
+
+        Some(cursors)
+    }
+
+    /// Truncate the `new_text` to replace the `range` to fit the max length, see [`super::TextInput::max_length`].
+    fn truncate_to_max_length<'a>(&self, range: &Range<usize>, new_text: &'a str) -> &'a str {
+        let Some(max_length) = self.max_length else {
+            return new_text;
+        };
+
+        let replaced_len = self.text.byte_to_char(range.end) - self.text.byte_to_char(range.start);
+        let remaining_len = self.text.len_chars() - replaced_len;
+        truncate_to_chars(new_text, max_length.saturating_sub(remaining_len))
+    }
+
+    /// Handle the typing of a single char for the [`InputState::auto_pairs`].
+    ///
+    /// Returns false if the char is not handled, to insert it as normal.
+    fn insert_auto_pair(
+        &mut self,
+        new_text: &str,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> bool {
+        let mut chars = new_text.chars();
+        let (Some(c), None) = (chars.next(), chars.next()) else {
+            return false;
+        };
+        if !self.selected_range.is_empty() || !self.secondary_selections.is_empty() {
+            return false;
+        }
+
+        let offset = self.cursor().offset;
+        let char_ix = self.text.byte_to_char(offset);
+        let prev_char = char_ix.checked_sub(1).and_then(|ix| self.text.get_char(ix));
+        let next_char = self.text.get_char(char_ix);
+
+        // Skip over the closing char, instead of inserting a duplicate one.
+        if is_closing_pair(c) && next_char == Some(c) {
+            self.move_to(Cursor::new(offset + c.len_utf8()), window, cx);
+            return true;
+        }
+
+        let Some(close) = closing_pair(c) else {
+            return false;
+        };
+        // Only pair before the whitespace or the closing chars, and not the quote after a word (e.g.: `don't`).
+        if next_char.is_some_and(|c| !c.is_whitespace() && !is_closing_pair(c))
+            || (is_quote(c) && prev_char.is_some_and(|c| c.is_alphanumeric() || c == '_'))
+        {
+            return false;
+        }
+
+        // Insert the pair in one change, so it is undone in one step.
+        let len = self.text.len_bytes();
+        self.replace_text_in_range(None, &format!("{}{}", c, close), window, cx);
+        if self.text.len_bytes() != len {
+            let cursor = offset + c.len_utf8();
+            self.selected_range = (cursor..cursor).into();
+            self.update_preferred_column();
+            cx.notify();
+        }
+        true
+    }
+
+    /// Select the word at the given offset.
+    ///
+    /// The offset is the UTF-8 offset, the word is split by the Unicode word boundaries.
+    fn select_word(&mut self, offset: usize, _: &mut Window, cx: &mut Context<Self>) {
+        let Some(range) = self.text.word_range(offset) else {
+            return;
+        };
+
+        self.selected_range = range.into();
         self.selected_word_range = Some(self.selected_range);
         cx.notify()
     }
@@ -1931,24 +2657,11 @@ impl InputState {
     }
 
     fn previous_boundary(&self, offset: usize) -> usize {
-        let char_ix = self.text.byte_to_char(offset).saturating_sub(1);
-        self.text.char_to_byte(char_ix)
-
-        // self.text
-        //     .grapheme_indices(true)
-        //     .rev()
-        //     .find_map(|(idx, _)| (idx < offset).then_some(idx))
-        //     .unwrap_or(0)
+        self.text.prev_grapheme_boundary(offset)
     }
 
     fn next_boundary(&self, offset: usize) -> usize {
-        let char_ix = self.text.byte_to_char(offset) + 1;
-        self.text.char_to_byte(char_ix).min(self.text.len_bytes())
-
-        // self.text
-        //     .grapheme_indices(true)
-        //     .find_map(|(idx, _)| (idx > offset).then_some(idx))
-        //     .unwrap_or(self.text.len())
+        self.text.next_grapheme_boundary(offset)
     }
 
     /// Returns the true to let InputElement to render cursor, when Input is focused and current BlinkCursor is visible.
@@ -1972,6 +2685,7 @@ impl InputState {
         Root::update(window, cx, |root, _, _| {
             root.focused_input = None;
         });
+        self.hide_completion_menu(cx);
         cx.emit(InputEvent::Blur);
     }
 
@@ -2016,6 +2730,14 @@ impl InputState {
             return true;
         }
 
+        // Allow to delete the text that is already over the limit (e.g.: by `set_value`).
+        if let Some(max_length) = self.max_length {
+            let len = new_text.chars().count();
+            if len > max_length && len > self.text.len_chars() {
+                return false;
+            }
+        }
+
         if let Some(validate) = &self.validate {
             if !validate(new_text, cx) {
                 return false;
@@ -2142,12 +2864,35 @@ impl EntityInputHandler for InputState {
             return;
         }
 
+        if range_utf16.is_none()
+            && self.marked_range.is_none()
+            && !self.secondary_selections.is_empty()
+        {
+            self.replace_text_in_selections(new_text, window, cx);
+            return;
+        }
+        if range_utf16.is_none()
+            && self.marked_range.is_none()
+            && self.auto_pairs
+            && self.insert_auto_pair(new_text, window, cx)
+        {
+            return;
+        }
+        // Other replacements (e.g.: Undo, IME) only apply to the primary cursor.
+        self.secondary_selections.clear();
+
         let range = range_utf16
             .as_ref()
             .map(|range_utf16| self.range_from_utf16(range_utf16))
             .or(self.marked_range.map(|range| range.into()))
             .unwrap_or(self.selected_range.into());
 
+        // Truncate the typing or pasting text to fit the max length.
+        let new_text = match self.truncate_to_max_length(&range, new_text) {
+            "" if !new_text.is_empty() && range.is_empty() => return,
+            new_text => new_text,
+        };
+
         let pending_text: SharedString = (self.text_for_range_utf8(0..range.start).to_string()
             + new_text
             + &self
@@ -2175,6 +2920,11 @@ impl EntityInputHandler for InputState {
         self.update_preferred_column();
         self.update_scroll_offset(None, cx);
         self.mode.update_auto_grow(&self.text_wrapper);
+        self.update_search_matches(cx);
+        // Only the typing (without the range) updates the completions, not the undo or the accepted completion.
+        if range_utf16.is_none() {
+            self.update_completion(cx);
+        }
         cx.emit(InputEvent::Change(self.unmask_value()));
         cx.notify();
     }
@@ -2191,6 +2941,7 @@ impl EntityInputHandler for InputState {
         if self.disabled {
             return;
         }
+        self.secondary_selections.clear();
 
         let range = range_utf16
             .as_ref()
@@ -2227,6 +2978,7 @@ impl EntityInputHandler for InputState {
                 .into();
         }
         self.mode.update_auto_grow(&self.text_wrapper);
+        self.update_search_matches(cx);
         cx.emit(InputEvent::Change(self.unmask_value()));
         cx.notify();
     }
@@ -2260,7 +3012,7 @@ impl EntityInputHandler for InputState {
                 if let Some(p) =
                     line.position_for_index(range.start.saturating_sub(index_offset), line_height)
                 {
-                    start_origin = Some(p + point(px(0.), y_offset));
+                    start_origin = Some(p + point(last_layout.line_offset_x(line), y_offset));
                 }
             }
 
@@ -2268,7 +3020,7 @@ impl EntityInputHandler for InputState {
                 if let Some(p) =
                     line.position_for_index(range.end.saturating_sub(index_offset), line_height)
                 {
-                    end_origin = Some(p + point(px(0.), y_offset));
+                    end_origin = Some(p + point(last_layout.line_offset_x(line), y_offset));
                 }
             }
 
@@ -2315,18 +3067,31 @@ impl Focusable for InputState {
 }
 
 impl Render for InputState {
-    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
         self.text_wrapper.update(&self.text, false, cx);
         self.mode
             .update_highlighter(&(0..0), &self.text, "", false, cx);
 
+        let placeholder = if self.placeholder_hidden {
+            SharedString::default()
+        } else if self.focus_handle.is_focused(window) {
+            self.focused_placeholder
+                .clone()
+                .unwrap_or_else(|| self.placeholder.clone())
+        } else {
+            self.placeholder.clone()
+        };
+
         div()
             .id("input-state")
             .flex_1()
             .when(self.mode.is_multi_line(), |this| this.h_full())
             .flex_grow()
             .overflow_x_hidden()
-            .child(TextElement::new(cx.entity().clone()).placeholder(self.placeholder.clone()))
+            .child(TextElement::new(cx.entity().clone()).placeholder(placeholder))
             .children(self.diagnostic_popover.clone())
+            .when_some(self.completion_menu.as_ref(), |this, menu| {
+                this.children(menu.render(self, window, cx))
+            })
     }
 }
@@ -1,21 +1,24 @@
+use std::time::Duration;
+
 use gpui::prelude::FluentBuilder as _;
 use gpui::{
-    div, px, relative, AnyElement, App, DefiniteLength, Entity, InteractiveElement as _,
-    IntoElement, IsZero, MouseButton, ParentElement as _, Rems, RenderOnce, StyleRefinement,
-    Styled, Window,
+    div, px, relative, Animation, AnimationExt as _, AnyElement, App, DefiniteLength, Entity,
+    InteractiveElement as _, IntoElement, IsZero, MouseButton, ParentElement as _, Pixels, Rems,
+    RenderOnce, SharedString, StyleRefinement, Styled, Window,
 };
 
+use crate::animation::cubic_bezier;
 use crate::button::{Button, ButtonVariants as _};
 use crate::indicator::Indicator;
 use crate::input::clear_button;
 use crate::input::element::{LINE_NUMBER_RIGHT_MARGIN, RIGHT_MARGIN};
 use crate::scroll::Scrollbar;
 use crate::ActiveTheme;
-use crate::{h_flex, StyledExt};
+use crate::{h_flex, v_flex, StyledExt};
 use crate::{IconName, Size};
 use crate::{Sizable, StyleSized};
 
-use super::InputState;
+use super::{InputState, TextDirection};
 
 #[derive(IntoElement)]
 pub struct TextInput {
@@ -31,6 +34,14 @@ pub struct TextInput {
     disabled: bool,
     bordered: bool,
     focus_bordered: bool,
+    direction: Option<TextDirection>,
+    auto_height: Option<(usize, usize)>,
+    tab_inserts: Option<bool>,
+    line_numbers: Option<bool>,
+    max_length: Option<usize>,
+    soft_limit: Option<usize>,
+    show_count: bool,
+    floating_label: Option<SharedString>,
 }
 
 impl Sizable for TextInput {
@@ -56,6 +67,14 @@ impl TextInput {
             disabled: false,
             bordered: true,
             focus_bordered: true,
+            direction: None,
+            auto_height: None,
+            tab_inserts: None,
+            line_numbers: None,
+            max_length: None,
+            soft_limit: None,
+            show_count: false,
+            floating_label: None,
         }
     }
 
@@ -117,6 +136,130 @@ impl TextInput {
         self
     }
 
+    /// Set the text direction of the input field, this will override the direction of the [`InputState`].
+    ///
+    /// See also [`InputState::direction`].
+    pub fn direction(mut self, direction: TextDirection) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Set the multi-line input to grow with the content from `min_rows` up to `max_rows`,
+    /// then scroll, this will override the rows of the [`InputState`].
+    ///
+    /// See also [`InputState::auto_grow`].
+    pub fn auto_height(mut self, min_rows: usize, max_rows: usize) -> Self {
+        self.auto_height = Some((min_rows, max_rows.max(min_rows)));
+        self
+    }
+
+    /// Set false to let `tab` and `shift-tab` move the focus in the multi-line input,
+    /// like the regular form fields.
+    ///
+    /// Default is true, the `tab` inserts the [`TabSize`] indent and `shift-tab`
+    /// outdents the current line or selected lines.
+    ///
+    /// [`TabSize`]: super::TabSize
+    pub fn tab_inserts(mut self, tab_inserts: bool) -> Self {
+        self.tab_inserts = Some(tab_inserts);
+        self
+    }
+
+    /// Set true to show the line numbers gutter in the multi-line input,
+    /// this will override the `line_number` option of the [`InputState`].
+    ///
+    /// The soft wrapped lines share the number of the line they continue.
+    pub fn line_numbers(mut self, line_numbers: bool) -> Self {
+        self.line_numbers = Some(line_numbers);
+        self
+    }
+
+    /// Set the max length of the text in Unicode scalar values,
+    /// the typing or pasting text beyond the limit is truncated.
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Set a soft limit of the text length, the counter is shown in the danger color when
+    /// the length is over the limit, but the input is not blocked.
+    ///
+    /// See also [`TextInput::show_count`].
+    pub fn soft_limit(mut self, soft_limit: usize) -> Self {
+        self.soft_limit = Some(soft_limit);
+        self
+    }
+
+    /// Set true to show the "used/limit" counter of the text length beneath the input.
+    pub fn show_count(mut self, show_count: bool) -> Self {
+        self.show_count = show_count;
+        self
+    }
+
+    fn render_count(
+        count: usize,
+        max_length: Option<usize>,
+        soft_limit: Option<usize>,
+        cx: &App,
+    ) -> impl IntoElement {
+        let over_limit = soft_limit.is_some_and(|limit| count > limit);
+        let label = match max_length.or(soft_limit) {
+            Some(limit) => format!("{}/{}", count, limit),
+            None => count.to_string(),
+        };
+
+        h_flex()
+            .justify_end()
+            .text_xs()
+            .text_color(if over_limit {
+                cx.theme().danger
+            } else {
+                cx.theme().muted_foreground
+            })
+            .child(label)
+    }
+
+    /// Set a floating label, the label is shown in place of the placeholder when the input is empty,
+    /// and moves to a small label above the text when the input is focused or filled.
+    ///
+    /// See also [`InputState::focused_placeholder`] to show a hint below the floated label.
+    pub fn floating_label(mut self, label: impl Into<SharedString>) -> Self {
+        self.floating_label = Some(label.into());
+        self
+    }
+
+    fn render_floating_label(
+        label: SharedString,
+        floated: bool,
+        focused: bool,
+        font_size: Pixels,
+        cx: &App,
+    ) -> impl IntoElement {
+        let color = if floated && focused {
+            cx.theme().ring
+        } else {
+            cx.theme().muted_foreground
+        };
+
+        div()
+            .absolute()
+            .left_0()
+            .top_0()
+            .whitespace_nowrap()
+            .text_color(color)
+            .child(label)
+            .with_animation(
+                ("floating-label", floated as usize),
+                Animation::new(Duration::from_secs_f64(0.15))
+                    .with_easing(cubic_bezier(0.4, 0., 0.2, 1.)),
+                move |this, delta| {
+                    let progress = if floated { delta } else { 1. - delta };
+                    this.top(px(-18.) * progress)
+                        .text_size(font_size * (1. - 0.25 * progress))
+                },
+            )
+    }
+
     fn render_toggle_mask_button(state: Entity<InputState>) -> impl IntoElement {
         Button::new("toggle-mask")
             .icon(IconName::Eye)
@@ -156,6 +299,21 @@ impl RenderOnce for TextInput {
         self.state.update(cx, |state, cx| {
             state.text_wrapper.set_font(font, font_size, cx);
             state.disabled = self.disabled;
+            state.max_length = self.max_length;
+            if let Some(direction) = self.direction {
+                state.direction = direction;
+            }
+            if let Some(tab_inserts) = self.tab_inserts {
+                state.tab_inserts = tab_inserts;
+            }
+            if let Some(line_numbers) = self.line_numbers {
+                state.line_numbers = Some(line_numbers);
+            }
+            if let Some((min_rows, max_rows)) = self.auto_height {
+                state.set_auto_height(min_rows, max_rows);
+            }
+            state.placeholder_hidden =
+                self.floating_label.is_some() && !state.focus_handle.is_focused(window);
         });
 
         let state = self.state.read(cx);
@@ -178,9 +336,14 @@ impl RenderOnce for TextInput {
             && !state.loading
             && state.text.len_bytes() > 0
             && state.mode.is_single_line();
+        let is_empty = state.text.len_bytes() == 0;
         let has_suffix = suffix.is_some() || state.loading || self.mask_toggle || show_clear_button;
 
-        div()
+        let count = self.show_count.then(|| {
+            Self::render_count(state.text.len_chars(), self.max_length, self.soft_limit, cx)
+        });
+
+        let input = div()
             .id(("input", self.state.entity_id()))
             .flex()
             .key_context(crate::input::CONTEXT)
@@ -197,6 +360,7 @@ impl RenderOnce for TextInput {
                     .on_action(window.listener_for(&self.state, InputState::enter))
                     .on_action(window.listener_for(&self.state, InputState::escape))
                     .on_action(window.listener_for(&self.state, InputState::paste))
+                    .on_action(window.listener_for(&self.state, InputState::paste_plain))
                     .on_action(window.listener_for(&self.state, InputState::cut))
                     .on_action(window.listener_for(&self.state, InputState::undo))
                     .on_action(window.listener_for(&self.state, InputState::redo))
@@ -218,6 +382,7 @@ impl RenderOnce for TextInput {
                     .on_action(window.listener_for(&self.state, InputState::select_down))
                     .on_action(window.listener_for(&self.state, InputState::page_up))
                     .on_action(window.listener_for(&self.state, InputState::page_down))
+                    .on_action(window.listener_for(&self.state, InputState::select_next_occurrence))
             })
             .on_action(window.listener_for(&self.state, InputState::select_all))
             .on_action(window.listener_for(&self.state, InputState::select_to_start_of_line))
@@ -270,8 +435,31 @@ impl RenderOnce for TextInput {
             .input_px(self.size)
             .items_center()
             .gap(gap_x)
+            .when(self.floating_label.is_some(), |this| {
+                // Leave the space above the text for the floated label.
+                this.when(state.mode.is_single_line(), |this| this.h_auto())
+                    .pt(px(20.))
+                    .pb(px(6.))
+            })
             .children(prefix)
-            .child(self.state.clone())
+            .map(|this| match self.floating_label.clone() {
+                Some(label) => this.child(
+                    div()
+                        .relative()
+                        .flex()
+                        .flex_1()
+                        .when(state.mode.is_multi_line(), |this| this.h_full())
+                        .child(self.state.clone())
+                        .child(Self::render_floating_label(
+                            label,
+                            focused || !is_empty,
+                            focused,
+                            font_size,
+                            cx,
+                        )),
+                ),
+                None => this.child(self.state.clone()),
+            })
             .when(has_suffix, |this| {
                 this.pr(self.size.input_px() / 2.).child(
                     h_flex()
@@ -354,6 +542,16 @@ impl RenderOnce for TextInput {
                 } else {
                     this
                 }
-            })
+            });
+
+        match count {
+            Some(count) => v_flex()
+                .w_full()
+                .gap_1()
+                .child(input)
+                .child(count)
+                .into_any_element(),
+            None => input.into_any_element(),
+        }
     }
 }
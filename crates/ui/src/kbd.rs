@@ -5,12 +5,40 @@ use gpui::{
 
 use crate::{ActiveTheme, StyledExt};
 
+/// The operating system style to display the [`Kbd`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Os {
+    MacOS,
+    Windows,
+    Linux,
+}
+
+impl Os {
+    /// Return the current operating system.
+    pub fn current() -> Self {
+        if cfg!(target_os = "macos") {
+            Self::MacOS
+        } else if cfg!(target_os = "windows") {
+            Self::Windows
+        } else {
+            Self::Linux
+        }
+    }
+}
+
+impl Default for Os {
+    fn default() -> Self {
+        Self::current()
+    }
+}
+
 /// A key binding tag
 #[derive(IntoElement, Clone, Debug)]
 pub struct Kbd {
     style: StyleRefinement,
     stroke: Keystroke,
     appearance: bool,
+    platform: Option<Os>,
 }
 
 impl From<Keystroke> for Kbd {
@@ -19,6 +47,7 @@ impl From<Keystroke> for Kbd {
             style: StyleRefinement::default(),
             stroke,
             appearance: true,
+            platform: None,
         }
     }
 }
@@ -29,9 +58,24 @@ impl Kbd {
             style: StyleRefinement::default(),
             stroke,
             appearance: true,
+            platform: None,
         }
     }
 
+    /// Create a new Kbd from the keystroke, the modifiers are displayed in the
+    /// platform style, e.g.: `⇧⌘P` on macOS and `Shift+Win+P` on Windows and Linux.
+    pub fn from_keystroke(stroke: &Keystroke) -> Self {
+        Self::new(stroke.clone())
+    }
+
+    /// Set the operating system style to display, default is the current OS.
+    ///
+    /// This is useful for documentation that needs a specific OS style.
+    pub fn platform(mut self, platform: Os) -> Self {
+        self.platform = Some(platform);
+        self
+    }
+
     /// Set the appearance of the keybinding.
     pub fn appearance(mut self, appearance: bool) -> Self {
         self.appearance = appearance;
@@ -64,10 +108,13 @@ impl Kbd {
     /// macOS: https://support.apple.com/en-us/HT201236
     /// Windows: https://support.microsoft.com/en-us/windows/keyboard-shortcuts-in-windows-dcc61a57-8ff0-cffe-9796-cb9706c75eec
     pub fn format(key: &Keystroke) -> String {
-        #[cfg(target_os = "macos")]
-        const DIVIDER: &str = "";
-        #[cfg(not(target_os = "macos"))]
-        const DIVIDER: &str = "+";
+        Self::format_for(key, Os::current())
+    }
+
+    /// Return the keybinding string by KeyStroke in the style of the given [`Os`].
+    pub fn format_for(key: &Keystroke, os: Os) -> String {
+        let mac = os == Os::MacOS;
+        let divider = if mac { "" } else { "+" };
 
         let mut parts = vec![];
 
@@ -75,92 +122,39 @@ impl Kbd {
         // And in Windows is: Ctrl+Alt+Shift+Win
 
         if key.modifiers.control {
-            #[cfg(target_os = "macos")]
-            parts.push("⌃");
-
-            #[cfg(not(target_os = "macos"))]
-            parts.push("Ctrl");
+            parts.push(if mac { "⌃" } else { "Ctrl" });
         }
 
         if key.modifiers.alt {
-            #[cfg(target_os = "macos")]
-            parts.push("⌥");
-
-            #[cfg(not(target_os = "macos"))]
-            parts.push("Alt");
+            parts.push(if mac { "⌥" } else { "Alt" });
         }
 
         if key.modifiers.shift {
-            #[cfg(target_os = "macos")]
-            parts.push("⇧");
-
-            #[cfg(not(target_os = "macos"))]
-            parts.push("Shift");
+            parts.push(if mac { "⇧" } else { "Shift" });
         }
 
         if key.modifiers.platform {
-            #[cfg(target_os = "macos")]
-            parts.push("⌘");
-
-            #[cfg(not(target_os = "macos"))]
-            parts.push("Win");
+            parts.push(if mac { "⌘" } else { "Win" });
         }
 
         let mut keys = String::new();
         let key_str = key.key.as_str();
         match key_str {
-            #[cfg(target_os = "macos")]
-            "ctrl" => keys.push('⌃'),
-            #[cfg(not(target_os = "macos"))]
-            "ctrl" => keys.push_str("Ctrl"),
-            #[cfg(target_os = "macos")]
-            "alt" => keys.push('⌥'),
-            #[cfg(not(target_os = "macos"))]
-            "alt" => keys.push_str("Alt"),
-            #[cfg(target_os = "macos")]
-            "shift" => keys.push('⇧'),
-            #[cfg(not(target_os = "macos"))]
-            "shift" => keys.push_str("Shift"),
-            #[cfg(target_os = "macos")]
-            "cmd" => keys.push('⌘'),
-            #[cfg(not(target_os = "macos"))]
-            "cmd" => keys.push_str("Win"),
-            #[cfg(target_os = "macos")]
+            "ctrl" => keys.push_str(if mac { "⌃" } else { "Ctrl" }),
+            "alt" => keys.push_str(if mac { "⌥" } else { "Alt" }),
+            "shift" => keys.push_str(if mac { "⇧" } else { "Shift" }),
+            "cmd" => keys.push_str(if mac { "⌘" } else { "Win" }),
             "space" => keys.push_str("Space"),
-            #[cfg(target_os = "macos")]
-            "backspace" => keys.push('⌫'),
-            #[cfg(not(target_os = "macos"))]
-            "backspace" => keys.push_str("Backspace"),
-            #[cfg(target_os = "macos")]
-            "delete" => keys.push('⌫'),
-            #[cfg(not(target_os = "macos"))]
-            "delete" => keys.push_str("Delete"),
-            #[cfg(target_os = "macos")]
-            "escape" => keys.push('⎋'),
-            #[cfg(not(target_os = "macos"))]
-            "escape" => keys.push_str("Esc"),
-            #[cfg(target_os = "macos")]
-            "enter" => keys.push('⏎'),
-            #[cfg(not(target_os = "macos"))]
-            "enter" => keys.push_str("Enter"),
+            "backspace" => keys.push_str(if mac { "⌫" } else { "Backspace" }),
+            "delete" => keys.push_str(if mac { "⌫" } else { "Delete" }),
+            "escape" => keys.push_str(if mac { "⎋" } else { "Esc" }),
+            "enter" => keys.push_str(if mac { "⏎" } else { "Enter" }),
             "pagedown" => keys.push_str("Page Down"),
             "pageup" => keys.push_str("Page Up"),
-            #[cfg(target_os = "macos")]
-            "left" => keys.push('←'),
-            #[cfg(not(target_os = "macos"))]
-            "left" => keys.push_str("Left"),
-            #[cfg(target_os = "macos")]
-            "right" => keys.push('→'),
-            #[cfg(not(target_os = "macos"))]
-            "right" => keys.push_str("Right"),
-            #[cfg(target_os = "macos")]
-            "up" => keys.push('↑'),
-            #[cfg(not(target_os = "macos"))]
-            "up" => keys.push_str("Up"),
-            #[cfg(target_os = "macos")]
-            "down" => keys.push('↓'),
-            #[cfg(not(target_os = "macos"))]
-            "down" => keys.push_str("Down"),
+            "left" => keys.push_str(if mac { "←" } else { "Left" }),
+            "right" => keys.push_str(if mac { "→" } else { "Right" }),
+            "up" => keys.push_str(if mac { "↑" } else { "Up" }),
+            "down" => keys.push_str(if mac { "↓" } else { "Down" }),
             _ => {
                 if key_str.len() == 1 {
                     keys.push_str(&key_str.to_uppercase());
@@ -175,7 +169,7 @@ impl Kbd {
         }
 
         parts.push(&keys);
-        parts.join(DIVIDER)
+        parts.join(divider)
     }
 }
 
@@ -187,8 +181,9 @@ impl Styled for Kbd {
 
 impl RenderOnce for Kbd {
     fn render(self, _: &mut gpui::Window, cx: &mut gpui::App) -> impl gpui::IntoElement {
+        let text = Self::format_for(&self.stroke, self.platform.unwrap_or_default());
         if !self.appearance {
-            return Self::format(&self.stroke).into_any_element();
+            return text.into_any_element();
         }
 
         div()
@@ -204,7 +199,7 @@ impl RenderOnce for Kbd {
             .line_height(relative(1.))
             .text_xs()
             .refine_style(&self.style)
-            .child(Self::format(&self.stroke))
+            .child(text)
             .into_any_element()
     }
 }
@@ -287,4 +282,21 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_format_for_platform() {
+        use super::{Kbd, Os};
+        use gpui::Keystroke;
+
+        let key = Keystroke::parse("cmd-shift-p").unwrap();
+        assert_eq!(Kbd::format_for(&key, Os::MacOS), "⇧⌘P");
+        assert_eq!(Kbd::format_for(&key, Os::Windows), "Shift+Win+P");
+        assert_eq!(Kbd::format_for(&key, Os::Linux), "Shift+Win+P");
+
+        let key = Keystroke::parse("ctrl-alt-enter").unwrap();
+        assert_eq!(Kbd::format_for(&key, Os::MacOS), "⌃⌥⏎");
+        assert_eq!(Kbd::format_for(&key, Os::Windows), "Ctrl+Alt+Enter");
+
+        assert_eq!(Kbd::format(&key), Kbd::format_for(&key, Os::current()));
+    }
 }
@@ -1,4 +1,6 @@
+mod dismissable_layer;
 mod event;
+mod fill_color;
 mod focusable;
 mod global_state;
 mod icon;
@@ -70,7 +72,9 @@ use gpui::{App, SharedString};
 pub use wry;
 
 pub use crate::Disableable;
+pub use dismissable_layer::{DismissableLayer, EscapeHatch};
 pub use event::InteractiveElementExt;
+pub use fill_color::FillColor;
 pub use focusable::FocusableCycle;
 pub use index_path::IndexPath;
 #[cfg(any(feature = "inspector", debug_assertions))]
@@ -80,7 +84,10 @@ pub use root::{ContextModal, Root};
 pub use styled::*;
 pub use time::*;
 pub use title_bar::*;
-pub use virtual_list::{h_virtual_list, v_virtual_list, VirtualList, VirtualListScrollHandle};
+pub use virtual_list::{
+    h_virtual_list, v_virtual_list, ScrollAlign, VirtualList, VirtualListScrollHandle,
+    VirtualListState,
+};
 pub use window_border::{window_border, window_paddings, WindowBorder};
 
 pub use icon::*;
@@ -110,6 +117,7 @@ pub fn init(cx: &mut App) {
     modal::init(cx);
     popover::init(cx);
     menu::init(cx);
+    sidebar::init(cx);
     table::init(cx);
     text::init(cx);
 }
@@ -9,6 +9,8 @@ pub(crate) enum RowEntry {
     Entry(IndexPath),
     SectionHeader(usize),
     SectionFooter(usize),
+    /// The separator after the item.
+    Separator(IndexPath),
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -16,6 +18,7 @@ pub(crate) struct MeasuredEntrySize {
     pub(crate) item_size: Size<Pixels>,
     pub(crate) section_header_size: Size<Pixels>,
     pub(crate) section_footer_size: Size<Pixels>,
+    pub(crate) separator_size: Size<Pixels>,
 }
 
 impl RowEntry {
@@ -28,7 +31,9 @@ impl RowEntry {
     pub(crate) fn eq_index_path(&self, path: &IndexPath) -> bool {
         match self {
             RowEntry::Entry(index_path) => index_path == path,
-            RowEntry::SectionHeader(_) | RowEntry::SectionFooter(_) => false,
+            RowEntry::SectionHeader(_) | RowEntry::SectionFooter(_) | RowEntry::Separator(_) => {
+                false
+            }
         }
     }
 
@@ -38,6 +43,7 @@ impl RowEntry {
             RowEntry::Entry(index_path) => *index_path,
             RowEntry::SectionHeader(ix) => IndexPath::default().section(*ix),
             RowEntry::SectionFooter(ix) => IndexPath::default().section(*ix),
+            RowEntry::Separator(index_path) => *index_path,
         }
     }
 
@@ -47,6 +53,12 @@ impl RowEntry {
         matches!(self, RowEntry::SectionFooter(_))
     }
 
+    #[inline]
+    #[allow(unused)]
+    pub(crate) fn is_separator(&self) -> bool {
+        matches!(self, RowEntry::Separator(_))
+    }
+
     #[inline]
     pub(crate) fn is_entry(&self) -> bool {
         matches!(self, RowEntry::Entry(_))
@@ -94,6 +106,11 @@ impl RowsCache {
             .position(|p| p.is_entry() && p.eq_index_path(path))
     }
 
+    /// Returns the measured sizes of the entries.
+    pub(crate) fn measured_size(&self) -> MeasuredEntrySize {
+        self.measured_size
+    }
+
     /// Returns the sections count in the cache.
     pub(crate) fn sections_count(&self) -> usize {
         self.sections.len()
@@ -143,14 +160,20 @@ impl RowsCache {
         path
     }
 
-    pub(crate) fn prepare_if_needed<F>(
+    /// Prepare the flattened rows if the sections or measured size changed.
+    ///
+    /// The `separator_after_f` returns true to insert a separator after the item,
+    /// the separator is never inserted after the last item of a section.
+    pub(crate) fn prepare_if_needed<F, S>(
         &mut self,
         sections_count: usize,
         measured_size: MeasuredEntrySize,
         cx: &App,
         rows_count_f: F,
+        separator_after_f: S,
     ) where
         F: Fn(usize, &App) -> usize,
+        S: Fn(IndexPath) -> bool,
     {
         let mut new_sections = vec![];
         for section_ix in 0..sections_count {
@@ -177,12 +200,18 @@ impl RowsCache {
                     children.push(RowEntry::SectionHeader(section));
                     entries_sizes.push(measured_size.section_header_size);
                     for row in 0..*items_count {
-                        children.push(RowEntry::Entry(IndexPath {
+                        let ix = IndexPath {
                             section,
                             row,
                             ..Default::default()
-                        }));
+                        };
+                        children.push(RowEntry::Entry(ix));
                         entries_sizes.push(measured_size.item_size);
+
+                        if row + 1 < *items_count && separator_after_f(ix) {
+                            children.push(RowEntry::Separator(ix));
+                            entries_sizes.push(measured_size.separator_size);
+                        }
                     }
                     children.push(RowEntry::SectionFooter(section));
                     entries_sizes.push(measured_size.section_footer_size);
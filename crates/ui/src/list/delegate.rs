@@ -1,8 +1,10 @@
 use gpui::{AnyElement, App, Context, IntoElement, ParentElement as _, Styled as _, Task, Window};
 
 use crate::{
+    divider::Divider,
+    dock::AnyDrag,
     h_flex,
-    list::{loading::Loading, List},
+    list::{loading::Loading, List, ListSeparatorItem},
     ActiveTheme as _, Icon, IconName, IndexPath, Selectable,
 };
 
@@ -66,6 +68,28 @@ pub trait ListDelegate: Sized + 'static {
         None::<AnyElement>
     }
 
+    /// Return true to insert a separator after the item at the given index, default is false.
+    ///
+    /// This is useful to divide the items into logical groups without sections.
+    /// The separator is not selectable, and it is never inserted after the last item of a section.
+    ///
+    /// This only call when the items count changed.
+    fn separator_after(&self, ix: IndexPath) -> bool {
+        false
+    }
+
+    /// Render the separator after the item at the given index, default is a horizontal [`Divider`].
+    ///
+    /// NOTE: Every separator should have same height.
+    fn render_separator(
+        &self,
+        ix: IndexPath,
+        window: &mut Window,
+        cx: &mut Context<List<Self>>,
+    ) -> impl IntoElement {
+        ListSeparatorItem::new().py_0().child(Divider::horizontal())
+    }
+
     /// Return a Element to show when list is empty.
     fn render_empty(&self, window: &mut Window, cx: &mut Context<List<Self>>) -> impl IntoElement {
         h_flex()
@@ -150,4 +174,32 @@ pub trait ListDelegate: Sized + 'static {
     /// so you must check if there is more data to load or lock
     /// the loading state.
     fn load_more(&mut self, window: &mut Window, cx: &mut Context<List<Self>>) {}
+
+    /// Return true to allow the item at the given index to be reordered by drag and drop, default is false.
+    ///
+    /// The item can only be moved within its section, the [`ListDelegate::drag_payload`]
+    /// of the item is ignored when this returns true.
+    fn can_move_item(&self, ix: IndexPath, cx: &App) -> bool {
+        false
+    }
+
+    /// Move the item at `from` to `to` in the same section.
+    ///
+    /// The `to` is the index after the move, e.g.: `items.remove(from.row)` then `items.insert(to.row, item)`.
+    fn move_item(
+        &mut self,
+        from: IndexPath,
+        to: IndexPath,
+        window: &mut Window,
+        cx: &mut Context<List<Self>>,
+    ) {
+    }
+
+    /// Return the drag payload of the item at the given index to make it draggable, default is None.
+    ///
+    /// The payload can be dropped on any `on_drop::<AnyDrag>` target, e.g. another List or [`crate::dock::Tiles`],
+    /// the source List does not change the items, handle the move in the drop target.
+    fn drag_payload(&self, ix: IndexPath, cx: &App) -> Option<AnyDrag> {
+        None
+    }
 }
@@ -2,6 +2,7 @@ use std::ops::Range;
 use std::time::Duration;
 
 use crate::actions::{Cancel, Confirm, SelectNext, SelectPrev};
+use crate::index_path::moved_ix;
 use crate::input::InputState;
 use crate::list::cache::{MeasuredEntrySize, RowEntry, RowsCache};
 use crate::list::ListDelegate;
@@ -14,16 +15,22 @@ use crate::{
     v_virtual_list, Icon, IndexPath, Selectable, Sizable as _, StyledExt, VirtualListScrollHandle,
 };
 use gpui::{
-    div, prelude::FluentBuilder, AppContext, Entity, FocusHandle, Focusable, InteractiveElement,
-    IntoElement, KeyBinding, Length, MouseButton, ParentElement, Render, Styled, Task, Window,
+    div, prelude::FluentBuilder, AppContext, DragMoveEvent, Entity, EntityId, FocusHandle,
+    Focusable, InteractiveElement, IntoElement, KeyBinding, Length, MouseButton, ParentElement,
+    Point, Render, Styled, Task, WeakEntity, Window,
 };
 use gpui::{
     px, size, App, AvailableSpace, Context, Edges, EventEmitter, ListSizingBehavior,
-    MouseDownEvent, Pixels, ScrollStrategy, Subscription,
+    MouseDownEvent, Pixels, ScrollStrategy, StatefulInteractiveElement as _, Subscription,
 };
 use rust_i18n::t;
 use smol::Timer;
 
+/// The offset to scroll per frame when dragging an item near the edge of the list.
+const DRAG_SCROLL_STEP: Pixels = px(4.);
+/// The interval of the frames to scroll when dragging an item near the edge of the list.
+const DRAG_SCROLL_INTERVAL: Duration = Duration::from_millis(16);
+
 pub fn init(cx: &mut App) {
     let context: Option<&str> = Some("List");
     cx.bind_keys([
@@ -43,6 +50,15 @@ pub enum ListEvent {
     Confirm(IndexPath),
     /// Pressed ESC to deselect the item.
     Cancel,
+    /// The item has been moved by drag and drop, see [`ListDelegate::move_item`].
+    Move(IndexPath, IndexPath),
+}
+
+/// The drag payload to reorder the item, see [`ListDelegate::can_move_item`].
+#[derive(Clone)]
+struct DragMoveItem {
+    entity_id: EntityId,
+    ix: IndexPath,
 }
 
 pub struct List<D: ListDelegate> {
@@ -60,11 +76,20 @@ pub struct List<D: ListDelegate> {
     pub(crate) size: Size,
     rows_cache: RowsCache,
     selected_index: Option<IndexPath>,
-    deferred_scroll_to_index: Option<(IndexPath, ScrollStrategy)>,
+    /// The item to scroll to on the next render, and whether to animate the scrolling.
+    deferred_scroll_to_index: Option<(IndexPath, ScrollStrategy, bool)>,
     mouse_right_clicked_index: Option<IndexPath>,
+    /// The index of the item that is being dragged out, see [`ListDelegate::drag_payload`].
+    dragging_index: Option<IndexPath>,
+    /// The item to drop the moving item on, and true to insert after it.
+    drop_target: Option<(IndexPath, bool)>,
+    /// The offset to scroll per frame while dragging an item near the top or bottom edge.
+    drag_scroll_step: Pixels,
     reset_on_cancel: bool,
+    show_dividers: bool,
     _search_task: Task<()>,
     _load_more_task: Task<()>,
+    _drag_scroll_task: Task<()>,
     _query_input_subscription: Subscription,
 }
 
@@ -88,6 +113,9 @@ where
             selected_index: None,
             deferred_scroll_to_index: None,
             mouse_right_clicked_index: None,
+            dragging_index: None,
+            drop_target: None,
+            drag_scroll_step: px(0.),
             scroll_handle: VirtualListScrollHandle::new(),
             scroll_state: ScrollbarState::default(),
             max_height: None,
@@ -96,9 +124,11 @@ where
             querying: false,
             size: Size::default(),
             reset_on_cancel: true,
+            show_dividers: false,
             paddings: Edges::default(),
             _search_task: Task::ready(()),
             _load_more_task: Task::ready(()),
+            _drag_scroll_task: Task::ready(()),
             _query_input_subscription,
         }
     }
@@ -119,6 +149,14 @@ where
         self
     }
 
+    /// Set to show a divider after every item (except the last item of a section), default is false.
+    ///
+    /// See also [`ListDelegate::separator_after`] to insert separators between groups.
+    pub fn show_dividers(mut self, show: bool) -> Self {
+        self.show_dividers = show;
+        self
+    }
+
     pub fn no_query(mut self) -> Self {
         self.query_input = None;
         self
@@ -141,6 +179,28 @@ where
         self.query_input = Some(query_input);
     }
 
+    /// Show or hide the query input, the query input is created when it is shown again.
+    pub fn set_query_visible(
+        &mut self,
+        visible: bool,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if visible == self.query_input.is_some() {
+            return;
+        }
+
+        if visible {
+            let query_input =
+                cx.new(|cx| InputState::new(window, cx).placeholder(t!("List.search_placeholder")));
+            self.set_query_input(query_input, window, cx);
+        } else {
+            self.query_input = None;
+        }
+        self.last_query = None;
+        cx.notify();
+    }
+
     /// Get the query input entity.
     pub fn query_input(&self) -> Option<&Entity<InputState>> {
         self.query_input.as_ref()
@@ -214,7 +274,21 @@ where
             cx.notify();
             return;
         }
-        self.deferred_scroll_to_index = Some((ix, strategy));
+        self.deferred_scroll_to_index = Some((ix, strategy, false));
+        cx.notify();
+    }
+
+    /// Scroll to the item at the given index with an animation.
+    ///
+    /// The animation is cancelled if the user scrolls manually.
+    pub fn scroll_to_item_animated(
+        &mut self,
+        ix: IndexPath,
+        strategy: ScrollStrategy,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.deferred_scroll_to_index = Some((ix, strategy, true));
         cx.notify();
     }
 
@@ -225,7 +299,7 @@ where
 
     pub fn scroll_to_selected_item(&mut self, _: &mut Window, cx: &mut Context<Self>) {
         if let Some(ix) = self.selected_index {
-            self.deferred_scroll_to_index = Some((ix, ScrollStrategy::Top));
+            self.deferred_scroll_to_index = Some((ix, ScrollStrategy::Top, false));
             cx.notify();
         }
     }
@@ -342,7 +416,8 @@ where
         window: &mut Window,
         cx: &mut Context<Self>,
     ) {
-        if self.rows_cache.len() == 0 {
+        // The items are not shown while loading.
+        if self.rows_cache.len() == 0 || self.delegate.loading(cx) {
             return;
         }
 
@@ -371,7 +446,7 @@ where
         window: &mut Window,
         cx: &mut Context<Self>,
     ) {
-        if self.rows_cache.len() == 0 {
+        if self.rows_cache.len() == 0 || self.delegate.loading(cx) {
             return;
         }
 
@@ -387,7 +462,7 @@ where
         window: &mut Window,
         cx: &mut Context<Self>,
     ) {
-        if self.rows_cache.len() == 0 {
+        if self.rows_cache.len() == 0 || self.delegate.loading(cx) {
             return;
         }
 
@@ -397,6 +472,109 @@ where
         self.select_item(next_ix, window, cx);
     }
 
+    /// Move the item at `from` to insert before or after (`after` is true) the item at `target`.
+    fn move_item(
+        &mut self,
+        from: IndexPath,
+        target: IndexPath,
+        after: bool,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if from.section != target.section || !self.delegate.can_move_item(from, cx) {
+            return;
+        }
+
+        let to_row = match (after, target.row > from.row) {
+            (false, true) => target.row - 1,
+            (true, false) => target.row + 1,
+            _ => target.row,
+        };
+        if to_row == from.row {
+            return;
+        }
+
+        let to = IndexPath::new(to_row).section(from.section);
+        self.delegate.move_item(from, to, window, cx);
+        // Keep the selection on the same item.
+        let moved = |ix: IndexPath| {
+            if ix.section != from.section {
+                return ix;
+            }
+
+            IndexPath::new(moved_ix(ix.row, from.row, to.row)).section(ix.section)
+        };
+        if let Some(ix) = self.selected_index {
+            self.selected_index = Some(moved(ix));
+            self.delegate
+                .set_selected_index(self.selected_index, window, cx);
+        }
+        self.mouse_right_clicked_index = self.mouse_right_clicked_index.map(moved);
+
+        cx.emit(ListEvent::Move(from, to));
+        cx.notify();
+    }
+
+    /// Scroll the list when dragging the item near the top or bottom edge of the list,
+    /// to allow dropping the item to the off-screen target.
+    ///
+    /// The list keeps scrolling while the pointer stays in the edge zone,
+    /// because the drag move event is only emitted when the mouse moves.
+    fn scroll_list_by_item_dragging(
+        &mut self,
+        mouse_position: Point<Pixels>,
+        cx: &mut Context<Self>,
+    ) {
+        let bounds = self.scroll_handle.base_handle().bounds();
+        if !bounds.contains(&mouse_position) {
+            self.drag_scroll_step = px(0.);
+            if self.drop_target.take().is_some() {
+                cx.notify();
+            }
+            return;
+        }
+
+        let edge = self.rows_cache.measured_size().item_size.height;
+        let step = if mouse_position.y < bounds.top() + edge {
+            DRAG_SCROLL_STEP
+        } else if mouse_position.y > bounds.bottom() - edge {
+            -DRAG_SCROLL_STEP
+        } else {
+            px(0.)
+        };
+
+        let scrolling = self.drag_scroll_step != px(0.);
+        self.drag_scroll_step = step;
+        if step == px(0.) || scrolling {
+            return;
+        }
+
+        self._drag_scroll_task = cx.spawn(async move |list, cx| loop {
+            let Ok(true) = list.update(cx, |list, cx| list.step_drag_scroll(cx)) else {
+                break;
+            };
+            Timer::after(DRAG_SCROLL_INTERVAL).await;
+        });
+    }
+
+    /// Scroll the list by one `drag_scroll_step`, returns false to stop scrolling.
+    fn step_drag_scroll(&mut self, cx: &mut Context<Self>) -> bool {
+        if self.dragging_index.is_none() || self.drag_scroll_step == px(0.) {
+            self.drag_scroll_step = px(0.);
+            return false;
+        }
+
+        let scroll_handle = self.scroll_handle.base_handle();
+        let max_offset = scroll_handle.max_offset().height;
+        let mut offset = scroll_handle.offset();
+        offset.y = (offset.y + self.drag_scroll_step)
+            .min(px(0.))
+            .max(-max_offset);
+        scroll_handle.set_offset(offset);
+        cx.notify();
+        true
+    }
+
     fn render_list_item(
         &self,
         ix: IndexPath,
@@ -408,6 +586,12 @@ where
             .mouse_right_clicked_index
             .map(|s| s.eq_row(ix))
             .unwrap_or(false);
+        let dragging = self.dragging_index.map(|s| s.eq_row(ix)).unwrap_or(false);
+        let can_move = self.delegate.can_move_item(ix, cx);
+        let drop_after = self
+            .drop_target
+            .and_then(|(target, after)| target.eq_row(ix).then_some(after));
+        let entity_id = cx.entity_id();
 
         div()
             .id("list-item")
@@ -417,6 +601,74 @@ where
                 item.selected(selected)
                     .secondary_selected(mouse_right_clicked)
             }))
+            .when(can_move, |this| {
+                let list = cx.entity().downgrade();
+                this.on_drag(DragMoveItem { entity_id, ix }, move |_, _, _, cx| {
+                    cx.stop_propagation();
+                    _ = list.update(cx, |list, cx| {
+                        list.dragging_index = Some(ix);
+                        cx.notify();
+                    });
+                    cx.new(|cx| DragListItem::new(list.clone(), ix, cx))
+                })
+            })
+            .when(self.dragging_index.is_some(), |this| {
+                this.on_drag_move(cx.listener(
+                    move |this, e: &DragMoveEvent<DragMoveItem>, _, cx| {
+                        let drag = e.drag(cx);
+                        if drag.entity_id != cx.entity_id()
+                            || drag.ix.section != ix.section
+                            || !e.bounds.contains(&e.event.position)
+                        {
+                            return;
+                        }
+
+                        let after = e.event.position.y > e.bounds.center().y;
+                        if this.drop_target != Some((ix, after)) {
+                            this.drop_target = Some((ix, after));
+                            cx.notify();
+                        }
+                    },
+                ))
+                .on_drop(cx.listener(
+                    move |this, drag: &DragMoveItem, window, cx| {
+                        if drag.entity_id != cx.entity_id() {
+                            return;
+                        }
+
+                        let after = this.drop_target.take().map_or(false, |(_, after)| after);
+                        this.move_item(drag.ix, ix, after, window, cx);
+                    },
+                ))
+            })
+            // The insertion indicator.
+            .when_some(drop_after, |this, after| {
+                this.child(
+                    div()
+                        .absolute()
+                        .left_0()
+                        .right_0()
+                        .h(px(2.))
+                        .map(|this| if after { this.bottom_0() } else { this.top_0() })
+                        .bg(cx.theme().drag_border),
+                )
+            })
+            .when_some(
+                self.delegate.drag_payload(ix, cx).filter(|_| !can_move),
+                |this, payload| {
+                    let list = cx.entity().downgrade();
+                    this.on_drag(payload, move |_, _, _, cx| {
+                        cx.stop_propagation();
+                        _ = list.update(cx, |list, cx| {
+                            list.dragging_index = Some(ix);
+                            cx.notify();
+                        });
+                        cx.new(|cx| DragListItem::new(list.clone(), ix, cx))
+                    })
+                },
+            )
+            // Lift the item out of the list while dragging.
+            .when(dragging, |this| this.opacity(0.4))
             .when(self.selectable, |this| {
                 this.on_mouse_down(
                     MouseButton::Left,
@@ -455,6 +707,15 @@ where
             .h_full()
             .when_some(self.max_height, |this, h| this.max_h(h))
             .overflow_hidden()
+            .when(self.dragging_index.is_some(), |this| {
+                this.on_drag_move(cx.listener(|this, e: &DragMoveEvent<DragMoveItem>, _, cx| {
+                    if e.drag(cx).entity_id != cx.entity_id() {
+                        return;
+                    }
+
+                    this.scroll_list_by_item_dragging(e.event.position, cx);
+                }))
+            })
             .when(items_count == 0, |this| {
                 this.child(self.delegate().render_empty(window, cx))
             })
@@ -497,6 +758,11 @@ where
                                                 .delegate()
                                                 .render_section_footer(section_ix, window, cx)
                                                 .map(|r| r.into_any_element()),
+                                            RowEntry::Separator(index) => Some(
+                                                list.delegate()
+                                                    .render_separator(index, window, cx)
+                                                    .into_any_element(),
+                                            ),
                                         })
                                     })
                                     .collect::<Vec<_>>()
@@ -541,10 +807,20 @@ where
             measured_size.section_footer_size = el.layout_as_root(available_space, window, cx);
         }
 
-        self.rows_cache
-            .prepare_if_needed(sections_count, measured_size, cx, |section_ix, cx| {
-                self.delegate.items_count(section_ix, cx)
-            });
+        measured_size.separator_size = self
+            .delegate
+            .render_separator(IndexPath::default(), window, cx)
+            .into_any_element()
+            .layout_as_root(available_space, window, cx);
+
+        let show_dividers = self.show_dividers;
+        self.rows_cache.prepare_if_needed(
+            sections_count,
+            measured_size,
+            cx,
+            |section_ix, cx| self.delegate.items_count(section_ix, cx),
+            |ix| show_dividers || self.delegate.separator_after(ix),
+        );
     }
 }
 
@@ -569,9 +845,14 @@ where
         self.prepare_items_if_needed(window, cx);
 
         // Scroll to the selected item if it is set.
-        if let Some((ix, strategy)) = self.deferred_scroll_to_index.take() {
+        if let Some((ix, strategy, animated)) = self.deferred_scroll_to_index.take() {
             if let Some(item_ix) = self.rows_cache.position_of(&ix) {
-                self.scroll_handle.scroll_to_item(item_ix, strategy);
+                if animated {
+                    self.scroll_handle
+                        .animate_scroll_to(item_ix, strategy.into());
+                } else {
+                    self.scroll_handle.scroll_to_item(item_ix, strategy);
+                }
             }
         }
 
@@ -643,3 +924,59 @@ where
             })
     }
 }
+
+/// The drag preview of the list item, see [`ListDelegate::drag_payload`].
+struct DragListItem<D: ListDelegate> {
+    list: WeakEntity<List<D>>,
+    ix: IndexPath,
+}
+
+impl<D> DragListItem<D>
+where
+    D: ListDelegate,
+{
+    fn new(list: WeakEntity<List<D>>, ix: IndexPath, cx: &mut Context<Self>) -> Self {
+        // The preview is released when the drag is dropped or cancelled,
+        // then restore the item in the source list.
+        cx.on_release({
+            let list = list.clone();
+            move |_, cx| {
+                _ = list.update(cx, |list, cx| {
+                    list.dragging_index = None;
+                    list.drop_target = None;
+                    list.drag_scroll_step = px(0.);
+                    cx.notify();
+                });
+            }
+        })
+        .detach();
+
+        Self { list, ix }
+    }
+}
+
+impl<D> Render for DragListItem<D>
+where
+    D: ListDelegate,
+{
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let ix = self.ix;
+        let item = self.list.upgrade().and_then(|list| {
+            list.update(cx, |list, cx| {
+                list.delegate
+                    .render_item(ix, window, cx)
+                    .map(|item| item.into_any_element())
+            })
+        });
+
+        div()
+            .min_w(px(160.))
+            .bg(cx.theme().background)
+            .border_1()
+            .border_color(cx.theme().border)
+            .rounded(cx.theme().radius)
+            .shadow_lg()
+            .opacity(0.9)
+            .children(item)
+    }
+}
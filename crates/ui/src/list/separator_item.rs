@@ -1,10 +1,12 @@
-use gpui::{
-    AnyElement, ParentElement, RenderOnce, StyleRefinement,
-};
+use gpui::{AnyElement, IntoElement, ParentElement, RenderOnce, StyleRefinement, Styled};
 use smallvec::SmallVec;
 
 use crate::{list::ListItem, Selectable, StyledExt};
 
+/// A separator item in the [`super::List`], it is not selectable.
+///
+/// See also [`super::ListDelegate::render_separator`].
+#[derive(IntoElement)]
 pub struct ListSeparatorItem {
     style: StyleRefinement,
     children: SmallVec<[AnyElement; 2]>,
@@ -25,6 +27,12 @@ impl ParentElement for ListSeparatorItem {
     }
 }
 
+impl Styled for ListSeparatorItem {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
 impl Selectable for ListSeparatorItem {
     fn selected(self, _: bool) -> Self {
         self
@@ -38,6 +46,7 @@ impl Selectable for ListSeparatorItem {
 impl RenderOnce for ListSeparatorItem {
     fn render(self, _: &mut gpui::Window, _: &mut gpui::App) -> impl gpui::IntoElement {
         ListItem::new("separator")
+            .separator()
             .refine_style(&self.style)
             .children(self.children)
             .disabled(true)
@@ -2,21 +2,28 @@ use crate::actions::{Cancel, Confirm, SelectNext, SelectPrev};
 use crate::menu::menu_item::MenuItem;
 use crate::scroll::{Scrollbar, ScrollbarState};
 use crate::{
-    button::Button, h_flex, popover::Popover, v_flex, ActiveTheme, Icon, IconName, Selectable,
-    Sizable as _,
+    button::Button, h_flex, popover::Popover, v_flex, v_virtual_list, ActiveTheme,
+    DismissableLayer, EscapeHatch, Icon, IconName, Selectable, Sizable as _,
+    VirtualListScrollHandle,
 };
 use crate::{Kbd, StyledExt};
 use gpui::{
-    anchored, canvas, div, prelude::FluentBuilder, px, rems, Action, AnyElement, App, AppContext,
-    Bounds, Context, Corner, DismissEvent, Edges, Entity, EventEmitter, FocusHandle, Focusable,
-    InteractiveElement, IntoElement, KeyBinding, ParentElement, Pixels, Render, ScrollHandle,
-    SharedString, StatefulInteractiveElement, Styled, WeakEntity, Window,
+    anchored, canvas, div, prelude::FluentBuilder, px, rems, size, Action, AnyElement, App,
+    AppContext, Bounds, Context, Corner, DismissEvent, Edges, Entity, EventEmitter, FocusHandle,
+    Focusable, InteractiveElement, IntoElement, KeyBinding, ParentElement, Pixels, Render,
+    ScrollHandle, ScrollStrategy, SharedString, StatefulInteractiveElement, Styled, WeakEntity,
+    Window,
 };
-use gpui::{AsKeystroke, MouseDownEvent, Subscription};
+use gpui::{AsKeystroke, Subscription};
 use std::ops::Deref;
 use std::rc::Rc;
 
 const ITEM_HEIGHT: Pixels = px(26.);
+const SEPARATOR_HEIGHT: Pixels = px(5.);
+/// The menu with more items than this is rendered by the virtual list.
+const VIRTUAL_ITEMS_THRESHOLD: usize = 100;
+/// The width of the virtual list menu if the `min_w` is not set.
+const VIRTUAL_MENU_WIDTH: Pixels = px(240.);
 
 pub fn init(cx: &mut App) {
     let context = Some("PopupMenu");
@@ -91,6 +98,14 @@ impl PopupMenuItem {
     fn is_separator(&self) -> bool {
         matches!(self, PopupMenuItem::Separator)
     }
+
+    /// The fixed height of the item in the virtual list menu.
+    fn height(&self) -> Pixels {
+        match self {
+            PopupMenuItem::Separator => SEPARATOR_HEIGHT,
+            _ => ITEM_HEIGHT,
+        }
+    }
 }
 
 pub struct PopupMenu {
@@ -105,11 +120,13 @@ pub struct PopupMenu {
     max_height: Option<Pixels>,
     hovered_menu_ix: Option<usize>,
     bounds: Bounds<Pixels>,
+    escape_hatches: Vec<EscapeHatch>,
 
     scrollable: bool,
     external_link_icon: bool,
     scroll_handle: ScrollHandle,
     scroll_state: ScrollbarState,
+    virtual_scroll_handle: VirtualListScrollHandle,
 
     previous_focus_handle: Option<FocusHandle>,
     _subscriptions: Vec<Subscription>,
@@ -142,9 +159,11 @@ impl PopupMenu {
                 has_icon: false,
                 hovered_menu_ix: None,
                 bounds: Bounds::default(),
+                escape_hatches: vec![],
                 scrollable: false,
                 scroll_handle: ScrollHandle::default(),
                 scroll_state: ScrollbarState::default(),
+                virtual_scroll_handle: VirtualListScrollHandle::new(),
                 external_link_icon: true,
                 _subscriptions,
             };
@@ -184,6 +203,12 @@ impl PopupMenu {
         self
     }
 
+    /// Add an [`EscapeHatch`] region, the click inside it will not dismiss the menu.
+    pub fn escape_hatch(mut self, hatch: &EscapeHatch) -> Self {
+        self.escape_hatches.push(hatch.clone());
+        self
+    }
+
     /// Add Menu Item
     pub fn menu(self, label: impl Into<SharedString>, action: Box<dyn Action>) -> Self {
         self.menu_with_disabled(label, action, false)
@@ -576,6 +601,7 @@ impl PopupMenu {
                 .unwrap_or(0);
 
             self.selected_index = Some(ix);
+            self.scroll_to_selected_item();
             cx.notify();
         }
     }
@@ -596,10 +622,26 @@ impl PopupMenu {
                 })
                 .unwrap_or(last_ix);
             self.selected_index = Some(ix);
+            self.scroll_to_selected_item();
             cx.notify();
         }
     }
 
+    fn is_virtual(&self) -> bool {
+        self.menu_items.len() > VIRTUAL_ITEMS_THRESHOLD
+    }
+
+    fn scroll_to_selected_item(&self) {
+        if !self.is_virtual() {
+            return;
+        }
+
+        if let Some(ix) = self.selected_index {
+            self.virtual_scroll_handle
+                .scroll_to_item(ix, ScrollStrategy::Top);
+        }
+    }
+
     fn dismiss(&mut self, _: &Cancel, window: &mut Window, cx: &mut Context<Self>) {
         if self.active_submenu().is_some() {
             return;
@@ -840,6 +882,87 @@ impl PopupMenu {
             ),
         }
     }
+
+    /// Render the items by the virtual list, so only the visible items are built.
+    ///
+    /// Each item has a fixed height, and the menu has a fixed width of the `min_w`.
+    fn render_virtual_items(
+        &self,
+        max_height: Pixels,
+        item_state: ItemState,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let view = cx.entity().clone();
+        // Same as the `p_1` and `gap_y_0p5` of the menu.
+        const GAP: Pixels = px(2.);
+        const PADDING: Pixels = px(4.);
+
+        // Ignore last separator
+        let items_count = match self.menu_items.last() {
+            Some(item) if item.is_separator() => self.menu_items.len() - 1,
+            _ => self.menu_items.len(),
+        };
+        let width = self
+            .min_width
+            .unwrap_or(VIRTUAL_MENU_WIDTH)
+            .min(item_state.max_width);
+        let item_sizes = Rc::new(
+            self.menu_items[..items_count]
+                .iter()
+                .map(|item| size(width - PADDING * 2., item.height()))
+                .collect::<Vec<_>>(),
+        );
+        let content_height = item_sizes
+            .iter()
+            .fold(PADDING * 2., |height, size| height + size.height + GAP)
+            - GAP;
+
+        div()
+            .id("items")
+            .relative()
+            .w(width)
+            .h(content_height.min(max_height))
+            .child({
+                canvas(
+                    move |bounds, _, cx| view.update(cx, |r, _| r.bounds = bounds),
+                    |_, _, _, _| {},
+                )
+                .absolute()
+                .size_full()
+            })
+            .child(
+                v_virtual_list(cx.entity().clone(), "virtual-items", item_sizes.clone(), {
+                    move |menu, visible_range, window, cx| {
+                        visible_range
+                            .map(|ix| {
+                                div().h(item_sizes[ix].height).child(menu.render_item(
+                                    ix,
+                                    &menu.menu_items[ix],
+                                    item_state,
+                                    window,
+                                    cx,
+                                ))
+                            })
+                            .collect::<Vec<_>>()
+                    }
+                })
+                .p_1()
+                .gap_y_0p5()
+                .track_scroll(&self.virtual_scroll_handle),
+            )
+            .child(
+                div()
+                    .absolute()
+                    .top_0()
+                    .left_0()
+                    .right_0()
+                    .bottom_0()
+                    .child(Scrollbar::uniform_scroll(
+                        &self.scroll_state,
+                        &self.virtual_scroll_handle,
+                    )),
+            )
+    }
 }
 
 impl FluentBuilder for PopupMenu {}
@@ -883,59 +1006,68 @@ impl Render for PopupMenu {
             .on_action(cx.listener(Self::select_prev))
             .on_action(cx.listener(Self::confirm))
             .on_action(cx.listener(Self::dismiss))
-            .on_mouse_down_out(cx.listener(|this, ev: &MouseDownEvent, window, cx| {
+            .map(|this| {
+                let mut layer = DismissableLayer::new().escape_hatches(&self.escape_hatches);
                 // Do not dismiss, if click inside the parent menu
-                if let Some(parent) = this.parent_menu.as_ref() {
-                    if let Some(parent) = parent.upgrade() {
-                        if parent.read(cx).bounds.contains(&ev.position) {
-                            return;
-                        }
-                    }
+                if let Some(parent) = self.parent_menu.as_ref().and_then(|p| p.upgrade()) {
+                    layer = layer.escape_region(parent.read(cx).bounds);
                 }
 
-                this.dismiss(&Cancel, window, cx);
-            }))
+                layer.apply(this, {
+                    let view = view.clone();
+                    move |_, window, cx| {
+                        view.update(cx, |this, cx| this.dismiss(&Cancel, window, cx));
+                    }
+                })
+            })
             .popover_style(cx)
             .text_color(cx.theme().popover_foreground)
             .relative()
-            .child(
-                div()
-                    .id("items")
-                    .when(self.scrollable, |this| {
-                        this.max_h(max_height)
-                            .overflow_y_scroll()
-                            .track_scroll(&self.scroll_handle)
-                    })
-                    .child(
-                        v_flex()
-                            .p_1()
-                            .gap_y_0p5()
-                            .min_w(rems(8.))
-                            .when_some(self.min_width, |this, min_width| this.min_w(min_width))
-                            .max_w(max_width)
-                            .child({
-                                canvas(
-                                    move |bounds, _, cx| view.update(cx, |r, _| r.bounds = bounds),
-                                    |_, _, _, _| {},
-                                )
-                                .absolute()
-                                .size_full()
-                            })
-                            .children(
-                                self.menu_items
-                                    .iter()
-                                    .enumerate()
-                                    // Ignore last separator
-                                    .filter(|(ix, item)| {
-                                        !(*ix + 1 == items_count && item.is_separator())
-                                    })
-                                    .map(|(ix, item)| {
-                                        self.render_item(ix, item, item_state, window, cx)
-                                    }),
-                            ),
-                    ),
-            )
-            .when(self.scrollable, |this| {
+            .when(self.is_virtual(), |this| {
+                this.child(self.render_virtual_items(max_height, item_state, cx))
+            })
+            .when(!self.is_virtual(), |this| {
+                this.child(
+                    div()
+                        .id("items")
+                        .when(self.scrollable, |this| {
+                            this.max_h(max_height)
+                                .overflow_y_scroll()
+                                .track_scroll(&self.scroll_handle)
+                        })
+                        .child(
+                            v_flex()
+                                .p_1()
+                                .gap_y_0p5()
+                                .min_w(rems(8.))
+                                .when_some(self.min_width, |this, min_width| this.min_w(min_width))
+                                .max_w(max_width)
+                                .child({
+                                    canvas(
+                                        move |bounds, _, cx| {
+                                            view.update(cx, |r, _| r.bounds = bounds)
+                                        },
+                                        |_, _, _, _| {},
+                                    )
+                                    .absolute()
+                                    .size_full()
+                                })
+                                .children(
+                                    self.menu_items
+                                        .iter()
+                                        .enumerate()
+                                        // Ignore last separator
+                                        .filter(|(ix, item)| {
+                                            !(*ix + 1 == items_count && item.is_separator())
+                                        })
+                                        .map(|(ix, item)| {
+                                            self.render_item(ix, item, item_state, window, cx)
+                                        }),
+                                ),
+                        ),
+                )
+            })
+            .when(self.scrollable && !self.is_virtual(), |this| {
                 // TODO: When the menu is limited by `overflow_y_scroll`, the sub-menu will cannot be displayed.
                 this.child(
                     div()
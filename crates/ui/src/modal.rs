@@ -1,10 +1,12 @@
-use std::{rc::Rc, time::Duration};
+use std::{f32::consts::PI, rc::Rc, time::Duration};
 
 use gpui::{
-    anchored, div, hsla, point, prelude::FluentBuilder, px, relative, Animation, AnimationExt as _,
-    AnyElement, App, Axis, Bounds, BoxShadow, ClickEvent, Div, Edges, FocusHandle, Hsla,
-    InteractiveElement, IntoElement, KeyBinding, MouseButton, ParentElement, Pixels, Point,
-    RenderOnce, SharedString, StyleRefinement, Styled, Window,
+    anchored, canvas, div, hsla, point, prelude::FluentBuilder, px, relative, size, Animation,
+    AnimationExt as _, AnyElement, App, AppContext as _, Axis, Bounds, BoxShadow, ClickEvent,
+    Context, CursorStyle, Div, DragMoveEvent, Edges, Empty, FocusHandle, Hsla, InteractiveElement,
+    IntoElement, KeyBinding, MouseButton, MouseDownEvent, ParentElement, Pixels, Point, Render,
+    RenderOnce, SharedString, Size, StatefulInteractiveElement as _, StyleRefinement, Styled,
+    Window,
 };
 use rust_i18n::t;
 
@@ -16,6 +18,12 @@ use crate::{
 };
 
 const CONTEXT: &str = "Modal";
+
+/// The offset of the slide down animation when the modal is opened.
+const SLIDE_OFFSET: Pixels = px(30.);
+/// The minimum size of the resizable modal.
+const MIN_RESIZE_SIZE: Size<Pixels> = size(px(200.), px(100.));
+const RESIZE_HANDLE_SIZE: Pixels = px(6.);
 pub fn init(cx: &mut App) {
     cx.bind_keys([
         KeyBinding::new("escape", Cancel, Some(CONTEXT)),
@@ -90,13 +98,27 @@ pub struct Modal {
     overlay: bool,
     overlay_closable: bool,
     keyboard: bool,
+    dismissable: bool,
+    draggable: bool,
+    resizable: bool,
 
     /// This will be change when open the modal, the focus handle is create when open the modal.
     pub(crate) focus_handle: FocusHandle,
     pub(crate) layer_ix: usize,
     pub(crate) overlay_visible: bool,
+    /// The number of the visible overlays below this modal.
+    pub(crate) overlay_depth: usize,
+    pub(crate) shake_count: usize,
+    /// The origin of the dragged or resized modal, None to use the default position.
+    pub(crate) position: Option<Point<Pixels>>,
+    /// The size of the resized modal, None to use the `width` and the content height.
+    pub(crate) size: Option<Size<Pixels>>,
 }
 
+/// The opacity of the overlay color for the stacked modals,
+/// the overlays are blended on the ones below, so use a lighter color.
+const STACKED_OVERLAY_OPACITY: f32 = 0.4;
+
 pub(crate) fn overlay_color(overlay: bool, cx: &App) -> Hsla {
     if !overlay {
         return hsla(0., 0., 0., 0.);
@@ -120,12 +142,19 @@ impl Modal {
             keyboard: true,
             layer_ix: 0,
             overlay_visible: false,
+            overlay_depth: 0,
+            shake_count: 0,
             on_close: Rc::new(|_, _, _| {}),
             on_ok: None,
             on_cancel: Rc::new(|_, _, _| true),
             button_props: ModalButtonProps::default(),
             show_close: true,
             overlay_closable: true,
+            dismissable: true,
+            draggable: false,
+            resizable: false,
+            position: None,
+            size: None,
         }
     }
 
@@ -258,11 +287,258 @@ impl Modal {
         self
     }
 
+    /// Set whether the modal can be dismissed by clicking the overlay or pressing `escape`, defaults to `true`.
+    ///
+    /// When `false`, the modal keeps the focus and shakes on those attempts,
+    /// so the user must choose an explicit action, e.g. the footer buttons.
+    pub fn dismissable(mut self, dismissable: bool) -> Self {
+        self.dismissable = dismissable;
+        self
+    }
+
+    /// Set true to move the modal by dragging its title, defaults to `false`.
+    ///
+    /// The modal is kept in the window bounds, and the position is retained while it is open.
+    pub fn draggable(mut self, draggable: bool) -> Self {
+        self.draggable = draggable;
+        self
+    }
+
+    /// Set true to resize the modal by dragging its edges and corners, defaults to `false`.
+    ///
+    /// The modal is kept in the window bounds, and the size is retained while it is open.
+    pub fn resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+
     pub(crate) fn has_overlay(&self) -> bool {
         self.overlay
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ResizeEdge {
+    Left,
+    Right,
+    Top,
+    Bottom,
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+impl ResizeEdge {
+    /// The corners are after the sides, to be on top of them.
+    const ALL: [ResizeEdge; 8] = [
+        ResizeEdge::Left,
+        ResizeEdge::Right,
+        ResizeEdge::Top,
+        ResizeEdge::Bottom,
+        ResizeEdge::TopLeft,
+        ResizeEdge::TopRight,
+        ResizeEdge::BottomLeft,
+        ResizeEdge::BottomRight,
+    ];
+
+    fn is_left(self) -> bool {
+        matches!(self, Self::Left | Self::TopLeft | Self::BottomLeft)
+    }
+
+    fn is_right(self) -> bool {
+        matches!(self, Self::Right | Self::TopRight | Self::BottomRight)
+    }
+
+    fn is_top(self) -> bool {
+        matches!(self, Self::Top | Self::TopLeft | Self::TopRight)
+    }
+
+    fn is_bottom(self) -> bool {
+        matches!(self, Self::Bottom | Self::BottomLeft | Self::BottomRight)
+    }
+
+    fn cursor(self) -> CursorStyle {
+        match self {
+            Self::Left | Self::Right => CursorStyle::ResizeLeftRight,
+            Self::Top | Self::Bottom => CursorStyle::ResizeUpDown,
+            Self::TopLeft | Self::BottomRight => CursorStyle::ResizeUpLeftDownRight,
+            Self::TopRight | Self::BottomLeft => CursorStyle::ResizeUpRightDownLeft,
+        }
+    }
+}
+
+/// The drag to move (without edge) or resize the modal at the `layer_ix`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct DragModal {
+    layer_ix: usize,
+    edge: Option<ResizeEdge>,
+}
+
+impl Render for DragModal {
+    fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+        Empty
+    }
+}
+
+/// Move the `bounds` by the `delta`, and keep it in the `container`.
+fn move_bounds(
+    bounds: Bounds<Pixels>,
+    delta: Point<Pixels>,
+    container: Bounds<Pixels>,
+) -> Point<Pixels> {
+    let max = container.bottom_right() - point(bounds.size.width, bounds.size.height);
+    point(
+        (bounds.origin.x + delta.x).min(max.x).max(container.left()),
+        (bounds.origin.y + delta.y).min(max.y).max(container.top()),
+    )
+}
+
+/// Resize the `bounds` by moving the `edge` with the `delta`,
+/// keep it in the `container` and not smaller than the [`MIN_RESIZE_SIZE`].
+fn resize_bounds(
+    bounds: Bounds<Pixels>,
+    delta: Point<Pixels>,
+    edge: ResizeEdge,
+    container: Bounds<Pixels>,
+) -> Bounds<Pixels> {
+    let (mut left, mut top) = (bounds.left(), bounds.top());
+    let (mut right, mut bottom) = (bounds.right(), bounds.bottom());
+
+    if edge.is_left() {
+        left = (left + delta.x)
+            .min(right - MIN_RESIZE_SIZE.width)
+            .max(container.left());
+    }
+    if edge.is_right() {
+        right = (right + delta.x)
+            .min(container.right())
+            .max(left + MIN_RESIZE_SIZE.width);
+    }
+    if edge.is_top() {
+        top = (top + delta.y)
+            .min(bottom - MIN_RESIZE_SIZE.height)
+            .max(container.top());
+    }
+    if edge.is_bottom() {
+        bottom = (bottom + delta.y)
+            .min(container.bottom())
+            .max(top + MIN_RESIZE_SIZE.height);
+    }
+
+    Bounds::from_corners(point(left, top), point(right, bottom))
+}
+
+/// Save the start position and bounds of the modal at the `layer_ix` to drag.
+fn start_drag_modal(layer_ix: usize, e: &MouseDownEvent, window: &mut Window, cx: &mut App) {
+    Root::update(window, cx, |root, _, _| {
+        if let Some(modal) = root.active_modals.get_mut(layer_ix) {
+            modal.drag_start = Some((e.position, modal.bounds));
+        }
+    });
+}
+
+fn update_drag_modal(
+    drag: DragModal,
+    e: &DragMoveEvent<DragModal>,
+    container: Bounds<Pixels>,
+    window: &mut Window,
+    cx: &mut App,
+) {
+    if *e.drag(cx) != drag {
+        return;
+    }
+
+    Root::update(window, cx, |root, _, cx| {
+        let Some(modal) = root.active_modals.get_mut(drag.layer_ix) else {
+            return;
+        };
+        let Some((start_position, start_bounds)) = modal.drag_start else {
+            return;
+        };
+
+        let delta = e.event.position - start_position;
+        match drag.edge {
+            None => modal.position = Some(move_bounds(start_bounds, delta, container)),
+            Some(edge) => {
+                let bounds = resize_bounds(start_bounds, delta, edge, container);
+                modal.position = Some(bounds.origin);
+                modal.size = Some(bounds.size);
+            }
+        }
+        cx.notify();
+    });
+}
+
+fn render_resize_handle(
+    layer_ix: usize,
+    edge: ResizeEdge,
+    container: Bounds<Pixels>,
+) -> impl IntoElement {
+    let drag = DragModal {
+        layer_ix,
+        edge: Some(edge),
+    };
+
+    div()
+        .id(("resize-handle", edge as usize))
+        .absolute()
+        .map(|this| match edge {
+            ResizeEdge::Left | ResizeEdge::Right => this.top_0().bottom_0().w(RESIZE_HANDLE_SIZE),
+            ResizeEdge::Top | ResizeEdge::Bottom => this.left_0().right_0().h(RESIZE_HANDLE_SIZE),
+            _ => this.size(RESIZE_HANDLE_SIZE * 2.),
+        })
+        .when(edge.is_left(), |this| this.left_0())
+        .when(edge.is_right(), |this| this.right_0())
+        .when(edge.is_top(), |this| this.top_0())
+        .when(edge.is_bottom(), |this| this.bottom_0())
+        .cursor(edge.cursor())
+        .on_mouse_down(MouseButton::Left, move |e, window, cx| {
+            start_drag_modal(layer_ix, e, window, cx)
+        })
+        .on_drag(drag, |drag, _, _, cx| {
+            cx.stop_propagation();
+            cx.new(|_| *drag)
+        })
+        .on_drag_move(move |e: &DragMoveEvent<DragModal>, window, cx| {
+            update_drag_modal(drag, e, container, window, cx)
+        })
+}
+
+/// Shake the modal at the `layer_ix` and keep the focus in it, when a dismiss is blocked.
+fn shake_modal(layer_ix: usize, focus_handle: &FocusHandle, window: &mut Window, cx: &mut App) {
+    window.focus(focus_handle);
+    Root::update(window, cx, |root, _, cx| {
+        if let Some(modal) = root.active_modals.get_mut(layer_ix) {
+            modal.shake_count += 1;
+        }
+        cx.notify();
+    });
+}
+
+/// The horizontal offset of the shake animation at the `delta` (0.0 to 1.0).
+fn shake_offset(delta: f32) -> Pixels {
+    px((delta * PI * 6.).sin() * 8. * (1. - delta))
+}
+
+/// This is equivalent to `shadow_xl` with an extra opacity.
+fn modal_shadow(opacity: f32) -> Vec<BoxShadow> {
+    vec![
+        BoxShadow {
+            color: hsla(0., 0., 0., 0.1 * opacity),
+            offset: point(px(0.), px(20.)),
+            blur_radius: px(25.),
+            spread_radius: px(-5.),
+        },
+        BoxShadow {
+            color: hsla(0., 0., 0., 0.1 * opacity),
+            offset: point(px(0.), px(8.)),
+            blur_radius: px(10.),
+            spread_radius: px(-6.),
+        },
+    ]
+}
+
 impl ParentElement for Modal {
     fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
         self.content.extend(elements);
@@ -351,8 +627,14 @@ impl RenderOnce for Modal {
             size: view_size,
         };
         let offset_top = px(layer_ix as f32 * 16.);
-        let y = self.margin_top.unwrap_or(view_size.height / 10.) + offset_top;
-        let x = bounds.center().x - self.width / 2.;
+        let (x, y) = match self.position {
+            // The slide down animation adds the offset to the top.
+            Some(position) => (position.x, position.y - SLIDE_OFFSET),
+            None => (
+                bounds.center().x - self.width / 2.,
+                self.margin_top.unwrap_or(view_size.height / 10.) + offset_top,
+            ),
+        };
 
         let base_size = window.text_style().font_size;
         let rem_size = window.rem_size();
@@ -373,6 +655,18 @@ impl RenderOnce for Modal {
 
         let animation = Animation::new(Duration::from_secs_f64(0.25))
             .with_easing(cubic_bezier(0.32, 0.72, 0., 1.));
+        let is_active = (self.layer_ix + 1) == Root::read(window, cx).active_modals.len();
+        let shake_count = self.shake_count;
+
+        // Keep the focus in the active modal that can't be dismissed,
+        // or is stacked on the other modals, to not move the focus to the modals below.
+        if is_active
+            && (!self.dismissable || self.layer_ix > 0)
+            && !self.focus_handle.contains_focused(window, cx)
+        {
+            let focus_handle = self.focus_handle.clone();
+            window.defer(cx, move |window, _| window.focus(&focus_handle));
+        }
 
         anchored()
             .position(point(window_paddings.left, window_paddings.top))
@@ -383,11 +677,24 @@ impl RenderOnce for Modal {
                     .w(view_size.width)
                     .h(view_size.height)
                     .when(self.overlay_visible, |this| {
-                        this.occlude().bg(overlay_color(self.overlay, cx))
+                        let overlay = overlay_color(self.overlay, cx);
+                        this.occlude().bg(if self.overlay_depth > 0 {
+                            overlay.opacity(STACKED_OVERLAY_OPACITY)
+                        } else {
+                            overlay
+                        })
                     })
-                    .when(self.overlay_closable, |this| {
+                    .when(is_active && !self.dismissable, |this| {
+                        this.on_mouse_down(MouseButton::Left, {
+                            let focus_handle = self.focus_handle.clone();
+                            move |_, window, cx| {
+                                shake_modal(layer_ix, &focus_handle, window, cx);
+                            }
+                        })
+                    })
+                    .when(self.overlay_closable && self.dismissable, |this| {
                         // Only the last modal owns the `mouse down - close modal` event.
-                        if (self.layer_ix + 1) != Root::read(window, cx).active_modals.len() {
+                        if !is_active {
                             return this;
                         }
 
@@ -416,20 +723,30 @@ impl RenderOnce for Modal {
                             .px_0()
                             .key_context(CONTEXT)
                             .track_focus(&self.focus_handle)
-                            .when(self.keyboard, |this| {
+                            .when(self.keyboard && !self.dismissable, |this| {
                                 this.on_action({
-                                    let on_cancel = on_cancel.clone();
-                                    let on_close = on_close.clone();
+                                    let focus_handle = self.focus_handle.clone();
                                     move |_: &Cancel, window, cx| {
-                                        // FIXME:
-                                        //
-                                        // Here some Modal have no focus_handle, so it will not work will Escape key.
-                                        // But by now, we `cx.close_modal()` going to close the last active model, so the Escape is unexpected to work.
-                                        on_cancel(&ClickEvent::default(), window, cx);
-                                        on_close(&ClickEvent::default(), window, cx);
-                                        window.close_modal(cx);
+                                        shake_modal(layer_ix, &focus_handle, window, cx);
                                     }
                                 })
+                            })
+                            .when(self.keyboard, |this| {
+                                this.when(self.dismissable, |this| {
+                                    this.on_action({
+                                        let on_cancel = on_cancel.clone();
+                                        let on_close = on_close.clone();
+                                        move |_: &Cancel, window, cx| {
+                                            // FIXME:
+                                            //
+                                            // Here some Modal have no focus_handle, so it will not work will Escape key.
+                                            // But by now, we `cx.close_modal()` going to close the last active model, so the Escape is unexpected to work.
+                                            on_cancel(&ClickEvent::default(), window, cx);
+                                            on_close(&ClickEvent::default(), window, cx);
+                                            window.close_modal(cx);
+                                        }
+                                    })
+                                })
                                 .on_action({
                                     let on_ok = on_ok.clone();
                                     let on_close = on_close.clone();
@@ -452,16 +769,43 @@ impl RenderOnce for Modal {
                             .relative()
                             .left(x)
                             .top(y)
-                            .w(self.width)
-                            .when_some(self.max_width, |this, w| this.max_w(w))
+                            .map(|this| match self.size {
+                                Some(size) => this.w(size.width).h(size.height),
+                                None => this
+                                    .w(self.width)
+                                    .when_some(self.max_width, |this, w| this.max_w(w)),
+                            })
                             .when_some(self.title, |this, title| {
+                                let drag = DragModal {
+                                    layer_ix,
+                                    edge: None,
+                                };
+
                                 this.child(
                                     div()
+                                        .id("title")
                                         .pl(paddings.left)
                                         .pr(paddings.right)
                                         .line_height(relative(1.))
                                         .font_semibold()
-                                        .child(title),
+                                        .child(title)
+                                        .when(self.draggable, |this| {
+                                            this.on_mouse_down(
+                                                MouseButton::Left,
+                                                move |e, window, cx| {
+                                                    start_drag_modal(layer_ix, e, window, cx)
+                                                },
+                                            )
+                                            .on_drag(drag, |drag, _, _, cx| {
+                                                cx.stop_propagation();
+                                                cx.new(|_| *drag)
+                                            })
+                                            .on_drag_move(
+                                                move |e: &DragMoveEvent<DragModal>, window, cx| {
+                                                    update_drag_modal(drag, e, bounds, window, cx)
+                                                },
+                                            )
+                                        }),
                                 )
                             })
                             .children(self.show_close.then(|| {
@@ -491,6 +835,35 @@ impl RenderOnce for Modal {
                                         .child(self.content),
                                 ),
                             )
+                            .when(self.resizable, |this| {
+                                this.children(
+                                    ResizeEdge::ALL
+                                        .map(|edge| render_resize_handle(layer_ix, edge, bounds)),
+                                )
+                            })
+                            .when(self.draggable || self.resizable, |this| {
+                                // Save the bounds in the container to start the drag.
+                                let origin = point(window_paddings.left, window_paddings.top);
+                                this.child(
+                                    canvas(
+                                        move |modal_bounds, window, cx| {
+                                            Root::update(window, cx, |root, _, _| {
+                                                if let Some(modal) =
+                                                    root.active_modals.get_mut(layer_ix)
+                                                {
+                                                    modal.bounds = Bounds {
+                                                        origin: modal_bounds.origin - origin,
+                                                        size: modal_bounds.size,
+                                                    };
+                                                }
+                                            })
+                                        },
+                                        |_, _, _, _| {},
+                                    )
+                                    .absolute()
+                                    .size_full(),
+                                )
+                            })
                             .when_some(self.footer, |this, footer| {
                                 this.child(
                                     h_flex()
@@ -502,24 +875,30 @@ impl RenderOnce for Modal {
                                         .children(footer(render_ok, render_cancel, window, cx)),
                                 )
                             })
-                            .with_animation("slide-down", animation.clone(), move |this, delta| {
-                                let y_offset = px(0.) + delta * px(30.);
-                                // This is equivalent to `shadow_xl` with an extra opacity.
-                                let shadow = vec![
-                                    BoxShadow {
-                                        color: hsla(0., 0., 0., 0.1 * delta),
-                                        offset: point(px(0.), px(20.)),
-                                        blur_radius: px(25.),
-                                        spread_radius: px(-5.),
-                                    },
-                                    BoxShadow {
-                                        color: hsla(0., 0., 0., 0.1 * delta),
-                                        offset: point(px(0.), px(8.)),
-                                        blur_radius: px(10.),
-                                        spread_radius: px(-6.),
-                                    },
-                                ];
-                                this.top(y + y_offset).shadow(shadow)
+                            .map(|this| {
+                                if shake_count == 0 {
+                                    this.with_animation(
+                                        "slide-down",
+                                        animation.clone(),
+                                        move |this, delta| {
+                                            let y_offset = px(0.) + delta * SLIDE_OFFSET;
+                                            this.top(y + y_offset).shadow(modal_shadow(delta))
+                                        },
+                                    )
+                                    .into_any_element()
+                                } else {
+                                    // Restart the shake animation on each blocked dismiss attempt.
+                                    this.with_animation(
+                                        ("shake", shake_count),
+                                        Animation::new(Duration::from_secs_f64(0.4)),
+                                        move |this, delta| {
+                                            this.top(y + SLIDE_OFFSET)
+                                                .left(x + shake_offset(delta))
+                                                .shadow(modal_shadow(1.))
+                                        },
+                                    )
+                                    .into_any_element()
+                                }
                             }),
                     )
                     .with_animation("fade-in", animation, move |this, delta| this.opacity(delta)),
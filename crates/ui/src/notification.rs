@@ -7,16 +7,19 @@ use std::{
 
 use gpui::{
     div, prelude::FluentBuilder, px, Animation, AnimationExt, AnyElement, App, AppContext,
-    ClickEvent, Context, DismissEvent, ElementId, Entity, EventEmitter, InteractiveElement as _,
-    IntoElement, ParentElement as _, Render, SharedString, StatefulInteractiveElement,
-    StyleRefinement, Styled, Subscription, Window,
+    ClickEvent, Context, Corner, DismissEvent, ElementId, Entity, EventEmitter,
+    InteractiveElement as _, IntoElement, ParentElement as _, Render, SharedString,
+    StatefulInteractiveElement, StyleRefinement, Styled, Subscription, Task, Window,
 };
-use smol::Timer;
+use rust_i18n::t;
 
 use crate::{
     animation::cubic_bezier,
     button::{Button, ButtonVariants as _},
-    h_flex, v_flex, ActiveTheme as _, Icon, IconName, Sizable as _, StyledExt,
+    h_flex,
+    progress::Progress,
+    root::Root,
+    v_flex, ActiveTheme as _, Icon, IconName, Sizable as _, StyledExt,
 };
 
 #[derive(Debug, Clone, Copy, Default)]
@@ -57,6 +60,79 @@ impl From<(TypeId, ElementId)> for NotificationId {
     }
 }
 
+/// The layout of the notifications in the window,
+/// set by [`crate::ContextModal::set_notification_layout`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotificationLayout {
+    /// The corner of the window to show the notifications, default is `Corner::TopRight`.
+    pub corner: Corner,
+    /// The max number of visible notifications, default is 10.
+    ///
+    /// When the cap is hit, the oldest auto hide notification will be dismissed for the new one,
+    /// the rest will be collapsed into a "+N more" summary.
+    pub max_visible: usize,
+}
+
+impl Default for NotificationLayout {
+    fn default() -> Self {
+        Self {
+            corner: Corner::TopRight,
+            max_visible: 10,
+        }
+    }
+}
+
+impl NotificationLayout {
+    /// Set the corner of the window to show the notifications.
+    pub fn corner(mut self, corner: Corner) -> Self {
+        self.corner = corner;
+        self
+    }
+
+    /// Set the max number of visible notifications, at least 1.
+    pub fn max_visible(mut self, max_visible: usize) -> Self {
+        self.max_visible = max_visible.max(1);
+        self
+    }
+
+    fn is_top(&self) -> bool {
+        matches!(self.corner, Corner::TopLeft | Corner::TopRight)
+    }
+
+    /// Returns the number of the visible notifications of the `len` notifications,
+    /// all of them are visible if the collapsed notifications are shown.
+    fn visible_count(&self, len: usize, show_collapsed: bool) -> usize {
+        if show_collapsed {
+            len
+        } else {
+            len.min(self.max_visible)
+        }
+    }
+
+    /// Returns the index of the oldest visible notification to dismiss for the new one
+    /// when the cap is hit, by whether each notification can be dismissed (auto hide and
+    /// not closing).
+    fn dismiss_over_cap(
+        &self,
+        dismissable: impl IntoIterator<Item = bool>,
+        len: usize,
+        show_collapsed: bool,
+    ) -> Option<usize> {
+        if show_collapsed || len <= self.max_visible {
+            return None;
+        }
+
+        dismissable
+            .into_iter()
+            .take(self.max_visible)
+            .position(|dismissable| dismissable)
+    }
+
+    fn is_left(&self) -> bool {
+        matches!(self.corner, Corner::TopLeft | Corner::BottomLeft)
+    }
+}
+
 /// A notification element.
 pub struct Notification {
     /// The id is used make the notification unique.
@@ -69,11 +145,16 @@ pub struct Notification {
     title: Option<SharedString>,
     message: Option<SharedString>,
     icon: Option<Icon>,
-    autohide: bool,
+    autohide: Option<bool>,
+    actions: Vec<(SharedString, Rc<dyn Fn(&mut Window, &mut App)>)>,
     action_builder: Option<Rc<dyn Fn(&mut Window, &mut Context<Self>) -> Button>>,
     content_builder: Option<Rc<dyn Fn(&mut Window, &mut Context<Self>) -> AnyElement>>,
     on_click: Option<Rc<dyn Fn(&ClickEvent, &mut Window, &mut App)>>,
     closing: bool,
+    /// The progress value (0 - 100) of the [`Notification::progress`], None if there is no progress bar.
+    progress: Option<f32>,
+    /// The corner of the notification list, to animate in and out from the edge.
+    corner: Corner,
 }
 
 impl From<String> for Notification {
@@ -123,11 +204,14 @@ impl Notification {
             message: None,
             type_: None,
             icon: None,
-            autohide: true,
+            autohide: None,
+            actions: Vec::new(),
             action_builder: None,
             content_builder: None,
             on_click: None,
             closing: false,
+            progress: None,
+            corner: Corner::TopRight,
         }
     }
 
@@ -199,12 +283,47 @@ impl Notification {
         self
     }
 
-    /// Set the auto hide of the notification, default is true.
+    /// Set the auto hide of the notification.
+    ///
+    /// Default is true, or false if the notification has the [`Notification::action`]s.
     pub fn autohide(mut self, autohide: bool) -> Self {
-        self.autohide = autohide;
+        self.autohide = Some(autohide);
         self
     }
 
+    fn should_autohide(&self) -> bool {
+        self.autohide
+            .unwrap_or(self.actions.is_empty() && self.progress.is_none())
+    }
+
+    /// Create a notification with a progress bar for a long-running task.
+    ///
+    /// The progress notification will not auto hide until it is completed,
+    /// use the [`Notification::handle`] to update it after pushed.
+    ///
+    /// ```ignore
+    /// struct Download;
+    /// let note = Notification::progress().id::<Download>().message("Downloading...");
+    /// let handle = note.handle();
+    /// window.push_notification(note, cx);
+    ///
+    /// handle.set_progress(50., window, cx);
+    /// handle.complete("Download completed.", window, cx);
+    /// ```
+    pub fn progress() -> Self {
+        let mut this = Self::new();
+        this.progress = Some(0.);
+        this
+    }
+
+    /// Returns a handle to update the pushed notification by its id,
+    /// so it must be called after the [`Notification::id`] is set.
+    pub fn handle(&self) -> NotificationHandle {
+        NotificationHandle {
+            id: self.id.clone(),
+        }
+    }
+
     /// Set the click callback of the notification.
     pub fn on_click(
         mut self,
@@ -214,8 +333,23 @@ impl Notification {
         self
     }
 
-    /// Set the action button of the notification.
-    pub fn action<F>(mut self, action: F) -> Self
+    /// Add an action button with the label to the notification, e.g.: "Undo".
+    ///
+    /// Clicking the action runs the `on_click` and then dismisses the notification,
+    /// the first action is the primary one. Keep one or two actions to fit the notification.
+    ///
+    /// The notification will not auto hide if it has actions, unless [`Notification::autohide`] is set.
+    pub fn action(
+        mut self,
+        label: impl Into<SharedString>,
+        on_click: impl Fn(&mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.actions.push((label.into(), Rc::new(on_click)));
+        self
+    }
+
+    /// Set a custom action button of the notification.
+    pub fn custom_action<F>(mut self, action: F) -> Self
     where
         F: Fn(&mut Window, &mut Context<Self>) -> Button + 'static,
     {
@@ -230,7 +364,9 @@ impl Notification {
 
         // Dismiss the notification after 0.15s to show the animation.
         cx.spawn(async move |view, cx| {
-            Timer::after(Duration::from_secs_f32(0.15)).await;
+            cx.background_executor()
+                .timer(Duration::from_secs_f32(0.15))
+                .await;
             cx.update(|cx| {
                 if let Some(view) = view.upgrade() {
                     view.update(cx, |view, cx| {
@@ -252,6 +388,54 @@ impl Notification {
         self
     }
 }
+/// A handle to update a pushed [`Notification`] in place, see [`Notification::progress`].
+///
+/// The updates are ignored if the notification has been dismissed.
+#[derive(Debug, Clone)]
+pub struct NotificationHandle {
+    id: NotificationId,
+}
+
+impl NotificationHandle {
+    /// Set the progress value (0 - 100) of the notification.
+    pub fn set_progress(&self, value: f32, window: &mut Window, cx: &mut App) {
+        self.update(window, cx, |note, _, _| {
+            note.progress = Some(value.clamp(0., 100.));
+        });
+    }
+
+    /// Set the message of the notification.
+    pub fn set_message(&self, message: impl Into<SharedString>, window: &mut Window, cx: &mut App) {
+        let message = message.into();
+        self.update(window, cx, |note, _, _| {
+            note.message = Some(message);
+        });
+    }
+
+    /// Complete the progress with the message,
+    /// then the notification becomes a normal notification to be dismissed.
+    pub fn complete(&self, message: impl Into<SharedString>, window: &mut Window, cx: &mut App) {
+        let message = message.into();
+        self.update(window, cx, |note, _, _| {
+            note.progress = None;
+            note.message = Some(message);
+        });
+    }
+
+    fn update(
+        &self,
+        window: &mut Window,
+        cx: &mut App,
+        f: impl FnOnce(&mut Notification, &mut Window, &mut Context<Notification>),
+    ) {
+        Root::update(window, cx, |root, window, cx| {
+            root.notification.update(cx, |list, cx| {
+                list.update_notification(&self.id, f, window, cx)
+            });
+        })
+    }
+}
+
 impl EventEmitter<DismissEvent> for Notification {}
 impl FluentBuilder for Notification {}
 impl Styled for Notification {
@@ -262,6 +446,12 @@ impl Styled for Notification {
 impl Render for Notification {
     fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
         let closing = self.closing;
+        let (from_top, from_left) = match self.corner {
+            Corner::TopLeft => (true, true),
+            Corner::TopRight => (true, false),
+            Corner::BottomLeft => (false, true),
+            Corner::BottomRight => (false, false),
+        };
         let icon = match self.type_ {
             None => self.icon.clone(),
             Some(type_) => Some(type_.icon(cx)),
@@ -299,8 +489,38 @@ impl Render for Notification {
                     })
                     .when_some(self.content_builder.clone(), |this, child_builder| {
                         this.child(child_builder(window, cx))
+                    })
+                    .when_some(self.progress, |this, value| {
+                        this.child(div().mt_2().child(Progress::new().value(value)))
                     }),
             )
+            .when(!self.actions.is_empty(), |this| {
+                this.child(
+                    h_flex()
+                        .gap_2()
+                        .mr_3p5()
+                        .children(self.actions.iter().enumerate().map(
+                            |(ix, (label, on_click))| {
+                                let on_click = on_click.clone();
+                                Button::new(("action", ix))
+                                    .label(label.clone())
+                                    .small()
+                                    .map(|this| {
+                                        if ix == 0 {
+                                            this.primary()
+                                        } else {
+                                            this.ghost()
+                                        }
+                                    })
+                                    .on_click(cx.listener(move |view, _, window, cx| {
+                                        cx.stop_propagation();
+                                        view.dismiss(window, cx);
+                                        on_click(window, cx);
+                                    }))
+                            },
+                        )),
+                )
+            })
             .when_some(self.action_builder.clone(), |this, action_builder| {
                 this.child(action_builder(window, cx).small().mr_3p5())
             })
@@ -333,16 +553,28 @@ impl Render for Notification {
                     if closing {
                         let x_offset = px(0.) + delta * px(45.);
                         let opacity = 1. - delta;
-                        this.left(px(0.) + x_offset)
-                            .shadow_none()
-                            .opacity(opacity)
-                            .when(opacity < 0.85, |this| this.shadow_none())
+                        this.map(|this| {
+                            if from_left {
+                                this.right(x_offset)
+                            } else {
+                                this.left(x_offset)
+                            }
+                        })
+                        .shadow_none()
+                        .opacity(opacity)
+                        .when(opacity < 0.85, |this| this.shadow_none())
                     } else {
                         let y_offset = px(-45.) + delta * px(45.);
                         let opacity = delta;
-                        this.top(px(0.) + y_offset)
-                            .opacity(opacity)
-                            .when(opacity < 0.85, |this| this.shadow_none())
+                        this.map(|this| {
+                            if from_top {
+                                this.top(y_offset)
+                            } else {
+                                this.bottom(y_offset)
+                            }
+                        })
+                        .opacity(opacity)
+                        .when(opacity < 0.85, |this| this.shadow_none())
                     }
                 },
             )
@@ -354,7 +586,12 @@ pub struct NotificationList {
     /// Notifications that will be auto hidden.
     pub(crate) notifications: VecDeque<Entity<Notification>>,
     expanded: bool,
+    layout: NotificationLayout,
+    /// Whether to show the collapsed notifications.
+    show_collapsed: bool,
     _subscriptions: HashMap<NotificationId, Subscription>,
+    /// The auto hide tasks of the visible notifications.
+    _autohide_tasks: HashMap<NotificationId, Task<()>>,
 }
 
 impl NotificationList {
@@ -362,7 +599,98 @@ impl NotificationList {
         Self {
             notifications: VecDeque::new(),
             expanded: false,
+            layout: NotificationLayout::default(),
+            show_collapsed: false,
             _subscriptions: HashMap::new(),
+            _autohide_tasks: HashMap::new(),
+        }
+    }
+
+    /// Set the max number of visible notifications, default is 10.
+    ///
+    /// The rest of notifications will be collapsed into a "+N more" summary,
+    /// and they will be shown when the visible ones are dismissed.
+    pub fn max_visible(mut self, max_visible: usize) -> Self {
+        self.layout = self.layout.max_visible(max_visible);
+        self
+    }
+
+    /// Set the layout of the notifications, see [`NotificationLayout`].
+    pub fn set_layout(
+        &mut self,
+        layout: NotificationLayout,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.layout = layout.max_visible(layout.max_visible);
+        let corner = self.layout.corner;
+        for notification in self.notifications.iter() {
+            notification.update(cx, |note, _| note.corner = corner);
+        }
+        self.autohide_visible(window, cx);
+        cx.notify();
+    }
+
+    /// Get the layout of the notifications.
+    pub fn layout(&self) -> NotificationLayout {
+        self.layout
+    }
+
+    /// Set the max number of visible notifications, see also [`NotificationList::max_visible`].
+    pub fn set_max_visible(
+        &mut self,
+        max_visible: usize,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.layout = self.layout.max_visible(max_visible);
+        self.autohide_visible(window, cx);
+        cx.notify();
+    }
+
+    fn visible_count(&self) -> usize {
+        self.layout
+            .visible_count(self.notifications.len(), self.show_collapsed)
+    }
+
+    /// Start the auto hide timer for the visible notifications,
+    /// the collapsed notifications will not be hidden until they are visible.
+    fn autohide_visible(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        for notification in self.notifications.iter().take(self.visible_count()) {
+            let note = notification.read(cx);
+            if !note.should_autohide() || self._autohide_tasks.contains_key(&note.id) {
+                continue;
+            }
+
+            let id = note.id.clone();
+            let notification = notification.clone();
+            // Sleep for 5 seconds to autohide the notification
+            let task = cx.spawn_in(window, async move |_, cx| {
+                cx.background_executor().timer(Duration::from_secs(5)).await;
+
+                if let Err(err) =
+                    notification.update_in(cx, |note, window, cx| note.dismiss(window, cx))
+                {
+                    tracing::error!("failed to auto hide notification: {:?}", err);
+                }
+            });
+            self._autohide_tasks.insert(id, task);
+        }
+    }
+
+    /// Dismiss the oldest auto hide notification for the new one when the cap is hit,
+    /// the new one will be promoted into view after the old one is dismissed.
+    fn dismiss_oldest_over_cap(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let ix = self.layout.dismiss_over_cap(
+            self.notifications.iter().map(|note| {
+                let note = note.read(cx);
+                note.should_autohide() && !note.closing
+            }),
+            self.notifications.len(),
+            self.show_collapsed,
+        );
+        if let Some(oldest) = ix.and_then(|ix| self.notifications.get(ix)).cloned() {
+            oldest.update(cx, |note, cx| note.dismiss(window, cx));
         }
     }
 
@@ -374,35 +702,58 @@ impl NotificationList {
     ) {
         let notification = notification.into();
         let id = notification.id.clone();
-        let autohide = notification.autohide;
 
         // Remove the notification by id, for keep unique.
         self.notifications.retain(|note| note.read(cx).id != id);
+        self._autohide_tasks.remove(&id);
 
+        let mut notification = notification;
+        notification.corner = self.layout.corner;
         let notification = cx.new(|_| notification);
 
         self._subscriptions.insert(
             id.clone(),
-            cx.subscribe(&notification, move |view, _, _: &DismissEvent, cx| {
-                view.notifications.retain(|note| id != note.read(cx).id);
-                view._subscriptions.remove(&id);
-            }),
+            cx.subscribe_in(
+                &notification,
+                window,
+                move |view, _, _: &DismissEvent, window, cx| {
+                    view.notifications.retain(|note| id != note.read(cx).id);
+                    view._subscriptions.remove(&id);
+                    view._autohide_tasks.remove(&id);
+                    if view.notifications.len() <= view.layout.max_visible {
+                        view.show_collapsed = false;
+                    }
+
+                    // Promote the collapsed notifications into view.
+                    view.autohide_visible(window, cx);
+                    cx.notify();
+                },
+            ),
         );
 
-        self.notifications.push_back(notification.clone());
-        if autohide {
-            // Sleep for 5 seconds to autohide the notification
-            cx.spawn_in(window, async move |_, cx| {
-                Timer::after(Duration::from_secs(5)).await;
+        self.notifications.push_back(notification);
+        self.dismiss_oldest_over_cap(window, cx);
+        self.autohide_visible(window, cx);
+        cx.notify();
+    }
 
-                if let Err(err) =
-                    notification.update_in(cx, |note, window, cx| note.dismiss(window, cx))
-                {
-                    tracing::error!("failed to auto hide notification: {:?}", err);
-                }
-            })
-            .detach();
-        }
+    /// Update the notification with the id in place, and restart the auto hide if it is needed.
+    fn update_notification(
+        &mut self,
+        id: &NotificationId,
+        f: impl FnOnce(&mut Notification, &mut Window, &mut Context<Notification>),
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(notification) = self.notifications.iter().find(|n| &n.read(cx).id == id) else {
+            return;
+        };
+
+        notification.update(cx, |note, cx| {
+            f(note, window, cx);
+            cx.notify();
+        });
+        self.autohide_visible(window, cx);
         cx.notify();
     }
 
@@ -421,6 +772,8 @@ impl NotificationList {
 
     pub fn clear(&mut self, _: &mut Window, cx: &mut Context<Self>) {
         self.notifications.clear();
+        self._autohide_tasks.clear();
+        self.show_collapsed = false;
         cx.notify();
     }
 
@@ -436,18 +789,171 @@ impl Render for NotificationList {
         cx: &mut gpui::Context<Self>,
     ) -> impl IntoElement {
         let size = window.viewport_size();
-        let items = self.notifications.iter().rev().take(10).rev().cloned();
-
-        div().absolute().top_4().right_4().child(
-            v_flex()
-                .id("notification-list")
-                .h(size.height - px(8.))
-                .on_hover(cx.listener(|view, hovered, _, cx| {
-                    view.expanded = *hovered;
-                    cx.notify()
-                }))
-                .gap_3()
-                .children(items),
-        )
+        let visible_count = self.visible_count();
+        let items = self.notifications.iter().take(visible_count).cloned();
+        let collapsed_count = self.notifications.len() - visible_count;
+
+        let layout = self.layout;
+
+        div()
+            .absolute()
+            .map(|this| {
+                if layout.is_top() {
+                    this.top_4()
+                } else {
+                    this.bottom_4()
+                }
+            })
+            .map(|this| {
+                if layout.is_left() {
+                    this.left_4()
+                } else {
+                    this.right_4()
+                }
+            })
+            .child(
+                v_flex()
+                    .id("notification-list")
+                    .h(size.height - px(8.))
+                    .when(!layout.is_top(), |this| this.justify_end())
+                    .on_hover(cx.listener(|view, hovered, _, cx| {
+                        view.expanded = *hovered;
+                        cx.notify()
+                    }))
+                    .gap_3()
+                    .children(items)
+                    .when(collapsed_count > 0, |this| {
+                        this.child(
+                            h_flex().w_112().justify_center().child(
+                                Button::new("notification-more")
+                                    .label(t!("Notification.more", count = collapsed_count))
+                                    .small()
+                                    .rounded(cx.theme().radius_lg)
+                                    .on_click(cx.listener(|view, _, window, cx| {
+                                        view.show_collapsed = true;
+                                        view.autohide_visible(window, cx);
+                                        cx.notify();
+                                    })),
+                            ),
+                        )
+                    }),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{any::TypeId, time::Duration};
+
+    use gpui::{AppContext as _, TestAppContext, VisualTestContext};
+
+    use super::{Notification, NotificationLayout, NotificationList};
+
+    struct Note;
+
+    #[test]
+    fn test_push_over_cap() {
+        let layout = NotificationLayout::default().max_visible(3);
+        assert_eq!(layout.visible_count(2, false), 2);
+        assert_eq!(layout.visible_count(5, false), 3);
+
+        // Not over the cap.
+        assert_eq!(layout.dismiss_over_cap([true; 3], 3, false), None);
+        // Dismiss the oldest auto hide one of the visible notifications.
+        assert_eq!(layout.dismiss_over_cap([true; 4], 4, false), Some(0));
+        assert_eq!(
+            layout.dismiss_over_cap([false, false, true, true], 4, false),
+            Some(2)
+        );
+        // Keep them if none of the visible notifications can be dismissed.
+        assert_eq!(
+            layout.dismiss_over_cap([false, false, false, true], 4, false),
+            None
+        );
+        // All of them are visible when expanded.
+        assert_eq!(layout.dismiss_over_cap([true; 4], 4, true), None);
+    }
+
+    #[gpui::test]
+    fn test_dismiss_promotes_collapsed(cx: &mut TestAppContext) {
+        let cx = cx.add_empty_window();
+        let list =
+            cx.update(|window, cx| cx.new(|cx| NotificationList::new(window, cx).max_visible(3)));
+        let visible = |cx: &mut VisualTestContext| -> Vec<String> {
+            list.read_with(cx, |list, cx| {
+                list.notifications
+                    .iter()
+                    .take(list.visible_count())
+                    .filter_map(|note| note.read(cx).message.as_ref().map(|m| m.to_string()))
+                    .collect()
+            })
+        };
+        let close = |ix: usize, cx: &mut VisualTestContext| {
+            list.update_in(cx, |list, window, cx| {
+                list.close((TypeId::of::<Note>(), ix.into()), window, cx)
+            });
+            cx.executor().advance_clock(Duration::from_millis(200));
+            cx.run_until_parked();
+        };
+
+        // Keep all of them over the cap, the rest are collapsed.
+        list.update_in(cx, |list, window, cx| {
+            for ix in 0..5 {
+                let note = Notification::new()
+                    .id1::<Note>(ix)
+                    .message(ix.to_string())
+                    .autohide(false);
+                list.push(note, window, cx);
+            }
+        });
+        assert_eq!(visible(cx), ["0", "1", "2"]);
+
+        close(1, cx);
+        assert_eq!(visible(cx), ["0", "2", "3"]);
+
+        close(0, cx);
+        close(2, cx);
+        assert_eq!(visible(cx), ["3", "4"]);
+        list.read_with(cx, |list, _| assert_eq!(list.notifications.len(), 2));
+    }
+
+    #[gpui::test]
+    fn test_push_dismisses_oldest(cx: &mut TestAppContext) {
+        let cx = cx.add_empty_window();
+        let list =
+            cx.update(|window, cx| cx.new(|cx| NotificationList::new(window, cx).max_visible(3)));
+
+        list.update_in(cx, |list, window, cx| {
+            list.push(Notification::new().message("0").autohide(false), window, cx);
+            for ix in 1..4 {
+                list.push(Notification::new().message(ix.to_string()), window, cx);
+            }
+        });
+        cx.executor().advance_clock(Duration::from_millis(200));
+        cx.run_until_parked();
+
+        // The oldest auto hide one is dismissed for the new one.
+        list.read_with(cx, |list, cx| {
+            let messages = list
+                .notifications
+                .iter()
+                .filter_map(|note| note.read(cx).message.as_ref().map(|m| m.to_string()))
+                .collect::<Vec<_>>();
+            assert_eq!(messages, ["0", "2", "3"]);
+        });
+    }
+
+    #[test]
+    fn test_expand_collapsed() {
+        let layout = NotificationLayout::default().max_visible(3);
+        // The "+2 more" summary.
+        assert_eq!(5 - layout.visible_count(5, false), 2);
+        // Show all of them after the summary is clicked.
+        assert_eq!(layout.visible_count(5, true), 5);
+
+        // At least 1 is visible.
+        let layout = layout.max_visible(0);
+        assert_eq!(layout.max_visible, 1);
+        assert_eq!(layout.visible_count(5, false), 1);
     }
 }
@@ -7,6 +7,9 @@ use super::{label::Label, label::Text, label::TEXT_GAP, label::TEXT_SIZE, origin
 
 pub const AXIS_GAP: f32 = 18.;
 
+/// The length of the major ticks, the minor ticks are half of it.
+const TICK_SIZE: f32 = 6.;
+
 pub struct AxisText {
     pub text: SharedString,
     pub tick: Pixels,
@@ -44,6 +47,8 @@ pub struct Axis {
     show_x_axis: bool,
     y: Option<Pixels>,
     y_label: Label,
+    y_ticks: Vec<Pixels>,
+    y_minor_ticks: Vec<Pixels>,
     show_y_axis: bool,
     stroke: Hsla,
 }
@@ -52,6 +57,7 @@ impl Axis {
     pub fn new() -> Self {
         Self {
             show_x_axis: true,
+            show_y_axis: true,
             ..Default::default()
         }
     }
@@ -116,6 +122,18 @@ impl Axis {
         self
     }
 
+    /// Set the major ticks of the y-axis, painted as the marks on the right of the y-axis.
+    pub fn y_ticks(mut self, ticks: impl IntoIterator<Item = impl Into<Pixels>>) -> Self {
+        self.y_ticks = ticks.into_iter().map(|t| t.into()).collect();
+        self
+    }
+
+    /// Set the minor ticks of the y-axis, painted shorter than the major ticks.
+    pub fn y_minor_ticks(mut self, ticks: impl IntoIterator<Item = impl Into<Pixels>>) -> Self {
+        self.y_minor_ticks = ticks.into_iter().map(|t| t.into()).collect();
+        self
+    }
+
     /// Set the stroke color of the Axis.
     pub fn stroke(mut self, stroke: impl Into<Hsla>) -> Self {
         self.stroke = stroke.into();
@@ -156,6 +174,19 @@ impl Axis {
                     window,
                 );
             }
+
+            for (ticks, size) in [
+                (&self.y_ticks, TICK_SIZE),
+                (&self.y_minor_ticks, TICK_SIZE / 2.),
+            ] {
+                for tick in ticks {
+                    self.draw_axis(
+                        origin_point(y, *tick, origin),
+                        origin_point(y + px(size), *tick, origin),
+                        window,
+                    );
+                }
+            }
         }
         self.y_label.paint(bounds, window, cx);
     }
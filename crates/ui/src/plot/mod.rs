@@ -1,22 +1,61 @@
 mod axis;
 mod grid;
 pub mod label;
+mod placeholder;
 pub mod scale;
 pub mod shape;
 pub mod tooltip;
 
 pub use gpui_component_macros::IntoPlot;
 
-use std::{fmt::Debug, ops::Add};
+use std::{fmt::Debug, ops::Add, rc::Rc};
 
-use gpui::{point, px, App, Bounds, IntoElement, Path, PathBuilder, Pixels, Point, Window};
+use gpui::{
+    point, px, AnyElement, App, Bounds, IntoElement, Path, PathBuilder, Pixels, Point, Window,
+};
 
 pub use axis::{Axis, AxisText, AXIS_GAP};
 pub use grid::Grid;
 pub use label::Label;
+pub use placeholder::Placeholder;
+
+/// The handler of the click on the data, with the index of the clicked data.
+pub type DataClickHandler = Rc<dyn Fn(usize, &mut Window, &mut App)>;
 
 pub trait Plot: IntoElement {
     fn paint(&mut self, bounds: Bounds<Pixels>, window: &mut Window, cx: &mut App);
+
+    /// Returns true if the plot shows a tooltip when hovered, see [`Plot::tooltip`], default: false
+    ///
+    /// The plot is only hit tested for the tooltip when this returns true.
+    fn has_tooltip(&self) -> bool {
+        false
+    }
+
+    /// Returns the tooltip of the data at the mouse `position` when the plot is hovered,
+    /// it is painted on top of the plot, default: None
+    ///
+    /// The `position` is relative to the origin of the `bounds`.
+    fn tooltip(
+        &self,
+        _bounds: Bounds<Pixels>,
+        _position: Point<Pixels>,
+        _window: &mut Window,
+        _cx: &mut App,
+    ) -> Option<AnyElement> {
+        None
+    }
+
+    /// Returns the click handler and the hit regions of the data, default: None
+    ///
+    /// Each region is the index of the data and its bounds relative to the origin of the `bounds`,
+    /// the handler is called with the index when a region is clicked.
+    fn click_regions(
+        &self,
+        _bounds: Bounds<Pixels>,
+    ) -> Option<(DataClickHandler, Vec<(usize, Bounds<Pixels>)>)> {
+        None
+    }
 }
 
 #[derive(Clone, Copy, Default)]
@@ -0,0 +1,70 @@
+use gpui::{fill, point, px, size, App, Bounds, Corners, Pixels, TextAlign, Window};
+use rust_i18n::t;
+
+use crate::ActiveTheme;
+
+use super::label::{Label, Text};
+
+/// The relative heights of the skeleton bars in loading state.
+const SKELETON_BARS: [f32; 6] = [0.45, 0.7, 0.55, 0.9, 0.65, 0.8];
+
+/// The placeholder of a plot, used to paint the loading or empty state.
+pub struct Placeholder {
+    loading: bool,
+}
+
+impl Placeholder {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self { loading: false }
+    }
+
+    /// Set the loading state, paint a skeleton instead of the "No data" text.
+    pub fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
+    /// Paint the Placeholder.
+    pub fn paint(&self, bounds: &Bounds<Pixels>, window: &mut Window, cx: &mut App) {
+        if self.loading {
+            self.paint_skeleton(bounds, window, cx);
+        } else {
+            let font_size = px(12.);
+            let origin = point(
+                bounds.size.width / 2.,
+                (bounds.size.height - font_size) / 2.,
+            );
+
+            Label::new(vec![Text::new(
+                t!("Chart.no_data"),
+                origin,
+                cx.theme().muted_foreground,
+            )
+            .font_size(font_size)
+            .align(TextAlign::Center)])
+            .paint(bounds, window, cx);
+        }
+    }
+
+    fn paint_skeleton(&self, bounds: &Bounds<Pixels>, window: &mut Window, cx: &mut App) {
+        let count = SKELETON_BARS.len() as f32;
+        // Split the width into bars and gaps, the gap is half of the bar width.
+        let bar_width = bounds.size.width / (count * 1.5 + 0.5);
+        let gap = bar_width / 2.;
+        let color = cx.theme().skeleton;
+
+        for (ix, ratio) in SKELETON_BARS.iter().enumerate() {
+            let height = bounds.size.height * *ratio;
+            let origin = point(
+                bounds.origin.x + gap + (bar_width + gap) * ix as f32,
+                bounds.origin.y + bounds.size.height - height,
+            );
+
+            window.paint_quad(
+                fill(Bounds::new(origin, size(bar_width, height)), color)
+                    .corner_radii(Corners::all(cx.theme().radius)),
+            );
+        }
+    }
+}
@@ -1,10 +1,12 @@
 mod band;
 mod linear;
+mod log;
 mod point;
 mod sealed;
 
 pub use band::ScaleBand;
 pub use linear::ScaleLinear;
+pub use log::ScaleLog;
 pub use point::ScalePoint;
 pub(crate) use sealed::Sealed;
 
@@ -15,3 +17,19 @@ pub trait Scale<T> {
     /// Get the least index of the scale.
     fn least_index(&self, tick: f32) -> usize;
 }
+
+/// The type of the value scale of a chart.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum ScaleType {
+    #[default]
+    Linear,
+    /// The logarithmic scale with the base, see [`ScaleLog`].
+    Log(f64),
+}
+
+impl ScaleType {
+    /// The logarithmic scale with the `base`, e.g.: 10
+    pub fn log(base: f64) -> Self {
+        Self::Log(base)
+    }
+}
@@ -93,8 +93,13 @@ where
 
         let avg_width = self.display_avg_width();
         let padding_outer_width = self.avg_width * self.padding_outer;
+        let step = avg_width * self.ratio();
+        if step == 0. || !step.is_finite() {
+            return 0;
+        }
+
         let adjusted_tick = tick - padding_outer_width;
-        let index = (adjusted_tick / (avg_width * self.ratio())).round() as i32;
+        let index = (adjusted_tick / step).round() as i32;
 
         (index.max(0) as usize).min(domain_len.saturating_sub(1))
     }
@@ -126,5 +131,6 @@ mod tests {
         assert_eq!(scale.tick(&2), Some(0.));
         assert_eq!(scale.tick(&3), Some(0.));
         assert_eq!(scale.band_width(), 0.);
+        assert_eq!(scale.least_index(50.), 0);
     }
 }
@@ -51,12 +51,14 @@ where
         }
 
         let ratio = ((*value - self.domain_min) / self.domain_diff).to_f32()?;
+        let tick = (1. - ratio) * self.range_diff + self.range_min;
 
-        Some((1. - ratio) * self.range_diff + self.range_min)
+        // Avoid NaN or infinite ticks, e.g.: the domain contains NaN.
+        tick.is_finite().then_some(tick)
     }
 
     fn least_index(&self, tick: f32) -> usize {
-        if self.domain_len == 0 {
+        if self.domain_len == 0 || self.range_diff == 0. {
             return 0;
         }
 
@@ -88,5 +90,16 @@ mod tests {
         assert_eq!(scale.tick(&1.), Some(0.));
         assert_eq!(scale.tick(&2.), Some(0.));
         assert_eq!(scale.tick(&3.), Some(0.));
+        assert_eq!(scale.least_index(50.), 0);
+    }
+
+    #[test]
+    fn test_scale_linear_nan() {
+        let scale = ScaleLinear::new(vec![1., f64::NAN, 3.], vec![0., 100.]);
+        assert_eq!(scale.tick(&f64::NAN), None);
+
+        let scale = ScaleLinear::new(vec![0., 0.], vec![0., 100.]);
+        assert_eq!(scale.tick(&0.), None);
+        assert_eq!(scale.least_index(50.), 1);
     }
 }
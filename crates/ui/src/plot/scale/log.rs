@@ -0,0 +1,197 @@
+// @reference: https://d3js.org/d3-scale/log
+
+use std::marker::PhantomData;
+
+use itertools::Itertools;
+use num_traits::ToPrimitive;
+
+use super::{sealed::Sealed, Scale};
+
+/// A logarithmic scale, the domain is extended to the whole powers of the base.
+///
+/// Only the positive values can be mapped, the others (<= 0) have no tick and are skipped.
+#[derive(Clone)]
+pub struct ScaleLog<T> {
+    base: f64,
+    domain: Vec<f64>,
+    log_min: f64,
+    log_max: f64,
+    range_min: f32,
+    range_diff: f32,
+    _marker: PhantomData<T>,
+}
+
+impl<T> ScaleLog<T>
+where
+    T: ToPrimitive + Sealed,
+{
+    pub fn new(domain: Vec<T>, range: Vec<f32>) -> Self {
+        let (range_min, range_max) = range
+            .iter()
+            .minmax()
+            .into_option()
+            .map_or((0., 0.), |(min, max)| (*min, *max));
+
+        let mut this = Self {
+            base: 10.,
+            domain: domain
+                .iter()
+                .filter_map(|v| v.to_f64())
+                .filter(|v| v.is_finite() && *v > 0.)
+                .collect(),
+            log_min: 0.,
+            log_max: 0.,
+            range_min,
+            range_diff: range_max - range_min,
+            _marker: PhantomData,
+        };
+        this.update_domain();
+        this
+    }
+
+    /// Set the base of the logarithm, default: 10
+    ///
+    /// The base must be greater than 1, otherwise it is ignored.
+    pub fn base(mut self, base: f64) -> Self {
+        if base > 1. && base.is_finite() {
+            self.base = base;
+            self.update_domain();
+        }
+        self
+    }
+
+    /// The logarithm in the base, snapped to the whole powers to avoid the floating errors.
+    fn log(&self, value: f64) -> f64 {
+        let log = value.ln() / self.base.ln();
+        if (log - log.round()).abs() < 1e-9 {
+            log.round()
+        } else {
+            log
+        }
+    }
+
+    fn update_domain(&mut self) {
+        let (min, max) = self
+            .domain
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), v| {
+                (min.min(*v), max.max(*v))
+            });
+        if min > max {
+            (self.log_min, self.log_max) = (0., 0.);
+            return;
+        }
+
+        self.log_min = self.log(min).floor();
+        self.log_max = self.log(max).ceil();
+        // A single power, extend to the next power to have a range.
+        if self.log_min == self.log_max {
+            self.log_max += 1.;
+        }
+    }
+
+    fn tick_f64(&self, value: f64) -> Option<f32> {
+        if self.domain.is_empty() || !value.is_finite() || value <= 0. {
+            return None;
+        }
+
+        let ratio = (self.log(value) - self.log_min) / (self.log_max - self.log_min);
+        let tick = (1. - ratio as f32) * self.range_diff + self.range_min;
+        tick.is_finite().then_some(tick)
+    }
+
+    /// Returns the powers of the base in the domain, with their ticks.
+    pub fn major_ticks(&self) -> Vec<(f64, f32)> {
+        if self.domain.is_empty() {
+            return vec![];
+        }
+
+        (self.log_min as i32..=self.log_max as i32)
+            .filter_map(|exp| {
+                let value = self.base.powi(exp);
+                self.tick_f64(value).map(|tick| (value, tick))
+            })
+            .collect()
+    }
+
+    /// Returns the ticks of the multiples of the powers between the major ticks,
+    /// e.g.: 2, 3, ..., 9, 20, 30, ..., 90 for the base 10.
+    pub fn minor_ticks(&self) -> Vec<f32> {
+        if self.domain.is_empty() {
+            return vec![];
+        }
+
+        let steps = self.base.ceil() as i32;
+        (self.log_min as i32..self.log_max as i32)
+            .flat_map(|exp| {
+                let power = self.base.powi(exp);
+                (2..steps).map(move |k| power * k as f64)
+            })
+            .filter_map(|value| self.tick_f64(value))
+            .collect()
+    }
+}
+
+impl<T> Scale<T> for ScaleLog<T>
+where
+    T: ToPrimitive + Sealed,
+{
+    fn tick(&self, value: &T) -> Option<f32> {
+        self.tick_f64(value.to_f64()?)
+    }
+
+    fn least_index(&self, tick: f32) -> usize {
+        if self.domain.is_empty() || self.range_diff == 0. {
+            return 0;
+        }
+
+        let index = (tick / self.range_diff).round() as usize;
+        index.min(self.domain.len().saturating_sub(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scale_log() {
+        let scale = ScaleLog::new(vec![1., 10., 100., 1000.], vec![0., 300.]);
+        assert_eq!(scale.tick(&1.), Some(300.));
+        assert_eq!(scale.tick(&10.), Some(200.));
+        assert_eq!(scale.tick(&100.), Some(100.));
+        assert_eq!(scale.tick(&1000.), Some(0.));
+        assert_eq!(
+            scale.major_ticks(),
+            vec![(1., 300.), (10., 200.), (100., 100.), (1000., 0.)]
+        );
+        assert_eq!(scale.minor_ticks().len(), 8 * 3);
+    }
+
+    #[test]
+    fn test_scale_log_nice_domain() {
+        let scale = ScaleLog::new(vec![3., 50., 400.], vec![0., 300.]).base(10.);
+        assert_eq!(scale.tick(&1.), Some(300.));
+        assert_eq!(scale.tick(&1000.), Some(0.));
+
+        let scale = ScaleLog::new(vec![2., 8.], vec![0., 100.]).base(2.);
+        assert_eq!(scale.tick(&2.), Some(100.));
+        assert_eq!(scale.tick(&4.), Some(50.));
+        assert_eq!(scale.tick(&8.), Some(0.));
+        assert!(scale.minor_ticks().is_empty());
+    }
+
+    #[test]
+    fn test_scale_log_non_positive() {
+        let scale = ScaleLog::new(vec![0., -5., 10., 100.], vec![0., 100.]);
+        assert_eq!(scale.tick(&0.), None);
+        assert_eq!(scale.tick(&-5.), None);
+        assert_eq!(scale.tick(&f64::NAN), None);
+        assert_eq!(scale.tick(&10.), Some(100.));
+
+        let scale = ScaleLog::new(vec![0., -1.], vec![0., 100.]);
+        assert_eq!(scale.tick(&1.), None);
+        assert!(scale.major_ticks().is_empty());
+        assert!(scale.minor_ticks().is_empty());
+    }
+}
@@ -51,7 +51,7 @@ where
     }
 
     fn least_index(&self, tick: f32) -> usize {
-        if self.domain.is_empty() {
+        if self.domain.is_empty() || self.range_tick == 0. {
             return 0;
         }
 
@@ -83,6 +83,7 @@ mod tests {
         assert_eq!(scale.tick(&1), Some(0.));
         assert_eq!(scale.tick(&2), Some(0.));
         assert_eq!(scale.tick(&3), Some(0.));
+        assert_eq!(scale.least_index(50.), 0);
     }
 
     #[test]
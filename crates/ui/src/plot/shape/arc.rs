@@ -1,6 +1,9 @@
 // @reference: https://d3js.org/d3-shape/arc
 
-use std::{f32::consts::PI, fmt::Debug};
+use std::{
+    f32::consts::{PI, TAU},
+    fmt::Debug,
+};
 
 use gpui::{point, px, Bounds, Hsla, Path, PathBuilder, Pixels, Point, Window};
 
@@ -26,6 +29,7 @@ impl<T> Debug for ArcData<'_, T> {
     }
 }
 
+#[derive(Clone, Copy)]
 pub struct Arc {
     inner_radius: f32,
     outer_radius: f32,
@@ -67,6 +71,18 @@ impl Arc {
         point(r * a.cos(), r * a.sin())
     }
 
+    /// Returns true if the `point` relative to the center is inside the Arc.
+    pub fn contains<T>(&self, arc: &ArcData<T>, point: Point<f32>) -> bool {
+        let r = point.x.hypot(point.y);
+        if r < self.inner_radius.max(0.) || r > self.outer_radius {
+            return false;
+        }
+
+        // The angle starts from 12 o'clock, clockwise.
+        let angle = (point.y.atan2(point.x) + HALF_PI).rem_euclid(TAU);
+        angle >= arc.start_angle && angle < arc.end_angle
+    }
+
     fn path<T>(&self, arc: &ArcData<T>, bounds: &Bounds<Pixels>) -> Option<Path<Pixels>> {
         let start_angle = arc.start_angle - HALF_PI;
         let end_angle = arc.end_angle - HALF_PI;
@@ -220,4 +236,26 @@ mod tests {
         assert_eq!(centroid.x, expected_radius * expected_angle.cos());
         assert_eq!(centroid.y, expected_radius * expected_angle.sin());
     }
+
+    #[test]
+    fn test_arc_contains() {
+        let arc = Arc::new().inner_radius(10.).outer_radius(20.);
+
+        // The right half of the circle.
+        let arc_data = ArcData {
+            data: &(),
+            index: 0,
+            value: 1.,
+            start_angle: 0.,
+            end_angle: PI,
+            pad_angle: 0.,
+        };
+
+        assert!(arc.contains(&arc_data, point(15., 0.)));
+        assert!(arc.contains(&arc_data, point(0., -15.)));
+        assert!(!arc.contains(&arc_data, point(-15., 0.)));
+        // In the hole or outside the Arc.
+        assert!(!arc.contains(&arc_data, point(5., 0.)));
+        assert!(!arc.contains(&arc_data, point(25., 0.)));
+    }
 }
@@ -14,6 +14,7 @@ pub struct Bar<T> {
     y1: Box<dyn Fn(&T) -> Option<f32>>,
     fill: Box<dyn Fn(&T) -> Hsla>,
     label: Option<Box<dyn Fn(&T, Point<Pixels>) -> Text>>,
+    horizontal: bool,
 }
 
 impl<T> Default for Bar<T> {
@@ -26,6 +27,7 @@ impl<T> Default for Bar<T> {
             y1: Box::new(|_| None),
             fill: Box::new(|_| gpui::black()),
             label: None,
+            horizontal: false,
         }
     }
 }
@@ -93,6 +95,15 @@ impl<T> Bar<T> {
         self
     }
 
+    /// Set the Bar to grow horizontally, default is false.
+    ///
+    /// In horizontal mode, the `x` is the band position on the y-axis,
+    /// and the `y0`, `y1` are the positions on the x-axis.
+    pub fn horizontal(mut self, horizontal: bool) -> Self {
+        self.horizontal = horizontal;
+        self
+    }
+
     fn path(&self, bounds: &Bounds<Pixels>) -> (Vec<PaintQuad>, Label) {
         let origin = bounds.origin;
         let mut graph = vec![];
@@ -103,6 +114,34 @@ impl<T> Bar<T> {
             let y_tick = (self.y1)(v);
 
             if let (Some(x_tick), Some(y_tick)) = (x_tick, y_tick) {
+                if self.horizontal {
+                    let is_negative = y_tick < self.y0;
+                    let p1 = origin_point(px(self.y0.min(y_tick)), px(x_tick), origin);
+                    let p2 = origin_point(
+                        px(self.y0.max(y_tick)),
+                        px(x_tick + self.band_width),
+                        origin,
+                    );
+
+                    let color = (self.fill)(v);
+                    graph.push(fill(Bounds::from_corners(p1, p2), color));
+
+                    if let Some(label) = &self.label {
+                        labels.push(label(
+                            v,
+                            point(
+                                if is_negative {
+                                    px(y_tick - TEXT_GAP)
+                                } else {
+                                    px(y_tick + TEXT_GAP)
+                                },
+                                px(x_tick + self.band_width / 2. - TEXT_HEIGHT / 2.),
+                            ),
+                        ));
+                    }
+                    continue;
+                }
+
                 let is_negative = y_tick > self.y0;
                 let (p1, p2) = if is_negative {
                     (
@@ -1,9 +1,12 @@
 use gpui::{
     div, prelude::FluentBuilder, px, AnyElement, App, Div, Hsla, IntoElement, ParentElement,
-    Pixels, Point, RenderOnce, StyleRefinement, Styled, Window,
+    Pixels, Point, RenderOnce, SharedString, StyleRefinement, Styled, Window,
 };
 
-use crate::{v_flex, ActiveTheme};
+use crate::{h_flex, v_flex, ActiveTheme};
+
+/// The gap between the tooltip and the hovered point.
+const TOOLTIP_GAP: f32 = 12.;
 
 #[derive(Default)]
 pub enum CrossLineAxis {
@@ -150,13 +153,74 @@ impl RenderOnce for Dot {
     }
 }
 
-#[derive(Clone, Copy, Default, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub enum TooltipPosition {
     #[default]
     Left,
     Right,
 }
 
+impl TooltipPosition {
+    /// Returns the position and the gap of the tooltip for the point at `x` in the plot `width`,
+    /// the tooltip is placed on the opposite half of the point to keep it in the plot.
+    pub fn for_point(x: f32, width: f32) -> (Self, Pixels) {
+        if x < width / 2. {
+            (Self::Left, px(x + TOOLTIP_GAP))
+        } else {
+            (Self::Right, px(width - x + TOOLTIP_GAP))
+        }
+    }
+}
+
+/// Returns the index of the nearest `xs` to the `x`, or `None` if the `xs` is empty.
+pub fn nearest_index(xs: &[f32], x: f32) -> Option<usize> {
+    xs.iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| (*a - x).abs().total_cmp(&(*b - x).abs()))
+        .map(|(ix, _)| ix)
+}
+
+/// The default content of the tooltip, the label and the value with a color indicator.
+#[derive(IntoElement)]
+pub struct TooltipItem {
+    label: SharedString,
+    value: SharedString,
+    color: Hsla,
+}
+
+impl TooltipItem {
+    pub fn new(
+        label: impl Into<SharedString>,
+        value: impl Into<SharedString>,
+        color: impl Into<Hsla>,
+    ) -> Self {
+        Self {
+            label: label.into(),
+            value: value.into(),
+            color: color.into(),
+        }
+    }
+}
+
+impl RenderOnce for TooltipItem {
+    fn render(self, _: &mut Window, cx: &mut App) -> impl IntoElement {
+        v_flex()
+            .gap_1()
+            .text_xs()
+            .child(
+                div()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(self.label),
+            )
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(div().size_2().rounded_sm().bg(self.color))
+                    .child(div().font_semibold().child(self.value)),
+            )
+    }
+}
+
 #[derive(Clone)]
 pub struct TooltipState {
     pub index: usize,
@@ -263,3 +327,31 @@ impl RenderOnce for Tooltip {
             .when_some(self.dots, |this, dots| this.children(dots))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use gpui::px;
+
+    use super::{nearest_index, TooltipPosition};
+
+    #[test]
+    fn test_nearest_index() {
+        assert_eq!(nearest_index(&[], 10.), None);
+        assert_eq!(nearest_index(&[0., 50., 100.], -10.), Some(0));
+        assert_eq!(nearest_index(&[0., 50., 100.], 24.), Some(0));
+        assert_eq!(nearest_index(&[0., 50., 100.], 26.), Some(1));
+        assert_eq!(nearest_index(&[0., 50., 100.], 200.), Some(2));
+    }
+
+    #[test]
+    fn test_tooltip_position_for_point() {
+        assert_eq!(
+            TooltipPosition::for_point(20., 200.),
+            (TooltipPosition::Left, px(32.))
+        );
+        assert_eq!(
+            TooltipPosition::for_point(150., 200.),
+            (TooltipPosition::Right, px(62.))
+        );
+    }
+}
@@ -7,7 +7,7 @@ use gpui::{
 };
 use std::{cell::RefCell, rc::Rc};
 
-use crate::{actions::Cancel, Selectable, StyledExt as _};
+use crate::{actions::Cancel, DismissableLayer, EscapeHatch, Selectable, StyledExt as _};
 
 const CONTEXT: &str = "Popover";
 
@@ -74,6 +74,7 @@ pub struct Popover<M: ManagedView> {
     trigger_style: Option<StyleRefinement>,
     mouse_button: MouseButton,
     no_style: bool,
+    escape_hatches: Vec<EscapeHatch>,
 }
 
 impl<M> Popover<M>
@@ -90,6 +91,7 @@ where
             content: None,
             mouse_button: MouseButton::Left,
             no_style: false,
+            escape_hatches: vec![],
         }
     }
 
@@ -142,6 +144,14 @@ where
         self
     }
 
+    /// Add an [`EscapeHatch`] region, the click inside it will not dismiss the popover.
+    ///
+    /// The trigger is always an escape-hatch, click it again to close the popover.
+    pub fn escape_hatch(mut self, hatch: &EscapeHatch) -> Self {
+        self.escape_hatches.push(hatch.clone());
+        self
+    }
+
     fn render_trigger(&mut self, open: bool, window: &mut Window, cx: &mut App) -> AnyElement {
         let Some(trigger) = self.trigger.take() else {
             return div().into_any_element();
@@ -274,6 +284,11 @@ impl<M: ManagedView> Element for Popover<M> {
                         let content_view_mut = element_state.content_view.clone();
                         let anchor = view.anchor;
                         let no_style = view.no_style;
+                        let mut layer =
+                            DismissableLayer::new().escape_hatches(&view.escape_hatches);
+                        if let Some(trigger_bounds) = element_state.trigger_bounds {
+                            layer = layer.escape_region(trigger_bounds);
+                        }
                         deferred(
                             anchored.child(
                                 div()
@@ -288,7 +303,7 @@ impl<M: ManagedView> Element for Popover<M> {
                                     })
                                     .child(content_view.clone())
                                     .when(!no_style, |this| {
-                                        this.on_mouse_down_out(move |_, window, _| {
+                                        layer.apply(this, move |_, window, _| {
                                             // Update the element_state.content_view to `None`,
                                             // so that the `paint`` method will not paint it.
                                             *content_view_mut.borrow_mut() = None;
@@ -385,8 +400,26 @@ impl<M: ManagedView> Element for Popover<M> {
                     element.paint(window, cx);
                 }
 
+                let hitbox_id = prepaint.hitbox.id;
+                let mouse_button = this.mouse_button;
+
                 if let Some(mut element) = request_layout.popover_element.take() {
                     element.paint(window, cx);
+
+                    // When mouse click down in the trigger bounds again, close the popover.
+                    let content_view = element_state.content_view.clone();
+                    window.on_mouse_event(move |event: &MouseDownEvent, phase, window, cx| {
+                        if phase == DispatchPhase::Bubble
+                            && event.button == mouse_button
+                            && hitbox_id.is_hovered(window)
+                        {
+                            cx.stop_propagation();
+                            window.prevent_default();
+
+                            *content_view.borrow_mut() = None;
+                            window.refresh();
+                        }
+                    });
                     return;
                 }
 
@@ -395,8 +428,6 @@ impl<M: ManagedView> Element for Popover<M> {
                     return;
                 };
                 let old_content_view = element_state.content_view.clone();
-                let hitbox_id = prepaint.hitbox.id;
-                let mouse_button = this.mouse_button;
                 window.on_mouse_event(move |event: &MouseDownEvent, phase, window, cx| {
                     if phase == DispatchPhase::Bubble
                         && event.button == mouse_button
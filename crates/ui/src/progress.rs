@@ -1,7 +1,7 @@
-use crate::ActiveTheme;
+use crate::{ActiveTheme, FillColor};
 use gpui::{
-    div, prelude::FluentBuilder, px, relative, App, IntoElement, ParentElement, RenderOnce, Styled,
-    Window,
+    div, prelude::FluentBuilder, px, relative, App, Axis, IntoElement, ParentElement, RenderOnce,
+    Styled, Window,
 };
 
 /// A Progress bar element.
@@ -9,6 +9,7 @@ use gpui::{
 pub struct Progress {
     value: f32,
     height: f32,
+    fill_color: Option<FillColor>,
 }
 
 impl Progress {
@@ -16,6 +17,7 @@ impl Progress {
         Progress {
             value: Default::default(),
             height: 8.,
+            fill_color: None,
         }
     }
 
@@ -23,17 +25,26 @@ impl Progress {
         self.value = value;
         self
     }
+
+    /// Set the fill color of the progress bar, default is the theme `progress_bar` color.
+    pub fn fill_color(mut self, fill_color: impl Into<FillColor>) -> Self {
+        self.fill_color = Some(fill_color.into());
+        self
+    }
 }
 
 impl RenderOnce for Progress {
     fn render(self, _: &mut Window, cx: &mut App) -> impl IntoElement {
         // Match the theme radius, if theme radius is zero use it.
         let radius = px(self.height / 2.).min(cx.theme().radius);
-        let relative_w = relative(match self.value {
+        let percentage = match self.value {
             v if v < 0. => 0.,
             v if v > 100. => 1.,
             v => v / 100.,
-        });
+        };
+        let fill_color = self
+            .fill_color
+            .unwrap_or(FillColor::Solid(cx.theme().progress_bar));
 
         div()
             .w_full()
@@ -42,13 +53,13 @@ impl RenderOnce for Progress {
             .rounded(radius)
             .bg(cx.theme().progress_bar.opacity(0.2))
             .child(
-                div()
+                fill_color
+                    .apply(div(), Axis::Horizontal, 0., percentage)
                     .absolute()
                     .top_0()
                     .left_0()
                     .h_full()
-                    .w(relative_w)
-                    .bg(cx.theme().progress_bar)
+                    .w(relative(percentage))
                     .map(|this| match self.value {
                         v if v >= 100. => this.rounded(radius),
                         _ => this.rounded_l(radius),
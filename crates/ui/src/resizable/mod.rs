@@ -36,6 +36,8 @@ pub struct ResizableState {
     sizes: Vec<Pixels>,
     pub(crate) resizing_panel_ix: Option<usize>,
     bounds: Bounds<Pixels>,
+    /// The sizes restored before the panels are rendered, applied on the panels count is known.
+    pending_sizes: Option<Vec<f32>>,
 }
 
 impl ResizableState {
@@ -46,6 +48,7 @@ impl ResizableState {
             sizes: vec![],
             resizing_panel_ix: None,
             bounds: Bounds::default(),
+            pending_sizes: None,
         })
     }
 
@@ -78,6 +81,41 @@ impl ResizableState {
                 .extend(vec![ResizablePanelState::default(); diff]);
             self.sizes.extend(vec![PANEL_MIN_SIZE; diff]);
         }
+
+        if let Some(sizes) = self.pending_sizes.take() {
+            self.apply_serialized(&sizes);
+        }
+    }
+
+    /// Get the sizes of the panels in pixels, to save the layout, see [`ResizableState::restore`].
+    pub fn serialized(&self) -> Vec<f32> {
+        self.sizes.iter().map(|size| size.0).collect()
+    }
+
+    /// Restore the sizes of the panels saved by [`ResizableState::serialized`].
+    ///
+    /// If the panels count is changed, the extra sizes are ignored,
+    /// and the missing ones are [`PANEL_MIN_SIZE`].
+    pub fn restore(&mut self, sizes: Vec<f32>, cx: &mut Context<Self>) {
+        if self.panels.is_empty() {
+            // Not rendered yet, apply it when the panels count is known.
+            self.pending_sizes = Some(sizes);
+        } else {
+            self.apply_serialized(&sizes);
+        }
+        cx.notify();
+    }
+
+    fn apply_serialized(&mut self, sizes: &[f32]) {
+        for (ix, panel) in self.panels.iter_mut().enumerate() {
+            let size = sizes
+                .get(ix)
+                .map(|size| px(*size))
+                .unwrap_or(PANEL_MIN_SIZE);
+            self.sizes[ix] = size;
+            panel.size = Some(size);
+            panel.restore_size = None;
+        }
     }
 
     pub(crate) fn update_panel_size(
@@ -85,13 +123,18 @@ impl ResizableState {
         panel_ix: usize,
         bounds: Bounds<Pixels>,
         size_range: Range<Pixels>,
+        collapsible: bool,
+        collapsed_size: Option<Pixels>,
         cx: &mut Context<Self>,
     ) {
         let size = bounds.size.along(self.axis);
         self.sizes[panel_ix] = size;
-        self.panels[panel_ix].size = Some(size);
-        self.panels[panel_ix].bounds = bounds;
-        self.panels[panel_ix].size_range = size_range;
+        let panel = &mut self.panels[panel_ix];
+        panel.size = Some(size);
+        panel.bounds = bounds;
+        panel.size_range = size_range;
+        panel.collapsible = collapsible;
+        panel.collapsed_size = collapsed_size;
         cx.notify();
     }
 
@@ -143,6 +186,11 @@ impl ResizableState {
             return PANEL_MIN_SIZE..Pixels::MAX;
         };
 
+        // The collapsed panel is allowed to keep the collapsed size.
+        if panel.restore_size.is_some() {
+            return panel.collapsed_size().min(panel.size_range.start)..panel.size_range.end;
+        }
+
         panel.size_range.clone()
     }
 
@@ -152,69 +200,86 @@ impl ResizableState {
         }
     }
 
-    /// The `ix`` is the index of the panel to resize,
+    /// The `ix`` is the index of the panel before the dragged handle,
     /// and the `size` is the new size for the panel.
     fn resize_panel(&mut self, ix: usize, size: Pixels, _: &mut Window, cx: &mut Context<Self>) {
-        let old_sizes = self.sizes.clone();
-
-        let mut ix = ix;
-        // Only resize the left panels.
-        if ix >= old_sizes.len() - 1 {
+        // The handle is between the panel `ix` and `ix + 1`.
+        if ix + 1 >= self.sizes.len() {
             return;
         }
-        let size = size.floor();
-        let container_size = self.bounds.size.along(self.axis);
         self.sync_real_panel_sizes(cx);
 
-        let move_changed = size - old_sizes[ix];
-        if move_changed == px(0.) {
+        let size_ranges = (0..self.sizes.len())
+            .map(|ix| self.panel_size_range(ix))
+            .collect::<Vec<_>>();
+        let container_size = self.bounds.size.along(self.axis);
+        let new_sizes =
+            redistribute_sizes(&self.sizes, &size_ranges, ix, size.floor(), container_size);
+        if new_sizes == self.sizes {
             return;
         }
 
-        let size_range = self.panel_size_range(ix);
-        let new_size = size.clamp(size_range.start, size_range.end);
-        let is_expand = move_changed > px(0.);
+        self.apply_sizes(new_sizes, cx);
+        // The panels are resized manually, so they are no longer collapsed.
+        self.panels[ix].restore_size = None;
+        self.panels[ix + 1].restore_size = None;
+    }
 
-        let main_ix = ix;
-        let mut new_sizes = old_sizes.clone();
+    /// Collapse a panel next to the handle between the panel `ix` and `ix + 1`,
+    /// or restore it if it was collapsed.
+    ///
+    /// The smaller one of the collapsible panels is collapsed.
+    pub(crate) fn toggle_collapse(&mut self, ix: usize, _: &mut Window, cx: &mut Context<Self>) {
+        if ix + 1 >= self.sizes.len() {
+            return;
+        }
+        self.sync_real_panel_sizes(cx);
 
-        if is_expand {
-            let mut changed = new_size - old_sizes[ix];
-            new_sizes[ix] = new_size;
+        let collapsed_ix = [ix, ix + 1]
+            .into_iter()
+            .find(|&i| self.panels[i].restore_size.is_some());
+        let (panel_ix, target_size) = if let Some(panel_ix) = collapsed_ix {
+            let restore_size = self.panels[panel_ix].restore_size.unwrap_or_default();
+            (panel_ix, restore_size)
+        } else {
+            let Some(panel_ix) = [ix, ix + 1]
+                .into_iter()
+                .filter(|&i| self.panels[i].collapsible)
+                .min_by_key(|&i| self.sizes[i])
+            else {
+                return;
+            };
+            (panel_ix, self.panels[panel_ix].collapsed_size())
+        };
 
-            while changed > px(0.) && ix < old_sizes.len() - 1 {
-                ix += 1;
-                let size_range = self.panel_size_range(ix);
-                let available_size = (new_sizes[ix] - size_range.start).max(px(0.));
-                let to_reduce = changed.min(available_size);
-                new_sizes[ix] -= to_reduce;
-                changed -= to_reduce;
-            }
+        let old_size = self.sizes[panel_ix];
+        let mut size_ranges = (0..self.sizes.len())
+            .map(|ix| self.panel_size_range(ix))
+            .collect::<Vec<_>>();
+        size_ranges[panel_ix].start = size_ranges[panel_ix].start.min(target_size);
+
+        // The size of the panel `ix` to move the handle to.
+        let size = if panel_ix == ix {
+            target_size
         } else {
-            let mut changed = new_size - size;
-            new_sizes[ix + 1] += old_sizes[ix] - new_size;
-            new_sizes[ix] = new_size;
-
-            while changed > px(0.) && ix > 0 {
-                ix -= 1;
-                let size_range = self.panel_size_range(ix);
-                let available_size = (new_sizes[ix] - size_range.start).max(px(0.));
-                let to_reduce = changed.min(available_size);
-                changed -= to_reduce;
-                new_sizes[ix] -= to_reduce;
-            }
-        }
+            self.sizes[ix] - (target_size - old_size)
+        };
+        let container_size = self.bounds.size.along(self.axis);
+        let new_sizes =
+            redistribute_sizes(&self.sizes, &size_ranges, ix, size.floor(), container_size);
 
-        // If total size exceeds container size, adjust the main panel
-        let total_size: Pixels = new_sizes.iter().map(|s| s.0).sum::<f32>().into();
-        if total_size > container_size {
-            let overflow = total_size - container_size;
-            new_sizes[main_ix] = (new_sizes[main_ix] - overflow).max(size_range.start);
-        }
+        self.panels[panel_ix].restore_size = if collapsed_ix.is_some() {
+            None
+        } else {
+            Some(old_size)
+        };
+        self.apply_sizes(new_sizes, cx);
+        cx.emit(ResizablePanelEvent::Resized);
+    }
 
-        for (i, _) in old_sizes.iter().enumerate() {
-            let size = new_sizes[i];
-            self.panels[i].size = Some(size);
+    fn apply_sizes(&mut self, new_sizes: Vec<Pixels>, cx: &mut Context<Self>) {
+        for (i, size) in new_sizes.iter().enumerate() {
+            self.panels[i].size = Some(*size);
         }
 
         self.sizes = new_sizes;
@@ -222,11 +287,205 @@ impl ResizableState {
     }
 }
 
+/// Move the handle between the panel `ix` and `ix + 1` to make the panel `ix` to be `size`.
+///
+/// The panel on the growing side of the handle grows up to its max size, and the panels on the
+/// shrinking side shrink to their min size one by one from the nearest, so the total size is kept.
+///
+/// If the total size exceeds the `container_size`, the growing panel gives back the overflow.
+fn redistribute_sizes(
+    sizes: &[Pixels],
+    size_ranges: &[Range<Pixels>],
+    ix: usize,
+    size: Pixels,
+    container_size: Pixels,
+) -> Vec<Pixels> {
+    let mut new_sizes = sizes.to_vec();
+    if ix + 1 >= sizes.len() {
+        return new_sizes;
+    }
+
+    let delta = size - sizes[ix];
+    if delta == px(0.) {
+        return new_sizes;
+    }
+
+    // The growing panel is next to the handle, and the shrinking panels are in the order from the handle.
+    let (grow_ix, shrink_ixs): (usize, Vec<usize>) = if delta > px(0.) {
+        (ix, (ix + 1..sizes.len()).collect())
+    } else {
+        (ix + 1, (0..=ix).rev().collect())
+    };
+
+    let growable = (size_ranges[grow_ix].end - sizes[grow_ix]).max(px(0.));
+    let shrinkable = shrink_ixs
+        .iter()
+        .map(|&i| (sizes[i] - size_ranges[i].start).max(px(0.)))
+        .fold(px(0.), |acc, s| acc + s);
+    let amount = delta.abs().min(growable).min(shrinkable);
+
+    new_sizes[grow_ix] += amount;
+    let mut remaining = amount;
+    for i in shrink_ixs {
+        if remaining <= px(0.) {
+            break;
+        }
+
+        let to_reduce = remaining.min((new_sizes[i] - size_ranges[i].start).max(px(0.)));
+        new_sizes[i] -= to_reduce;
+        remaining -= to_reduce;
+    }
+
+    let total_size = new_sizes.iter().fold(px(0.), |acc, s| acc + *s);
+    if container_size > px(0.) && total_size > container_size {
+        let overflow = total_size - container_size;
+        new_sizes[grow_ix] = (new_sizes[grow_ix] - overflow).max(size_ranges[grow_ix].start);
+    }
+
+    new_sizes
+}
+
 impl EventEmitter<ResizablePanelEvent> for ResizableState {}
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone)]
 pub(crate) struct ResizablePanelState {
     pub size: Option<Pixels>,
     pub size_range: Range<Pixels>,
     bounds: Bounds<Pixels>,
+    /// Whether the panel can be collapsed by double clicking the resize handle.
+    collapsible: bool,
+    /// The size of the panel when collapsed, default is the start of the `size_range`.
+    collapsed_size: Option<Pixels>,
+    /// The size before collapsed, to restore the panel, `None` if not collapsed.
+    pub restore_size: Option<Pixels>,
+}
+
+impl Default for ResizablePanelState {
+    fn default() -> Self {
+        Self {
+            size: None,
+            size_range: Range::default(),
+            bounds: Bounds::default(),
+            collapsible: true,
+            collapsed_size: None,
+            restore_size: None,
+        }
+    }
+}
+
+impl ResizablePanelState {
+    pub(crate) fn collapsed_size(&self) -> Pixels {
+        self.collapsed_size.unwrap_or(self.size_range.start)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sizes(sizes: &[f32]) -> Vec<Pixels> {
+        sizes.iter().map(|s| px(*s)).collect()
+    }
+
+    fn total(sizes: &[Pixels]) -> Pixels {
+        sizes.iter().fold(px(0.), |acc, s| acc + *s)
+    }
+
+    fn new_state(panels_count: usize) -> ResizableState {
+        let mut state = ResizableState {
+            axis: Axis::Horizontal,
+            panels: vec![],
+            sizes: vec![],
+            resizing_panel_ix: None,
+            bounds: Bounds::default(),
+            pending_sizes: None,
+        };
+        state.sync_panels_count(Axis::Horizontal, panels_count);
+        state
+    }
+
+    #[test]
+    fn test_restore_with_changed_panels_count() {
+        // The same panels count.
+        let mut state = new_state(3);
+        state.apply_serialized(&[200., 300., 400.]);
+        assert_eq!(state.sizes, sizes(&[200., 300., 400.]));
+        assert_eq!(state.serialized(), vec![200., 300., 400.]);
+        assert_eq!(state.panels[1].size, Some(px(300.)));
+
+        // The extra sizes are ignored.
+        let mut state = new_state(2);
+        state.apply_serialized(&[200., 300., 400.]);
+        assert_eq!(state.sizes, sizes(&[200., 300.]));
+
+        // The missing sizes are the min size.
+        let mut state = new_state(4);
+        state.apply_serialized(&[200., 300.]);
+        assert_eq!(state.sizes, sizes(&[200., 300., 100., 100.]));
+        assert_eq!(state.panels[3].size, Some(PANEL_MIN_SIZE));
+    }
+
+    #[test]
+    fn test_restore_before_rendered() {
+        let mut state = new_state(0);
+        state.pending_sizes = Some(vec![200., 300.]);
+
+        // Applied when the panels count is known.
+        state.sync_panels_count(Axis::Vertical, 3);
+        assert_eq!(state.pending_sizes, None);
+        assert_eq!(state.sizes, sizes(&[200., 300., 100.]));
+    }
+
+    #[test]
+    fn test_redistribute_sizes_final_handle() {
+        let old_sizes = sizes(&[200., 300., 300.]);
+        let ranges = vec![px(100.)..Pixels::MAX; 3];
+        let container = px(800.);
+
+        // Drag the final handle to the right.
+        let new_sizes = redistribute_sizes(&old_sizes, &ranges, 1, px(400.), container);
+        assert_eq!(new_sizes, sizes(&[200., 400., 200.]));
+        assert_eq!(total(&new_sizes), container);
+
+        // Drag the final handle to the right over the min size of the last panel.
+        let new_sizes = redistribute_sizes(&old_sizes, &ranges, 1, px(600.), container);
+        assert_eq!(new_sizes, sizes(&[200., 500., 100.]));
+        assert_eq!(total(&new_sizes), container);
+
+        // Drag the final handle to the left, pushing the first panel.
+        let new_sizes = redistribute_sizes(&old_sizes, &ranges, 1, px(50.), container);
+        assert_eq!(new_sizes, sizes(&[150., 100., 550.]));
+        assert_eq!(total(&new_sizes), container);
+
+        // Drag the final handle to the left as far as possible.
+        let new_sizes = redistribute_sizes(&old_sizes, &ranges, 1, px(0.), container);
+        assert_eq!(new_sizes, sizes(&[100., 100., 600.]));
+        assert_eq!(total(&new_sizes), container);
+    }
+
+    #[test]
+    fn test_redistribute_sizes_with_size_range() {
+        let old_sizes = sizes(&[200., 300., 300.]);
+        let ranges = vec![
+            px(100.)..Pixels::MAX,
+            px(100.)..px(350.),
+            px(100.)..px(400.),
+        ];
+        let container = px(800.);
+
+        // The middle panel can not grow over its max size.
+        let new_sizes = redistribute_sizes(&old_sizes, &ranges, 1, px(500.), container);
+        assert_eq!(new_sizes, sizes(&[200., 350., 250.]));
+        assert_eq!(total(&new_sizes), container);
+
+        // The last panel can not grow over its max size.
+        let new_sizes = redistribute_sizes(&old_sizes, &ranges, 1, px(100.), container);
+        assert_eq!(new_sizes, sizes(&[200., 200., 400.]));
+        assert_eq!(total(&new_sizes), container);
+
+        // The first handle pushes the panels after it.
+        let new_sizes = redistribute_sizes(&old_sizes, &ranges, 0, px(500.), container);
+        assert_eq!(new_sizes, sizes(&[500., 100., 200.]));
+        assert_eq!(total(&new_sizes), container);
+    }
 }
@@ -147,6 +147,8 @@ pub struct ResizablePanel {
     size_range: Range<Pixels>,
     children: Vec<AnyElement>,
     visible: bool,
+    collapsible: bool,
+    collapsed_size: Option<Pixels>,
 }
 
 impl ResizablePanel {
@@ -159,6 +161,8 @@ impl ResizablePanel {
             axis: Axis::Horizontal,
             children: vec![],
             visible: true,
+            collapsible: true,
+            collapsed_size: None,
         }
     }
 
@@ -185,6 +189,20 @@ impl ResizablePanel {
         self.size_range = range.into();
         self
     }
+
+    /// Set whether the panel can be collapsed by double clicking the resize handle, default: true
+    ///
+    /// Double click the handle again to restore the panel to the size before collapsed.
+    pub fn collapsible(mut self, collapsible: bool) -> Self {
+        self.collapsible = collapsible;
+        self
+    }
+
+    /// Set the size of the panel when collapsed, default is the start of the `size_range`.
+    pub fn collapsed_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.collapsed_size = Some(size.into());
+        self
+    }
 }
 
 impl RenderOnce for ResizablePanel {
@@ -202,6 +220,12 @@ impl RenderOnce for ResizablePanel {
             .get(self.panel_ix)
             .expect("BUG: The `index` of ResizablePanel should be one of in `state`.");
         let size_range = self.size_range.clone();
+        // The collapsed panel can be smaller than the min size.
+        let min_size = if panel_state.restore_size.is_some() {
+            panel_state.collapsed_size().min(size_range.start)
+        } else {
+            size_range.start
+        };
 
         div()
             .id(("resizable-panel", self.panel_ix))
@@ -210,10 +234,10 @@ impl RenderOnce for ResizablePanel {
             .size_full()
             .relative()
             .when(self.axis.is_vertical(), |this| {
-                this.min_h(size_range.start).max_h(size_range.end)
+                this.min_h(min_size).max_h(size_range.end)
             })
             .when(self.axis.is_horizontal(), |this| {
-                this.min_w(size_range.start).max_w(size_range.end)
+                this.min_w(min_size).max_w(size_range.end)
             })
             // 1. initial_size is None, to use auto size.
             // 2. initial_size is Some and size is none, to use the initial size of the panel for first time render.
@@ -238,7 +262,14 @@ impl RenderOnce for ResizablePanel {
                         let state = state.clone();
                         move |bounds, _, cx| {
                             state.update(cx, |state, cx| {
-                                state.update_panel_size(self.panel_ix, bounds, self.size_range, cx)
+                                state.update_panel_size(
+                                    self.panel_ix,
+                                    bounds,
+                                    self.size_range,
+                                    self.collapsible,
+                                    self.collapsed_size,
+                                    cx,
+                                )
                             })
                         }
                     },
@@ -250,17 +281,25 @@ impl RenderOnce for ResizablePanel {
             .children(self.children)
             .when(self.panel_ix > 0, |this| {
                 let ix = self.panel_ix - 1;
-                this.child(resize_handle(("resizable-handle", ix), self.axis).on_drag(
-                    DragPanel((ix, self.axis)),
-                    move |drag_panel, _, _, cx| {
-                        cx.stop_propagation();
-                        // Set current resizing panel ix
-                        state.update(cx, |state, _| {
-                            state.resizing_panel_ix = Some(ix);
-                        });
-                        cx.new(|_| drag_panel.deref().clone())
-                    },
-                ))
+                this.child(
+                    resize_handle(("resizable-handle", ix), self.axis)
+                        .on_double_click({
+                            let state = state.clone();
+                            move |_, window, cx| {
+                                state.update(cx, |state, cx| {
+                                    state.toggle_collapse(ix, window, cx);
+                                })
+                            }
+                        })
+                        .on_drag(DragPanel((ix, self.axis)), move |drag_panel, _, _, cx| {
+                            cx.stop_propagation();
+                            // Set current resizing panel ix
+                            state.update(cx, |state, _| {
+                                state.resizing_panel_ix = Some(ix);
+                            });
+                            cx.new(|_| drag_panel.deref().clone())
+                        }),
+                )
             })
     }
 }
@@ -2,13 +2,13 @@ use crate::{
     drawer::Drawer,
     input::InputState,
     modal::Modal,
-    notification::{Notification, NotificationList},
+    notification::{Notification, NotificationLayout, NotificationList},
     window_border, ActiveTheme, Placement,
 };
 use gpui::{
-    canvas, div, prelude::FluentBuilder as _, AnyView, App, AppContext, Context, DefiniteLength,
-    Entity, FocusHandle, InteractiveElement, IntoElement, ParentElement as _, Render, Styled,
-    Window,
+    canvas, div, prelude::FluentBuilder as _, AnyView, App, AppContext, Bounds, Context, Corner,
+    DefiniteLength, Entity, FocusHandle, InteractiveElement, IntoElement, ParentElement as _,
+    Pixels, Point, Render, Size, Styled, WeakFocusHandle, Window,
 };
 use std::{any::TypeId, rc::Rc};
 
@@ -31,10 +31,21 @@ pub trait ContextModal: Sized {
     fn close_drawer(&mut self, cx: &mut App);
 
     /// Opens a Modal.
+    ///
+    /// If there is an active Modal, the new one is stacked on top of it, see [`ContextModal::push_modal`].
     fn open_modal<F>(&mut self, cx: &mut App, build: F)
     where
         F: Fn(Modal, &mut Window, &mut App) -> Modal + 'static;
 
+    /// Pushes a Modal on top of the current one, e.g.: a confirm within a dialog.
+    ///
+    /// Each Modal has its own overlay that dims the Modals below a bit more,
+    /// the focus is trapped in the topmost Modal, and `escape` closes only the topmost one,
+    /// then the focus is returned to the Modal below.
+    fn push_modal<F>(&mut self, cx: &mut App, build: F)
+    where
+        F: Fn(Modal, &mut Window, &mut App) -> Modal + 'static;
+
     /// Return true, if there is an active Modal.
     fn has_active_modal(&mut self, cx: &mut App) -> bool;
 
@@ -56,6 +67,12 @@ pub trait ContextModal: Sized {
     /// Returns number of notifications.
     fn notifications(&mut self, cx: &mut App) -> Rc<Vec<Entity<Notification>>>;
 
+    /// Sets the corner and the max visible count of the notifications in the window.
+    fn set_notification_layout(&mut self, layout: NotificationLayout, cx: &mut App);
+
+    /// Returns the layout of the notifications.
+    fn notification_layout(&mut self, cx: &mut App) -> NotificationLayout;
+
     /// Return current focused Input entity.
     fn focused_input(&mut self, cx: &mut App) -> Option<Entity<InputState>>;
     /// Returns true if there is a focused Input entity.
@@ -75,15 +92,18 @@ impl ContextModal for Window {
         F: Fn(Drawer, &mut Window, &mut App) -> Drawer + 'static,
     {
         Root::update(self, cx, move |root, window, cx| {
-            if root.active_drawer.is_none() {
-                root.previous_focus_handle = window.focused(cx);
-            }
+            // Keep the focus handle of the first opened drawer, to restore focus when closed.
+            let previous_focus_handle = match root.active_drawer.take() {
+                Some(drawer) => drawer.previous_focus_handle,
+                None => window.focused(cx).map(|handle| handle.downgrade()),
+            };
 
             let focus_handle = cx.focus_handle();
             focus_handle.focus(window);
 
             root.active_drawer = Some(ActiveDrawer {
                 focus_handle,
+                previous_focus_handle,
                 placement,
                 builder: Rc::new(build),
             });
@@ -98,8 +118,9 @@ impl ContextModal for Window {
     fn close_drawer(&mut self, cx: &mut App) {
         Root::update(self, cx, |root, window, cx| {
             root.focused_input = None;
-            root.active_drawer = None;
-            root.focus_back(window, cx);
+            if let Some(drawer) = root.active_drawer.take() {
+                root.focus_back(drawer.previous_focus_handle, window, cx);
+            }
             cx.notify();
         })
     }
@@ -109,23 +130,34 @@ impl ContextModal for Window {
         F: Fn(Modal, &mut Window, &mut App) -> Modal + 'static,
     {
         Root::update(self, cx, move |root, window, cx| {
-            // Only save focus handle if there are no active modals.
-            // This is used to restore focus when all modals are closed.
-            if root.active_modals.len() == 0 {
-                root.previous_focus_handle = window.focused(cx);
-            }
+            // Save the focus handle of the element that opened the modal,
+            // this is used to restore focus when the modal is closed.
+            let previous_focus_handle = window.focused(cx).map(|handle| handle.downgrade());
 
             let focus_handle = cx.focus_handle();
             focus_handle.focus(window);
 
             root.active_modals.push(ActiveModal {
                 focus_handle,
+                previous_focus_handle,
+                shake_count: 0,
+                position: None,
+                size: None,
+                bounds: Bounds::default(),
+                drag_start: None,
                 builder: Rc::new(build),
             });
             cx.notify();
         })
     }
 
+    fn push_modal<F>(&mut self, cx: &mut App, build: F)
+    where
+        F: Fn(Modal, &mut Window, &mut App) -> Modal + 'static,
+    {
+        self.open_modal(cx, build)
+    }
+
     fn has_active_modal(&mut self, cx: &mut App) -> bool {
         Root::read(self, cx).active_modals.len() > 0
     }
@@ -133,14 +165,8 @@ impl ContextModal for Window {
     fn close_modal(&mut self, cx: &mut App) {
         Root::update(self, cx, move |root, window, cx| {
             root.focused_input = None;
-            root.active_modals.pop();
-
-            if let Some(top_modal) = root.active_modals.last() {
-                // Focus the next modal.
-                top_modal.focus_handle.focus(window);
-            } else {
-                // Restore focus if there are no more modals.
-                root.focus_back(window, cx);
+            if let Some(modal) = root.active_modals.pop() {
+                root.focus_back(modal.previous_focus_handle, window, cx);
             }
             cx.notify();
         })
@@ -149,8 +175,13 @@ impl ContextModal for Window {
     fn close_all_modals(&mut self, cx: &mut App) {
         Root::update(self, cx, |root, window, cx| {
             root.focused_input = None;
+            // The first modal keeps the focus handle before any modal was opened.
+            let previous_focus_handle = root
+                .active_modals
+                .first()
+                .and_then(|modal| modal.previous_focus_handle.clone());
             root.active_modals.clear();
-            root.focus_back(window, cx);
+            root.focus_back(previous_focus_handle, window, cx);
             cx.notify();
         })
     }
@@ -187,6 +218,19 @@ impl ContextModal for Window {
         Rc::new(entity.read(cx).notifications())
     }
 
+    fn set_notification_layout(&mut self, layout: NotificationLayout, cx: &mut App) {
+        Root::update(self, cx, move |root, window, cx| {
+            root.notification
+                .update(cx, |view, cx| view.set_layout(layout, window, cx));
+            cx.notify();
+        })
+    }
+
+    fn notification_layout(&mut self, cx: &mut App) -> NotificationLayout {
+        let entity = Root::read(self, cx).notification.clone();
+        entity.read(cx).layout()
+    }
+
     fn has_focused_input(&mut self, cx: &mut App) -> bool {
         Root::read(self, cx).focused_input.is_some()
     }
@@ -200,9 +244,9 @@ impl ContextModal for Window {
 ///
 /// It is used to manage the Drawer, Modal, and Notification.
 pub struct Root {
-    /// Used to store the focus handle of the previous view.
-    /// When the Modal, Drawer closes, we will focus back to the previous view.
-    previous_focus_handle: Option<FocusHandle>,
+    /// The focus handle to focus when the Modal, Drawer closes and the
+    /// element that opened it no longer exists.
+    fallback_focus_handle: Option<FocusHandle>,
     active_drawer: Option<ActiveDrawer>,
     pub(crate) active_modals: Vec<ActiveModal>,
     pub(super) focused_input: Option<Entity<InputState>>,
@@ -214,6 +258,8 @@ pub struct Root {
 #[derive(Clone)]
 struct ActiveDrawer {
     focus_handle: FocusHandle,
+    /// The focus handle of the element that opened the drawer.
+    previous_focus_handle: Option<WeakFocusHandle>,
     placement: Placement,
     builder: Rc<dyn Fn(Drawer, &mut Window, &mut App) -> Drawer + 'static>,
 }
@@ -221,13 +267,24 @@ struct ActiveDrawer {
 #[derive(Clone)]
 pub(crate) struct ActiveModal {
     focus_handle: FocusHandle,
+    /// The focus handle of the element that opened the modal.
+    previous_focus_handle: Option<WeakFocusHandle>,
+    /// The times of the blocked dismiss attempts, used to restart the shake animation.
+    pub(crate) shake_count: usize,
+    /// The position and size of the dragged or resized modal, retained while it is open.
+    pub(crate) position: Option<Point<Pixels>>,
+    pub(crate) size: Option<Size<Pixels>>,
+    /// The last rendered bounds of the modal, in the modal layer.
+    pub(crate) bounds: Bounds<Pixels>,
+    /// The mouse position and the modal bounds at the start of the drag.
+    pub(crate) drag_start: Option<(Point<Pixels>, Bounds<Pixels>)>,
     builder: Rc<dyn Fn(Modal, &mut Window, &mut App) -> Modal + 'static>,
 }
 
 impl Root {
     pub fn new(view: AnyView, window: &mut Window, cx: &mut Context<Self>) -> Self {
         Self {
-            previous_focus_handle: None,
+            fallback_focus_handle: None,
             active_drawer: None,
             active_modals: Vec::new(),
             focused_input: None,
@@ -257,8 +314,37 @@ impl Root {
             .read(cx)
     }
 
-    fn focus_back(&mut self, window: &mut Window, _: &mut App) {
-        if let Some(handle) = self.previous_focus_handle.clone() {
+    /// Set the focus handle to focus when a Modal or Drawer closes, but the
+    /// element that opened it no longer exists, default is `None`.
+    pub fn set_fallback_focus_handle(&mut self, focus_handle: Option<FocusHandle>) {
+        self.fallback_focus_handle = focus_handle;
+    }
+
+    /// Restore the focus after a Modal or Drawer closed.
+    ///
+    /// If the `previous_focus_handle` is released, fall back to the top most
+    /// Modal, then the Drawer, and the [`Root::set_fallback_focus_handle`].
+    fn focus_back(
+        &mut self,
+        previous_focus_handle: Option<WeakFocusHandle>,
+        window: &mut Window,
+        _: &mut App,
+    ) {
+        let handle = previous_focus_handle
+            .and_then(|handle| handle.upgrade())
+            .or_else(|| {
+                self.active_modals
+                    .last()
+                    .map(|modal| modal.focus_handle.clone())
+            })
+            .or_else(|| {
+                self.active_drawer
+                    .as_ref()
+                    .map(|drawer| drawer.focus_handle.clone())
+            })
+            .or_else(|| self.fallback_focus_handle.clone());
+
+        if let Some(handle) = handle {
             window.focus(&handle);
         }
     }
@@ -271,20 +357,51 @@ impl Root {
         let root = window.root::<Root>()??;
 
         let active_drawer_placement = root.read(cx).active_drawer.clone().map(|d| d.placement);
-
-        let (mt, mr) = match active_drawer_placement {
-            Some(Placement::Right) => (None, root.read(cx).drawer_size),
-            Some(Placement::Top) => (root.read(cx).drawer_size, None),
+        let drawer_size = root.read(cx).drawer_size;
+        let corner = root.read(cx).notification.read(cx).layout().corner;
+        let is_top = matches!(corner, Corner::TopLeft | Corner::TopRight);
+        let is_left = matches!(corner, Corner::TopLeft | Corner::BottomLeft);
+
+        // Offset the notifications by the drawer at the same side.
+        let (y_offset, x_offset) = match active_drawer_placement {
+            Some(Placement::Top) if is_top => (drawer_size, None),
+            Some(Placement::Bottom) if !is_top => (drawer_size, None),
+            Some(Placement::Left) if is_left => (None, drawer_size),
+            Some(Placement::Right) if !is_left => (None, drawer_size),
             _ => (None, None),
         };
 
         Some(
             div()
                 .absolute()
-                .top_0()
-                .right_0()
-                .when_some(mt, |this, offset| this.mt(offset))
-                .when_some(mr, |this, offset| this.mr(offset))
+                .map(|this| {
+                    if is_top {
+                        this.top_0()
+                    } else {
+                        this.bottom_0()
+                    }
+                })
+                .map(|this| {
+                    if is_left {
+                        this.left_0()
+                    } else {
+                        this.right_0()
+                    }
+                })
+                .when_some(y_offset, |this, offset| {
+                    if is_top {
+                        this.mt(offset)
+                    } else {
+                        this.mb(offset)
+                    }
+                })
+                .when_some(x_offset, |this, offset| {
+                    if is_left {
+                        this.ml(offset)
+                    } else {
+                        this.mr(offset)
+                    }
+                })
                 .child(root.read(cx).notification.clone()),
         )
     }
@@ -326,9 +443,9 @@ impl Root {
             return None;
         }
 
-        let mut show_overlay_ix = None;
+        let mut overlay_depth = 0;
 
-        let mut modals = active_modals
+        let modals = active_modals
             .iter()
             .enumerate()
             .map(|(i, active_modal)| {
@@ -341,23 +458,22 @@ impl Root {
                 //
                 // So we keep the focus handle in the `active_modal`, this is owned by the `Root`.
                 modal.focus_handle = active_modal.focus_handle.clone();
+                modal.shake_count = active_modal.shake_count;
+                modal.position = active_modal.position;
+                modal.size = active_modal.size;
 
                 modal.layer_ix = i;
-                // Find the modal which one needs to show overlay.
+                // Each modal has its own overlay, the stacked ones dim a bit more.
                 if modal.has_overlay() {
-                    show_overlay_ix = Some(i);
+                    modal.overlay_visible = true;
+                    modal.overlay_depth = overlay_depth;
+                    overlay_depth += 1;
                 }
 
                 modal
             })
             .collect::<Vec<_>>();
 
-        if let Some(ix) = show_overlay_ix {
-            if let Some(modal) = modals.get_mut(ix) {
-                modal.overlay_visible = true;
-            }
-        }
-
         Some(div().children(modals))
     }
 
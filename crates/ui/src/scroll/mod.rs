@@ -1,7 +1,11 @@
+mod scroll_animation;
+mod scroll_to_top;
 mod scrollable;
 mod scrollable_mask;
 mod scrollbar;
 
+pub use scroll_animation::*;
+pub use scroll_to_top::*;
 pub use scrollable::*;
 pub use scrollable_mask::*;
 pub use scrollbar::*;
@@ -0,0 +1,71 @@
+use std::time::{Duration, Instant};
+
+use gpui::{App, Pixels, Point, ScrollHandle, Window};
+
+/// The default duration of the scroll animation.
+pub(crate) const SCROLL_ANIMATION_DURATION: Duration = Duration::from_millis(250);
+const FRAME_DURATION: Duration = Duration::from_millis(16);
+
+/// Scroll the `scroll_handle` to the `target` offset with an animation.
+///
+/// The `easing` is a function like [`gpui::ease_in_out`] or [`crate::animation::cubic_bezier`].
+///
+/// The animation is cancelled if the offset is changed by others during the animation,
+/// e.g.: the user scrolls manually, or another animation is started on the same handle.
+///
+/// ```ignore
+/// animate_scroll_to(
+///     &scroll_handle,
+///     point(px(0.), px(0.)),
+///     Duration::from_millis(300),
+///     gpui::ease_in_out,
+///     window,
+///     cx,
+/// );
+/// ```
+pub fn animate_scroll_to(
+    scroll_handle: &ScrollHandle,
+    target: Point<Pixels>,
+    duration: Duration,
+    easing: impl Fn(f32) -> f32 + 'static,
+    window: &mut Window,
+    cx: &mut App,
+) {
+    let scroll_handle = scroll_handle.clone();
+    let start = scroll_handle.offset();
+    if start == target {
+        return;
+    }
+
+    window
+        .spawn(cx, async move |cx| {
+            let started_at = Instant::now();
+            let mut last_offset = None;
+
+            loop {
+                cx.background_executor().timer(FRAME_DURATION).await;
+
+                // Stop if the offset was changed since the last frame.
+                if let Some(last_offset) = last_offset {
+                    if scroll_handle.offset() != last_offset {
+                        break;
+                    }
+                }
+
+                let progress = if duration.is_zero() {
+                    1.
+                } else {
+                    (started_at.elapsed().as_secs_f32() / duration.as_secs_f32()).min(1.)
+                };
+                let delta = easing(progress);
+                let offset = start + (target - start) * delta;
+                scroll_handle.set_offset(offset);
+                last_offset = Some(offset);
+
+                if cx.update(|window, _| window.refresh()).is_err() || progress >= 1. {
+                    break;
+                }
+            }
+        })
+        .detach();
+}
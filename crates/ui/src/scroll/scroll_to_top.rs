@@ -0,0 +1,96 @@
+use gpui::{
+    div, ease_in_out, point, prelude::FluentBuilder as _, px, App, ElementId, IntoElement,
+    ParentElement as _, Pixels, RenderOnce, ScrollHandle, Styled as _, Window,
+};
+
+use crate::{
+    button::{Button, ButtonRounded, ButtonVariants as _},
+    ActiveTheme as _, IconName, Sizable as _,
+};
+
+use super::{animate_scroll_to, scrollbar::WIDTH, ScrollbarShow, SCROLL_ANIMATION_DURATION};
+
+const DEFAULT_THRESHOLD: Pixels = px(200.);
+const MARGIN: Pixels = px(16.);
+
+/// A floating button to scroll back to the top of a scroll area.
+///
+/// The button is shown after the `scroll_handle` is scrolled down past the threshold,
+/// and placed at the bottom right corner of the parent, so the parent must be `relative`.
+///
+/// ```ignore
+/// div()
+///     .relative()
+///     .size_full()
+///     .child(
+///         div()
+///             .id("content")
+///             .size_full()
+///             .overflow_y_scroll()
+///             .track_scroll(&scroll_handle)
+///             .children(items),
+///     )
+///     .child(ScrollToTopButton::new("to-top", &scroll_handle))
+/// ```
+#[derive(IntoElement)]
+pub struct ScrollToTopButton {
+    id: ElementId,
+    scroll_handle: ScrollHandle,
+    threshold: Pixels,
+}
+
+impl ScrollToTopButton {
+    /// Create a new button bound to the `scroll_handle`.
+    pub fn new(id: impl Into<ElementId>, scroll_handle: &ScrollHandle) -> Self {
+        Self {
+            id: id.into(),
+            scroll_handle: scroll_handle.clone(),
+            threshold: DEFAULT_THRESHOLD,
+        }
+    }
+
+    /// Set the scrolled distance to show the button, default is 200px.
+    pub fn threshold(mut self, threshold: impl Into<Pixels>) -> Self {
+        self.threshold = threshold.into();
+        self
+    }
+}
+
+impl RenderOnce for ScrollToTopButton {
+    fn render(self, _: &mut Window, cx: &mut App) -> impl IntoElement {
+        let visible = -self.scroll_handle.offset().y > self.threshold;
+        // Keep the button away from the wider scrollbar of the `Hover` and `Always` modes.
+        let right = match cx.theme().scrollbar_show {
+            ScrollbarShow::Scrolling => MARGIN,
+            ScrollbarShow::Hover | ScrollbarShow::Always => MARGIN + WIDTH,
+        };
+        let scroll_handle = self.scroll_handle;
+
+        div()
+            .absolute()
+            .bottom(MARGIN)
+            .right(right)
+            .when(visible, |this| {
+                this.child(
+                    Button::new(self.id)
+                        .icon(IconName::ArrowUp)
+                        .outline()
+                        .small()
+                        .rounded(ButtonRounded::Size(px(999.)))
+                        .shadow_md()
+                        .on_click(move |_, window, cx| {
+                            cx.stop_propagation();
+                            let offset = scroll_handle.offset();
+                            animate_scroll_to(
+                                &scroll_handle,
+                                point(offset.x, px(0.)),
+                                SCROLL_ANIMATION_DURATION,
+                                ease_in_out,
+                                window,
+                                cx,
+                            );
+                        }),
+                )
+            })
+    }
+}
@@ -1,7 +1,11 @@
-use crate::{v_flex, ActiveTheme, Collapsible};
+use std::time::Duration;
+
+use super::{register_focus_handle, CollapseItem, ExpandItem};
+use crate::{actions::Confirm, h_flex, v_flex, ActiveTheme, Collapsible, Icon, IconName};
 use gpui::{
-    div, prelude::FluentBuilder as _, App, Div, IntoElement, ParentElement, RenderOnce,
-    SharedString, Styled as _, Window,
+    div, percentage, prelude::FluentBuilder as _, Animation, AnimationExt as _, App, Div,
+    InteractiveElement as _, IntoElement, ParentElement, RenderOnce, SharedString,
+    StatefulInteractiveElement as _, Styled as _, Window,
 };
 
 /// A sidebar group
@@ -10,6 +14,8 @@ pub struct SidebarGroup<E: Collapsible + IntoElement + 'static> {
     base: Div,
     label: SharedString,
     collapsed: bool,
+    collapsible: bool,
+    default_open: bool,
     children: Vec<E>,
 }
 
@@ -19,10 +25,26 @@ impl<E: Collapsible + IntoElement> SidebarGroup<E> {
             base: div().gap_2().flex_col(),
             label: label.into(),
             collapsed: false,
+            collapsible: false,
+            default_open: true,
             children: Vec::new(),
         }
     }
 
+    /// Set the group to be collapsible by clicking the label, default is false.
+    ///
+    /// The open state is kept by the label of the group.
+    pub fn collapsible(mut self, collapsible: bool) -> Self {
+        self.collapsible = collapsible;
+        self
+    }
+
+    /// Set the initial open state of the collapsible group, default is true.
+    pub fn default_open(mut self, open: bool) -> Self {
+        self.default_open = open;
+        self
+    }
+
     pub fn child(mut self, child: E) -> Self {
         self.children.push(child);
         self
@@ -44,28 +66,111 @@ impl<E: Collapsible + IntoElement> Collapsible for SidebarGroup<E> {
     }
 }
 impl<E: Collapsible + IntoElement> RenderOnce for SidebarGroup<E> {
-    fn render(self, _: &mut Window, cx: &mut App) -> impl IntoElement {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let open_state = window.use_keyed_state(
+            SharedString::from(format!("sidebar-group/{}", self.label)),
+            cx,
+            |_, _| self.default_open,
+        );
+        // The items are always visible when the sidebar is collapsed to icons.
+        let open = !self.collapsible || self.collapsed || *open_state.read(cx);
+
         v_flex()
             .relative()
             .p_2()
             .when(!self.collapsed, |this| {
                 this.child(
-                    div()
+                    h_flex()
+                        .id("label")
                         .flex_shrink_0()
                         .px_2()
+                        .gap_2()
+                        .justify_between()
                         .rounded(cx.theme().radius)
                         .text_xs()
                         .text_color(cx.theme().sidebar_foreground.opacity(0.7))
                         .h_8()
-                        .child(self.label),
+                        .child(self.label.clone())
+                        .when(self.collapsible, |this| {
+                            let focus_handle = window
+                                .use_keyed_state(
+                                    SharedString::from(format!(
+                                        "sidebar-group/{}/focus",
+                                        self.label
+                                    )),
+                                    cx,
+                                    |_, cx| cx.focus_handle(),
+                                )
+                                .read(cx)
+                                .clone();
+                            register_focus_handle(&focus_handle, cx);
+
+                            this.track_focus(&focus_handle)
+                                .border_1()
+                                .border_color(cx.theme().transparent)
+                                .when(focus_handle.is_focused(window), |this| {
+                                    this.border_color(cx.theme().ring)
+                                })
+                                .on_action({
+                                    let open_state = open_state.clone();
+                                    move |_: &Confirm, _, cx| {
+                                        open_state.update(cx, |open, cx| {
+                                            *open = !*open;
+                                            cx.notify();
+                                        });
+                                    }
+                                })
+                                .on_action({
+                                    let open_state = open_state.clone();
+                                    move |_: &ExpandItem, _, cx| {
+                                        open_state.update(cx, |open, cx| {
+                                            *open = true;
+                                            cx.notify();
+                                        });
+                                    }
+                                })
+                                .on_action({
+                                    let open_state = open_state.clone();
+                                    move |_: &CollapseItem, _, cx| {
+                                        open_state.update(cx, |open, cx| {
+                                            *open = false;
+                                            cx.notify();
+                                        });
+                                    }
+                                })
+                                .cursor_pointer()
+                                .hover(|this| {
+                                    this.bg(cx.theme().sidebar_accent.opacity(0.8))
+                                        .text_color(cx.theme().sidebar_accent_foreground)
+                                })
+                                .child(
+                                    Icon::new(IconName::ChevronRight)
+                                        .size_3p5()
+                                        .when(open, |this| this.rotate(percentage(90. / 360.))),
+                                )
+                                .on_click(move |_, _, cx| {
+                                    open_state.update(cx, |open, cx| {
+                                        *open = !*open;
+                                        cx.notify();
+                                    });
+                                })
+                        }),
+                )
+            })
+            .when(open, |this| {
+                this.child(
+                    self.base
+                        .children(
+                            self.children
+                                .into_iter()
+                                .map(|child| child.collapsed(self.collapsed)),
+                        )
+                        .with_animation(
+                            SharedString::from(format!("sidebar-group-open/{}", self.label)),
+                            Animation::new(Duration::from_secs_f64(0.15)),
+                            |this, delta| this.opacity(delta),
+                        ),
                 )
             })
-            .child(
-                self.base.children(
-                    self.children
-                        .into_iter()
-                        .map(|child| child.collapsed(self.collapsed)),
-                ),
-            )
     }
 }
@@ -1,8 +1,11 @@
-use crate::{h_flex, v_flex, ActiveTheme as _, Collapsible, Icon, IconName, StyledExt};
+use super::{register_focus_handle, CollapseItem, ExpandItem};
+use crate::{
+    actions::Confirm, h_flex, v_flex, ActiveTheme as _, Collapsible, Icon, IconName, StyledExt,
+};
 use gpui::{
-    div, percentage, prelude::FluentBuilder as _, AnyElement, App, ClickEvent, ElementId,
-    InteractiveElement as _, IntoElement, ParentElement as _, RenderOnce, SharedString,
-    StatefulInteractiveElement as _, Styled as _, Window,
+    anchored, deferred, div, percentage, prelude::FluentBuilder as _, px, AnyElement, App,
+    ClickEvent, ElementId, InteractiveElement as _, IntoElement, ParentElement as _, RenderOnce,
+    SharedString, StatefulInteractiveElement as _, Styled as _, Window,
 };
 use std::rc::Rc;
 
@@ -65,6 +68,7 @@ pub struct SidebarMenuItem {
     collapsed: bool,
     children: Vec<Self>,
     suffix: Option<AnyElement>,
+    badge: Option<SharedString>,
 }
 
 impl SidebarMenuItem {
@@ -79,6 +83,7 @@ impl SidebarMenuItem {
             collapsed: false,
             children: Vec::new(),
             suffix: None,
+            badge: None,
         }
     }
 
@@ -120,46 +125,102 @@ impl SidebarMenuItem {
         self
     }
 
+    /// Set the sub items of the menu item, the sub items can have their own submenu to form a tree.
+    ///
+    /// The submenu is expanded when the item or any of its descendants is active,
+    /// and can be toggled by clicking the item. When the sidebar is collapsed,
+    /// the submenu is shown in a flyout on hover.
+    pub fn submenu(self, items: Vec<SidebarMenuItem>) -> Self {
+        self.children(items)
+    }
+
     /// Set the suffix for the menu item.
     pub fn suffix(mut self, suffix: impl IntoElement) -> Self {
         self.suffix = Some(suffix.into_any_element());
         self
     }
 
+    /// Set the badge for the menu item, e.g. the count of the unread messages.
+    ///
+    /// The badge is shown at the right side, and hidden when the sidebar is collapsed.
+    pub fn badge(mut self, badge: impl Into<SharedString>) -> Self {
+        self.badge = Some(badge.into());
+        self
+    }
+
     fn is_submenu(&self) -> bool {
         self.children.len() > 0
     }
 
-    fn is_open(&self) -> bool {
-        if self.is_submenu() {
-            self.active
-        } else {
-            false
-        }
+    /// Returns true if any of the descendants is active.
+    fn has_active_child(&self) -> bool {
+        self.children
+            .iter()
+            .any(|item| item.active || item.has_active_child())
     }
 }
 
 impl RenderOnce for SidebarMenuItem {
-    fn render(self, _: &mut Window, cx: &mut App) -> impl IntoElement {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
         let handler = self.handler.clone();
         let is_collapsed = self.collapsed;
         let is_active = self.active;
-        let is_open = self.is_open();
         let is_submenu = self.is_submenu();
+        let is_active_path = is_submenu && self.has_active_child();
+
+        // The open state toggled by click, `None` to follow the active path.
+        let open_state = window.use_keyed_state(
+            SharedString::from(format!("sidebar-menu-item/{}/open", self.label)),
+            cx,
+            |_, _| None::<bool>,
+        );
+        let hover_state = window.use_keyed_state(
+            SharedString::from(format!("sidebar-menu-item/{}/hover", self.label)),
+            cx,
+            |_, _| false,
+        );
+        let is_open = is_submenu && open_state.read(cx).unwrap_or(is_active || is_active_path);
+        let show_flyout = is_submenu && is_collapsed && *hover_state.read(cx);
+        let focus_handle = window
+            .use_keyed_state(
+                SharedString::from(format!("sidebar-menu-item/{}/focus", self.label)),
+                cx,
+                |_, cx| cx.focus_handle(),
+            )
+            .read(cx)
+            .clone();
+        let is_focused = focus_handle.is_focused(window);
+        register_focus_handle(&focus_handle, cx);
 
         div()
             .id(self.id.clone())
             .w_full()
+            .relative()
+            .when(is_submenu && is_collapsed, |this| {
+                this.on_hover({
+                    let hover_state = hover_state.clone();
+                    move |hovered, _, cx| {
+                        hover_state.update(cx, |state, cx| {
+                            *state = *hovered;
+                            cx.notify();
+                        })
+                    }
+                })
+            })
             .child(
                 h_flex()
                     .size_full()
                     .id("item")
+                    .track_focus(&focus_handle)
                     .overflow_x_hidden()
                     .flex_shrink_0()
                     .p_2()
                     .gap_x_2()
                     .rounded(cx.theme().radius)
                     .text_sm()
+                    .border_1()
+                    .border_color(cx.theme().transparent)
+                    .when(is_focused, |this| this.border_color(cx.theme().ring))
                     .hover(|this| {
                         if is_active {
                             return this;
@@ -173,12 +234,17 @@ impl RenderOnce for SidebarMenuItem {
                             .bg(cx.theme().sidebar_accent)
                             .text_color(cx.theme().sidebar_accent_foreground)
                     })
+                    .when(is_active_path, |this| {
+                        this.font_medium()
+                            .text_color(cx.theme().sidebar_accent_foreground)
+                    })
                     .when_some(self.icon.clone(), |this, icon| this.child(icon))
                     .when(is_collapsed, |this| {
-                        this.justify_center().when(is_active, |this| {
-                            this.bg(cx.theme().sidebar_accent)
-                                .text_color(cx.theme().sidebar_accent_foreground)
-                        })
+                        this.justify_center()
+                            .when(is_active || is_active_path, |this| {
+                                this.bg(cx.theme().sidebar_accent)
+                                    .text_color(cx.theme().sidebar_accent_foreground)
+                            })
                     })
                     .when(!is_collapsed, |this| {
                         this.h_7()
@@ -194,7 +260,23 @@ impl RenderOnce for SidebarMenuItem {
                                             .overflow_x_hidden()
                                             .child(self.label.clone()),
                                     )
-                                    .when_some(self.suffix, |this, suffix| this.child(suffix)),
+                                    .when_some(self.suffix, |this, suffix| this.child(suffix))
+                                    .when_some(self.badge, |this, badge| {
+                                        this.child(
+                                            h_flex()
+                                                .flex_shrink_0()
+                                                .justify_center()
+                                                .h_5()
+                                                .min_w_5()
+                                                .px_1p5()
+                                                .rounded_full()
+                                                .text_xs()
+                                                .font_medium()
+                                                .bg(cx.theme().sidebar_primary)
+                                                .text_color(cx.theme().sidebar_primary_foreground)
+                                                .child(badge),
+                                        )
+                                    }),
                             )
                             .when(is_submenu, |this| {
                                 this.child(
@@ -204,24 +286,120 @@ impl RenderOnce for SidebarMenuItem {
                                 )
                             })
                     })
-                    .on_click(move |ev, window, cx| handler(ev, window, cx)),
+                    .on_action({
+                        let handler = handler.clone();
+                        let open_state = open_state.clone();
+                        move |_: &Confirm, window, cx| {
+                            if is_submenu && !is_collapsed {
+                                open_state.update(cx, |state, cx| {
+                                    *state = Some(!is_open);
+                                    cx.notify();
+                                });
+                            }
+                            handler(&ClickEvent::default(), window, cx)
+                        }
+                    })
+                    .when(is_submenu && !is_collapsed, |this| {
+                        this.on_action({
+                            let open_state = open_state.clone();
+                            move |_: &ExpandItem, _, cx| {
+                                open_state.update(cx, |state, cx| {
+                                    *state = Some(true);
+                                    cx.notify();
+                                });
+                            }
+                        })
+                        .on_action({
+                            let open_state = open_state.clone();
+                            move |_: &CollapseItem, _, cx| {
+                                open_state.update(cx, |state, cx| {
+                                    *state = Some(false);
+                                    cx.notify();
+                                });
+                            }
+                        })
+                    })
+                    .on_click({
+                        let open_state = open_state.clone();
+                        move |ev, window, cx| {
+                            if is_submenu && !is_collapsed {
+                                open_state.update(cx, |state, cx| {
+                                    *state = Some(!is_open);
+                                    cx.notify();
+                                });
+                            }
+                            handler(ev, window, cx)
+                        }
+                    }),
             )
-            .when(is_submenu && is_open && !is_collapsed, |this| {
+            .map(|this| {
+                if is_submenu && is_open && !is_collapsed {
+                    return this.child(
+                        v_flex()
+                            .id("submenu")
+                            .border_l_1()
+                            .border_color(cx.theme().sidebar_border)
+                            .gap_1()
+                            .ml_3p5()
+                            .pl_2p5()
+                            .py_0p5()
+                            .children(
+                                self.children
+                                    .into_iter()
+                                    .enumerate()
+                                    .map(|(ix, item)| item.id(ix)),
+                            ),
+                    );
+                }
+                if !show_flyout {
+                    return this;
+                }
+
                 this.child(
-                    v_flex()
-                        .id("submenu")
-                        .border_l_1()
-                        .border_color(cx.theme().sidebar_border)
-                        .gap_1()
-                        .ml_3p5()
-                        .pl_2p5()
-                        .py_0p5()
-                        .children(
-                            self.children
-                                .into_iter()
-                                .enumerate()
-                                .map(|(ix, item)| item.id(ix)),
-                        ),
+                    div().absolute().top_0().left_full().child(
+                        deferred(
+                            anchored().snap_to_window_with_margin(px(8.)).child(
+                                // The left padding to keep hovering when moving into the flyout.
+                                div()
+                                    .id("flyout")
+                                    .pl_2()
+                                    .on_hover(move |hovered, _, cx| {
+                                        hover_state.update(cx, |state, cx| {
+                                            *state = *hovered;
+                                            cx.notify();
+                                        })
+                                    })
+                                    .child(
+                                        v_flex()
+                                            .min_w(px(180.))
+                                            .p_1()
+                                            .gap_1()
+                                            .bg(cx.theme().popover)
+                                            .text_color(cx.theme().popover_foreground)
+                                            .border_1()
+                                            .border_color(cx.theme().border)
+                                            .rounded(cx.theme().radius)
+                                            .shadow_md()
+                                            .child(
+                                                div()
+                                                    .px_2()
+                                                    .py_1()
+                                                    .text_xs()
+                                                    .font_medium()
+                                                    .text_color(cx.theme().muted_foreground)
+                                                    .child(self.label.clone()),
+                                            )
+                                            .children(
+                                                self.children
+                                                    .into_iter()
+                                                    .enumerate()
+                                                    .map(|(ix, item)| item.id(ix)),
+                                            ),
+                                    ),
+                            ),
+                        )
+                        .with_priority(1),
+                    ),
                 )
             })
     }
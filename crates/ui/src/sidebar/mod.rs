@@ -1,12 +1,14 @@
 use crate::{
+    actions::{Confirm, SelectNext, SelectPrev},
     button::{Button, ButtonVariants},
     h_flex,
     scroll::ScrollbarAxis,
-    v_flex, ActiveTheme, Collapsible, Icon, IconName, Side, Sizable, StyledExt,
+    v_flex, ActiveTheme, Collapsible, FocusableCycle, Icon, IconName, Side, Sizable, StyledExt,
 };
 use gpui::{
-    div, prelude::FluentBuilder, px, AnyElement, App, ClickEvent, DefiniteLength,
-    InteractiveElement as _, IntoElement, ParentElement, Pixels, RenderOnce, Styled, Window,
+    actions, div, prelude::FluentBuilder, px, AnyElement, App, AppContext as _, ClickEvent,
+    DefiniteLength, Entity, FocusHandle, Global, InteractiveElement as _, IntoElement, KeyBinding,
+    ParentElement, Pixels, RenderOnce, Styled, Window,
 };
 use std::rc::Rc;
 
@@ -21,6 +23,62 @@ pub use menu::*;
 
 const DEFAULT_WIDTH: Pixels = px(255.);
 const COLLAPSED_WIDTH: Pixels = px(48.);
+const CONTEXT: &str = "Sidebar";
+
+actions!(sidebar, [ExpandItem, CollapseItem]);
+
+pub(crate) fn init(cx: &mut App) {
+    let context = Some(CONTEXT);
+    cx.bind_keys([
+        KeyBinding::new("up", SelectPrev, context),
+        KeyBinding::new("down", SelectNext, context),
+        KeyBinding::new("enter", Confirm { secondary: false }, context),
+        KeyBinding::new("left", CollapseItem, context),
+        KeyBinding::new("right", ExpandItem, context),
+    ]);
+}
+
+/// The focus handles of the sidebar items in the render order, for the keyboard navigation.
+#[derive(Clone, Default)]
+struct SidebarNav {
+    items: Vec<FocusHandle>,
+}
+
+impl FocusableCycle for SidebarNav {
+    fn cycle_focus_handles(&self, _: &mut Window, _: &mut App) -> Vec<FocusHandle> {
+        self.items.clone()
+    }
+}
+
+/// The stack of the sidebars in rendering, the items register to the last one.
+#[derive(Default)]
+struct SidebarNavStack(Vec<Entity<SidebarNav>>);
+
+impl Global for SidebarNavStack {}
+
+/// Register the focus handle of an item to the sidebar in rendering,
+/// to be reached by the Up/Down keys.
+pub(super) fn register_focus_handle(focus_handle: &FocusHandle, cx: &mut App) {
+    let Some(nav) = cx
+        .try_global::<SidebarNavStack>()
+        .and_then(|stack| stack.0.last().cloned())
+    else {
+        return;
+    };
+
+    nav.update(cx, |nav, _| nav.items.push(focus_handle.clone()));
+}
+
+/// Pop the sidebar from the [`SidebarNavStack`] after all the items are rendered.
+#[derive(IntoElement)]
+struct SidebarNavEnd;
+
+impl RenderOnce for SidebarNavEnd {
+    fn render(self, _: &mut Window, cx: &mut App) -> impl IntoElement {
+        cx.default_global::<SidebarNavStack>().0.pop();
+        div()
+    }
+}
 
 /// A sidebar
 #[derive(IntoElement)]
@@ -36,6 +94,7 @@ pub struct Sidebar<E: Collapsible + IntoElement + 'static> {
     width: DefiniteLength,
     border_width: Pixels,
     collapsed: bool,
+    focus_handle: Option<FocusHandle>,
 }
 
 impl<E: Collapsible + IntoElement> Sidebar<E> {
@@ -49,6 +108,7 @@ impl<E: Collapsible + IntoElement> Sidebar<E> {
             width: DEFAULT_WIDTH.into(),
             border_width: px(1.),
             collapsed: false,
+            focus_handle: None,
         }
     }
 
@@ -84,6 +144,17 @@ impl<E: Collapsible + IntoElement> Sidebar<E> {
         self
     }
 
+    /// Track the focus of the sidebar with the `focus_handle`.
+    ///
+    /// When the sidebar is focused, the Up/Down keys move the focus between the menu items,
+    /// Enter to activate the item, Left/Right to collapse/expand the submenu or group.
+    ///
+    /// The menu items can also be reached by the keyboard without this.
+    pub fn track_focus(mut self, focus_handle: &FocusHandle) -> Self {
+        self.focus_handle = Some(focus_handle.clone());
+        self
+    }
+
     /// Set the header of the sidebar.
     pub fn header(mut self, header: impl IntoElement) -> Self {
         self.header = Some(header.into_any_element());
@@ -185,9 +256,32 @@ impl RenderOnce for SidebarToggleButton {
 }
 
 impl<E: Collapsible + IntoElement> RenderOnce for Sidebar<E> {
-    fn render(mut self, _: &mut Window, cx: &mut App) -> impl IntoElement {
+    fn render(mut self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let nav = window.use_keyed_state("sidebar-nav", cx, |_, _| SidebarNav::default());
+        nav.update(cx, |nav, _| nav.items.clear());
+        cx.default_global::<SidebarNavStack>().0.push(nav.clone());
+
         v_flex()
             .id("sidebar")
+            .key_context(CONTEXT)
+            .when_some(self.focus_handle.take(), |this, focus_handle| {
+                this.track_focus(&focus_handle)
+            })
+            .on_action({
+                let nav = nav.clone();
+                move |_: &SelectPrev, window, cx| {
+                    let nav = nav.read(cx).clone();
+                    if !nav.items.is_empty() {
+                        nav.cycle_focus(false, window, cx);
+                    }
+                }
+            })
+            .on_action(move |_: &SelectNext, window, cx| {
+                let nav = nav.read(cx).clone();
+                if !nav.items.is_empty() {
+                    nav.cycle_focus(true, window, cx);
+                }
+            })
             .w(self.width)
             .when(self.collapsed, |this| this.w(COLLAPSED_WIDTH))
             .flex_shrink_0()
@@ -220,5 +314,6 @@ impl<E: Collapsible + IntoElement> RenderOnce for Sidebar<E> {
             .when_some(self.footer.take(), |this, footer| {
                 this.child(h_flex().id("footer").gap_2().p_2().child(footer))
             })
+            .child(SidebarNavEnd)
     }
 }
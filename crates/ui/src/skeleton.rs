@@ -1,14 +1,23 @@
-use crate::{ActiveTheme, StyledExt};
+use crate::{v_flex, ActiveTheme, StyledExt};
 use gpui::{
-    bounce, div, ease_in_out, Animation, AnimationExt, IntoElement, RenderOnce, StyleRefinement,
-    Styled,
+    bounce, div, ease_in_out, linear, linear_color_stop, linear_gradient, prelude::FluentBuilder,
+    relative, Animation, AnimationExt, AnyElement, App, ElementId, Hsla, IntoElement,
+    ParentElement, RenderOnce, StyleRefinement, Styled,
 };
 use std::time::Duration;
 
+/// The default duration of one pulse or shimmer cycle.
+const DEFAULT_SPEED: Duration = Duration::from_secs(2);
+/// The width of the last line of [`Skeleton::lines`], relative to the other lines.
+const LAST_LINE_WIDTH: f32 = 0.6;
+
 #[derive(IntoElement)]
 pub struct Skeleton {
     style: StyleRefinement,
     secondary: bool,
+    shimmer: bool,
+    speed: Duration,
+    lines: Option<usize>,
 }
 
 impl Skeleton {
@@ -16,6 +25,9 @@ impl Skeleton {
         Self {
             style: StyleRefinement::default(),
             secondary: false,
+            shimmer: false,
+            speed: DEFAULT_SPEED,
+            lines: None,
         }
     }
 
@@ -24,6 +36,82 @@ impl Skeleton {
         self.secondary = secondary;
         self
     }
+
+    /// Set true to use an animated gradient sweep instead of the pulse, default: false
+    pub fn shimmer(mut self, shimmer: bool) -> Self {
+        self.shimmer = shimmer;
+        self
+    }
+
+    /// Set the duration of one pulse or shimmer cycle, default: 2s
+    pub fn speed(mut self, speed: Duration) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Render `n` text line placeholders with the last line shorter, for paragraph loading.
+    ///
+    /// The style (e.g. width) is applied to the container of the lines,
+    /// except the corner radius (e.g. `rounded`) is applied to each line.
+    pub fn lines(mut self, n: usize) -> Self {
+        self.lines = Some(n);
+        self
+    }
+
+    fn render_line(
+        id: impl Into<ElementId>,
+        style: &StyleRefinement,
+        color: Hsla,
+        shimmer: bool,
+        speed: Duration,
+        cx: &App,
+    ) -> AnyElement {
+        let base = div().w_full().h_4().bg(color).refine_style(style);
+
+        if shimmer {
+            let highlight = cx.theme().background.opacity(0.5);
+            let transparent = cx.theme().transparent;
+
+            base.relative()
+                .overflow_hidden()
+                .child(
+                    div()
+                        .absolute()
+                        .top_0()
+                        .bottom_0()
+                        .w(relative(0.5))
+                        .flex()
+                        .child(div().h_full().w(relative(0.5)).bg(linear_gradient(
+                            90.,
+                            linear_color_stop(transparent, 0.),
+                            linear_color_stop(highlight, 1.),
+                        )))
+                        .child(div().h_full().w(relative(0.5)).bg(linear_gradient(
+                            90.,
+                            linear_color_stop(highlight, 0.),
+                            linear_color_stop(transparent, 1.),
+                        )))
+                        .with_animation(
+                            id,
+                            Animation::new(speed).repeat().with_easing(linear),
+                            |this, delta| this.left(relative(-0.5 + delta * 1.5)),
+                        ),
+                )
+                .into_any_element()
+        } else {
+            base.with_animation(
+                id,
+                Animation::new(speed)
+                    .repeat()
+                    .with_easing(bounce(ease_in_out)),
+                move |this, delta| {
+                    let v = 1.0 - delta * 0.5;
+                    this.opacity(v)
+                },
+            )
+            .into_any_element()
+        }
+    }
 }
 
 impl Styled for Skeleton {
@@ -34,24 +122,45 @@ impl Styled for Skeleton {
 
 impl RenderOnce for Skeleton {
     fn render(self, _: &mut gpui::Window, cx: &mut gpui::App) -> impl IntoElement {
-        div()
+        // NOTE: GPUI does not expose a reduced motion setting yet, so the animation
+        // is always running.
+        let color = if self.secondary {
+            cx.theme().skeleton.opacity(0.5)
+        } else {
+            cx.theme().skeleton
+        };
+
+        let Some(lines) = self.lines else {
+            return Self::render_line("skeleton", &self.style, color, self.shimmer, self.speed, cx);
+        };
+
+        let mut line_style = StyleRefinement::default();
+        line_style.corner_radii = self.style.corner_radii.clone();
+
+        v_flex()
             .w_full()
-            .h_4()
-            .bg(if self.secondary {
-                cx.theme().skeleton.opacity(0.5)
-            } else {
-                cx.theme().skeleton
-            })
+            .gap_2()
             .refine_style(&self.style)
-            .with_animation(
-                "skeleton",
-                Animation::new(Duration::from_secs(2))
-                    .repeat()
-                    .with_easing(bounce(ease_in_out)),
-                move |this, delta| {
-                    let v = 1.0 - delta * 0.5;
-                    this.opacity(v)
-                },
-            )
+            .children((0..lines).map(|ix| {
+                let line = Self::render_line(
+                    ("skeleton-line", ix),
+                    &line_style,
+                    color,
+                    self.shimmer,
+                    self.speed,
+                    cx,
+                );
+
+                div()
+                    .map(|this| {
+                        if ix + 1 == lines && lines > 1 {
+                            this.w(relative(LAST_LINE_WIDTH))
+                        } else {
+                            this.w_full()
+                        }
+                    })
+                    .child(line)
+            }))
+            .into_any_element()
     }
 }
@@ -1,11 +1,12 @@
-use std::ops::Range;
+use std::{ops::Range, rc::Rc};
 
-use crate::{h_flex, tooltip::Tooltip, ActiveTheme, AxisExt, StyledExt};
+use crate::{h_flex, tooltip::Tooltip, ActiveTheme, AxisExt, FillColor, StyledExt};
 use gpui::{
     canvas, div, prelude::FluentBuilder as _, px, Along, App, AppContext as _, Axis, Background,
-    Bounds, Context, Corners, DragMoveEvent, Empty, Entity, EntityId, EventEmitter, Hsla,
-    InteractiveElement, IntoElement, MouseButton, MouseDownEvent, ParentElement as _, Pixels,
-    Point, Render, RenderOnce, StatefulInteractiveElement as _, StyleRefinement, Styled, Window,
+    Bounds, Context, Corners, DragMoveEvent, Empty, Entity, EntityId, EventEmitter, FocusHandle,
+    Hsla, InteractiveElement, IntoElement, KeyDownEvent, MouseButton, MouseDownEvent,
+    ParentElement as _, Pixels, Point, Render, RenderOnce, SharedString,
+    StatefulInteractiveElement as _, StyleRefinement, Styled, Window,
 };
 
 #[derive(Clone)]
@@ -19,6 +20,8 @@ impl Render for DragThumb {
 
 pub enum SliderEvent {
     Change(SliderValue),
+    /// The value of the range slider has changed, emitted after [`SliderEvent::Change`].
+    RangeChange((f32, f32)),
 }
 
 /// The value of the slider, can be a single value or a range of values.
@@ -128,6 +131,8 @@ pub struct SliderState {
     percentage: Range<f32>,
     /// The bounds of the slider after rendered.
     bounds: Bounds<Pixels>,
+    /// The focus handles of the start and end thumbs, created on first render.
+    focus_handles: Option<(FocusHandle, FocusHandle)>,
 }
 
 impl SliderState {
@@ -139,9 +144,15 @@ impl SliderState {
             value: SliderValue::default(),
             percentage: (0.0..0.0),
             bounds: Bounds::default(),
+            focus_handles: None,
         }
     }
 
+    /// Create a range slider with two thumbs, the value is `(start, end)`.
+    pub fn range(start: f32, end: f32) -> Self {
+        Self::new().default_value((start, end))
+    }
+
     /// Set the minimum value of the slider, default: 0.0
     pub fn min(mut self, min: f32) -> Self {
         self.min = min;
@@ -187,25 +198,72 @@ impl SliderState {
     }
 
     fn update_thumb_pos(&mut self) {
+        let total = self.max - self.min;
+        let percentage = |value: f32| {
+            if total > 0. {
+                (value.clamp(self.min, self.max) - self.min) / total
+            } else {
+                0.
+            }
+        };
+
         match self.value {
             SliderValue::Single(value) => {
-                let percentage = value.clamp(self.min, self.max) / self.max;
-                self.percentage = 0.0..percentage;
+                self.percentage = 0.0..percentage(value);
             }
             SliderValue::Range(start, end) => {
-                let clamped_start = start.clamp(self.min, self.max);
-                let clamped_end = end.clamp(self.min, self.max);
-                self.percentage = (clamped_start / self.max)..(clamped_end / self.max);
+                self.percentage = percentage(start)..percentage(end);
             }
         }
     }
 
+    fn focus_handles(&mut self, cx: &mut Context<Self>) -> (FocusHandle, FocusHandle) {
+        self.focus_handles
+            .get_or_insert_with(|| (cx.focus_handle(), cx.focus_handle()))
+            .clone()
+    }
+
+    /// Move the start or end thumb by `steps` of the step value, e.g. by keyboard.
+    ///
+    /// If the `marks` is not empty, move to the next mark in the direction of `steps` instead.
+    fn move_thumb(&mut self, is_start: bool, steps: f32, marks: &[f32], cx: &mut Context<Self>) {
+        let value = if is_start {
+            self.value.start()
+        } else {
+            self.value.end()
+        };
+        let value = if marks.is_empty() {
+            (value + self.step * steps).clamp(self.min, self.max)
+        } else {
+            next_mark(value, marks, self.min, self.max, steps > 0.).unwrap_or(value)
+        };
+
+        if is_start {
+            self.value.set_start(value);
+        } else {
+            self.value.set_end(value);
+        }
+        self.update_thumb_pos();
+        self.emit_change(cx);
+    }
+
+    fn emit_change(&self, cx: &mut Context<Self>) {
+        cx.emit(SliderEvent::Change(self.value));
+        if let SliderValue::Range(start, end) = self.value {
+            cx.emit(SliderEvent::RangeChange((start, end)));
+        }
+        cx.notify();
+    }
+
     /// Update value by mouse position
+    ///
+    /// If the `marks` is not empty, the value will snap to the closest mark.
     fn update_value_by_position(
         &mut self,
         axis: Axis,
         position: Point<Pixels>,
         is_start: bool,
+        marks: &[f32],
         _: &mut Window,
         cx: &mut Context<Self>,
     ) {
@@ -229,6 +287,17 @@ impl SliderState {
         };
 
         let value = min + (max - min) * percentage;
+        if let Some(mark) = closest_mark(value, marks, min, max) {
+            if is_start {
+                self.value.set_start(mark);
+            } else {
+                self.value.set_end(mark);
+            }
+            self.update_thumb_pos();
+            self.emit_change(cx);
+            return;
+        }
+
         let value = (value / step).round() * step;
 
         if is_start {
@@ -238,8 +307,29 @@ impl SliderState {
             self.percentage.end = percentage;
             self.value.set_end(value);
         }
-        cx.emit(SliderEvent::Change(self.value));
-        cx.notify();
+        self.emit_change(cx);
+    }
+}
+
+/// Returns the closest mark to the `value` within the `min..=max` domain.
+fn closest_mark(value: f32, marks: &[f32], min: f32, max: f32) -> Option<f32> {
+    marks
+        .iter()
+        .copied()
+        .filter(|mark| (min..=max).contains(mark))
+        .min_by(|a, b| (a - value).abs().total_cmp(&(b - value).abs()))
+}
+
+/// Returns the next mark after (or before if not `forward`) the `value` within the `min..=max` domain.
+fn next_mark(value: f32, marks: &[f32], min: f32, max: f32, forward: bool) -> Option<f32> {
+    let marks = marks
+        .iter()
+        .copied()
+        .filter(|mark| (min..=max).contains(mark));
+    if forward {
+        marks.filter(|mark| *mark > value).min_by(f32::total_cmp)
+    } else {
+        marks.filter(|mark| *mark < value).max_by(f32::total_cmp)
     }
 }
 
@@ -257,6 +347,10 @@ pub struct Slider {
     axis: Axis,
     style: StyleRefinement,
     disabled: bool,
+    fill_color: Option<FillColor>,
+    marks: Vec<f32>,
+    snap_to_marks: bool,
+    mark_labels: Vec<(f32, SharedString)>,
 }
 
 impl Slider {
@@ -267,6 +361,10 @@ impl Slider {
             state: state.clone(),
             style: StyleRefinement::default(),
             disabled: false,
+            fill_color: None,
+            marks: Vec::new(),
+            snap_to_marks: false,
+            mark_labels: Vec::new(),
         }
     }
 
@@ -282,12 +380,53 @@ impl Slider {
         self
     }
 
+    /// Set the axis of the slider, default: [`Axis::Horizontal`]
+    ///
+    /// The vertical slider grows the fill upward from the bottom.
+    pub fn axis(mut self, axis: Axis) -> Self {
+        self.axis = axis;
+        self
+    }
+
     /// Set the disabled state of the slider, default: false
     pub fn disabled(mut self, disabled: bool) -> Self {
         self.disabled = disabled;
         self
     }
 
+    /// Set the fill color of the slider bar, default is the bar color.
+    pub fn fill_color(mut self, fill_color: impl Into<FillColor>) -> Self {
+        self.fill_color = Some(fill_color.into());
+        self
+    }
+
+    /// Set the values to draw tick marks on the track, the marks out of the min and max are ignored.
+    pub fn marks(mut self, marks: Vec<f32>) -> Self {
+        self.marks = marks;
+        self
+    }
+
+    /// Set to snap the thumb to the nearest mark when dragging or pressing arrow keys, default: false
+    pub fn snap_to_marks(mut self, snap_to_marks: bool) -> Self {
+        self.snap_to_marks = snap_to_marks;
+        self
+    }
+
+    /// Set the labels of the marks, rendered beneath the track (or beside the vertical track).
+    pub fn mark_labels(mut self, mark_labels: Vec<(f32, SharedString)>) -> Self {
+        self.mark_labels = mark_labels;
+        self
+    }
+
+    /// Returns the marks to snap to, empty if snapping is disabled.
+    fn snap_marks(&self) -> Rc<[f32]> {
+        if self.snap_to_marks {
+            self.marks.as_slice().into()
+        } else {
+            Rc::new([])
+        }
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn render_thumb(
         &self,
@@ -299,18 +438,30 @@ impl Slider {
         window: &mut Window,
         cx: &mut App,
     ) -> impl gpui::IntoElement {
+        let (start_focus_handle, end_focus_handle) =
+            self.state.update(cx, |state, cx| state.focus_handles(cx));
         let state = self.state.read(cx);
         let entity_id = self.state.entity_id();
         let value = state.value;
+        let is_range = value.is_range();
         let axis = self.axis;
         let id = ("slider-thumb", is_start as u32);
+        let marks = self.snap_marks();
 
         if self.disabled {
             return div().id(id);
         }
 
+        let (focus_handle, other_focus_handle) = if is_start {
+            (start_focus_handle, end_focus_handle)
+        } else {
+            (end_focus_handle, start_focus_handle)
+        };
+        let is_focused = focus_handle.is_focused(window);
+
         div()
             .id(id)
+            .track_focus(&focus_handle)
             .absolute()
             .when(axis.is_horizontal(), |this| {
                 this.top(px(-5.)).left(start_pos).ml(-px(8.))
@@ -327,6 +478,7 @@ impl Slider {
             .when(cx.theme().shadow, |this| this.shadow_md())
             .size_4()
             .p(px(1.))
+            .when(is_focused, |this| this.bg(cx.theme().ring))
             .child(
                 div()
                     .flex_shrink_0()
@@ -334,9 +486,34 @@ impl Slider {
                     .corner_radii(radius)
                     .bg(thumb_color),
             )
-            .on_mouse_down(MouseButton::Left, |_, _, cx| {
-                cx.stop_propagation();
+            .on_mouse_down(MouseButton::Left, {
+                let focus_handle = focus_handle.clone();
+                move |_, window, cx| {
+                    cx.stop_propagation();
+                    window.focus(&focus_handle);
+                }
             })
+            .on_key_down(window.listener_for(&self.state, {
+                let marks = marks.clone();
+                move |state, e: &KeyDownEvent, window, cx| {
+                    let steps = match e.keystroke.key.as_str() {
+                        "left" | "down" => -1.,
+                        "right" | "up" => 1.,
+                        // Move to the end thumb by `tab`, and back to the start thumb by
+                        // `shift-tab`, otherwise let the focus leave the slider.
+                        "tab" if is_range && is_start != e.keystroke.modifiers.shift => {
+                            window.focus(&other_focus_handle);
+                            cx.stop_propagation();
+                            cx.notify();
+                            return;
+                        }
+                        _ => return,
+                    };
+
+                    state.move_thumb(is_start, steps, &marks, cx);
+                    cx.stop_propagation();
+                }
+            }))
             .on_drag(DragThumb((entity_id, is_start)), |drag, _, _, cx| {
                 cx.stop_propagation();
                 cx.new(|_| drag.clone())
@@ -355,6 +532,7 @@ impl Slider {
                                 axis,
                                 e.event.position,
                                 *is_start,
+                                &marks,
                                 window,
                                 cx,
                             )
@@ -384,9 +562,20 @@ impl RenderOnce for Slider {
         let state = self.state.read(cx);
         let is_range = state.value().is_range();
         let bar_size = state.bounds.size.along(axis);
-        let bar_start = state.percentage.start * bar_size;
-        let bar_end = state.percentage.end * bar_size;
+        let percentage = state.percentage.clone();
+        let bar_start = percentage.start * bar_size;
+        let bar_end = percentage.end * bar_size;
         let rem_size = window.rem_size();
+        let (min, max) = (state.min, state.max);
+        let mark_pos = move |value: f32| {
+            if max > min {
+                ((value - min) / (max - min)).clamp(0., 1.) * bar_size
+            } else {
+                px(0.)
+            }
+        };
+        let marks = self.snap_marks();
+        let has_labels = !self.mark_labels.is_empty();
 
         let bar_color = self
             .style
@@ -429,6 +618,7 @@ impl RenderOnce for Slider {
             .justify_center()
             .when(axis.is_vertical(), |this| this.h(px(120.)))
             .when(axis.is_horizontal(), |this| this.w_full())
+            .when(axis.is_horizontal() && has_labels, |this| this.flex_col())
             .refine_style(&self.style)
             .bg(cx.theme().transparent)
             .text_color(cx.theme().foreground)
@@ -452,7 +642,7 @@ impl RenderOnce for Slider {
                                     }
 
                                     state.update_value_by_position(
-                                        axis, e.position, is_start, window, cx,
+                                        axis, e.position, is_start, &marks, window, cx,
                                     )
                                 },
                             ),
@@ -483,9 +673,45 @@ impl RenderOnce for Slider {
                                     .when(axis.is_vertical(), |this| {
                                         this.w_full().bottom(bar_start).top(bar_size - bar_end)
                                     })
-                                    .bg(bar_color)
+                                    .map(|this| match &self.fill_color {
+                                        Some(fill_color) => fill_color.apply(
+                                            this,
+                                            axis,
+                                            percentage.start,
+                                            percentage.end,
+                                        ),
+                                        None => this.bg(bar_color),
+                                    })
                                     .rounded_full(),
                             )
+                            .children(
+                                self.marks
+                                    .iter()
+                                    .copied()
+                                    .filter(|mark| (min..=max).contains(mark))
+                                    .map(|mark| {
+                                        let pos = mark_pos(mark);
+                                        let filled = pos >= bar_start && pos <= bar_end;
+
+                                        div()
+                                            .absolute()
+                                            .size_1()
+                                            .rounded_full()
+                                            .when(axis.is_horizontal(), |this| {
+                                                this.top(px(1.)).left(pos - px(2.))
+                                            })
+                                            .when(axis.is_vertical(), |this| {
+                                                this.left(px(1.)).bottom(pos - px(2.))
+                                            })
+                                            .map(|this| {
+                                                if filled {
+                                                    this.bg(thumb_color)
+                                                } else {
+                                                    this.bg(bar_color.opacity(0.5))
+                                                }
+                                            })
+                                    }),
+                            )
                             .when(is_range, |this| {
                                 this.child(self.render_thumb(
                                     bar_start,
@@ -517,5 +743,55 @@ impl RenderOnce for Slider {
                             }),
                     ),
             )
+            .when(has_labels, |this| {
+                this.child(
+                    div()
+                        .relative()
+                        .flex_shrink_0()
+                        .text_xs()
+                        .text_color(cx.theme().muted_foreground)
+                        .when(axis.is_horizontal(), |this| this.w_full().h_4())
+                        .when(axis.is_vertical(), |this| this.h_full().w_10())
+                        .children(self.mark_labels.iter().map(|(value, label)| {
+                            let pos = mark_pos(*value);
+
+                            div()
+                                .absolute()
+                                .when(axis.is_horizontal(), |this| {
+                                    this.top_0().left(pos - px(20.)).w(px(40.)).text_center()
+                                })
+                                .when(axis.is_vertical(), |this| {
+                                    this.left_0().bottom(pos - px(8.)).h_4()
+                                })
+                                .child(label.clone())
+                        })),
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{closest_mark, next_mark};
+
+    #[test]
+    fn test_closest_mark() {
+        let marks = [0., 25., 50., 75., 100., 150.];
+        assert_eq!(closest_mark(10., &marks, 0., 100.), Some(0.));
+        assert_eq!(closest_mark(13., &marks, 0., 100.), Some(25.));
+        assert_eq!(closest_mark(130., &marks, 0., 100.), Some(100.));
+        assert_eq!(closest_mark(10., &[], 0., 100.), None);
+        assert_eq!(closest_mark(10., &[150.], 0., 100.), None);
+    }
+
+    #[test]
+    fn test_next_mark() {
+        let marks = [50., 0., 100., 25., 75.];
+        assert_eq!(next_mark(25., &marks, 0., 100., true), Some(50.));
+        assert_eq!(next_mark(30., &marks, 0., 100., true), Some(50.));
+        assert_eq!(next_mark(25., &marks, 0., 100., false), Some(0.));
+        assert_eq!(next_mark(100., &marks, 0., 100., true), None);
+        assert_eq!(next_mark(0., &marks, 0., 100., false), None);
+        assert_eq!(next_mark(50., &marks, 0., 60., true), None);
     }
 }
@@ -1,9 +1,12 @@
 use crate::{
-    h_flex, text::Text, tooltip::Tooltip, ActiveTheme, Disableable, Side, Sizable, Size, StyledExt,
+    h_flex,
+    text::Text,
+    tooltip::{FocusableTooltip as _, Tooltip},
+    ActiveTheme, Disableable, Side, Sizable, Size, StyledExt,
 };
 use gpui::{
     div, prelude::FluentBuilder as _, px, Animation, AnimationExt as _, App, ElementId,
-    InteractiveElement, IntoElement, ParentElement as _, RenderOnce, SharedString,
+    FocusHandle, InteractiveElement, IntoElement, ParentElement as _, RenderOnce, SharedString,
     StatefulInteractiveElement, StyleRefinement, Styled, Window,
 };
 use std::{rc::Rc, time::Duration};
@@ -20,6 +23,7 @@ pub struct Switch {
     on_click: Option<Rc<dyn Fn(&bool, &mut Window, &mut App)>>,
     size: Size,
     tooltip: Option<SharedString>,
+    focus_handle: Option<FocusHandle>,
 }
 
 impl Switch {
@@ -35,9 +39,18 @@ impl Switch {
             label_side: Side::Right,
             size: Size::Medium,
             tooltip: None,
+            focus_handle: None,
         }
     }
 
+    /// Track the focus of the switch by the `focus_handle`.
+    ///
+    /// The tooltip of the switch will be shown when the `focus_handle` is focused by keyboard.
+    pub fn track_focus(mut self, focus_handle: &FocusHandle) -> Self {
+        self.focus_handle = Some(focus_handle.clone());
+        self
+    }
+
     pub fn checked(mut self, checked: bool) -> Self {
         self.checked = checked;
         self
@@ -140,10 +153,17 @@ impl RenderOnce for Switch {
                         .border(inset)
                         .border_color(cx.theme().transparent)
                         .bg(bg)
+                        .when_some(self.focus_handle.as_ref(), |this, focus_handle| {
+                            this.track_focus(focus_handle)
+                        })
                         .when_some(self.tooltip.clone(), |this, tooltip| {
-                            this.tooltip(move |window, cx| {
-                                Tooltip::new(tooltip.clone()).build(window, cx)
-                            })
+                            this.focusable_tooltip(
+                                &self.id,
+                                self.focus_handle.as_ref(),
+                                move |window, cx| Tooltip::new(tooltip.clone()).build(window, cx),
+                                window,
+                                cx,
+                            )
                         })
                         .child(
                             // Switch Toggle
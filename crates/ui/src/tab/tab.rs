@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use crate::button::{Button, ButtonVariants as _};
 use crate::{h_flex, ActiveTheme, Icon, IconName, Selectable, Sizable, Size, StyledExt};
 use gpui::prelude::FluentBuilder as _;
 use gpui::{
@@ -390,7 +391,9 @@ pub struct Tab {
     size: Size,
     pub(super) disabled: bool,
     pub(super) selected: bool,
+    pub(super) closable: bool,
     on_click: Option<Arc<dyn Fn(&ClickEvent, &mut Window, &mut App) + 'static>>,
+    on_close: Option<Arc<dyn Fn(&ClickEvent, &mut Window, &mut App) + 'static>>,
 }
 
 impl From<&'static str> for Tab {
@@ -435,11 +438,13 @@ impl Default for Tab {
             children: Vec::new(),
             disabled: false,
             selected: false,
+            closable: false,
             prefix: None,
             suffix: None,
             variant: TabVariant::default(),
             size: Size::default(),
             on_click: None,
+            on_close: None,
         }
     }
 }
@@ -518,6 +523,23 @@ impl Tab {
         self
     }
 
+    /// Set true to show a close button on the tab, default: false
+    ///
+    /// The close button is shown only when there is a close handler, see [`super::TabBar::on_event`].
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+
+    /// Set the close button handler for the tab, the button is shown if the tab is [`Tab::closable`].
+    pub fn on_close(
+        mut self,
+        on_close: impl Fn(&ClickEvent, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_close = Some(Arc::new(on_close));
+        self
+    }
+
     /// Set the click handler for the tab.
     pub fn on_click(
         mut self,
@@ -655,6 +677,21 @@ impl RenderOnce for Tab {
                     }),
             )
             .when_some(self.suffix, |this, suffix| this.child(suffix))
+            .when(self.closable && !self.disabled, |this| {
+                this.when_some(self.on_close, |this, on_close| {
+                    this.child(
+                        Button::new("close")
+                            .icon(IconName::Close)
+                            .ghost()
+                            .xsmall()
+                            .mr_1()
+                            .on_click(move |event, window, cx| {
+                                cx.stop_propagation();
+                                on_close(event, window, cx);
+                            }),
+                    )
+                })
+            })
             .when(!self.disabled, |this| {
                 this.when_some(self.on_click.clone(), |this, on_click| {
                     this.on_click(move |event, window, cx| on_click(event, window, cx))
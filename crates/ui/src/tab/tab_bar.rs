@@ -5,9 +5,9 @@ use crate::popup_menu::PopupMenuExt as _;
 use crate::{h_flex, ActiveTheme, IconName, Selectable, Sizable, Size, StyledExt};
 use gpui::prelude::FluentBuilder as _;
 use gpui::{
-    div, Action, AnyElement, App, Corner, Div, Edges, ElementId, IntoElement, ParentElement,
-    Pixels, RenderOnce, ScrollHandle, Stateful, StatefulInteractiveElement as _, StyleRefinement,
-    Styled, Window,
+    div, Action, AnyElement, App, AppContext as _, ClickEvent, Context, Corner, Div, Edges,
+    ElementId, IntoElement, ParentElement, Pixels, Render, RenderOnce, ScrollHandle, SharedString,
+    Stateful, StatefulInteractiveElement as _, StyleRefinement, Styled, Window,
 };
 use gpui::{px, InteractiveElement};
 use smallvec::SmallVec;
@@ -18,8 +18,47 @@ use super::{Tab, TabVariant};
 #[action(namespace = tab_bar, no_json)]
 pub struct SelectTab(usize);
 
+/// The events of the [`TabBar`], see [`TabBar::on_event`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TabsEvent {
+    /// The close button of the tab at the index is clicked, see [`Tab::closable`].
+    CloseRequested(usize),
+    /// The tab is dragged from the first index and dropped to the second index,
+    /// see [`TabBar::reorderable`].
+    Reorder(usize, usize),
+}
+
+/// The dragging tab of a reorderable [`TabBar`].
+#[derive(Clone)]
+pub struct DragTab {
+    bar_id: ElementId,
+    ix: usize,
+    label: Option<SharedString>,
+}
+
+impl Render for DragTab {
+    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        div()
+            .id("drag-tab")
+            .cursor_grab()
+            .py_1()
+            .px_3()
+            .max_w_48()
+            .overflow_hidden()
+            .whitespace_nowrap()
+            .border_1()
+            .border_color(cx.theme().border)
+            .rounded(cx.theme().radius)
+            .text_color(cx.theme().tab_foreground)
+            .bg(cx.theme().tab_active)
+            .opacity(0.75)
+            .children(self.label.clone())
+    }
+}
+
 #[derive(IntoElement)]
 pub struct TabBar {
+    id: ElementId,
     base: Stateful<Div>,
     style: StyleRefinement,
     scroll_handle: Option<ScrollHandle>,
@@ -32,6 +71,9 @@ pub struct TabBar {
     size: Size,
     menu: bool,
     on_click: Option<Arc<dyn Fn(&usize, &mut Window, &mut App) + 'static>>,
+    on_event: Option<Arc<dyn Fn(&TabsEvent, &mut Window, &mut App) + 'static>>,
+    on_add: Option<Arc<dyn Fn(&ClickEvent, &mut Window, &mut App) + 'static>>,
+    reorderable: bool,
     /// Special for internal TabPanel to remove the top border.
     tab_item_top_offset: Pixels,
 }
@@ -39,8 +81,10 @@ pub struct TabBar {
 impl TabBar {
     /// Create a new TabBar.
     pub fn new(id: impl Into<ElementId>) -> Self {
+        let id = id.into();
         Self {
-            base: div().id(id).px(px(-1.)),
+            base: div().id(id.clone()).px(px(-1.)),
+            id,
             style: StyleRefinement::default(),
             children: SmallVec::new(),
             scroll_handle: None,
@@ -51,6 +95,9 @@ impl TabBar {
             last_empty_space: div().w_3().into_any_element(),
             selected_index: None,
             on_click: None,
+            on_event: None,
+            on_add: None,
+            reorderable: false,
             menu: false,
             tab_item_top_offset: px(0.),
         }
@@ -143,6 +190,27 @@ impl TabBar {
         self
     }
 
+    /// Set the handler of the [`TabsEvent`], for the closable tabs and the reordering.
+    pub fn on_event(
+        mut self,
+        on_event: impl Fn(&TabsEvent, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_event = Some(Arc::new(on_event));
+        self
+    }
+
+    /// Set true to allow dragging the tabs to reorder, emits [`TabsEvent::Reorder`], default: false
+    pub fn reorderable(mut self, reorderable: bool) -> Self {
+        self.reorderable = reorderable;
+        self
+    }
+
+    /// Show a "+" button after the tabs, and set the click handler of it.
+    pub fn on_add(mut self, on_add: impl Fn(&ClickEvent, &mut Window, &mut App) + 'static) -> Self {
+        self.on_add = Some(Arc::new(on_add));
+        self
+    }
+
     pub(crate) fn tab_item_top_offset(mut self, offset: impl Into<Pixels>) -> Self {
         self.tab_item_top_offset = offset.into();
         self
@@ -261,6 +329,8 @@ impl RenderOnce for TabBar {
                     .gap(gap)
                     .children(self.children.into_iter().enumerate().map(|(ix, child)| {
                         item_labels.push((child.label.clone(), child.disabled));
+                        let label = child.label.clone();
+                        let closable = child.closable;
                         child
                             .id(ix)
                             .mt(self.tab_item_top_offset)
@@ -272,11 +342,56 @@ impl RenderOnce for TabBar {
                             .when_some(self.on_click.clone(), move |this, on_click| {
                                 this.on_click(move |_, window, cx| on_click(&ix, window, cx))
                             })
+                            .when_some(self.on_event.clone(), |this, on_event| {
+                                this.when(closable, |this| {
+                                    let on_event = on_event.clone();
+                                    this.on_close(move |_, window, cx| {
+                                        on_event(&TabsEvent::CloseRequested(ix), window, cx)
+                                    })
+                                })
+                                .when(self.reorderable, |this| {
+                                    let bar_id = self.id.clone();
+                                    this.on_drag(
+                                        DragTab {
+                                            bar_id: bar_id.clone(),
+                                            ix,
+                                            label,
+                                        },
+                                        |drag, _, _, cx| {
+                                            cx.stop_propagation();
+                                            cx.new(|_| drag.clone())
+                                        },
+                                    )
+                                    .drag_over::<DragTab>(|this, _, _, cx| {
+                                        this.border_l_2().border_color(cx.theme().drag_border)
+                                    })
+                                    .on_drop(
+                                        move |drag: &DragTab, window, cx| {
+                                            if drag.bar_id == bar_id && drag.ix != ix {
+                                                on_event(
+                                                    &TabsEvent::Reorder(drag.ix, ix),
+                                                    window,
+                                                    cx,
+                                                );
+                                            }
+                                        },
+                                    )
+                                })
+                            })
                     }))
                     .when(self.suffix.is_some() || self.menu, |this| {
                         this.child(self.last_empty_space)
                     }),
             )
+            .when_some(self.on_add, |this, on_add| {
+                this.child(
+                    Button::new("add")
+                        .xsmall()
+                        .ghost()
+                        .icon(IconName::Plus)
+                        .on_click(move |event, window, cx| on_add(event, window, cx)),
+                )
+            })
             .when(self.menu, |this| {
                 this.child(
                     Button::new("more")
@@ -3,6 +3,8 @@ use gpui::{
     ParentElement as _, Pixels, Render, SharedString, Styled as _, TextAlign, Window,
 };
 
+use serde::{Deserialize, Serialize};
+
 use crate::ActiveTheme as _;
 
 /// Represents a column in a table, used for initializing table columns.
@@ -112,6 +114,12 @@ impl Column {
         self
     }
 
+    /// Set whether the column is fixed on right side, default is false.
+    pub fn fixed_right(mut self) -> Self {
+        self.fixed = Some(ColumnFixed::Right);
+        self
+    }
+
     /// Set whether the column is resizable, default is true.
     pub fn resizable(mut self, resizable: bool) -> Self {
         self.resizable = resizable;
@@ -135,7 +143,33 @@ impl FluentBuilder for Column {}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ColumnFixed {
+    /// The column is pinned to the left edge, the left fixed columns must be the first columns.
     Left,
+    /// The column is pinned to the right edge, the right fixed columns must be the last columns.
+    Right,
+}
+
+/// The number of the fixed columns on each side of the table.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct FixedCols {
+    pub(crate) left: usize,
+    pub(crate) right: usize,
+}
+
+impl FixedCols {
+    pub(crate) fn new(col_groups: &[ColGroup]) -> Self {
+        let count = |fixed: ColumnFixed| {
+            col_groups
+                .iter()
+                .filter(|col| col.column.fixed == Some(fixed))
+                .count()
+        };
+
+        Self {
+            left: count(ColumnFixed::Left),
+            right: count(ColumnFixed::Right),
+        }
+    }
 }
 
 /// Used to sort the column runtime info in Table internal.
@@ -146,6 +180,10 @@ pub(crate) struct ColGroup {
     ///
     /// Including the width with next columns by col_span.
     pub(crate) width: Pixels,
+    /// The flex factor of the column, see [`crate::table::TableDelegate::col_flex`].
+    pub(crate) flex: Option<f32>,
+    /// The extra width from the leftover space of the table for the flex column.
+    pub(crate) flex_extra: Pixels,
     /// The bounds of the column in the table after it renders.
     pub(crate) bounds: Bounds<Pixels>,
 }
@@ -154,6 +192,146 @@ impl ColGroup {
     pub(crate) fn is_resizable(&self) -> bool {
         self.column.resizable
     }
+
+    /// The actual width to render, including the extra width of flex column.
+    pub(crate) fn render_width(&self) -> Pixels {
+        self.width + self.flex_extra
+    }
+}
+
+/// Distribute the leftover space of the `available` width to the flex columns
+/// proportionally by their flex factor.
+///
+/// If the total width of the columns exceeds the `available` width,
+/// the flex columns fall back to their base width.
+pub(crate) fn layout_flex_cols(col_groups: &mut [ColGroup], available: Pixels) {
+    let total_width = col_groups
+        .iter()
+        .fold(px(0.), |total, col_group| total + col_group.width);
+    let total_flex: f32 = col_groups
+        .iter()
+        .filter_map(|col_group| col_group.flex)
+        .filter(|flex| *flex > 0.)
+        .sum();
+    let leftover = available - total_width;
+
+    for col_group in col_groups.iter_mut() {
+        col_group.flex_extra = match col_group.flex {
+            Some(flex) if flex > 0. && leftover > px(0.) => {
+                (leftover * (flex / total_flex)).floor()
+            }
+            _ => px(0.),
+        };
+    }
+}
+
+/// Returns the horizontal scroll offset to make the column at `col_ix` visible,
+/// or `None` if the column is fixed or already visible.
+///
+/// The `viewport_width` is the width of the scrollable area, excluding the fixed columns.
+pub(crate) fn col_scroll_offset(
+    col_groups: &[ColGroup],
+    fixed_cols: FixedCols,
+    col_ix: usize,
+    viewport_width: Pixels,
+    offset_x: Pixels,
+) -> Option<Pixels> {
+    let scrollable_end = col_groups.len().saturating_sub(fixed_cols.right);
+    if col_ix < fixed_cols.left || col_ix >= scrollable_end {
+        return None;
+    }
+
+    let scrollable_cols = &col_groups[fixed_cols.left..scrollable_end];
+    let width_of = |cols: &[ColGroup]| {
+        cols.iter()
+            .fold(px(0.), |total, col_group| total + col_group.render_width())
+    };
+    let col_left = width_of(&scrollable_cols[..col_ix - fixed_cols.left]);
+    let col_right = col_left + col_groups[col_ix].render_width();
+    let max_scroll = (width_of(scrollable_cols) - viewport_width).max(px(0.));
+
+    let scroll = -offset_x;
+    let new_scroll = if col_left < scroll {
+        col_left
+    } else if col_right > scroll + viewport_width {
+        // Align to the left edge if the column is wider than the viewport.
+        (col_right - viewport_width).min(col_left)
+    } else {
+        return None;
+    };
+
+    Some(-new_scroll.clamp(px(0.), max_scroll))
+}
+
+/// Used to serialize and deserialize the columns layout of the Table.
+///
+/// See [`crate::table::Table::dump_columns`] and [`crate::table::Table::restore_columns`].
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TableColumnState {
+    /// The columns in display order.
+    pub columns: Vec<ColumnState>,
+}
+
+/// The layout of a column, identified by the [`Column::key`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ColumnState {
+    pub key: SharedString,
+    pub width: Pixels,
+    /// The flex factor, this will be `None` once the column is resized by user.
+    #[serde(default)]
+    pub flex: Option<f32>,
+}
+
+impl TableColumnState {
+    pub(crate) fn new(col_groups: &[ColGroup]) -> Self {
+        Self {
+            columns: col_groups
+                .iter()
+                .map(|col_group| ColumnState {
+                    key: col_group.column.key.clone(),
+                    width: col_group.width,
+                    flex: col_group.flex,
+                })
+                .collect(),
+        }
+    }
+
+    /// Reorder the `col_groups` by the state and apply the widths.
+    ///
+    /// The columns are matched by key, the unknown keys in the state are ignored,
+    /// and the columns not in the state are kept after the restored ones.
+    ///
+    /// Returns the moves `(col_ix, to_ix)` applied to the `col_groups` in order.
+    pub(crate) fn apply(&self, col_groups: &mut Vec<ColGroup>) -> Vec<(usize, usize)> {
+        let mut moves = vec![];
+        let mut to_ix = 0;
+        for state in self.columns.iter() {
+            let Some(col_ix) = col_groups
+                .iter()
+                .position(|col_group| col_group.column.key == state.key)
+            else {
+                continue;
+            };
+            if col_ix < to_ix {
+                // Duplicate key in the state.
+                continue;
+            }
+
+            if col_ix != to_ix {
+                let col_group = col_groups.remove(col_ix);
+                col_groups.insert(to_ix, col_group);
+                moves.push((col_ix, to_ix));
+            }
+
+            let col_group = &mut col_groups[to_ix];
+            col_group.width = state.width;
+            col_group.flex = state.flex;
+            col_group.flex_extra = px(0.);
+            to_ix += 1;
+        }
+
+        moves
+    }
 }
 
 #[derive(Clone)]
@@ -175,6 +353,17 @@ pub enum ColumnSort {
     Descending,
 }
 
+impl ColumnSort {
+    /// Returns the next sort when toggle the sort: Default -> Descending -> Ascending -> Default.
+    pub(crate) fn next(self) -> Self {
+        match self {
+            ColumnSort::Ascending => ColumnSort::Default,
+            ColumnSort::Descending => ColumnSort::Ascending,
+            ColumnSort::Default => ColumnSort::Descending,
+        }
+    }
+}
+
 impl Render for DragColumn {
     fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
         div()
@@ -200,3 +389,122 @@ impl Render for ResizeColumn {
         Empty
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use gpui::{px, Bounds};
+
+    use super::{
+        col_scroll_offset, layout_flex_cols, ColGroup, Column, ColumnFixed, FixedCols,
+        TableColumnState,
+    };
+
+    fn col_group(width: f32, flex: Option<f32>) -> ColGroup {
+        ColGroup {
+            column: Column::default(),
+            width: px(width),
+            flex,
+            flex_extra: px(0.),
+            bounds: Bounds::default(),
+        }
+    }
+
+    fn keyed_col_groups(keys: &[&'static str]) -> Vec<ColGroup> {
+        keys.iter()
+            .map(|key| ColGroup {
+                column: Column::new(*key, *key),
+                width: px(100.),
+                flex: None,
+                flex_extra: px(0.),
+                bounds: Bounds::default(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_layout_flex_cols() {
+        let mut col_groups = vec![
+            col_group(100., None),
+            col_group(100., Some(1.)),
+            col_group(100., Some(3.)),
+        ];
+
+        layout_flex_cols(&mut col_groups, px(700.));
+        let widths: Vec<_> = col_groups.iter().map(|g| g.render_width()).collect();
+        assert_eq!(widths, vec![px(100.), px(200.), px(400.)]);
+
+        // Fall back to the base width when the columns exceeds the available width.
+        layout_flex_cols(&mut col_groups, px(250.));
+        let widths: Vec<_> = col_groups.iter().map(|g| g.render_width()).collect();
+        assert_eq!(widths, vec![px(100.), px(100.), px(100.)]);
+
+        // Zero flex does not take any space.
+        col_groups[2].flex = Some(0.);
+        layout_flex_cols(&mut col_groups, px(500.));
+        let widths: Vec<_> = col_groups.iter().map(|g| g.render_width()).collect();
+        assert_eq!(widths, vec![px(100.), px(300.), px(100.)]);
+    }
+
+    #[test]
+    fn test_column_state_round_trip() {
+        let mut col_groups = keyed_col_groups(&["id", "name", "price", "change"]);
+        // Move `change` to the first, then `id` to the last.
+        let col_group = col_groups.remove(3);
+        col_groups.insert(0, col_group);
+        let col_group = col_groups.remove(1);
+        col_groups.insert(3, col_group);
+        col_groups[1].width = px(240.);
+        col_groups[1].flex = None;
+
+        let state = TableColumnState::new(&col_groups);
+        let json = serde_json::to_string(&state).unwrap();
+        let state: TableColumnState = serde_json::from_str(&json).unwrap();
+
+        let mut restored = keyed_col_groups(&["id", "name", "price", "change"]);
+        let moves = state.apply(&mut restored);
+        assert_eq!(moves, vec![(3, 0), (2, 1), (3, 2)]);
+
+        let keys: Vec<_> = restored.iter().map(|g| g.column.key.as_ref()).collect();
+        assert_eq!(keys, vec!["change", "name", "price", "id"]);
+        let widths: Vec<_> = restored.iter().map(|g| g.width).collect();
+        assert_eq!(widths, vec![px(100.), px(240.), px(100.), px(100.)]);
+        assert_eq!(restored[1].flex, None);
+        assert_eq!(TableColumnState::new(&restored), state);
+
+        // Unknown keys are ignored, and the new columns are kept at the end.
+        let mut restored = keyed_col_groups(&["id", "name", "volume", "price"]);
+        state.apply(&mut restored);
+        let keys: Vec<_> = restored.iter().map(|g| g.column.key.as_ref()).collect();
+        assert_eq!(keys, vec!["name", "price", "id", "volume"]);
+    }
+
+    #[test]
+    fn test_col_scroll_offset() {
+        let mut col_groups: Vec<_> = (0..10).map(|_| col_group(100., None)).collect();
+        col_groups[0].column.fixed = Some(ColumnFixed::Left);
+        let fixed_cols = FixedCols::new(&col_groups);
+        let viewport = px(300.);
+
+        // Fixed and visible columns do not scroll.
+        assert_eq!(
+            col_scroll_offset(&col_groups, fixed_cols, 0, viewport, px(0.)),
+            None
+        );
+        assert_eq!(
+            col_scroll_offset(&col_groups, fixed_cols, 3, viewport, px(0.)),
+            None
+        );
+
+        // Scroll to the far right column, align its right edge to the viewport.
+        let offset = col_scroll_offset(&col_groups, fixed_cols, 9, viewport, px(0.));
+        assert_eq!(offset, Some(px(-600.)));
+
+        // Scroll back to a column on the left of the viewport.
+        let offset = col_scroll_offset(&col_groups, fixed_cols, 2, viewport, px(-600.));
+        assert_eq!(offset, Some(px(-100.)));
+
+        // Clamp to the content width.
+        let offset = col_scroll_offset(&col_groups, fixed_cols, 9, px(250.), px(0.));
+        assert_eq!(offset, Some(px(-650.)));
+    }
+}
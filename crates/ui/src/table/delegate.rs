@@ -1,8 +1,8 @@
 use std::ops::Range;
 
 use gpui::{
-    div, App, Context, Div, InteractiveElement as _, IntoElement, ParentElement as _, Stateful,
-    Styled as _, Window,
+    div, App, Context, Div, Hsla, InteractiveElement as _, IntoElement, ParentElement as _,
+    SharedString, Stateful, Styled as _, Window,
 };
 
 use crate::{
@@ -24,6 +24,18 @@ pub trait TableDelegate: Sized + 'static {
     /// This only call on Table prepare or refresh.
     fn column(&self, col_ix: usize, cx: &App) -> &Column;
 
+    /// Returns the flex factor of the column at the given index, default is `None`.
+    ///
+    /// After all columns are laid out with their width, the flex columns absorb
+    /// the leftover horizontal space of the table proportionally by this factor.
+    /// If the total width of the columns exceeds the table, the flex columns
+    /// keep their width and the table scrolls.
+    ///
+    /// This only call on Table prepare or refresh.
+    fn col_flex(&self, col_ix: usize, cx: &App) -> Option<f32> {
+        None
+    }
+
     /// Perform sort on the column at the given index.
     fn perform_sort(
         &mut self,
@@ -34,6 +46,21 @@ pub trait TableDelegate: Sized + 'static {
     ) {
     }
 
+    /// Perform sort on multiple columns, the `sorts` is the `(col_ix, sort)` list in precedence order.
+    ///
+    /// This is called when `shift` click the sort icon to add a secondary sort column,
+    /// default to sort by the first column with [`TableDelegate::perform_sort`].
+    fn perform_sort_multi(
+        &mut self,
+        sorts: &[(usize, ColumnSort)],
+        window: &mut Window,
+        cx: &mut Context<Table<Self>>,
+    ) {
+        if let Some((col_ix, sort)) = sorts.first() {
+            self.perform_sort(*col_ix, *sort, window, cx);
+        }
+    }
+
     /// Render the header cell at the given column index, default to the column name.
     fn render_th(
         &self,
@@ -56,6 +83,15 @@ pub trait TableDelegate: Sized + 'static {
         h_flex().id(("row", row_ix))
     }
 
+    /// Returns the accent color of the row at the given row index, default is `None`.
+    ///
+    /// If returns a color, a thin bar will be rendered on the left edge of the row,
+    /// it keeps pinned when the table is scrolled horizontally.
+    /// This is useful to indicate the status or category of the row.
+    fn row_accent(&self, row_ix: usize, cx: &App) -> Option<Hsla> {
+        None
+    }
+
     /// Render the context menu for the row at the given row index.
     fn context_menu(&self, row_ix: usize, menu: PopupMenu, window: &Window, cx: &App) -> PopupMenu {
         menu
@@ -70,6 +106,32 @@ pub trait TableDelegate: Sized + 'static {
         cx: &mut Context<Table<Self>>,
     ) -> impl IntoElement;
 
+    /// Returns the plain text of the cell at the given row and column, default is empty.
+    ///
+    /// This is used by [`Table::export_delimited`] to export the table data.
+    fn cell_text(&self, row_ix: usize, col_ix: usize, cx: &App) -> SharedString {
+        SharedString::default()
+    }
+
+    /// Returns true if the cell at the given row and column can be edited in place, default is false.
+    ///
+    /// Double click on an editable cell to start editing with the value of [`TableDelegate::cell_text`],
+    /// press `enter` or blur to commit, press `escape` to cancel.
+    fn is_cell_editable(&self, row_ix: usize, col_ix: usize, cx: &App) -> bool {
+        false
+    }
+
+    /// Update the cell at the given row and column with the new value after in place editing.
+    fn edit_cell(
+        &mut self,
+        row_ix: usize,
+        col_ix: usize,
+        new_value: SharedString,
+        window: &mut Window,
+        cx: &mut Context<Table<Self>>,
+    ) {
+    }
+
     /// Move the column at the given `col_ix` to insert before the column at the given `to_ix`.
     fn move_column(
         &mut self,
@@ -80,6 +142,21 @@ pub trait TableDelegate: Sized + 'static {
     ) {
     }
 
+    /// Return true to enable the drag handle of the row at the given index, default is false.
+    fn can_move_row(&self, row_ix: usize, cx: &App) -> bool {
+        false
+    }
+
+    /// Move the row at the given `row_ix` to insert before the row at the given `to_ix`.
+    fn move_row(
+        &mut self,
+        row_ix: usize,
+        to_ix: usize,
+        window: &mut Window,
+        cx: &mut Context<Table<Self>>,
+    ) {
+    }
+
     /// Return a Element to show when table is empty.
     fn render_empty(&self, window: &mut Window, cx: &mut Context<Table<Self>>) -> impl IntoElement {
         h_flex()
@@ -131,6 +208,43 @@ pub trait TableDelegate: Sized + 'static {
     /// so you must check if there is more data to load or lock the loading state.
     fn load_more(&mut self, window: &mut Window, cx: &mut Context<Table<Self>>) {}
 
+    /// Return the total number of rows to enable the pagination, default is `None`.
+    ///
+    /// When this returns `Some`, the table renders a footer with the pagination controls
+    /// instead of the infinite scroll, so `load_more` will never be called.
+    /// The delegate only needs to provide the rows of the current page, see [`TableDelegate::set_page`].
+    fn total_rows(&self, cx: &App) -> Option<usize> {
+        None
+    }
+
+    /// Return the number of rows per page when the pagination is enabled.
+    ///
+    /// Default: 20
+    fn page_size(&self, cx: &App) -> usize {
+        20
+    }
+
+    /// Return the options of the page size selector in the pagination footer,
+    /// return an empty list to hide the selector.
+    ///
+    /// Default: `[10, 20, 50, 100]`
+    fn page_size_options(&self, cx: &App) -> Vec<usize> {
+        vec![10, 20, 50, 100]
+    }
+
+    /// Called when the page size is changed by the page size selector,
+    /// then [`TableDelegate::set_page`] will be called to go to the first page.
+    fn set_page_size(
+        &mut self,
+        page_size: usize,
+        window: &mut Window,
+        cx: &mut Context<Table<Self>>,
+    ) {
+    }
+
+    /// Called when the page (0-based) is changed, the delegate should load the rows of the page.
+    fn set_page(&mut self, page: usize, window: &mut Window, cx: &mut Context<Table<Self>>) {}
+
     /// Render the last empty column, default to empty.
     fn render_last_empty_col(
         &mut self,
@@ -0,0 +1,67 @@
+use std::borrow::Cow;
+
+/// Escape a field for delimited text (CSV / TSV) by RFC 4180.
+///
+/// The field will be quoted if it contains the delimiter, a double quote or a line break,
+/// and the double quotes in the field will be escaped as `""`.
+pub(super) fn escape_field(field: &str, delimiter: char) -> Cow<'_, str> {
+    let need_quote = field
+        .chars()
+        .any(|c| c == delimiter || c == '"' || c == '\n' || c == '\r');
+    if !need_quote {
+        return Cow::Borrowed(field);
+    }
+
+    Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+}
+
+/// Append a row of fields to the `out` with the given delimiter, the row is ended with CRLF.
+pub(super) fn write_row<'a>(
+    out: &mut String,
+    fields: impl IntoIterator<Item = &'a str>,
+    delimiter: char,
+) {
+    for (ix, field) in fields.into_iter().enumerate() {
+        if ix > 0 {
+            out.push(delimiter);
+        }
+        out.push_str(&escape_field(field, delimiter));
+    }
+    out.push_str("\r\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{escape_field, write_row};
+
+    #[test]
+    fn test_escape_field() {
+        assert_eq!(escape_field("Hello", ','), "Hello");
+        assert_eq!(escape_field("", ','), "");
+        assert_eq!(escape_field("Hello, World", ','), "\"Hello, World\"");
+        assert_eq!(escape_field("Hello, World", '\t'), "Hello, World");
+        assert_eq!(escape_field("a\tb", '\t'), "\"a\tb\"");
+        assert_eq!(escape_field("say \"hi\"", ','), "\"say \"\"hi\"\"\"");
+        assert_eq!(escape_field("line 1\nline 2", ','), "\"line 1\nline 2\"");
+        assert_eq!(
+            escape_field("line 1\r\nline 2", ','),
+            "\"line 1\r\nline 2\""
+        );
+    }
+
+    #[test]
+    fn test_write_row() {
+        let mut out = String::new();
+        write_row(&mut out, ["Name", "Price"], ',');
+        write_row(&mut out, ["Apple, Inc.", "1,000"], ',');
+        write_row(&mut out, ["6\" Pie", "multi\nline"], ',');
+        assert_eq!(
+            out,
+            "Name,Price\r\n\"Apple, Inc.\",\"1,000\"\r\n\"6\"\" Pie\",\"multi\nline\"\r\n"
+        );
+
+        let mut out = String::new();
+        write_row(&mut out, ["a", "b, c", ""], '\t');
+        assert_eq!(out, "a\tb, c\t\r\n");
+    }
+}
@@ -1,37 +1,54 @@
-use std::{ops::Range, rc::Rc, time::Duration};
+use std::{collections::BTreeSet, ops::Range, rc::Rc, time::Duration};
 
 use crate::{
-    actions::{Cancel, SelectNext, SelectPrev},
+    actions::{Cancel, Confirm, SelectNext, SelectPrev},
     context_menu::ContextMenuExt,
     h_flex,
+    index_path::moved_ix,
+    indicator::Indicator,
+    input::{InputEvent, InputState, TextInput},
     popup_menu::PopupMenu,
     scroll::{self, ScrollableMask, Scrollbar, ScrollbarState},
-    v_flex, ActiveTheme, Icon, IconName, Sizable, Size, StyleSized as _, StyledExt,
+    tooltip::{FocusableTooltip as _, Tooltip},
+    v_flex, ActiveTheme, Colorize as _, Icon, IconName, Sizable, Size, StyleSized as _, StyledExt,
     VirtualListScrollHandle,
 };
 use gpui::{
-    actions, canvas, div, prelude::FluentBuilder, px, uniform_list, App, AppContext, Axis, Bounds,
-    Context, Div, DragMoveEvent, Edges, EventEmitter, FocusHandle, Focusable, InteractiveElement,
-    IntoElement, KeyBinding, ListSizingBehavior, MouseButton, MouseDownEvent, ParentElement,
-    Pixels, Point, Render, ScrollStrategy, ScrollWheelEvent, SharedString,
-    StatefulInteractiveElement as _, Styled, Task, UniformListScrollHandle, Window,
+    actions, canvas, div, ease_in_out, point, prelude::FluentBuilder, px, uniform_list, App,
+    AppContext, Axis, Bounds, Context, Div, DragMoveEvent, Edges, ElementId, Entity, EntityId,
+    EventEmitter, FocusHandle, Focusable, InteractiveElement, IntoElement, KeyBinding,
+    ListSizingBehavior, MouseButton, MouseDownEvent, ParentElement, Pixels, Point, Render,
+    ScrollStrategy, ScrollWheelEvent, SharedString, StatefulInteractiveElement as _, Styled,
+    Subscription, Task, UniformListScrollHandle, Window,
 };
+use rust_i18n::t;
 
 mod column;
 mod delegate;
+mod export;
 mod loading;
+mod pagination;
+mod selection;
 
 pub use column::*;
 pub use delegate::*;
+use pagination::SetPageSize;
+use selection::RowSelection;
 
 actions!(table, [SelectPrevColumn, SelectNextColumn]);
 
+/// The width of the row accent bar, see [`TableDelegate::row_accent`].
+const ROW_ACCENT_WIDTH: Pixels = px(3.);
+
 pub fn init(cx: &mut App) {
     let context = Some("Table");
     cx.bind_keys([
         KeyBinding::new("escape", Cancel, context),
+        KeyBinding::new("enter", Confirm { secondary: false }, context),
         KeyBinding::new("up", SelectPrev, context),
         KeyBinding::new("down", SelectNext, context),
+        KeyBinding::new("shift-up", SelectPrev, context),
+        KeyBinding::new("shift-down", SelectNext, context),
         KeyBinding::new("left", SelectPrevColumn, context),
         KeyBinding::new("right", SelectNextColumn, context),
     ]);
@@ -43,15 +60,70 @@ enum SelectionState {
     Row,
 }
 
+/// The selection mode of the table rows.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum SelectionMode {
+    /// Only one row can be selected.
+    #[default]
+    Single,
+    /// Multiple rows can be selected, `shift` click to select a range,
+    /// `ctrl` (`cmd` on macOS) click to toggle a row.
+    Multi,
+}
+
 #[derive(Clone)]
 pub enum TableEvent {
     /// Single click or move to selected row.
     SelectRow(usize),
-    /// Double click on the row.
-    DoubleClickedRow(usize),
+    /// The selected rows changed in [`SelectionMode::Multi`], sorted by row index.
+    SelectRows(Vec<usize>),
+    /// Activate the row by double click or press `enter` on the selected row.
+    ActivateRow(usize),
     SelectColumn(usize),
     ColumnWidthsChanged(Vec<Pixels>),
     MoveColumn(usize, usize),
+    /// The row has been moved by drag and drop, see [`TableDelegate::move_row`].
+    MoveRow(usize, usize),
+    /// The cell has been edited in place, see [`TableDelegate::edit_cell`].
+    CellEdited {
+        row: usize,
+        col: usize,
+    },
+    /// The page has been changed in the pagination footer, see [`TableDelegate::set_page`].
+    PageChanged(usize),
+}
+
+/// The cell is being edited in place.
+struct EditingCell {
+    row_ix: usize,
+    col_ix: usize,
+    input: Entity<InputState>,
+    _subscription: Subscription,
+}
+
+/// The drag payload of the row, see [`TableDelegate::can_move_row`].
+#[derive(Clone)]
+struct DragRow {
+    entity_id: EntityId,
+    row_ix: usize,
+}
+
+impl Render for DragRow {
+    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        h_flex()
+            .px_2()
+            .py_1()
+            .gap_1()
+            .bg(cx.theme().table_head)
+            .text_color(cx.theme().muted_foreground)
+            .text_sm()
+            .opacity(0.9)
+            .border_1()
+            .border_color(cx.theme().border)
+            .shadow_md()
+            .child(Icon::new(IconName::Menu).xsmall())
+            .child(format!("{}", self.row_ix + 1))
+    }
 }
 
 /// The visible range of the rows and columns.
@@ -82,8 +154,13 @@ pub struct Table<D: TableDelegate> {
     bounds: Bounds<Pixels>,
     /// The bounds of the fixed head cols.
     fixed_head_cols_bounds: Bounds<Pixels>,
+    /// The bounds of the right fixed head cols.
+    fixed_right_head_cols_bounds: Bounds<Pixels>,
 
     col_groups: Vec<ColGroup>,
+    fixed_cols: FixedCols,
+    /// The sorted columns in precedence order, see [`TableDelegate::perform_sort_multi`].
+    sorts: Vec<(usize, ColumnSort)>,
 
     /// Whether the table can loop selection, default is true.
     ///
@@ -101,6 +178,10 @@ pub struct Table<D: TableDelegate> {
     pub col_movable: bool,
     /// Enable/disable fixed columns feature.
     pub col_fixed: bool,
+    /// The selection mode of the rows, default is [`SelectionMode::Single`].
+    pub selection_mode: SelectionMode,
+    /// Show the busy overlay over the rows, see [`Table::set_busy`].
+    busy: bool,
 
     pub vertical_scroll_handle: UniformListScrollHandle,
     pub vertical_scroll_state: ScrollbarState,
@@ -109,12 +190,17 @@ pub struct Table<D: TableDelegate> {
 
     scrollbar_visible: Edges<bool>,
     selected_row: Option<usize>,
+    row_selection: RowSelection,
     selection_state: SelectionState,
     right_clicked_row: Option<usize>,
     selected_col: Option<usize>,
+    /// Whether the sort tooltip of the selected column is dismissed by `escape`.
+    sort_tooltip_dismissed: bool,
 
     /// The column index that is being resized.
     resizing_col: Option<usize>,
+    /// The cell that is being edited.
+    editing_cell: Option<EditingCell>,
 
     /// Set stripe style of the table.
     stripe: bool,
@@ -124,6 +210,8 @@ pub struct Table<D: TableDelegate> {
     size: Size,
     /// The visible range of the rows and columns.
     visible_range: VisibleRangeState,
+    /// The current page (0-based) when the pagination is enabled.
+    page: usize,
 
     _measure: Vec<Duration>,
     _load_more_task: Task<()>,
@@ -138,22 +226,29 @@ where
             focus_handle: cx.focus_handle(),
             delegate,
             col_groups: Vec::new(),
+            fixed_cols: FixedCols::default(),
+            sorts: Vec::new(),
             horizontal_scroll_handle: VirtualListScrollHandle::new(),
             vertical_scroll_handle: UniformListScrollHandle::new(),
             vertical_scroll_state: ScrollbarState::default(),
             horizontal_scroll_state: ScrollbarState::default(),
             selection_state: SelectionState::Row,
             selected_row: None,
+            row_selection: RowSelection::default(),
             right_clicked_row: None,
             selected_col: None,
+            sort_tooltip_dismissed: false,
             resizing_col: None,
+            editing_cell: None,
             bounds: Bounds::default(),
             fixed_head_cols_bounds: Bounds::default(),
+            fixed_right_head_cols_bounds: Bounds::default(),
             stripe: false,
             border: true,
             size: Size::default(),
             scrollbar_visible: Edges::all(true),
             visible_range: VisibleRangeState::default(),
+            page: 0,
             loop_selection: true,
             col_selectable: true,
             row_selectable: true,
@@ -161,6 +256,8 @@ where
             col_movable: true,
             col_resizable: true,
             col_fixed: true,
+            selection_mode: SelectionMode::default(),
+            busy: false,
             _load_more_task: Task::ready(()),
             _measure: Vec::new(),
         };
@@ -230,6 +327,12 @@ where
         self
     }
 
+    /// Set the selection mode of the rows, default is [`SelectionMode::Single`].
+    pub fn selection_mode(mut self, selection_mode: SelectionMode) -> Self {
+        self.selection_mode = selection_mode;
+        self
+    }
+
     /// Set the size to the table.
     pub fn set_size(&mut self, size: Size, cx: &mut Context<Self>) {
         self.size = size;
@@ -262,23 +365,69 @@ where
                 let column = self.delegate().column(col_ix, cx);
                 ColGroup {
                     width: column.width,
+                    flex: self.delegate().col_flex(col_ix, cx),
+                    flex_extra: px(0.),
                     bounds: Bounds::default(),
                     column: column.clone(),
                 }
             })
             .collect();
+        self.fixed_cols = FixedCols::new(&self.col_groups);
+        let col_groups = &self.col_groups;
+        self.sorts.retain(|(col_ix, sort)| {
+            col_groups
+                .get(*col_ix)
+                .is_some_and(|g| g.column.sort == Some(*sort))
+        });
         cx.notify();
     }
 
-    fn fixed_left_cols_count(&self) -> usize {
+    /// Returns the sorted columns `(col_ix, sort)` in precedence order.
+    pub fn sorts(&self) -> &[(usize, ColumnSort)] {
+        &self.sorts
+    }
+
+    /// Dump the columns layout of the table, including the column order and widths.
+    ///
+    /// Use [`Table::restore_columns`] to apply it back.
+    pub fn dump_columns(&self) -> TableColumnState {
+        TableColumnState::new(&self.col_groups)
+    }
+
+    /// Restore the columns order and widths from the [`TableColumnState`].
+    ///
+    /// The columns are matched by [`Column::key`], the moves are forwarded to
+    /// [`TableDelegate::move_column`] to keep the delegate columns in the same order,
+    /// the rows data is not touched.
+    pub fn restore_columns(
+        &mut self,
+        state: &TableColumnState,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let moves = state.apply(&mut self.col_groups);
+        for (col_ix, to_ix) in moves {
+            self.delegate.move_column(col_ix, to_ix, window, cx);
+            self.move_sorts(col_ix, to_ix);
+        }
+        self.fixed_cols = FixedCols::new(&self.col_groups);
+        cx.notify();
+    }
+
+    /// Distribute the leftover width of the table to the flex columns.
+    fn layout_flex_cols(&mut self) {
+        let border_width = if self.border { px(2.) } else { px(0.) };
+        // Keep the space of the last empty column for the vertical scrollbar.
+        let available = self.bounds.size.width - border_width - scroll::WIDTH;
+        layout_flex_cols(&mut self.col_groups, available);
+    }
+
+    fn fixed_cols(&self) -> FixedCols {
         if !self.col_fixed {
-            return 0;
+            return FixedCols::default();
         }
 
-        self.col_groups
-            .iter()
-            .filter(|col| col.column.fixed == Some(ColumnFixed::Left))
-            .count()
+        self.fixed_cols
     }
 
     /// Scroll to the row at the given index.
@@ -288,15 +437,83 @@ where
         cx.notify();
     }
 
+    /// Scroll to the row at the given index with an animation.
+    ///
+    /// The animation is cancelled if the user scrolls manually.
+    pub fn scroll_to_row_animated(
+        &mut self,
+        row_ix: usize,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let rows_count = self.delegate.rows_count(cx);
+        let (item_size, scroll_handle) = {
+            let state = self.vertical_scroll_handle.0.borrow();
+            (state.last_item_size, state.base_handle.clone())
+        };
+        let Some(item_size) = item_size.filter(|_| rows_count > 0) else {
+            // Not laid out yet, scroll without animation.
+            self.scroll_to_row(row_ix, cx);
+            return;
+        };
+
+        let row_height = item_size.contents.height / rows_count as f32;
+        let max_offset = scroll_handle.max_offset().height;
+        let offset = scroll_handle.offset();
+        let target_y = -(row_height * row_ix.min(rows_count - 1) as f32).min(max_offset);
+
+        scroll::animate_scroll_to(
+            &scroll_handle,
+            point(offset.x, target_y),
+            scroll::SCROLL_ANIMATION_DURATION,
+            ease_in_out,
+            window,
+            cx,
+        );
+    }
+
     // Scroll to the column at the given index.
     pub fn scroll_to_col(&mut self, col_ix: usize, cx: &mut Context<Self>) {
-        let col_ix = col_ix.saturating_sub(self.fixed_left_cols_count());
+        // The fixed columns are always visible.
+        let fixed_cols = self.fixed_cols();
+        let viewport_width = self.bounds.size.width
+            - self.fixed_head_cols_bounds.size.width
+            - self.fixed_right_head_cols_bounds.size.width;
+        let mut offset = self.horizontal_scroll_handle.offset();
+        let Some(offset_x) = col_scroll_offset(
+            &self.col_groups,
+            fixed_cols,
+            col_ix,
+            viewport_width,
+            offset.x,
+        ) else {
+            return;
+        };
 
-        self.horizontal_scroll_handle
-            .scroll_to_item(col_ix, ScrollStrategy::Top);
+        offset.x = offset_x;
+        self.horizontal_scroll_handle.set_offset(offset);
         cx.notify();
     }
 
+    /// Set the table is busy to show a busy overlay over the existing rows,
+    /// e.g. when the delegate is reloading or sorting the data in background.
+    ///
+    /// Unlike the [`TableDelegate::loading`] that replaces the table with the skeleton,
+    /// the rows and scroll position are kept, and the input is blocked until it is done.
+    pub fn set_busy(&mut self, busy: bool, cx: &mut Context<Self>) {
+        if self.busy == busy {
+            return;
+        }
+
+        self.busy = busy;
+        cx.notify();
+    }
+
+    /// Returns true if the table is busy, see [`Table::set_busy`].
+    pub fn is_busy(&self) -> bool {
+        self.busy
+    }
+
     /// Returns the selected row index.
     pub fn selected_row(&self) -> Option<usize> {
         self.selected_row
@@ -307,11 +524,75 @@ where
         self.selection_state = SelectionState::Row;
         self.right_clicked_row = None;
         self.selected_row = Some(row_ix);
+        let changed = self.row_selection.select(row_ix);
         if let Some(row_ix) = self.selected_row {
             self.vertical_scroll_handle
                 .scroll_to_item(row_ix, ScrollStrategy::Top);
         }
         cx.emit(TableEvent::SelectRow(row_ix));
+        if changed && self.selection_mode == SelectionMode::Multi {
+            cx.emit(TableEvent::SelectRows(self.row_selection.to_vec()));
+        }
+        cx.notify();
+    }
+
+    /// Returns the selected row indexes.
+    pub fn selected_rows(&self) -> &BTreeSet<usize> {
+        &self.row_selection.rows
+    }
+
+    /// Sets the selected rows to the given indexes.
+    ///
+    /// In [`SelectionMode::Single`] mode, only the first row will be selected.
+    pub fn set_selected_rows(
+        &mut self,
+        rows: impl IntoIterator<Item = usize>,
+        cx: &mut Context<Self>,
+    ) {
+        let mut rows: BTreeSet<usize> = rows.into_iter().collect();
+        if self.selection_mode == SelectionMode::Single {
+            match rows.first() {
+                Some(&row_ix) => self.set_selected_row(row_ix, cx),
+                None => self.clear_selection(cx),
+            }
+            return;
+        }
+
+        let rows_count = self.delegate.rows_count(cx);
+        rows.retain(|row_ix| *row_ix < rows_count);
+
+        self.selection_state = SelectionState::Row;
+        self.right_clicked_row = None;
+        self.selected_row = rows.last().copied();
+        if self.row_selection.set(rows) {
+            cx.emit(TableEvent::SelectRows(self.row_selection.to_vec()));
+        }
+        cx.notify();
+    }
+
+    /// Select the rows from the anchor row to the given row.
+    fn extend_selection_to(&mut self, row_ix: usize, cx: &mut Context<Self>) {
+        let changed = self.row_selection.extend_to(row_ix, self.selected_row);
+
+        self.selection_state = SelectionState::Row;
+        self.right_clicked_row = None;
+        self.selected_row = Some(row_ix);
+        self.vertical_scroll_handle
+            .scroll_to_item(row_ix, ScrollStrategy::Top);
+        if changed {
+            cx.emit(TableEvent::SelectRows(self.row_selection.to_vec()));
+        }
+        cx.notify();
+    }
+
+    /// Toggle the selection of the given row, and make it the new anchor.
+    fn toggle_row_selection(&mut self, row_ix: usize, cx: &mut Context<Self>) {
+        self.row_selection.toggle(row_ix);
+
+        self.selection_state = SelectionState::Row;
+        self.right_clicked_row = None;
+        self.selected_row = Some(row_ix);
+        cx.emit(TableEvent::SelectRows(self.row_selection.to_vec()));
         cx.notify();
     }
 
@@ -324,6 +605,7 @@ where
     pub fn set_selected_col(&mut self, col_ix: usize, cx: &mut Context<Self>) {
         self.selection_state = SelectionState::Column;
         self.selected_col = Some(col_ix);
+        self.sort_tooltip_dismissed = false;
         if let Some(col_ix) = self.selected_col {
             self.scroll_to_col(col_ix, cx);
         }
@@ -335,10 +617,110 @@ where
     pub fn clear_selection(&mut self, cx: &mut Context<Self>) {
         self.selection_state = SelectionState::Row;
         self.selected_row = None;
+        let changed = self.row_selection.clear();
         self.selected_col = None;
+        self.sort_tooltip_dismissed = false;
+        if changed && self.selection_mode == SelectionMode::Multi {
+            cx.emit(TableEvent::SelectRows(vec![]));
+        }
         cx.notify();
     }
 
+    /// Returns the `(row_ix, col_ix)` of the cell that is being edited.
+    pub fn editing_cell(&self) -> Option<(usize, usize)> {
+        self.editing_cell
+            .as_ref()
+            .map(|cell| (cell.row_ix, cell.col_ix))
+    }
+
+    /// Start editing the cell at the given row and column in place.
+    ///
+    /// Do nothing if the cell is not editable, see [`TableDelegate::is_cell_editable`].
+    pub fn start_editing(
+        &mut self,
+        row_ix: usize,
+        col_ix: usize,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if !self.delegate.is_cell_editable(row_ix, col_ix, cx) {
+            return;
+        }
+
+        self.commit_editing(window, cx);
+
+        let value = self.delegate.cell_text(row_ix, col_ix, cx);
+        let input = cx.new(|cx| InputState::new(window, cx).default_value(value));
+        let _subscription = cx.subscribe_in(
+            &input,
+            window,
+            |table, _, event: &InputEvent, window, cx| match event {
+                InputEvent::PressEnter { .. } => {
+                    table.commit_editing(window, cx);
+                    table.focus_handle.focus(window);
+                }
+                InputEvent::Blur => table.commit_editing(window, cx),
+                _ => {}
+            },
+        );
+        input.update(cx, |input, cx| input.focus(window, cx));
+
+        self.editing_cell = Some(EditingCell {
+            row_ix,
+            col_ix,
+            input,
+            _subscription,
+        });
+        cx.notify();
+    }
+
+    /// Commit the value of the editing cell to the delegate.
+    fn commit_editing(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(cell) = self.editing_cell.take() else {
+            return;
+        };
+
+        let value = cell.input.read(cx).value();
+        self.delegate
+            .edit_cell(cell.row_ix, cell.col_ix, value, window, cx);
+        cx.emit(TableEvent::CellEdited {
+            row: cell.row_ix,
+            col: cell.col_ix,
+        });
+        cx.notify();
+    }
+
+    /// Cancel the editing without commit the value.
+    fn cancel_editing(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.editing_cell.take().is_some() {
+            self.focus_handle.focus(window);
+            cx.notify();
+        }
+    }
+
+    /// Export all rows of the table as delimited text, e.g. `,` for CSV, `\t` for TSV.
+    ///
+    /// The first line is the header with the column names, and the cell values
+    /// come from [`TableDelegate::cell_text`]. Fields are quoted by RFC 4180.
+    pub fn export_delimited(&self, delimiter: char, cx: &App) -> String {
+        let columns_count = self.delegate.columns_count(cx);
+        let mut out = String::new();
+
+        export::write_row(
+            &mut out,
+            (0..columns_count).map(|col_ix| self.delegate.column(col_ix, cx).name.as_ref()),
+            delimiter,
+        );
+        for row_ix in 0..self.delegate.rows_count(cx) {
+            let cells = (0..columns_count)
+                .map(|col_ix| self.delegate.cell_text(row_ix, col_ix, cx))
+                .collect::<Vec<_>>();
+            export::write_row(&mut out, cells.iter().map(|cell| cell.as_ref()), delimiter);
+        }
+
+        out
+    }
+
     /// Returns the visible range of the rows and columns.
     pub fn visible_range(&self) -> &VisibleRangeState {
         &self.visible_range
@@ -354,10 +736,17 @@ where
         if ev.button == MouseButton::Right {
             self.right_clicked_row = Some(row_ix);
         } else {
-            self.set_selected_row(row_ix, cx);
+            let multi = self.selection_mode == SelectionMode::Multi;
+            if multi && ev.modifiers.shift {
+                self.extend_selection_to(row_ix, cx);
+            } else if multi && ev.modifiers.secondary() {
+                self.toggle_row_selection(row_ix, cx);
+            } else {
+                self.set_selected_row(row_ix, cx);
+            }
 
             if ev.click_count == 2 {
-                cx.emit(TableEvent::DoubleClickedRow(row_ix));
+                cx.emit(TableEvent::ActivateRow(row_ix));
             }
         }
     }
@@ -382,7 +771,30 @@ where
         self.selected_row.is_some() || self.selected_col.is_some()
     }
 
-    fn action_cancel(&mut self, _: &Cancel, _: &mut Window, cx: &mut Context<Self>) {
+    /// Whether the sort tooltip of the selected column is shown by keyboard.
+    fn is_sort_tooltip_visible(&self, window: &Window) -> bool {
+        self.sortable
+            && !self.sort_tooltip_dismissed
+            && self.focus_handle.is_focused(window)
+            && self
+                .selected_col
+                .and_then(|col_ix| self.col_groups.get(col_ix))
+                .map_or(false, |col_group| col_group.column.sort.is_some())
+    }
+
+    fn action_cancel(&mut self, _: &Cancel, window: &mut Window, cx: &mut Context<Self>) {
+        if self.editing_cell.is_some() {
+            self.cancel_editing(window, cx);
+            return;
+        }
+
+        // Dismiss the sort tooltip first, the sort icon is never focused.
+        if self.is_sort_tooltip_visible(window) {
+            self.sort_tooltip_dismissed = true;
+            cx.notify();
+            return;
+        }
+
         if self.has_selection() {
             self.clear_selection(cx);
             return;
@@ -390,7 +802,43 @@ where
         cx.propagate();
     }
 
-    fn action_select_prev(&mut self, _: &SelectPrev, _: &mut Window, cx: &mut Context<Self>) {
+    /// Select the row by keyboard, extend the selection if `shift` is held in multi mode.
+    fn select_row_by_keyboard(
+        &mut self,
+        row_ix: usize,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.selection_mode == SelectionMode::Multi && window.modifiers().shift {
+            self.extend_selection_to(row_ix, cx);
+        } else {
+            self.set_selected_row(row_ix, cx);
+        }
+    }
+
+    fn action_confirm(&mut self, _: &Confirm, _: &mut Window, cx: &mut Context<Self>) {
+        if self.editing_cell.is_some() {
+            return;
+        }
+
+        let Some(row_ix) = self.selected_row else {
+            cx.propagate();
+            return;
+        };
+
+        if self.selection_state != SelectionState::Row {
+            cx.propagate();
+            return;
+        }
+
+        cx.emit(TableEvent::ActivateRow(row_ix));
+    }
+
+    fn action_select_prev(&mut self, _: &SelectPrev, window: &mut Window, cx: &mut Context<Self>) {
+        if self.editing_cell.is_some() {
+            return;
+        }
+
         let rows_count = self.delegate.rows_count(cx);
         if rows_count < 1 {
             return;
@@ -405,10 +853,14 @@ where
             }
         }
 
-        self.set_selected_row(selected_row, cx);
+        self.select_row_by_keyboard(selected_row, window, cx);
     }
 
-    fn action_select_next(&mut self, _: &SelectNext, _: &mut Window, cx: &mut Context<Self>) {
+    fn action_select_next(&mut self, _: &SelectNext, window: &mut Window, cx: &mut Context<Self>) {
+        if self.editing_cell.is_some() {
+            return;
+        }
+
         let rows_count = self.delegate.rows_count(cx);
         if rows_count < 1 {
             return;
@@ -426,7 +878,7 @@ where
             _ => 0,
         };
 
-        self.set_selected_row(selected_row, cx);
+        self.select_row_by_keyboard(selected_row, window, cx);
     }
 
     fn action_select_prev_col(
@@ -510,7 +962,7 @@ where
         }
         let size = size.floor();
 
-        let old_width = col_group.width;
+        let old_width = col_group.render_width();
         let new_width = size;
         if new_width < MIN_WIDTH {
             return;
@@ -520,6 +972,9 @@ where
         if changed_width > px(-1.0) && changed_width < px(1.0) {
             return;
         }
+        // The flex column becomes a fixed width column once it is resized by user.
+        col_group.flex = None;
+        col_group.flex_extra = px(0.);
         col_group.width = new_width.min(MAX_WIDTH);
 
         cx.notify();
@@ -530,33 +985,55 @@ where
             return;
         }
 
-        let sort = self.col_groups.get(col_ix).and_then(|g| g.column.sort);
-        if sort.is_none() {
+        let Some(sort) = self.col_groups.get(col_ix).and_then(|g| g.column.sort) else {
             return;
-        }
-
-        let sort = sort.unwrap();
-        let sort = match sort {
-            ColumnSort::Ascending => ColumnSort::Default,
-            ColumnSort::Descending => ColumnSort::Ascending,
-            ColumnSort::Default => ColumnSort::Descending,
         };
 
-        for (ix, col_group) in self.col_groups.iter_mut().enumerate() {
-            if ix == col_ix {
-                col_group.column.sort = Some(sort);
-            } else {
-                if col_group.column.sort.is_some() {
+        let sort = sort.next();
+        let multi = window.modifiers().shift;
+        if multi {
+            if let Some(ix) = self.sorts.iter().position(|(ix, _)| *ix == col_ix) {
+                if sort == ColumnSort::Default {
+                    self.sorts.remove(ix);
+                } else {
+                    self.sorts[ix].1 = sort;
+                }
+            } else if sort != ColumnSort::Default {
+                self.sorts.push((col_ix, sort));
+            }
+            self.col_groups[col_ix].column.sort = Some(sort);
+        } else {
+            self.sorts.clear();
+            if sort != ColumnSort::Default {
+                self.sorts.push((col_ix, sort));
+            }
+
+            for (ix, col_group) in self.col_groups.iter_mut().enumerate() {
+                if ix == col_ix {
+                    col_group.column.sort = Some(sort);
+                } else if col_group.column.sort.is_some() {
                     col_group.column.sort = Some(ColumnSort::Default);
                 }
             }
         }
 
-        self.delegate_mut().perform_sort(col_ix, sort, window, cx);
+        if multi {
+            let sorts = self.sorts.clone();
+            self.delegate_mut().perform_sort_multi(&sorts, window, cx);
+        } else {
+            self.delegate_mut().perform_sort(col_ix, sort, window, cx);
+        }
 
         cx.notify();
     }
 
+    /// Keep the sorted column indices in sync after the column moved.
+    fn move_sorts(&mut self, col_ix: usize, to_ix: usize) {
+        for (ix, _) in self.sorts.iter_mut() {
+            *ix = moved_ix(*ix, col_ix, to_ix);
+        }
+    }
+
     fn move_column(
         &mut self,
         col_ix: usize,
@@ -571,11 +1048,70 @@ where
         self.delegate.move_column(col_ix, to_ix, window, cx);
         let col_group = self.col_groups.remove(col_ix);
         self.col_groups.insert(to_ix, col_group);
+        self.move_sorts(col_ix, to_ix);
 
         cx.emit(TableEvent::MoveColumn(col_ix, to_ix));
         cx.notify();
     }
 
+    fn move_row(
+        &mut self,
+        row_ix: usize,
+        to_ix: usize,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if row_ix == to_ix || !self.delegate.can_move_row(row_ix, cx) {
+            return;
+        }
+
+        self.delegate.move_row(row_ix, to_ix, window, cx);
+        // Keep the selection on the same rows.
+        self.selected_row = self.selected_row.map(|ix| moved_ix(ix, row_ix, to_ix));
+        self.row_selection.rows = self
+            .row_selection
+            .rows
+            .iter()
+            .map(|ix| moved_ix(*ix, row_ix, to_ix))
+            .collect();
+        self.row_selection.anchor = self
+            .row_selection
+            .anchor
+            .map(|ix| moved_ix(ix, row_ix, to_ix));
+
+        cx.emit(TableEvent::MoveRow(row_ix, to_ix));
+        cx.notify();
+    }
+
+    /// Scroll table when dragging the row near the top or bottom edge of the table,
+    /// to allow dropping the row to the off-screen target.
+    fn scroll_table_by_row_dragging(
+        &mut self,
+        mouse_position: Point<Pixels>,
+        cx: &mut Context<Self>,
+    ) {
+        let scroll_handle = self.vertical_scroll_handle.0.borrow().base_handle.clone();
+        let bounds = scroll_handle.bounds();
+        if mouse_position.x < bounds.left() || mouse_position.x > bounds.right() {
+            return;
+        }
+
+        let row_height = self.size.table_row_height();
+        let rows_count = self.delegate.rows_count(cx);
+        let max_offset = (row_height * rows_count as f32 - bounds.size.height).max(px(0.));
+        let mut offset = scroll_handle.offset();
+        if mouse_position.y < bounds.top() + row_height {
+            offset.y = (offset.y + px(4.)).min(px(0.));
+        } else if mouse_position.y > bounds.bottom() - row_height {
+            offset.y = (offset.y - px(4.)).max(-max_offset);
+        } else {
+            return;
+        }
+
+        scroll_handle.set_offset(offset);
+        cx.notify();
+    }
+
     /// Dispatch delegate's `load_more` method when the visible range is near the end.
     fn load_more_if_need(
         &mut self,
@@ -634,7 +1170,7 @@ where
             return div();
         };
 
-        let col_width = col_group.width;
+        let col_width = col_group.render_width();
         let col_padding = col_group.column.paddings;
 
         div()
@@ -654,6 +1190,49 @@ where
             })
     }
 
+    /// Render the td of the cell, or the input if the cell is being edited.
+    fn render_td_cell(
+        &mut self,
+        row_ix: usize,
+        col_ix: usize,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Div {
+        let editing_input = self
+            .editing_cell
+            .as_ref()
+            .filter(|cell| cell.row_ix == row_ix && cell.col_ix == col_ix)
+            .map(|cell| cell.input.clone());
+        let editable = self.delegate.is_cell_editable(row_ix, col_ix, cx);
+
+        let content = match editing_input {
+            Some(input) => TextInput::new(&input)
+                .appearance(false)
+                .with_size(self.size)
+                .into_any_element(),
+            None => self
+                .measure_render_td(row_ix, col_ix, window, cx)
+                .into_any_element(),
+        };
+
+        self.render_col_wrap(col_ix, window, cx).child(
+            self.render_cell(col_ix, window, cx)
+                .child(content)
+                .when(editable, |this| {
+                    this.on_mouse_down(
+                        MouseButton::Left,
+                        cx.listener(move |table, ev: &MouseDownEvent, window, cx| {
+                            if ev.click_count == 2 {
+                                // Avoid the row to emit the `ActivateRow` event.
+                                cx.stop_propagation();
+                                table.start_editing(row_ix, col_ix, window, cx);
+                            }
+                        }),
+                    )
+                }),
+        )
+    }
+
     /// Show Column selection style, when the column is selected and the selection state is Column.
     fn render_col_wrap(&self, col_ix: usize, _: &mut Window, cx: &mut Context<Self>) -> Div {
         let el = h_flex().h_full();
@@ -707,7 +1286,7 @@ where
             .occlude()
             .absolute()
             .left(self.fixed_head_cols_bounds.size.width)
-            .right_0()
+            .right(self.fixed_right_head_cols_bounds.size.width)
             .bottom_0()
             .h(scroll::WIDTH)
             .on_scroll_wheel(cx.listener(|_, _: &ScrollWheelEvent, _, cx| {
@@ -818,7 +1397,7 @@ where
         &self,
         col_ix: usize,
         col_group: &ColGroup,
-        _: &mut Window,
+        window: &mut Window,
         cx: &mut Context<Self>,
     ) -> Option<impl IntoElement> {
         if !self.sortable {
@@ -829,15 +1408,27 @@ where
             return None;
         };
 
+        let ordinal = if self.sorts.len() > 1 {
+            self.sorts
+                .iter()
+                .position(|(ix, _)| *ix == col_ix)
+                .map(|ix| ix + 1)
+        } else {
+            None
+        };
+
         let (icon, is_on) = match sort {
             ColumnSort::Ascending => (IconName::SortAscending, true),
             ColumnSort::Descending => (IconName::SortDescending, true),
             ColumnSort::Default => (IconName::ChevronsUpDown, false),
         };
+        // Show the tooltip when the column is selected by keyboard, `escape` is handled by the table.
+        let focused = self.selected_col == Some(col_ix) && self.is_sort_tooltip_visible(window);
+        let id = ElementId::from(("icon-sort", col_ix));
 
         Some(
-            div()
-                .id(("icon-sort", col_ix))
+            h_flex()
+                .id(id.clone())
                 .p(px(2.))
                 .rounded(cx.theme().radius / 2.)
                 .map(|this| match is_on {
@@ -853,6 +1444,22 @@ where
                     Icon::new(icon)
                         .size_3()
                         .text_color(cx.theme().secondary_foreground),
+                )
+                .when_some(ordinal, |this, ordinal| {
+                    this.child(
+                        div()
+                            .text_size(px(9.))
+                            .line_height(px(10.))
+                            .text_color(cx.theme().secondary_foreground)
+                            .child(ordinal.to_string()),
+                    )
+                })
+                .keyboard_tooltip(
+                    &id,
+                    focused,
+                    |window, cx| Tooltip::new(t!("Table.sort")).build(window, cx),
+                    window,
+                    cx,
                 ),
         )
     }
@@ -905,7 +1512,7 @@ where
                                 entity_id,
                                 col_ix,
                                 name,
-                                width: col_group.width,
+                                width: col_group.render_width(),
                             },
                             |drag, _, _, cx| {
                                 cx.stop_propagation();
@@ -949,16 +1556,22 @@ where
     fn render_table_head(
         &mut self,
         left_columns_count: usize,
+        right_columns_count: usize,
         window: &mut Window,
         cx: &mut Context<Self>,
     ) -> impl IntoElement {
         let view = cx.entity().clone();
         let horizontal_scroll_handle = self.horizontal_scroll_handle.clone();
+        let columns_count = self.col_groups.len();
+        let right_start_ix = columns_count.saturating_sub(right_columns_count);
 
         // Reset fixed head columns bounds, if no fixed columns are present
         if left_columns_count == 0 {
             self.fixed_head_cols_bounds = Bounds::default();
         }
+        if right_columns_count == 0 {
+            self.fixed_right_head_cols_bounds = Bounds::default();
+        }
 
         h_flex()
             .w_full()
@@ -1019,17 +1632,48 @@ where
                         h_flex()
                             .relative()
                             .children(
-                                self.col_groups
-                                    .iter()
-                                    .skip(left_columns_count)
-                                    .enumerate()
-                                    .map(|(col_ix, _)| {
-                                        self.render_th(left_columns_count + col_ix, window, cx)
-                                    }),
+                                (left_columns_count..right_start_ix)
+                                    .map(|col_ix| self.render_th(col_ix, window, cx)),
                             )
                             .child(self.delegate.render_last_empty_col(window, cx)),
                     ),
             )
+            .when(right_columns_count > 0, |this| {
+                // Render right fixed columns
+                this.child(
+                    h_flex()
+                        .relative()
+                        .h_full()
+                        .flex_shrink_0()
+                        .bg(cx.theme().table_head)
+                        .children(
+                            (right_start_ix..columns_count)
+                                .map(|col_ix| self.render_th(col_ix, window, cx)),
+                        )
+                        .child(
+                            // Fixed columns border
+                            div()
+                                .absolute()
+                                .top_0()
+                                .left_0()
+                                .bottom_0()
+                                .w_0()
+                                .flex_shrink_0()
+                                .border_l_1()
+                                .border_color(cx.theme().border),
+                        )
+                        .child(
+                            canvas(
+                                move |bounds, _, cx| {
+                                    view.update(cx, |r, _| r.fixed_right_head_cols_bounds = bounds)
+                                },
+                                |_, _, _, _| {},
+                            )
+                            .absolute()
+                            .size_full(),
+                        ),
+                )
+            })
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -1038,6 +1682,7 @@ where
         row_ix: usize,
         rows_count: usize,
         left_columns_count: usize,
+        right_columns_count: usize,
         col_sizes: Rc<Vec<gpui::Size<Pixels>>>,
         columns_count: usize,
         extra_rows_count: usize,
@@ -1046,7 +1691,9 @@ where
     ) -> impl IntoElement {
         let horizontal_scroll_handle = self.horizontal_scroll_handle.clone();
         let is_stripe_row = self.stripe && row_ix % 2 != 0;
-        let is_selected = self.selected_row == Some(row_ix);
+        let is_selected = self.row_selection.rows.contains(&row_ix);
+        let can_move_row = self.delegate.can_move_row(row_ix, cx);
+        let entity_id = cx.entity_id();
         let view = cx.entity().clone();
 
         if row_ix < rows_count {
@@ -1092,11 +1739,7 @@ where
                                 let mut items = Vec::with_capacity(left_columns_count);
 
                                 (0..left_columns_count).for_each(|col_ix| {
-                                    items.push(self.render_col_wrap(col_ix, window, cx).child(
-                                        self.render_cell(col_ix, window, cx).child(
-                                            self.measure_render_td(row_ix, col_ix, window, cx),
-                                        ),
-                                    ));
+                                    items.push(self.render_td_cell(row_ix, col_ix, window, cx));
                                 });
 
                                 items
@@ -1143,13 +1786,7 @@ where
                                         visible_range.for_each(|col_ix| {
                                             let col_ix = col_ix + left_columns_count;
                                             let el =
-                                                table.render_col_wrap(col_ix, window, cx).child(
-                                                    table.render_cell(col_ix, window, cx).child(
-                                                        table.measure_render_td(
-                                                            row_ix, col_ix, window, cx,
-                                                        ),
-                                                    ),
-                                                );
+                                                table.render_td_cell(row_ix, col_ix, window, cx);
 
                                             items.push(el);
                                         });
@@ -1162,25 +1799,54 @@ where
                         )
                         .child(self.delegate.render_last_empty_col(window, cx)),
                 )
-                // Row selected style
-                .when_some(self.selected_row, |this, _| {
-                    this.when(
-                        is_selected && self.selection_state == SelectionState::Row,
-                        |this| {
-                            this.border_color(gpui::transparent_white()).child(
+                .when(right_columns_count > 0, |this| {
+                    // Right fixed columns
+                    let right_start_ix = columns_count.saturating_sub(right_columns_count);
+                    this.child(
+                        h_flex()
+                            .relative()
+                            .h_full()
+                            .flex_shrink_0()
+                            .children({
+                                let mut items = Vec::with_capacity(right_columns_count);
+
+                                (right_start_ix..columns_count).for_each(|col_ix| {
+                                    items.push(self.render_td_cell(row_ix, col_ix, window, cx));
+                                });
+
+                                items
+                            })
+                            .child(
+                                // Fixed columns border
                                 div()
-                                    .top(if row_ix == 0 { px(0.) } else { px(-1.) })
-                                    .left(px(0.))
-                                    .right(px(0.))
-                                    .bottom(px(-1.))
                                     .absolute()
-                                    .bg(cx.theme().table_active)
-                                    .border_1()
-                                    .border_color(cx.theme().table_active_border),
-                            )
-                        },
+                                    .top_0()
+                                    .left_0()
+                                    .bottom_0()
+                                    .w_0()
+                                    .flex_shrink_0()
+                                    .border_l_1()
+                                    .border_color(cx.theme().border),
+                            ),
                     )
                 })
+                // Row selected style
+                .when(
+                    is_selected && self.selection_state == SelectionState::Row,
+                    |this| {
+                        this.border_color(gpui::transparent_white()).child(
+                            div()
+                                .top(if row_ix == 0 { px(0.) } else { px(-1.) })
+                                .left(px(0.))
+                                .right(px(0.))
+                                .bottom(px(-1.))
+                                .absolute()
+                                .bg(cx.theme().table_active)
+                                .border_1()
+                                .border_color(cx.theme().table_active_border),
+                        )
+                    },
+                )
                 // Row right click row style
                 .when(self.right_clicked_row == Some(row_ix), |this| {
                     this.border_color(gpui::transparent_white()).child(
@@ -1194,6 +1860,55 @@ where
                             .border_color(cx.theme().selection),
                     )
                 })
+                // Row accent bar
+                .when_some(self.delegate.row_accent(row_ix, cx), |this, color| {
+                    this.child(
+                        div()
+                            .absolute()
+                            .top_0()
+                            .left_0()
+                            .bottom_0()
+                            .w(ROW_ACCENT_WIDTH)
+                            .bg(color),
+                    )
+                })
+                // Row drag handle
+                .when(can_move_row, |this| {
+                    this.child(
+                        div()
+                            .id(("row-drag-handle", row_ix))
+                            .absolute()
+                            .top_0()
+                            .left_0()
+                            .bottom_0()
+                            .w(px(14.))
+                            .flex()
+                            .items_center()
+                            .justify_center()
+                            .cursor_grab()
+                            .opacity(0.5)
+                            .hover(|this| this.opacity(1.))
+                            .child(
+                                Icon::new(IconName::Menu)
+                                    .xsmall()
+                                    .text_color(cx.theme().muted_foreground),
+                            )
+                            .on_drag(DragRow { entity_id, row_ix }, |drag, _, _, cx| {
+                                cx.stop_propagation();
+                                cx.new(|_| drag.clone())
+                            }),
+                    )
+                })
+                .drag_over::<DragRow>(|this, _, _, cx| {
+                    this.border_t_2().border_color(cx.theme().drag_border)
+                })
+                .on_drop(cx.listener(move |table, drag: &DragRow, window, cx| {
+                    if drag.entity_id != cx.entity_id() {
+                        return;
+                    }
+
+                    table.move_row(drag.row_ix, row_ix, window, cx);
+                }))
                 .on_mouse_down(
                     MouseButton::Left,
                     cx.listener(move |this, ev, window, cx| {
@@ -1318,17 +2033,24 @@ where
 {
     fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
         self.measure(window, cx);
+        self.layout_flex_cols();
 
         let view = cx.entity().clone();
         let vertical_scroll_handle = self.vertical_scroll_handle.clone();
         let horizontal_scroll_handle = self.horizontal_scroll_handle.clone();
         let columns_count: usize = self.delegate.columns_count(cx);
-        let left_columns_count = self
-            .col_groups
-            .iter()
-            .filter(|col| self.col_fixed && col.column.fixed == Some(ColumnFixed::Left))
-            .count();
-        let rows_count = self.delegate.rows_count(cx);
+        let fixed_cols = self.fixed_cols();
+        let left_columns_count = fixed_cols.left;
+        let right_columns_count = fixed_cols.right;
+        let total_rows = self.delegate.total_rows(cx);
+        let rows_count = match total_rows {
+            // Only render the rows of the current page.
+            Some(_) => self
+                .delegate
+                .rows_count(cx)
+                .min(self.delegate.page_size(cx)),
+            None => self.delegate.rows_count(cx),
+        };
         let loading = self.delegate.loading(cx);
         let extra_rows_count = self.calculate_extra_rows_needed(rows_count);
         let render_rows_count = if self.stripe {
@@ -1341,14 +2063,18 @@ where
             .key_context("Table")
             .id("table")
             .track_focus(&self.focus_handle)
-            .on_action(cx.listener(Self::action_cancel))
-            .on_action(cx.listener(Self::action_select_next))
-            .on_action(cx.listener(Self::action_select_prev))
-            .on_action(cx.listener(Self::action_select_next_col))
-            .on_action(cx.listener(Self::action_select_prev_col))
+            .when(!self.busy, |this| {
+                this.on_action(cx.listener(Self::action_cancel))
+                    .on_action(cx.listener(Self::action_confirm))
+                    .on_action(cx.listener(Self::action_select_next))
+                    .on_action(cx.listener(Self::action_select_prev))
+                    .on_action(cx.listener(Self::action_select_next_col))
+                    .on_action(cx.listener(Self::action_select_prev_col))
+                    .on_action(cx.listener(Self::on_action_set_page_size))
+            })
             .size_full()
             .overflow_hidden()
-            .child(self.render_table_head(left_columns_count, window, cx))
+            .child(self.render_table_head(left_columns_count, right_columns_count, window, cx))
             .context_menu({
                 let view = view.clone();
                 move |this, window: &mut Window, cx: &mut Context<PopupMenu>| {
@@ -1383,16 +2109,23 @@ where
                                                 .col_groups
                                                 .iter()
                                                 .skip(left_columns_count)
+                                                .take(
+                                                    columns_count
+                                                        .saturating_sub(left_columns_count)
+                                                        .saturating_sub(right_columns_count),
+                                                )
                                                 .map(|col| col.bounds.size)
                                                 .collect(),
                                         );
 
-                                        table.load_more_if_need(
-                                            rows_count,
-                                            visible_range.end,
-                                            window,
-                                            cx,
-                                        );
+                                        if total_rows.is_none() {
+                                            table.load_more_if_need(
+                                                rows_count,
+                                                visible_range.end,
+                                                window,
+                                                cx,
+                                            );
+                                        }
                                         table.update_visible_range_if_need(
                                             visible_range.clone(),
                                             Axis::Vertical,
@@ -1421,6 +2154,7 @@ where
                                                 row_ix,
                                                 rows_count,
                                                 left_columns_count,
+                                                right_columns_count,
                                                 col_sizes.clone(),
                                                 columns_count,
                                                 extra_rows_count,
@@ -1441,6 +2175,9 @@ where
                         ),
                     )
                 }
+            })
+            .when_some(total_rows, |this, total_rows| {
+                this.child(self.render_pagination(total_rows, window, cx))
             });
 
         let view = cx.entity().clone();
@@ -1468,9 +2205,41 @@ where
                             cx.notify();
                         }))
                     })
+                    .when(self.busy, |this| {
+                        this.child(
+                            div()
+                                .id("table-busy")
+                                .absolute()
+                                .inset_0()
+                                .flex()
+                                .items_center()
+                                .justify_center()
+                                .bg(cx.theme().table.opacity(0.5))
+                                .when(self.border, |this| this.rounded(cx.theme().radius))
+                                .occlude()
+                                .child(Indicator::new().with_size(self.size)),
+                        )
+                    })
             })
+            .on_drag_move(cx.listener(|table, e: &DragMoveEvent<DragRow>, _, cx| {
+                if e.drag(cx).entity_id != cx.entity_id() {
+                    return;
+                }
+
+                table.scroll_table_by_row_dragging(e.event.position, cx);
+            }))
             .child(canvas(
-                move |bounds, _, cx| view.update(cx, |r, _| r.bounds = bounds),
+                move |bounds, _, cx| {
+                    view.update(cx, |r, cx| {
+                        // Relayout the flex columns when the table width changed.
+                        if r.bounds.size.width != bounds.size.width
+                            && r.col_groups.iter().any(|g| g.flex.is_some())
+                        {
+                            cx.notify();
+                        }
+                        r.bounds = bounds;
+                    })
+                },
                 |_, _, _, _| {},
             ))
             .when(!window.is_inspector_picking(cx), |this| {
@@ -0,0 +1,248 @@
+use gpui::{
+    div, prelude::FluentBuilder as _, Action, App, Context, Corner, IntoElement,
+    ParentElement as _, Styled as _, Window,
+};
+use rust_i18n::t;
+
+use crate::{
+    button::{Button, ButtonVariants as _},
+    h_flex,
+    popup_menu::PopupMenuExt as _,
+    ActiveTheme as _, Disableable as _, IconName, Selectable as _, Sizable as _,
+};
+
+use super::{Table, TableDelegate, TableEvent};
+
+/// Change the page size by the page size selector in the pagination footer.
+#[derive(Action, Debug, Clone, Copy, PartialEq, Eq)]
+#[action(namespace = table, no_json)]
+pub(super) struct SetPageSize(pub(super) usize);
+
+/// Returns the number of pages, there is always at least one page.
+pub(super) fn page_count(total_rows: usize, page_size: usize) -> usize {
+    total_rows.div_ceil(page_size.max(1)).max(1)
+}
+
+/// Returns the page numbers to show in the pagination footer, `None` is an ellipsis.
+///
+/// The first, the last and the siblings of the current page are always shown,
+/// an ellipsis that would only hide a single page is replaced by that page.
+pub(super) fn page_numbers(page: usize, page_count: usize) -> Vec<Option<usize>> {
+    const SIBLINGS: usize = 1;
+
+    let last_page = page_count.saturating_sub(1);
+    let page = page.min(last_page);
+    let siblings = page.saturating_sub(SIBLINGS)..=(page + SIBLINGS).min(last_page);
+
+    let mut numbers = Vec::new();
+    let mut prev: Option<usize> = None;
+    for ix in std::iter::once(0)
+        .chain(siblings)
+        .chain(std::iter::once(last_page))
+    {
+        if let Some(prev) = prev {
+            if ix <= prev {
+                continue;
+            }
+            match ix - prev {
+                1 => {}
+                2 => numbers.push(Some(prev + 1)),
+                _ => numbers.push(None),
+            }
+        }
+        numbers.push(Some(ix));
+        prev = Some(ix);
+    }
+    numbers
+}
+
+impl<D> Table<D>
+where
+    D: TableDelegate,
+{
+    /// Returns true if the pagination is enabled by [`TableDelegate::total_rows`].
+    pub fn is_paginated(&self, cx: &App) -> bool {
+        self.delegate.total_rows(cx).is_some()
+    }
+
+    /// Returns the current page (0-based) when the pagination is enabled.
+    pub fn page(&self) -> usize {
+        self.page
+    }
+
+    /// Returns the number of pages, or `None` if the pagination is disabled.
+    pub fn page_count(&self, cx: &App) -> Option<usize> {
+        self.delegate
+            .total_rows(cx)
+            .map(|total_rows| page_count(total_rows, self.delegate.page_size(cx)))
+    }
+
+    /// Go to the `page` (0-based), the page will be clamped to the page count.
+    ///
+    /// This calls [`TableDelegate::set_page`] and emits [`TableEvent::PageChanged`],
+    /// it does nothing if the pagination is disabled.
+    pub fn set_page(&mut self, page: usize, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(page_count) = self.page_count(cx) else {
+            return;
+        };
+
+        self.page = page.min(page_count - 1);
+        self.selected_row = None;
+        self.selected_rows.clear();
+        self.selection_anchor = None;
+        self.right_clicked_row = None;
+        self.delegate.set_page(self.page, window, cx);
+        self.scroll_to_row(0, cx);
+        cx.emit(TableEvent::PageChanged(self.page));
+        cx.notify();
+    }
+
+    pub(super) fn on_action_set_page_size(
+        &mut self,
+        action: &SetPageSize,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.delegate.page_size(cx) == action.0 {
+            return;
+        }
+
+        self.delegate.set_page_size(action.0, window, cx);
+        self.set_page(0, window, cx);
+    }
+
+    pub(super) fn render_pagination(
+        &self,
+        total_rows: usize,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let page_size = self.delegate.page_size(cx);
+        let page_count = page_count(total_rows, page_size);
+        let page = self.page.min(page_count - 1);
+        let page_size_options = self.delegate.page_size_options(cx);
+        let first_row = (page * page_size + 1).min(total_rows);
+        let last_row = ((page + 1) * page_size).min(total_rows);
+
+        h_flex()
+            .id("table-pagination")
+            .flex_shrink_0()
+            .justify_between()
+            .gap_3()
+            .px_2()
+            .py_1()
+            .border_t_1()
+            .border_color(cx.theme().table_row_border)
+            .text_color(cx.theme().muted_foreground)
+            .map(|this| match self.size {
+                crate::Size::XSmall | crate::Size::Small => this.text_xs(),
+                _ => this.text_sm(),
+            })
+            .child(
+                h_flex()
+                    .gap_2()
+                    .when(!page_size_options.is_empty(), |this| {
+                        this.child(t!("Table.rows_per_page").to_string()).child(
+                            Button::new("page-size")
+                                .label(page_size.to_string())
+                                .icon(IconName::ChevronDown)
+                                .xsmall()
+                                .outline()
+                                .popup_menu_with_anchor(
+                                    Corner::BottomLeft,
+                                    move |mut this, _, _| {
+                                        for size in page_size_options.iter().copied() {
+                                            this = this.menu_with_check(
+                                                size.to_string(),
+                                                size == page_size,
+                                                Box::new(SetPageSize(size)),
+                                            );
+                                        }
+                                        this
+                                    },
+                                ),
+                        )
+                    })
+                    .child(
+                        t!(
+                            "Table.page_summary",
+                            start = first_row,
+                            end = last_row,
+                            total = total_rows
+                        )
+                        .to_string(),
+                    ),
+            )
+            .child(
+                h_flex()
+                    .gap_1()
+                    .child(
+                        Button::new("prev-page")
+                            .icon(IconName::ChevronLeft)
+                            .xsmall()
+                            .ghost()
+                            .disabled(page == 0)
+                            .on_click(cx.listener(move |this, _, window, cx| {
+                                this.set_page(page.saturating_sub(1), window, cx);
+                            })),
+                    )
+                    .children(page_numbers(page, page_count).into_iter().map(|number| {
+                        match number {
+                            Some(number) => Button::new(("page", number))
+                                .label((number + 1).to_string())
+                                .xsmall()
+                                .ghost()
+                                .selected(number == page)
+                                .on_click(cx.listener(move |this, _, window, cx| {
+                                    if this.page != number {
+                                        this.set_page(number, window, cx);
+                                    }
+                                }))
+                                .into_any_element(),
+                            None => div().px_1().child("…").into_any_element(),
+                        }
+                    }))
+                    .child(
+                        Button::new("next-page")
+                            .icon(IconName::ChevronRight)
+                            .xsmall()
+                            .ghost()
+                            .disabled(page + 1 >= page_count)
+                            .on_click(cx.listener(move |this, _, window, cx| {
+                                this.set_page(page + 1, window, cx);
+                            })),
+                    ),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{page_count, page_numbers};
+
+    #[test]
+    fn test_page_count() {
+        assert_eq!(page_count(0, 20), 1);
+        assert_eq!(page_count(1, 20), 1);
+        assert_eq!(page_count(20, 20), 1);
+        assert_eq!(page_count(21, 20), 2);
+        assert_eq!(page_count(100, 0), 100);
+    }
+
+    #[test]
+    fn test_page_numbers() {
+        assert_eq!(page_numbers(0, 1), vec![Some(0)]);
+        assert_eq!(page_numbers(0, 3), vec![Some(0), Some(1), Some(2)]);
+        assert_eq!(page_numbers(0, 10), vec![Some(0), Some(1), None, Some(9)]);
+        assert_eq!(
+            page_numbers(3, 10),
+            vec![Some(0), Some(1), Some(2), Some(3), Some(4), None, Some(9)]
+        );
+        assert_eq!(
+            page_numbers(5, 10),
+            vec![Some(0), None, Some(4), Some(5), Some(6), None, Some(9)]
+        );
+        assert_eq!(page_numbers(9, 10), vec![Some(0), None, Some(8), Some(9)]);
+        assert_eq!(page_numbers(20, 10), vec![Some(0), None, Some(8), Some(9)]);
+    }
+}
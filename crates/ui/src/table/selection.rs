@@ -0,0 +1,113 @@
+use std::collections::BTreeSet;
+
+/// The selected rows of the [`super::Table`] in [`super::SelectionMode::Multi`] mode.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(super) struct RowSelection {
+    pub(super) rows: BTreeSet<usize>,
+    /// The row where the range selection starts from.
+    pub(super) anchor: Option<usize>,
+}
+
+impl RowSelection {
+    /// Select only the given row, and make it the new anchor.
+    ///
+    /// Returns true if the selected rows are changed.
+    pub(super) fn select(&mut self, row_ix: usize) -> bool {
+        let rows = BTreeSet::from([row_ix]);
+        let changed = self.rows != rows;
+        self.rows = rows;
+        self.anchor = Some(row_ix);
+        changed
+    }
+
+    /// Select the rows from the anchor (or the `fallback` if no anchor) to the given row.
+    ///
+    /// Returns true if the selected rows are changed.
+    pub(super) fn extend_to(&mut self, row_ix: usize, fallback: Option<usize>) -> bool {
+        let anchor = self.anchor.or(fallback).unwrap_or(row_ix);
+        let rows: BTreeSet<usize> = (anchor.min(row_ix)..=anchor.max(row_ix)).collect();
+        let changed = self.rows != rows;
+        self.rows = rows;
+        self.anchor = Some(anchor);
+        changed
+    }
+
+    /// Toggle the selection of the given row, and make it the new anchor.
+    pub(super) fn toggle(&mut self, row_ix: usize) {
+        if !self.rows.remove(&row_ix) {
+            self.rows.insert(row_ix);
+        }
+        self.anchor = Some(row_ix);
+    }
+
+    /// Set the selected rows, the first row is the new anchor.
+    ///
+    /// Returns true if the selected rows are changed.
+    pub(super) fn set(&mut self, rows: BTreeSet<usize>) -> bool {
+        let changed = self.rows != rows;
+        self.anchor = rows.first().copied();
+        self.rows = rows;
+        changed
+    }
+
+    /// Clear the selection.
+    ///
+    /// Returns true if the selected rows are changed.
+    pub(super) fn clear(&mut self) -> bool {
+        let changed = !self.rows.is_empty();
+        self.rows.clear();
+        self.anchor = None;
+        changed
+    }
+
+    /// Returns the selected rows in order.
+    pub(super) fn to_vec(&self) -> Vec<usize> {
+        self.rows.iter().copied().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RowSelection;
+
+    #[test]
+    fn test_click_then_shift_click() {
+        let mut selection = RowSelection::default();
+        assert!(selection.select(2));
+        assert_eq!(selection.to_vec(), vec![2]);
+        assert!(!selection.select(2));
+
+        assert!(selection.extend_to(5, None));
+        assert_eq!(selection.to_vec(), vec![2, 3, 4, 5]);
+        assert_eq!(selection.anchor, Some(2));
+
+        // Extend to the other side of the anchor.
+        assert!(selection.extend_to(0, None));
+        assert_eq!(selection.to_vec(), vec![0, 1, 2]);
+
+        // The plain click replaces the set.
+        assert!(selection.select(4));
+        assert_eq!(selection.to_vec(), vec![4]);
+    }
+
+    #[test]
+    fn test_click_then_secondary_click() {
+        let mut selection = RowSelection::default();
+        selection.select(1);
+        selection.toggle(3);
+        selection.toggle(5);
+        assert_eq!(selection.to_vec(), vec![1, 3, 5]);
+
+        selection.toggle(3);
+        assert_eq!(selection.to_vec(), vec![1, 5]);
+        assert_eq!(selection.anchor, Some(3));
+
+        // The shift click extends from the last toggled row.
+        selection.extend_to(6, None);
+        assert_eq!(selection.to_vec(), vec![3, 4, 5, 6]);
+
+        assert!(selection.clear());
+        assert!(!selection.clear());
+        assert_eq!(selection.anchor, None);
+    }
+}
@@ -0,0 +1,5 @@
+mod tag;
+mod tag_group;
+
+pub use tag::*;
+pub use tag_group::*;
@@ -130,7 +130,7 @@ pub struct Tag {
     children: Vec<AnyElement>,
 }
 impl Tag {
-    fn new() -> Self {
+    pub(super) fn new() -> Self {
         Self {
             style: StyleRefinement::default(),
             variant: TagVariant::default(),
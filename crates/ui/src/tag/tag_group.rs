@@ -0,0 +1,333 @@
+use gpui::{
+    div, prelude::FluentBuilder as _, px, AppContext as _, Context, DragMoveEvent, EntityId,
+    EventEmitter, InteractiveElement as _, IntoElement, ParentElement as _, Pixels, Render,
+    ScrollHandle, SharedString, StatefulInteractiveElement as _, Styled, Window,
+};
+
+use crate::{h_flex, ActiveTheme as _, Sizable, Size};
+
+use super::{Tag, TagVariant};
+
+/// The distance to the edge of the [`TagGroup`] to start auto scrolling when dragging.
+const AUTO_SCROLL_EDGE: Pixels = px(24.);
+/// The scroll distance of each drag move when auto scrolling.
+const AUTO_SCROLL_STEP: Pixels = px(8.);
+
+/// An item of the [`TagGroup`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TagGroupItem {
+    label: SharedString,
+    variant: TagVariant,
+    outline: bool,
+}
+
+impl TagGroupItem {
+    pub fn new(label: impl Into<SharedString>) -> Self {
+        Self {
+            label: label.into(),
+            variant: TagVariant::default(),
+            outline: false,
+        }
+    }
+
+    /// Set the variant of the tag, default is [`TagVariant::Secondary`].
+    pub fn variant(mut self, variant: TagVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Use outline style.
+    pub fn outline(mut self) -> Self {
+        self.outline = true;
+        self
+    }
+
+    /// Returns the label of the tag.
+    pub fn label(&self) -> &SharedString {
+        &self.label
+    }
+}
+
+impl From<&'static str> for TagGroupItem {
+    fn from(label: &'static str) -> Self {
+        Self::new(label)
+    }
+}
+
+impl From<SharedString> for TagGroupItem {
+    fn from(label: SharedString) -> Self {
+        Self::new(label)
+    }
+}
+
+impl From<String> for TagGroupItem {
+    fn from(label: String) -> Self {
+        Self::new(label)
+    }
+}
+
+/// The overflow layout of the [`TagGroup`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TagGroupOverflow {
+    /// Wrap the tags into multiple lines.
+    #[default]
+    Wrap,
+    /// Keep the tags in one line and scroll horizontally.
+    Scroll,
+}
+
+pub enum TagGroupEvent {
+    /// A tag has been moved by dragging.
+    ///
+    /// The `from` is the original index, and the `to` is the new index of the tag after moved.
+    Reordered { from: usize, to: usize },
+}
+
+#[derive(Clone)]
+struct DragTag {
+    group_id: EntityId,
+    ix: usize,
+    item: TagGroupItem,
+    size: Size,
+}
+
+impl Render for DragTag {
+    fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+        div().opacity(0.75).child(
+            Tag::new()
+                .with_variant(self.item.variant)
+                .when(self.item.outline, |this| this.outline())
+                .with_size(self.size)
+                .child(self.item.label.clone()),
+        )
+    }
+}
+
+/// A group of tags that can be reordered by dragging.
+pub struct TagGroup {
+    items: Vec<TagGroupItem>,
+    overflow: TagGroupOverflow,
+    reorderable: bool,
+    size: Size,
+    scroll_handle: ScrollHandle,
+}
+
+impl EventEmitter<TagGroupEvent> for TagGroup {}
+
+impl TagGroup {
+    pub fn new<I>(items: impl IntoIterator<Item = I>, _: &mut Window, _: &mut Context<Self>) -> Self
+    where
+        I: Into<TagGroupItem>,
+    {
+        Self {
+            items: items.into_iter().map(Into::into).collect(),
+            overflow: TagGroupOverflow::default(),
+            reorderable: true,
+            size: Size::default(),
+            scroll_handle: ScrollHandle::new(),
+        }
+    }
+
+    /// Set the overflow layout of the tags, default is [`TagGroupOverflow::Wrap`].
+    pub fn overflow(mut self, overflow: TagGroupOverflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Set whether the tags can be reordered by dragging, default: true
+    pub fn reorderable(mut self, reorderable: bool) -> Self {
+        self.reorderable = reorderable;
+        self
+    }
+
+    /// Returns the items in current order.
+    pub fn items(&self) -> &[TagGroupItem] {
+        &self.items
+    }
+
+    /// Replace the items of the group.
+    pub fn set_items<I>(&mut self, items: impl IntoIterator<Item = I>, cx: &mut Context<Self>)
+    where
+        I: Into<TagGroupItem>,
+    {
+        self.items = items.into_iter().map(Into::into).collect();
+        cx.notify();
+    }
+
+    /// Move the tag at `from` to be placed before the tag at `ix`.
+    ///
+    /// The `ix` can be `items.len()` to move the tag to the end.
+    fn move_item(&mut self, from: usize, ix: usize, cx: &mut Context<Self>) {
+        if let Some(to) = move_item(&mut self.items, from, ix) {
+            cx.emit(TagGroupEvent::Reordered { from, to });
+            cx.notify();
+        }
+    }
+
+    fn on_drop(&mut self, drag: &DragTag, ix: usize, cx: &mut Context<Self>) {
+        if drag.group_id != cx.entity_id() {
+            return;
+        }
+
+        self.move_item(drag.ix, ix, cx);
+    }
+
+    /// Auto scroll when dragging near the left or right edge in scroll mode.
+    fn on_drag_move(
+        &mut self,
+        drag: &DragMoveEvent<DragTag>,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.overflow != TagGroupOverflow::Scroll || drag.drag(cx).group_id != cx.entity_id() {
+            return;
+        }
+
+        let bounds = drag.bounds;
+        let position = drag.event.position;
+        if !bounds.contains(&position) {
+            return;
+        }
+
+        let mut offset = self.scroll_handle.offset();
+        let max_offset = self.scroll_handle.max_offset().width;
+        if position.x < bounds.left() + AUTO_SCROLL_EDGE {
+            offset.x = (offset.x + AUTO_SCROLL_STEP).min(px(0.));
+        } else if position.x > bounds.right() - AUTO_SCROLL_EDGE {
+            offset.x = (offset.x - AUTO_SCROLL_STEP).max(-max_offset);
+        } else {
+            return;
+        }
+
+        self.scroll_handle.set_offset(offset);
+        cx.notify();
+    }
+}
+
+/// Move the item at `from` to be placed before the item at `ix`, returns the new index of the item.
+///
+/// Returns `None` if the order is not changed.
+fn move_item<T>(items: &mut Vec<T>, from: usize, ix: usize) -> Option<usize> {
+    if from >= items.len() || ix > items.len() {
+        return None;
+    }
+
+    let to = if ix > from { ix - 1 } else { ix };
+    if to == from {
+        return None;
+    }
+
+    let item = items.remove(from);
+    items.insert(to, item);
+    Some(to)
+}
+
+impl Sizable for TagGroup {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl Render for TagGroup {
+    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let group_id = cx.entity_id();
+        let reorderable = self.reorderable;
+        let size = self.size;
+        let items_len = self.items.len();
+
+        h_flex()
+            .id(("tag-group", group_id))
+            .gap_2()
+            .map(|this| match self.overflow {
+                TagGroupOverflow::Wrap => this.flex_wrap(),
+                TagGroupOverflow::Scroll => this
+                    .overflow_x_scroll()
+                    .track_scroll(&self.scroll_handle)
+                    .when(reorderable, |this| {
+                        this.on_drag_move(cx.listener(Self::on_drag_move))
+                    }),
+            })
+            .children(self.items.iter().enumerate().map(|(ix, item)| {
+                div()
+                    .id(ix)
+                    .flex_shrink_0()
+                    .child(
+                        Tag::new()
+                            .with_variant(item.variant)
+                            .when(item.outline, |this| this.outline())
+                            .with_size(size)
+                            .child(item.label.clone()),
+                    )
+                    .when(reorderable, |this| {
+                        this.on_drag(
+                            DragTag {
+                                group_id,
+                                ix,
+                                item: item.clone(),
+                                size,
+                            },
+                            |drag, _, _, cx| {
+                                cx.stop_propagation();
+                                cx.new(|_| drag.clone())
+                            },
+                        )
+                        .drag_over::<DragTag>(move |this, drag, _, cx| {
+                            if drag.group_id == group_id {
+                                this.border_l_2().border_color(cx.theme().drag_border)
+                            } else {
+                                this
+                            }
+                        })
+                        .on_drop(cx.listener(
+                            move |this, drag: &DragTag, _, cx| this.on_drop(drag, ix, cx),
+                        ))
+                    })
+            }))
+            .when(reorderable, |this| {
+                // Empty space to allow drop the tag to the end.
+                this.child(
+                    div()
+                        .id("tag-group-end")
+                        .flex_1()
+                        .min_w_4()
+                        .min_h_5()
+                        .drag_over::<DragTag>(move |this, drag, _, cx| {
+                            if drag.group_id == group_id {
+                                this.border_l_2().border_color(cx.theme().drag_border)
+                            } else {
+                                this
+                            }
+                        })
+                        .on_drop(cx.listener(move |this, drag: &DragTag, _, cx| {
+                            this.on_drop(drag, items_len, cx)
+                        })),
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::move_item;
+
+    #[test]
+    fn test_move_item() {
+        let mut items = vec!["a", "b", "c", "d"];
+        assert_eq!(move_item(&mut items, 0, 2), Some(1));
+        assert_eq!(items, vec!["b", "a", "c", "d"]);
+
+        assert_eq!(move_item(&mut items, 3, 0), Some(0));
+        assert_eq!(items, vec!["d", "b", "a", "c"]);
+
+        assert_eq!(move_item(&mut items, 1, 4), Some(3));
+        assert_eq!(items, vec!["d", "a", "c", "b"]);
+
+        // Drop before self or after self is not changed.
+        assert_eq!(move_item(&mut items, 1, 1), None);
+        assert_eq!(move_item(&mut items, 1, 2), None);
+        assert_eq!(move_item(&mut items, 4, 0), None);
+        assert_eq!(move_item(&mut items, 0, 5), None);
+        assert_eq!(items, vec!["d", "a", "c", "b"]);
+    }
+}
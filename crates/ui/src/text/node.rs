@@ -7,11 +7,9 @@ use gpui::{
     StatefulInteractiveElement, Styled, StyledImage as _, Window,
 };
 use markdown::mdast;
-use ropey::Rope;
 
 use crate::{
-    h_flex,
-    highlighter::SyntaxHighlighter,
+    h_flex, highlighter,
     text::inline::{Inline, InlineState},
     tooltip::Tooltip,
     v_flex, ActiveTheme as _, Icon, IconName,
@@ -291,9 +289,7 @@ impl CodeBlock {
         let theme = cx.theme().highlight_theme.clone();
         let mut styles = vec![];
         if let Some(lang) = &lang {
-            let mut highlighter = SyntaxHighlighter::new(&lang, cx);
-            highlighter.update(None, &Rope::from_str(code.as_str()), cx);
-            styles = highlighter.styles(&(0..code.len()), &theme);
+            styles = highlighter::highlight(&lang, &code, &theme, cx);
         };
 
         let state = InlineState::default();
@@ -150,7 +150,7 @@ impl Colorize for Hsla {
     fn parse_hex(hex: &str) -> Result<Self> {
         let hex = hex.trim_start_matches('#');
         let len = hex.len();
-        if len != 6 && len != 8 {
+        if (len != 6 && len != 8) || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
             return Err(anyhow::anyhow!("invalid hex color"));
         }
 
@@ -9,14 +9,19 @@ use std::{
 };
 
 mod color;
+mod preview;
+mod ramp;
 mod registry;
 mod schema;
 mod theme_color;
+mod themed;
 
 pub use color::*;
+pub use ramp::*;
 pub use registry::*;
 pub use schema::*;
 pub use theme_color::*;
+pub use themed::*;
 
 pub fn init(cx: &mut App) {
     registry::init(cx);
@@ -30,8 +35,16 @@ pub trait ActiveTheme {
 }
 
 impl ActiveTheme for App {
+    /// Returns the theme overridden by [`themed`] if in the subtree, otherwise the global theme.
     #[inline(always)]
     fn theme(&self) -> &Theme {
+        if let Some(theme) = self
+            .try_global::<themed::ThemeStack>()
+            .and_then(|stack| stack.0.last())
+        {
+            return theme;
+        }
+
         Theme::global(self)
     }
 }
@@ -39,6 +52,9 @@ impl ActiveTheme for App {
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Theme {
     pub colors: ThemeColor,
+    /// The palette ramps of the semantic colors, see [`Theme::update_ramps`].
+    #[serde(skip)]
+    pub ramps: ThemeRamps,
     pub highlight_theme: Arc<HighlightTheme>,
     pub light_theme: Rc<ThemeConfig>,
     pub dark_theme: Rc<ThemeConfig>,
@@ -101,6 +117,25 @@ impl Theme {
         self.mode.is_dark()
     }
 
+    /// Regenerate the [`ThemeRamps`] from the current colors.
+    ///
+    /// This is called by [`Theme::apply_config`], call it after changing the colors manually.
+    pub fn update_ramps(&mut self) {
+        self.ramps = ThemeRamps::new(&self.colors, self.is_dark());
+    }
+
+    /// Recolor the theme around the `accent` color, keeps the current light/dark mode.
+    ///
+    /// See [`ThemeColor::apply_accent`] for the colors to change,
+    /// call [`Theme::apply_config`] with the active theme config to revert it.
+    pub fn set_accent(accent: Hsla, cx: &mut App) {
+        let theme = Theme::global_mut(cx);
+        let is_dark = theme.is_dark();
+        theme.colors.apply_accent(accent, is_dark);
+        theme.update_ramps();
+        cx.refresh_windows();
+    }
+
     /// Returns the current theme name.
     pub fn theme_name(&self) -> &SharedString {
         if self.is_dark() {
@@ -189,6 +224,7 @@ impl From<ThemeColor> for Theme {
             scrollbar_show: ScrollbarShow::default(),
             tile_grid_size: px(8.),
             tile_shadow: true,
+            ramps: ThemeRamps::new(&colors, false),
             colors,
             light_theme: Rc::new(ThemeConfig::default()),
             dark_theme: Rc::new(ThemeConfig::default()),
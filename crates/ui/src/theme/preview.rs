@@ -0,0 +1,70 @@
+use std::rc::Rc;
+
+use gpui::{App, Context, Global, Subscription, Window};
+
+use super::{Theme, ThemeConfig};
+
+/// The state of the theme preview, see [`Theme::preview`].
+#[derive(Default)]
+struct ThemePreview(Option<ThemePreviewState>);
+
+struct ThemePreviewState {
+    /// The theme before the preview, to revert on discard.
+    original: Theme,
+    /// Discard the preview when the entity that started the preview is released.
+    _release: Subscription,
+}
+
+impl Global for ThemePreview {}
+
+impl Theme {
+    /// Temporarily apply the `config` to preview it, e.g. in a theme editor.
+    ///
+    /// The theme before the first preview is kept, then use [`Theme::commit_preview`] to keep
+    /// the previewed theme, or [`Theme::discard_preview`] to revert it.
+    ///
+    /// Call it again during a preview will update the previewed theme, the original theme is unchanged.
+    /// If the entity that starts the preview is released before commit, the preview will be discarded.
+    pub fn preview<V: 'static>(config: &Rc<ThemeConfig>, window: &mut Window, cx: &mut Context<V>) {
+        let prev_state = cx.default_global::<ThemePreview>().0.take();
+        let original = match prev_state {
+            Some(state) => state.original,
+            None => Theme::global(cx).clone(),
+        };
+        let _release = cx.on_release(|_, cx| Theme::discard_preview(None, cx));
+
+        cx.set_global(ThemePreview(Some(ThemePreviewState { original, _release })));
+        Theme::global_mut(cx).apply_config(config);
+        window.refresh();
+    }
+
+    /// Returns true if the theme is in preview, see [`Theme::preview`].
+    pub fn is_previewing(cx: &App) -> bool {
+        cx.try_global::<ThemePreview>()
+            .map_or(false, |preview| preview.0.is_some())
+    }
+
+    /// Keep the previewed theme as the current theme, and end the preview.
+    pub fn commit_preview(cx: &mut App) {
+        if cx.has_global::<ThemePreview>() {
+            _ = cx.global_mut::<ThemePreview>().0.take();
+        }
+    }
+
+    /// Revert the theme to the one before the preview, and end the preview.
+    pub fn discard_preview(window: Option<&mut Window>, cx: &mut App) {
+        if !cx.has_global::<ThemePreview>() {
+            return;
+        }
+        let Some(state) = cx.global_mut::<ThemePreview>().0.take() else {
+            return;
+        };
+
+        cx.set_global(state.original);
+        if let Some(window) = window {
+            window.refresh();
+        } else {
+            cx.refresh_windows();
+        }
+    }
+}
@@ -0,0 +1,260 @@
+use gpui::{Hsla, Rgba};
+
+use super::{Colorize as _, ThemeColor};
+
+/// The scales of a [`ColorRamp`].
+pub const RAMP_SCALES: [usize; 11] = [50, 100, 200, 300, 400, 500, 600, 700, 800, 900, 950];
+
+/// The OKLCH lightness of each scale in [`RAMP_SCALES`] in light mode.
+const RAMP_LIGHTNESS: [f32; 11] = [
+    0.975, 0.94, 0.885, 0.81, 0.72, 0.63, 0.55, 0.47, 0.39, 0.32, 0.25,
+];
+
+/// The OKLCH lightness above which the foreground of the accent color is dark.
+const ACCENT_FOREGROUND_LIGHTNESS: f32 = 0.65;
+
+/// A lightness ramp from 50 to 950 generated from a base color.
+///
+/// The shades are generated in the OKLCH color space with the hue of the base color,
+/// so the steps are perceptually even. In dark mode the ramp is reversed,
+/// so the 50 is always the most subtle shade against the background.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ColorRamp([Hsla; 11]);
+
+impl ColorRamp {
+    /// Generate the ramp from the `base` color.
+    pub fn new(base: Hsla, is_dark: bool) -> Self {
+        let (_, chroma, hue) = to_oklch(base.to_rgb());
+
+        let mut shades = [Hsla::default(); 11];
+        for (ix, shade) in shades.iter_mut().enumerate() {
+            let lightness = if is_dark {
+                RAMP_LIGHTNESS[RAMP_LIGHTNESS.len() - 1 - ix]
+            } else {
+                RAMP_LIGHTNESS[ix]
+            };
+
+            // Reduce the chroma to the lightest and darkest shades, like the Tailwind CSS palette.
+            let taper = (1. - ((lightness - 0.6) / 0.5).powi(2)).clamp(0.2, 1.);
+            let mut chroma = chroma * taper;
+            let mut rgb = from_oklch(lightness, chroma, hue);
+            // Reduce the chroma until the color is in the sRGB gamut.
+            while !in_gamut(&rgb) && chroma > 0.001 {
+                chroma *= 0.9;
+                rgb = from_oklch(lightness, chroma, hue);
+            }
+
+            let mut color: Hsla = Rgba {
+                r: rgb.r.clamp(0., 1.),
+                g: rgb.g.clamp(0., 1.),
+                b: rgb.b.clamp(0., 1.),
+                a: 1.,
+            }
+            .into();
+            color.a = base.a;
+            *shade = color;
+        }
+
+        Self(shades)
+    }
+
+    /// Returns the shade of the `scale`, e.g. `50`, `100`, ..., `950`.
+    ///
+    /// If the `scale` is not one of [`RAMP_SCALES`], the next greater one is used.
+    pub fn shade(&self, scale: usize) -> Hsla {
+        let ix = RAMP_SCALES
+            .iter()
+            .position(|s| *s >= scale)
+            .unwrap_or(RAMP_SCALES.len() - 1);
+        self.0[ix]
+    }
+
+    /// Returns all shades in the order of [`RAMP_SCALES`].
+    pub fn shades(&self) -> &[Hsla; 11] {
+        &self.0
+    }
+}
+
+/// The palette ramps of the semantic colors, computed when the theme changes.
+///
+/// ```ignore
+/// let bg = cx.theme().ramps.primary.shade(100);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ThemeRamps {
+    pub primary: ColorRamp,
+    /// Generated from the `muted_foreground` color.
+    pub neutral: ColorRamp,
+    pub danger: ColorRamp,
+    pub success: ColorRamp,
+    pub warning: ColorRamp,
+    pub info: ColorRamp,
+}
+
+impl ThemeRamps {
+    pub fn new(colors: &ThemeColor, is_dark: bool) -> Self {
+        Self {
+            primary: ColorRamp::new(colors.primary, is_dark),
+            neutral: ColorRamp::new(colors.muted_foreground, is_dark),
+            danger: ColorRamp::new(colors.danger, is_dark),
+            success: ColorRamp::new(colors.success, is_dark),
+            warning: ColorRamp::new(colors.warning, is_dark),
+            info: ColorRamp::new(colors.info, is_dark),
+        }
+    }
+}
+
+impl ThemeColor {
+    /// Recompute the primary colors and the related colors (ring, link, selection, etc.) from the `accent` color.
+    ///
+    /// The colors are picked from the tonal [`ColorRamp`] of the `accent`, so any seed color
+    /// has the same lightness relationships, e.g. the primary is `600` in light mode and `400` in dark mode.
+    pub fn apply_accent(&mut self, accent: Hsla, is_dark: bool) {
+        let ramp = ColorRamp::new(accent.alpha(1.), false);
+        let (primary, hover, active) = if is_dark {
+            (ramp.shade(400), ramp.shade(300), ramp.shade(500))
+        } else {
+            (ramp.shade(600), ramp.shade(500), ramp.shade(700))
+        };
+        // Pick the foreground by the lightness of the primary to keep the contrast.
+        let foreground = if to_oklch(primary.to_rgb()).0 > ACCENT_FOREGROUND_LIGHTNESS {
+            ramp.shade(950)
+        } else {
+            ramp.shade(50)
+        };
+
+        self.primary = primary;
+        self.primary_hover = hover;
+        self.primary_active = active;
+        self.primary_foreground = foreground;
+        self.ring = primary;
+        self.caret = primary;
+        self.link = primary;
+        self.link_hover = hover;
+        self.link_active = active;
+        self.progress_bar = primary;
+        self.slider_bar = primary;
+        self.slider_thumb = foreground;
+        self.sidebar_primary = primary;
+        self.sidebar_primary_foreground = foreground;
+        self.drag_border = primary.opacity(0.65);
+        self.drop_target = primary.opacity(0.2);
+        self.selection = primary.alpha(0.3);
+        self.list_active = primary.alpha(0.2);
+        self.list_active_border = self.background.blend(primary.opacity(0.6));
+        self.table_active = self.list_active;
+        self.table_active_border = self.list_active_border;
+    }
+}
+
+fn in_gamut(rgb: &Rgba) -> bool {
+    const EPSILON: f32 = 0.0001;
+    [rgb.r, rgb.g, rgb.b]
+        .iter()
+        .all(|c| (-EPSILON..=1. + EPSILON).contains(c))
+}
+
+fn to_linear(c: f32) -> f32 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn from_linear(c: f32) -> f32 {
+    if c <= 0.0031308 {
+        12.92 * c
+    } else {
+        1.055 * c.max(0.).powf(1. / 2.4) - 0.055
+    }
+}
+
+/// Convert sRGB to OKLCH, returns `(lightness, chroma, hue)`, the hue is in radians.
+#[allow(clippy::excessive_precision)]
+fn to_oklch(rgb: Rgba) -> (f32, f32, f32) {
+    let (r, g, b) = (to_linear(rgb.r), to_linear(rgb.g), to_linear(rgb.b));
+
+    let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+    let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+    let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+
+    let lightness = 0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s;
+    let a = 1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s;
+    let b = 0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s;
+
+    (lightness, (a * a + b * b).sqrt(), b.atan2(a))
+}
+
+/// Convert OKLCH to sRGB, the result may be out of the sRGB gamut.
+#[allow(clippy::excessive_precision)]
+fn from_oklch(lightness: f32, chroma: f32, hue: f32) -> Rgba {
+    let (a, b) = (chroma * hue.cos(), chroma * hue.sin());
+
+    let l = (lightness + 0.3963377774 * a + 0.2158037573 * b).powi(3);
+    let m = (lightness - 0.1055613458 * a - 0.0638541728 * b).powi(3);
+    let s = (lightness - 0.0894841775 * a - 1.2914855480 * b).powi(3);
+
+    Rgba {
+        r: from_linear(4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s),
+        g: from_linear(-1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s),
+        b: from_linear(-0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s),
+        a: 1.,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::rgb;
+
+    use super::*;
+
+    #[test]
+    fn test_oklch_round_trip() {
+        let color = rgb(0x3b82f6);
+        let (l, c, h) = to_oklch(color);
+        let result = from_oklch(l, c, h);
+        assert!((result.r - color.r).abs() < 0.001);
+        assert!((result.g - color.g).abs() < 0.001);
+        assert!((result.b - color.b).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_color_ramp() {
+        let base: Hsla = rgb(0x3b82f6).into();
+
+        let ramp = ColorRamp::new(base, false);
+        let lightness = ramp
+            .shades()
+            .iter()
+            .map(|c| to_oklch(c.to_rgb()).0)
+            .collect::<Vec<_>>();
+        assert!(lightness.windows(2).all(|w| w[0] > w[1]));
+        assert!((lightness[0] - 0.975).abs() < 0.01);
+        assert_eq!(ramp.shade(500), ramp.shades()[5]);
+        assert_eq!(ramp.shade(450), ramp.shade(500));
+        assert_eq!(ramp.shade(1000), ramp.shade(950));
+
+        let dark_ramp = ColorRamp::new(base, true);
+        assert_eq!(dark_ramp.shade(50), ramp.shade(950));
+        assert_eq!(dark_ramp.shade(950), ramp.shade(50));
+    }
+
+    #[test]
+    fn test_apply_accent() {
+        let accent: Hsla = rgb(0xe11d48).into();
+        let ramp = ColorRamp::new(accent, false);
+
+        let mut colors = ThemeColor::default();
+        colors.apply_accent(accent, false);
+        assert_eq!(colors.primary, ramp.shade(600));
+        assert_eq!(colors.primary_foreground, ramp.shade(50));
+        assert_eq!(colors.ring, colors.primary);
+        assert_eq!(colors.sidebar_primary, colors.primary);
+
+        colors.apply_accent(accent, true);
+        assert_eq!(colors.primary, ramp.shade(400));
+        assert_eq!(colors.primary_foreground, ramp.shade(950));
+        assert_eq!(colors.primary_hover, ramp.shade(300));
+    }
+}
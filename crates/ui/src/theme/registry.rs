@@ -5,7 +5,7 @@ use notify::Watcher as _;
 use std::{
     collections::HashMap,
     fs,
-    path::PathBuf,
+    path::{Path, PathBuf},
     rc::Rc,
     sync::{Arc, LazyLock},
 };
@@ -117,6 +117,86 @@ impl ThemeRegistry {
         Ok(())
     }
 
+    /// Watch a theme file, and hot apply the changes to the active theme when the file is saved.
+    ///
+    /// The themes in the file are added to the registry (replace the themes with the same name),
+    /// so the active theme is reloaded if it is defined in the file.
+    ///
+    /// If the file is failed to read or parse, the `on_error` callback is called with the error message,
+    /// and the previous themes are kept.
+    pub fn watch_file<F>(path: PathBuf, cx: &mut App, on_error: F) -> Result<()>
+    where
+        F: Fn(SharedString, &mut App) + 'static,
+    {
+        let Some(dir) = path.parent().map(Path::to_path_buf) else {
+            return Err(anyhow::anyhow!("invalid theme file: {}", path.display()));
+        };
+        let file_name = path.file_name().map(|name| name.to_os_string());
+
+        let (tx, rx) = smol::channel::bounded(100);
+        let mut watcher =
+            notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+                let Ok(event) = res else {
+                    return;
+                };
+
+                // Watch the parent directory, because some editors save the file by renaming.
+                if !matches!(
+                    event.kind,
+                    notify::EventKind::Create(_) | notify::EventKind::Modify(_)
+                ) || !event
+                    .paths
+                    .iter()
+                    .any(|path| path.file_name() == file_name.as_deref())
+                {
+                    return;
+                }
+
+                if let Err(err) = tx.send_blocking(()) {
+                    tracing::error!("Failed to send theme file event: {:?}", err);
+                }
+            })?;
+        watcher.watch(&dir, notify::RecursiveMode::NonRecursive)?;
+
+        let on_error = Rc::new(on_error);
+        Self::reload_file(&path, on_error.clone(), cx);
+        cx.spawn(async move |cx| {
+            // Keep the watcher alive with the task.
+            let _watcher = watcher;
+            while (rx.recv().await).is_ok() {
+                tracing::info!("Reloading theme file: {}...", path.display());
+                _ = cx.update(|cx| Self::reload_file(&path, on_error.clone(), cx));
+            }
+        })
+        .detach();
+
+        Ok(())
+    }
+
+    fn reload_file(path: &Path, on_error: Rc<dyn Fn(SharedString, &mut App)>, cx: &mut App) {
+        let theme_set = fs::read_to_string(path)
+            .map_err(anyhow::Error::from)
+            .and_then(|content| Ok(serde_json::from_str::<ThemeSet>(&content)?));
+
+        match theme_set {
+            Ok(theme_set) => {
+                let registry = Self::global_mut(cx);
+                for theme in theme_set.themes {
+                    registry.has_custom_themes = true;
+                    registry.themes.insert(theme.name.clone(), Rc::new(theme));
+                }
+                tracing::info!("Theme file reloaded: {}", path.display());
+            }
+            Err(err) => {
+                tracing::error!("Failed to reload theme file: {}, {}", path.display(), err);
+                on_error(
+                    format!("Failed to load theme {}: {}", path.display(), err).into(),
+                    cx,
+                );
+            }
+        }
+    }
+
     /// Returns a reference to the map of themes (including default themes).
     pub fn themes(&self) -> &HashMap<SharedString, Rc<ThemeConfig>> {
         &self.themes
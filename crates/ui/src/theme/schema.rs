@@ -630,6 +630,7 @@ impl Theme {
 
         self.colors.apply_config(&config, &default_theme);
         self.mode = config.mode;
+        self.update_ramps();
     }
 }
 
@@ -0,0 +1,124 @@
+use std::rc::Rc;
+
+use gpui::{
+    AnyElement, App, Bounds, Element, ElementId, Global, GlobalElementId, InspectorElementId,
+    IntoElement, LayoutId, Pixels, Window,
+};
+
+use super::Theme;
+
+/// The stack of the overridden themes, the last one is used by [`super::ActiveTheme`].
+#[derive(Default)]
+pub(super) struct ThemeStack(pub(super) Vec<Rc<Theme>>);
+
+impl Global for ThemeStack {}
+
+/// Render the subtree with an alternate [`Theme`], e.g. a dark preview pane in a light app.
+///
+/// The `cx.theme()` returns the `theme` in the `f` and during the layout and paint of the
+/// returned element, the global theme is unchanged.
+///
+/// NOTE: The event handlers are called outside the subtree, so they still get the global theme.
+///
+/// ```ignore
+/// let mut dark_theme = cx.theme().clone();
+/// dark_theme.apply_config(&ThemeRegistry::global(cx).default_dark_theme());
+///
+/// themed(dark_theme, |_, cx| {
+///     div().bg(cx.theme().background).child(Button::new("ok").primary().label("OK"))
+/// })
+/// ```
+pub fn themed<E>(
+    theme: impl Into<Rc<Theme>>,
+    f: impl FnOnce(&mut Window, &mut App) -> E + 'static,
+) -> Themed
+where
+    E: IntoElement,
+{
+    Themed {
+        theme: theme.into(),
+        builder: Some(Box::new(move |window, cx| f(window, cx).into_any_element())),
+    }
+}
+
+/// An element to render the subtree with an alternate [`Theme`], see [`themed`].
+pub struct Themed {
+    theme: Rc<Theme>,
+    builder: Option<Box<dyn FnOnce(&mut Window, &mut App) -> AnyElement>>,
+}
+
+impl Themed {
+    fn with_theme<R>(&self, cx: &mut App, f: impl FnOnce(&mut App) -> R) -> R {
+        cx.default_global::<ThemeStack>().0.push(self.theme.clone());
+        let result = f(cx);
+        cx.default_global::<ThemeStack>().0.pop();
+        result
+    }
+}
+
+impl IntoElement for Themed {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for Themed {
+    type RequestLayoutState = AnyElement;
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn source_location(&self) -> Option<&'static std::panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _: Option<&GlobalElementId>,
+        _: Option<&InspectorElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let builder = self
+            .builder
+            .take()
+            .expect("themed element is requested layout twice");
+
+        self.with_theme(cx, |cx| {
+            let mut element = builder(window, cx);
+            let layout_id = element.request_layout(window, cx);
+            (layout_id, element)
+        })
+    }
+
+    fn prepaint(
+        &mut self,
+        _: Option<&GlobalElementId>,
+        _: Option<&InspectorElementId>,
+        _: Bounds<Pixels>,
+        element: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Self::PrepaintState {
+        self.with_theme(cx, |cx| {
+            element.prepaint(window, cx);
+        })
+    }
+
+    fn paint(
+        &mut self,
+        _: Option<&GlobalElementId>,
+        _: Option<&InspectorElementId>,
+        _: Bounds<Pixels>,
+        element: &mut Self::RequestLayoutState,
+        _: &mut Self::PrepaintState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        self.with_theme(cx, |cx| element.paint(window, cx))
+    }
+}
@@ -1,6 +1,6 @@
 use std::rc::Rc;
 
-use chrono::NaiveDate;
+use chrono::{Local, NaiveDate};
 use gpui::{
     anchored, deferred, div, prelude::FluentBuilder as _, px, App, AppContext, Context, ElementId,
     Empty, Entity, EventEmitter, FocusHandle, Focusable, InteractiveElement as _, IntoElement,
@@ -30,10 +30,15 @@ pub enum DatePickerEvent {
     Change(Date),
 }
 
+/// A function to calculate the `(start, end)` dates from today.
+pub type RelativeDateRange = fn(NaiveDate) -> (NaiveDate, NaiveDate);
+
 #[derive(Clone)]
 pub enum DateRangePresetValue {
     Single(NaiveDate),
     Range(NaiveDate, NaiveDate),
+    /// Calculated from today when the preset is clicked.
+    Relative(RelativeDateRange),
 }
 
 #[derive(Clone)]
@@ -57,6 +62,45 @@ impl DateRangePreset {
             value: DateRangePresetValue::Range(start, end),
         }
     }
+
+    /// Creates a new DateRangePreset calculated from today when it is clicked.
+    ///
+    /// In range mode both the start and end are selected, otherwise the start is selected.
+    ///
+    /// ```ignore
+    /// DateRangePreset::relative("Last 7 Days", |today| (today - Days::new(6), today))
+    /// ```
+    pub fn relative(label: impl Into<SharedString>, f: RelativeDateRange) -> Self {
+        DateRangePreset {
+            label: label.into(),
+            value: DateRangePresetValue::Relative(f),
+        }
+    }
+
+    /// Returns the date to select by this preset.
+    fn date(&self, today: NaiveDate, is_range: bool) -> Date {
+        match self.value {
+            DateRangePresetValue::Single(single) => Date::Single(Some(single)),
+            DateRangePresetValue::Range(start, end) => Date::Range(Some(start), Some(end)),
+            DateRangePresetValue::Relative(f) => {
+                let (start, end) = f(today);
+                if is_range {
+                    Date::Range(Some(start), Some(end))
+                } else {
+                    Date::Single(Some(start))
+                }
+            }
+        }
+    }
+}
+
+impl<S> From<(S, RelativeDateRange)> for DateRangePreset
+where
+    S: Into<SharedString>,
+{
+    fn from((label, f): (S, RelativeDateRange)) -> Self {
+        Self::relative(label, f)
+    }
 }
 
 /// Use to store the state of the date picker.
@@ -224,14 +268,9 @@ impl DatePickerState {
         window: &mut Window,
         cx: &mut Context<Self>,
     ) {
-        match preset.value {
-            DateRangePresetValue::Single(single) => {
-                self.update_date(Date::Single(Some(single)), true, window, cx)
-            }
-            DateRangePresetValue::Range(start, end) => {
-                self.update_date(Date::Range(Some(start), Some(end)), true, window, cx)
-            }
-        }
+        let today = Local::now().naive_local().date();
+        let is_range = matches!(self.date, Date::Range(_, _));
+        self.update_date(preset.date(today, is_range), true, window, cx);
     }
 }
 
@@ -308,9 +347,14 @@ impl DatePicker {
         self
     }
 
-    /// Set preset ranges for the date picker.
-    pub fn presets(mut self, presets: Vec<DateRangePreset>) -> Self {
-        self.presets = Some(presets);
+    /// Set preset ranges for the date picker, they are shown on the left of the calendar.
+    ///
+    /// Clicking a preset selects its date, closes the calendar and emits [`DatePickerEvent::Change`].
+    pub fn presets(
+        mut self,
+        presets: impl IntoIterator<Item = impl Into<DateRangePreset>>,
+    ) -> Self {
+        self.presets = Some(presets.into_iter().map(Into::into).collect());
         self
     }
 
@@ -469,3 +513,40 @@ impl RenderOnce for DatePicker {
             })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Datelike as _, Days, NaiveDate};
+
+    use super::{Date, DateRangePreset, RelativeDateRange};
+
+    #[test]
+    fn test_preset_date() {
+        let today = NaiveDate::from_ymd_opt(2024, 5, 15).unwrap();
+        let start = NaiveDate::from_ymd_opt(2024, 5, 1).unwrap();
+
+        let preset = DateRangePreset::single("Start", start);
+        assert_eq!(preset.date(today, true), Date::Single(Some(start)));
+
+        let preset = DateRangePreset::range("Range", start, today);
+        assert_eq!(
+            preset.date(today, false),
+            Date::Range(Some(start), Some(today))
+        );
+
+        let this_month: RelativeDateRange = |today| (today.with_day(1).unwrap(), today);
+        let preset = DateRangePreset::from(("This Month", this_month));
+        assert_eq!(
+            preset.date(today, true),
+            Date::Range(Some(start), Some(today))
+        );
+        assert_eq!(preset.date(today, false), Date::Single(Some(start)));
+
+        let preset =
+            DateRangePreset::relative("Last 7 Days", |today| (today - Days::new(6), today));
+        assert_eq!(
+            preset.date(today, true),
+            Date::Range(NaiveDate::from_ymd_opt(2024, 5, 9), Some(today))
+        );
+    }
+}
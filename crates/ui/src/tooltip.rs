@@ -1,9 +1,85 @@
+use std::{
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
 use gpui::{
-    div, prelude::FluentBuilder, px, Action, AnyElement, AnyView, App, AppContext, Context,
-    IntoElement, ParentElement, Render, SharedString, StyleRefinement, Styled, Window,
+    anchored, canvas, deferred, div, point, prelude::FluentBuilder, px, Action,
+    AnchoredPositionMode, AnyElement, AnyView, App, AppContext, Bounds, Context, Corner, ElementId,
+    FocusHandle, InteractiveElement, IntoElement, ParentElement, Pixels, Point, Render,
+    SharedString, Size, StatefulInteractiveElement, StyleRefinement, Styled, Task, Window,
+};
+
+use crate::{h_flex, text::Text, ActiveTheme, Kbd, Placement, StyledExt};
+
+/// The default delay before showing the tooltip, same as GPUI.
+const DEFAULT_DELAY: Duration = Duration::from_millis(500);
+/// The min space to show the tooltip at the top or bottom, otherwise flip to the other side.
+const MIN_VERTICAL_SPACE: Pixels = px(40.);
+/// The min space to show the tooltip at the left or right, otherwise flip to the other side.
+const MIN_HORIZONTAL_SPACE: Pixels = px(160.);
+/// The offset of the [`Tooltip::follow_cursor`] tooltip from the mouse pointer.
+const CURSOR_OFFSET: Point<Pixels> = Point {
+    x: px(12.),
+    y: px(16.),
 };
+/// The min interval to reposition the [`Tooltip::follow_cursor`] tooltip.
+const CURSOR_THROTTLE: Duration = Duration::from_millis(16);
+
+/// The placement of the [`Tooltip`] relative to the target element, see [`Tooltip::placement`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TooltipPlacement {
+    Top,
+    Bottom,
+    Left,
+    Right,
+    /// The top or bottom side with more space.
+    #[default]
+    Auto,
+}
+
+impl From<Placement> for TooltipPlacement {
+    fn from(placement: Placement) -> Self {
+        match placement {
+            Placement::Top => Self::Top,
+            Placement::Bottom => Self::Bottom,
+            Placement::Left => Self::Left,
+            Placement::Right => Self::Right,
+        }
+    }
+}
 
-use crate::{h_flex, text::Text, ActiveTheme, Kbd, StyledExt};
+impl TooltipPlacement {
+    /// Resolve the side to show the tooltip, flip to the other side if the preferred side
+    /// does not have enough space in the window.
+    fn resolve(self, bounds: Bounds<Pixels>, viewport: Size<Pixels>) -> Placement {
+        let space_top = bounds.top();
+        let space_bottom = viewport.height - bounds.bottom();
+        let space_left = bounds.left();
+        let space_right = viewport.width - bounds.right();
+
+        match self {
+            Self::Top if space_top < MIN_VERTICAL_SPACE && space_bottom > space_top => {
+                Placement::Bottom
+            }
+            Self::Top => Placement::Top,
+            Self::Bottom if space_bottom < MIN_VERTICAL_SPACE && space_top > space_bottom => {
+                Placement::Top
+            }
+            Self::Bottom => Placement::Bottom,
+            Self::Left if space_left < MIN_HORIZONTAL_SPACE && space_right > space_left => {
+                Placement::Right
+            }
+            Self::Left => Placement::Left,
+            Self::Right if space_right < MIN_HORIZONTAL_SPACE && space_left > space_right => {
+                Placement::Left
+            }
+            Self::Right => Placement::Right,
+            Self::Auto if space_bottom >= space_top => Placement::Bottom,
+            Self::Auto => Placement::Top,
+        }
+    }
+}
 
 enum TooltipContext {
     Text(Text),
@@ -15,6 +91,10 @@ pub struct Tooltip {
     content: TooltipContext,
     key_binding: Option<Kbd>,
     action: Option<(Box<dyn Action>, Option<SharedString>)>,
+    delay: Option<Duration>,
+    hide_delay: Option<Duration>,
+    placement: Option<TooltipPlacement>,
+    follow_cursor: bool,
 }
 
 impl Tooltip {
@@ -25,6 +105,10 @@ impl Tooltip {
             content: TooltipContext::Text(text.into()),
             key_binding: None,
             action: None,
+            delay: None,
+            hide_delay: None,
+            placement: None,
+            follow_cursor: false,
         }
     }
 
@@ -38,6 +122,10 @@ impl Tooltip {
             style: StyleRefinement::default(),
             key_binding: None,
             action: None,
+            delay: None,
+            hide_delay: None,
+            placement: None,
+            follow_cursor: false,
             content: TooltipContext::Element(Box::new(move |window, cx| {
                 builder(window, cx).into_any_element()
             })),
@@ -56,6 +144,62 @@ impl Tooltip {
         self
     }
 
+    /// Set the hover dwell time before showing the tooltip, default: 500ms
+    ///
+    /// This is only used by [`TooltipExt::tooltip_with`].
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = Some(delay);
+        self
+    }
+
+    /// Set the time to keep the tooltip after the mouse leaves, default: 0
+    ///
+    /// This is only used by [`TooltipExt::tooltip_with`].
+    pub fn hide_delay(mut self, delay: Duration) -> Self {
+        self.hide_delay = Some(delay);
+        self
+    }
+
+    /// Set the placement of the tooltip relative to the element, default: [`TooltipPlacement::Auto`]
+    ///
+    /// The tooltip flips to the other side if the preferred side would overflow the window.
+    ///
+    /// This is only used by [`TooltipExt::tooltip_with`].
+    pub fn placement(mut self, placement: impl Into<TooltipPlacement>) -> Self {
+        self.placement = Some(placement.into());
+        self
+    }
+
+    /// Set true to show the tooltip at the mouse pointer and follow it within the element,
+    /// default: false
+    ///
+    /// This is useful for large elements like charts and maps, the [`Tooltip::placement`]
+    /// is ignored in this mode.
+    ///
+    /// This is only used by [`TooltipExt::tooltip_with`].
+    pub fn follow_cursor(mut self, follow_cursor: bool) -> Self {
+        self.follow_cursor = follow_cursor;
+        self
+    }
+
+    /// Returns the show and hide options, or `None` if none of them is set.
+    fn options(&self) -> Option<TooltipOptions> {
+        if self.delay.is_none()
+            && self.hide_delay.is_none()
+            && self.placement.is_none()
+            && !self.follow_cursor
+        {
+            return None;
+        }
+
+        Some(TooltipOptions {
+            delay: self.delay.unwrap_or(DEFAULT_DELAY),
+            hide_delay: self.hide_delay.unwrap_or_default(),
+            placement: self.placement.unwrap_or_default(),
+            follow_cursor: self.follow_cursor,
+        })
+    }
+
     /// Build the tooltip and return it as an `AnyView`.
     pub fn build(self, _: &mut Window, cx: &mut App) -> AnyView {
         cx.new(|_| self).into()
@@ -120,3 +264,311 @@ impl Render for Tooltip {
         )
     }
 }
+
+/// The keyboard dismiss state of the tooltip target.
+struct TooltipFocusState {
+    /// Dismissed by `escape`, reset when the target is blurred.
+    dismissed: bool,
+}
+
+impl TooltipFocusState {
+    /// Sync the state with the `focused` of the target, returns whether the tooltip is visible.
+    fn sync(&mut self, focused: bool) -> bool {
+        if !focused {
+            self.dismissed = false;
+        }
+        focused && !self.dismissed
+    }
+}
+
+/// Extension to show the tooltip on hover and keyboard focus.
+pub(crate) trait FocusableTooltip:
+    StatefulInteractiveElement + ParentElement + FluentBuilder + Sized
+{
+    /// Set the tooltip of the element, the tooltip is shown on hover with the delay,
+    /// or immediately when the `focus_handle` of the element is focused.
+    ///
+    /// The `focus_handle` must be tracked by the element (or its ancestors) by the caller,
+    /// if it is `None`, this is the same as the `tooltip` of GPUI.
+    fn focusable_tooltip(
+        self,
+        id: &ElementId,
+        focus_handle: Option<&FocusHandle>,
+        build: impl Fn(&mut Window, &mut App) -> AnyView + 'static,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Self {
+        let focused = focus_handle.map_or(false, |handle| handle.is_focused(window));
+        self.keyboard_tooltip(id, focused, build, window, cx)
+    }
+
+    /// Set the tooltip of the element, the tooltip is shown on hover with the delay,
+    /// or immediately when `focused` is true, e.g.: the item is selected by keyboard.
+    ///
+    /// The focused tooltip is hidden when `focused` becomes false or by `escape`,
+    /// and the hover tooltip is suppressed while it is visible.
+    fn keyboard_tooltip(
+        self,
+        id: &ElementId,
+        focused: bool,
+        build: impl Fn(&mut Window, &mut App) -> AnyView + 'static,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Self {
+        let state =
+            window.use_keyed_state(SharedString::from(format!("{}/tooltip", id)), cx, |_, _| {
+                TooltipFocusState { dismissed: false }
+            });
+        let visible = state.update(cx, |state, _| state.sync(focused));
+        let build = Rc::new(build);
+
+        self.when(visible, |this| {
+            this.on_key_down(move |event, _, cx| {
+                if event.keystroke.key == "escape" {
+                    state.update(cx, |state, cx| {
+                        state.dismissed = true;
+                        cx.notify();
+                    });
+                    cx.stop_propagation();
+                }
+            })
+        })
+        .map(|this| {
+            if visible {
+                this.child(
+                    div().absolute().left_0().top_full().child(
+                        deferred(
+                            anchored()
+                                .snap_to_window_with_margin(px(8.))
+                                .child(build(window, cx)),
+                        )
+                        .with_priority(1),
+                    ),
+                )
+            } else {
+                this.tooltip(move |window, cx| build(window, cx))
+            }
+        })
+    }
+}
+
+impl<E> FocusableTooltip for E where
+    E: StatefulInteractiveElement + ParentElement + FluentBuilder + Sized
+{
+}
+
+/// The show and hide options of the [`Tooltip`] used by [`TooltipExt::tooltip_with`].
+#[derive(Debug, Clone, Copy)]
+struct TooltipOptions {
+    delay: Duration,
+    hide_delay: Duration,
+    placement: TooltipPlacement,
+    follow_cursor: bool,
+}
+
+/// The hover state of the [`TooltipExt::tooltip_with`] target.
+struct HoverTooltipState {
+    /// The options read from the first built tooltip.
+    options: Option<TooltipOptions>,
+    visible: bool,
+    /// The tooltip view built when it is shown, dropped when hidden.
+    view: Option<AnyView>,
+    bounds: Bounds<Pixels>,
+    mouse_position: Point<Pixels>,
+    last_moved_at: Option<Instant>,
+    _task: Task<()>,
+}
+
+/// Extension to show a [`Tooltip`] on hover with its delay and placement.
+pub trait TooltipExt: StatefulInteractiveElement + ParentElement + FluentBuilder + Sized {
+    /// Set the tooltip of the element with the [`Tooltip::delay`], [`Tooltip::hide_delay`],
+    /// [`Tooltip::placement`] and [`Tooltip::follow_cursor`] of the built tooltip.
+    ///
+    /// The options are read once from the first built tooltip, and the tooltip view is
+    /// built once each time it is shown. If none of the options is set, this is the same
+    /// as the `tooltip` of GPUI.
+    ///
+    /// ```ignore
+    /// div()
+    ///     .id("info")
+    ///     .child("Hover me")
+    ///     .tooltip_with(
+    ///         "info",
+    ///         |_, _| Tooltip::new("Info").delay(Duration::from_millis(200)).placement(Placement::Right),
+    ///         window,
+    ///         cx,
+    ///     )
+    /// ```
+    fn tooltip_with(
+        self,
+        id: impl Into<ElementId>,
+        build: impl Fn(&mut Window, &mut App) -> Tooltip + 'static,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Self {
+        let id: ElementId = id.into();
+        let build = Rc::new(build);
+
+        let state =
+            window.use_keyed_state(SharedString::from(format!("{}/hover-tooltip", id)), cx, {
+                let build = build.clone();
+                move |window, cx| HoverTooltipState {
+                    options: build(window, cx).options(),
+                    visible: false,
+                    view: None,
+                    bounds: Bounds::default(),
+                    mouse_position: Point::default(),
+                    last_moved_at: None,
+                    _task: Task::ready(()),
+                }
+            });
+        let Some(TooltipOptions {
+            delay,
+            hide_delay,
+            placement,
+            follow_cursor,
+        }) = state.read(cx).options
+        else {
+            return self.tooltip(move |window, cx| build(window, cx).build(window, cx));
+        };
+        let visible = state.read(cx).visible;
+
+        self.on_hover({
+            let state = state.clone();
+            move |hovered, _, cx| {
+                let hovered = *hovered;
+                let delay = if hovered { delay } else { hide_delay };
+
+                // Replace the task to cancel the pending show or hide.
+                state.update(cx, |state, cx| {
+                    state._task = cx.spawn(async move |this, cx| {
+                        if !delay.is_zero() {
+                            cx.background_executor().timer(delay).await;
+                        }
+                        _ = this.update(cx, |state, cx| {
+                            state.visible = hovered;
+                            if !hovered {
+                                state.view = None;
+                            }
+                            cx.notify();
+                        });
+                    });
+                });
+            }
+        })
+        .when(follow_cursor, |this| {
+            let state = state.clone();
+            this.on_mouse_move(move |event, _, cx| {
+                state.update(cx, |state, cx| {
+                    state.mouse_position = event.position;
+
+                    // Throttle the relayout when the mouse moves fast.
+                    let now = Instant::now();
+                    if state.visible
+                        && state
+                            .last_moved_at
+                            .map_or(true, |at| now.duration_since(at) >= CURSOR_THROTTLE)
+                    {
+                        state.last_moved_at = Some(now);
+                        cx.notify();
+                    }
+                });
+            })
+        })
+        .child(
+            canvas(
+                {
+                    let state = state.clone();
+                    move |bounds, _, cx| state.update(cx, |state, _| state.bounds = bounds)
+                },
+                |_, _, _, _| {},
+            )
+            .absolute()
+            .size_full(),
+        )
+        .when(visible, |this| {
+            let view = state.update(cx, |state, cx| {
+                state
+                    .view
+                    .get_or_insert_with(|| build(window, cx).build(window, cx))
+                    .clone()
+            });
+            let state = state.read(cx);
+            let bounds = state.bounds;
+            let (position, anchor) = if follow_cursor {
+                (state.mouse_position + CURSOR_OFFSET, Corner::TopLeft)
+            } else {
+                match placement.resolve(bounds, window.viewport_size()) {
+                    Placement::Top => (bounds.origin, Corner::BottomLeft),
+                    Placement::Bottom => (bounds.bottom_left(), Corner::TopLeft),
+                    Placement::Left => (bounds.origin, Corner::TopRight),
+                    Placement::Right => (point(bounds.right(), bounds.top()), Corner::TopLeft),
+                }
+            };
+
+            this.child(
+                div().absolute().child(
+                    deferred(
+                        anchored()
+                            .position_mode(AnchoredPositionMode::Window)
+                            .position(position)
+                            .anchor(anchor)
+                            .snap_to_window_with_margin(px(8.))
+                            .child(view),
+                    )
+                    .with_priority(1),
+                ),
+            )
+        })
+    }
+}
+
+impl<E> TooltipExt for E where E: StatefulInteractiveElement + ParentElement + FluentBuilder + Sized {}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Tooltip, TooltipFocusState, TooltipPlacement, DEFAULT_DELAY};
+    use crate::Placement;
+
+    #[test]
+    fn test_tooltip_options() {
+        assert!(Tooltip::new("Info").options().is_none());
+
+        let options = Tooltip::new("Info")
+            .placement(Placement::Right)
+            .options()
+            .unwrap();
+        assert_eq!(options.delay, DEFAULT_DELAY);
+        assert_eq!(options.hide_delay, Duration::ZERO);
+        assert_eq!(options.placement, TooltipPlacement::Right);
+        assert!(!options.follow_cursor);
+
+        let options = Tooltip::new("Info")
+            .delay(Duration::from_millis(200))
+            .follow_cursor(true)
+            .options()
+            .unwrap();
+        assert_eq!(options.delay, Duration::from_millis(200));
+        assert_eq!(options.placement, TooltipPlacement::Auto);
+        assert!(options.follow_cursor);
+    }
+
+    #[test]
+    fn test_focus_state_sync() {
+        let mut state = TooltipFocusState { dismissed: false };
+        assert!(!state.sync(false));
+        // Show on focus.
+        assert!(state.sync(true));
+
+        // Hide by escape, and keep hidden while focused.
+        state.dismissed = true;
+        assert!(!state.sync(true));
+
+        // Reset on blur, and show on the next focus.
+        assert!(!state.sync(false));
+        assert!(!state.dismissed);
+        assert!(state.sync(true));
+    }
+}
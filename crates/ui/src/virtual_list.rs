@@ -18,20 +18,63 @@ use std::{
 };
 
 use gpui::{
-    div, point, px, size, Along, AnyElement, App, AvailableSpace, Axis, Bounds, ContentMask,
-    Context, DeferredScrollToItem, Div, Element, ElementId, Entity, GlobalElementId, Half, Hitbox,
+    div, ease_in_out, point, px, size, Along, AnyElement, App, AvailableSpace, Axis, Bounds,
+    ContentMask, Context, Div, Element, ElementId, Entity, GlobalElementId, Half, Hitbox,
     InteractiveElement, IntoElement, IsZero as _, ListSizingBehavior, Pixels, Point, Render,
     ScrollHandle, ScrollStrategy, Size, Stateful, StatefulInteractiveElement, StyleRefinement,
     Styled, Window,
 };
 use smallvec::SmallVec;
 
-use crate::{scroll::ScrollHandleOffsetable, AxisExt};
+use crate::{
+    scroll::{animate_scroll_to, ScrollHandleOffsetable, SCROLL_ANIMATION_DURATION},
+    AxisExt,
+};
+
+/// The alignment of the item in the viewport when scrolling to it, see [`VirtualListScrollHandle::scroll_to`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ScrollAlign {
+    /// Align the item to the start (top or left) of the viewport.
+    Start,
+    /// Align the item to the center of the viewport.
+    Center,
+    /// Align the item to the end (bottom or right) of the viewport.
+    End,
+    /// Scroll as little as possible to make the item visible, this is the default.
+    #[default]
+    Nearest,
+}
+
+impl From<ScrollStrategy> for ScrollAlign {
+    fn from(strategy: ScrollStrategy) -> Self {
+        match strategy {
+            ScrollStrategy::Center => Self::Center,
+            _ => Self::Nearest,
+        }
+    }
+}
+
+/// The scroll position of the [`VirtualList`], captured by [`VirtualListScrollHandle::state`].
+///
+/// This can be used to restore the scroll position after the list is rebuilt,
+/// e.g.: navigate away and back.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct VirtualListState {
+    /// The scroll offset of the list.
+    pub offset: Point<Pixels>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct DeferredScrollToItem {
+    item_index: usize,
+    align: ScrollAlign,
+    animated: bool,
+}
 
 struct VirtualListScrollHandleState {
     axis: Axis,
     items_count: usize,
-    pub deferred_scroll_to_item: Option<DeferredScrollToItem>,
+    deferred_scroll_to_item: Option<DeferredScrollToItem>,
 }
 
 #[derive(Clone)]
@@ -94,19 +137,48 @@ impl VirtualListScrollHandle {
 
     /// Scroll to the item at the given index.
     pub fn scroll_to_item(&self, ix: usize, strategy: ScrollStrategy) {
-        self.scroll_to_item_with_offset(ix, strategy, 0);
+        self.scroll_to(ix, strategy.into());
     }
 
-    /// Scroll to the item at the given index, with an additional offset items.
-    fn scroll_to_item_with_offset(&self, ix: usize, strategy: ScrollStrategy, offset: usize) {
+    /// Scroll to the item at the given index with the alignment in the viewport.
+    ///
+    /// The scroll is applied on the next layout, the index is clamped to the last item.
+    pub fn scroll_to(&self, ix: usize, align: ScrollAlign) {
         let mut state = self.state.borrow_mut();
         state.deferred_scroll_to_item = Some(DeferredScrollToItem {
             item_index: ix,
-            strategy,
-            offset,
+            align,
+            animated: false,
         });
     }
 
+    /// Like [`VirtualListScrollHandle::scroll_to`], but scroll with an animation.
+    ///
+    /// The animation is cancelled if the user scrolls manually, see [`crate::scroll::animate_scroll_to`].
+    pub fn animate_scroll_to(&self, ix: usize, align: ScrollAlign) {
+        let mut state = self.state.borrow_mut();
+        state.deferred_scroll_to_item = Some(DeferredScrollToItem {
+            item_index: ix,
+            align,
+            animated: true,
+        });
+    }
+
+    /// Capture the current scroll position, see [`VirtualListScrollHandle::restore_state`].
+    pub fn state(&self) -> VirtualListState {
+        VirtualListState {
+            offset: self.base_handle.offset(),
+        }
+    }
+
+    /// Restore the scroll position captured by [`VirtualListScrollHandle::state`].
+    ///
+    /// The offset is clamped on the next layout if the content became smaller, e.g.: the items count shrank.
+    pub fn restore_state(&self, state: VirtualListState) {
+        self.state.borrow_mut().deferred_scroll_to_item = None;
+        self.base_handle.set_offset(state.offset);
+    }
+
     /// Scrolls to the bottom of the list.
     pub fn scroll_to_bottom(&self) {
         let items_count = self.state.borrow().items_count;
@@ -239,16 +311,30 @@ impl VirtualList {
         content_bounds: &Bounds<Pixels>,
         scroll_to_item: DeferredScrollToItem,
     ) -> Point<Pixels> {
-        let Some(bounds) = items_bounds
-            .get(scroll_to_item.item_index + scroll_to_item.offset)
-            .cloned()
-        else {
+        let ix = scroll_to_item
+            .item_index
+            .min(items_bounds.len().saturating_sub(1));
+        let Some(bounds) = items_bounds.get(ix).cloned() else {
             return scroll_offset;
         };
 
         let mut scroll_offset = scroll_offset;
-        match scroll_to_item.strategy {
-            ScrollStrategy::Center => {
+        match scroll_to_item.align {
+            ScrollAlign::Start => {
+                if self.axis.is_vertical() {
+                    scroll_offset.y = content_bounds.top() - bounds.top();
+                } else {
+                    scroll_offset.x = content_bounds.left() - bounds.left();
+                }
+            }
+            ScrollAlign::End => {
+                if self.axis.is_vertical() {
+                    scroll_offset.y = content_bounds.bottom() - bounds.bottom();
+                } else {
+                    scroll_offset.x = content_bounds.right() - bounds.right();
+                }
+            }
+            ScrollAlign::Center => {
                 if self.axis.is_vertical() {
                     scroll_offset.y = content_bounds.top() + content_bounds.size.height.half()
                         - bounds.top()
@@ -259,7 +345,7 @@ impl VirtualList {
                         - bounds.size.width.half()
                 }
             }
-            _ => {
+            ScrollAlign::Nearest => {
                 // Ref: https://github.com/zed-industries/zed/blob/0d145289e0867a8d5d63e5e1397a5ca69c9d49c3/crates/gpui/src/elements/div.rs#L3026
                 if self.axis.is_vertical() {
                     if bounds.top() + scroll_offset.y < content_bounds.top() {
@@ -276,7 +362,6 @@ impl VirtualList {
                 }
             }
         }
-        self.scroll_handle.set_offset(scroll_offset);
         scroll_offset
     }
 }
@@ -541,12 +626,36 @@ impl Element for VirtualList {
 
         let mut scroll_offset = self.scroll_handle.offset();
         if let Some(scroll_to_item) = scroll_state.deferred_scroll_to_item.take() {
-            scroll_offset = self.scroll_to_deferred_item(
+            let target = self.scroll_to_deferred_item(
                 scroll_offset,
                 &items_bounds,
                 &content_bounds,
                 scroll_to_item,
             );
+
+            if scroll_to_item.animated {
+                // Clamp the target, otherwise the clamping in the frames would cancel the animation.
+                let min_scroll_offset = (content_bounds.size.along(axis)
+                    - layout.size_layout.content_size.along(axis))
+                .min(px(0.));
+                let mut target = target.min(&point(px(0.), px(0.)));
+                match axis {
+                    Axis::Horizontal => target.x = target.x.max(min_scroll_offset),
+                    Axis::Vertical => target.y = target.y.max(min_scroll_offset),
+                }
+
+                animate_scroll_to(
+                    self.scroll_handle.base_handle(),
+                    target,
+                    SCROLL_ANIMATION_DURATION,
+                    ease_in_out,
+                    window,
+                    cx,
+                );
+            } else {
+                self.scroll_handle.set_offset(target);
+                scroll_offset = target;
+            }
         }
         scroll_offset = scroll_offset.min(&point(px(0.), px(0.)));
 
@@ -581,6 +690,18 @@ impl Element for VirtualList {
                         }
                     }
 
+                    // Keep the clamped offset, e.g.: a restored offset after the items count shrank.
+                    if scroll_offset.along(self.axis)
+                        != self.scroll_handle.offset().along(self.axis)
+                    {
+                        let mut offset = self.scroll_handle.offset();
+                        match self.axis {
+                            Axis::Horizontal => offset.x = scroll_offset.x,
+                            Axis::Vertical => offset.y = scroll_offset.y,
+                        }
+                        self.scroll_handle.set_offset(offset);
+                    }
+
                     let (first_visible_element_ix, last_visible_element_ix) = match self.axis {
                         Axis::Horizontal => {
                             let mut cumulative_size = px(0.);
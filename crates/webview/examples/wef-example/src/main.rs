@@ -6,12 +6,14 @@ use gpui::{
     Styled, Timer, Window, WindowBounds, WindowOptions, div, px, size,
 };
 use gpui_component::{
-    Root,
+    Disableable as _, IconName, Root, Sizable as _,
+    button::{Button, ButtonVariants as _},
+    h_flex,
     input::{InputEvent, InputState, TextInput},
 };
 use gpui_webview::{
     WebView,
-    events::TitleChangedEvent,
+    events::{NavigationStateChangedEvent, TitleChangedEvent},
     wef::{self, Frame, FuncRegistry, Settings},
 };
 use serde::Serialize;
@@ -62,6 +64,12 @@ impl Main {
                 })
                 .detach();
 
+            // update the navigation buttons
+            cx.subscribe(&webview, |_, _, _: &NavigationStateChangedEvent, cx| {
+                cx.notify()
+            })
+            .detach();
+
             // create address input
             let address_state = cx.new(|cx| InputState::new(window, cx).default_value(url));
 
@@ -87,9 +95,53 @@ impl Main {
 
 impl Render for Main {
     fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let webview = self.webview.read(cx);
+        let is_loading = webview.is_loading();
+        let can_go_back = webview.can_go_back();
+        let can_go_forward = webview.can_go_forward();
+
         div()
             .size_full()
-            .child(TextInput::new(&self.address_state))
+            .child(
+                h_flex()
+                    .gap_1()
+                    .p_1()
+                    .child(
+                        Button::new("back")
+                            .icon(IconName::ArrowLeft)
+                            .ghost()
+                            .small()
+                            .disabled(!can_go_back)
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                this.webview.read(cx).go_back();
+                            })),
+                    )
+                    .child(
+                        Button::new("forward")
+                            .icon(IconName::ArrowRight)
+                            .ghost()
+                            .small()
+                            .disabled(!can_go_forward)
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                this.webview.read(cx).go_forward();
+                            })),
+                    )
+                    .child(
+                        Button::new("reload")
+                            .ghost()
+                            .small()
+                            .label(if is_loading { "Stop" } else { "Reload" })
+                            .on_click(cx.listener(move |this, _, _, cx| {
+                                let webview = this.webview.read(cx);
+                                if is_loading {
+                                    webview.stop_load();
+                                } else {
+                                    webview.reload();
+                                }
+                            })),
+                    )
+                    .child(div().flex_1().child(TextInput::new(&self.address_state))),
+            )
             .child(self.webview.clone())
             .children(Root::render_modal_layer(window, cx))
     }
@@ -1,4 +1,4 @@
-use std::{rc::Rc, sync::Arc};
+use std::{path::PathBuf, rc::Rc, sync::Arc};
 
 use gpui::{AnyWindowHandle, AppContext, AsyncApp, ParentElement, RenderImage, Styled, WeakEntity};
 use gpui_component::{
@@ -13,7 +13,7 @@ use wef::{
 
 use crate::{
     WebView,
-    context_menu::{ContextMenuInfo, build_context_menu},
+    context_menu::{ContextMenuInfo, build_context_menu, build_custom_context_menu},
     events::*,
     frame_view::FrameView,
     utils::from_wef_cursor_type,
@@ -178,12 +178,20 @@ impl BrowserHandler for WebViewHandler {
         can_go_forward: bool,
     ) {
         if let Some(entity) = self.entity.upgrade() {
-            _ = self.cx.update_entity(&entity, |_, cx| {
+            _ = self.cx.update_entity(&entity, |webview, cx| {
                 cx.emit(LoadingStateChangedEvent {
                     is_loading,
                     can_go_back,
                     can_go_forward,
                 });
+                webview.set_navigation_state(
+                    NavigationStateChangedEvent {
+                        is_loading,
+                        can_go_back,
+                        can_go_forward,
+                    },
+                    cx,
+                );
             });
         }
     }
@@ -233,14 +241,33 @@ impl BrowserHandler for WebViewHandler {
     fn on_context_menu(&mut self, frame: Frame, params: ContextMenuParams) {
         if let Some(entity) = self.entity.upgrade() {
             _ = self.cx.update_window(self.window_handle, |_, window, cx| {
+                // Call the host handler outside of the entity update, so that it can read the WebView.
+                let items = match entity.read(cx).handler.clone() {
+                    Some(handler) => handler.on_context_menu(&params, window, cx),
+                    None => Some(Vec::new()),
+                };
+
                 cx.update_entity(&entity, |webview, cx| {
+                    let menu = match items {
+                        // Suppressed by the host.
+                        None => {
+                            webview.context_menu = None;
+                            cx.notify();
+                            return;
+                        }
+                        Some(items) if items.is_empty() => {
+                            build_context_menu(webview, &params, window, cx)
+                        }
+                        Some(items) => build_custom_context_menu(items, window, cx),
+                    };
+
                     webview.context_menu = Some(ContextMenuInfo {
                         crood: Point::new(
                             LogicalUnit(params.crood.x.0 + webview.bounds.origin.x.0 as i32),
                             LogicalUnit(params.crood.y.0 + webview.bounds.origin.y.0 as i32),
                         ),
                         frame,
-                        menu: build_context_menu(webview, &params, window, cx),
+                        menu,
                         link_url: params.link_url.map(ToString::to_string),
                     });
                     cx.notify();
@@ -249,6 +276,45 @@ impl BrowserHandler for WebViewHandler {
         }
     }
 
+    fn on_before_download(&mut self, id: u32, suggested_name: &str, url: &str) -> Option<PathBuf> {
+        let entity = self.entity.upgrade()?;
+        self.cx
+            .update_window(self.window_handle, |_, window, cx| {
+                let handler = entity.read(cx).download_handler.clone()?;
+                let path = handler(suggested_name, url, window, cx)?;
+                cx.update_entity(&entity, |_, cx| {
+                    cx.emit(DownloadStartedEvent {
+                        id,
+                        url: url.to_string(),
+                        suggested_name: suggested_name.to_string(),
+                        path: path.clone(),
+                    });
+                });
+                Some(path)
+            })
+            .ok()
+            .flatten()
+    }
+
+    fn on_download_updated(
+        &mut self,
+        id: u32,
+        received_bytes: i64,
+        total_bytes: i64,
+        is_complete: bool,
+    ) {
+        if let Some(entity) = self.entity.upgrade() {
+            _ = self.cx.update_entity(&entity, |_, cx| {
+                cx.emit(DownloadProgressEvent {
+                    id,
+                    received_bytes,
+                    total_bytes,
+                    is_complete,
+                });
+            });
+        }
+    }
+
     fn on_js_dialog(
         &mut self,
         type_: JsDialogType,
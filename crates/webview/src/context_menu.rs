@@ -1,4 +1,4 @@
-use gpui::{Action, App, Entity, Window};
+use gpui::{Action, App, Entity, SharedString, Window};
 use gpui_component::popup_menu::PopupMenu;
 use rust_i18n::t;
 use schemars::JsonSchema;
@@ -21,6 +21,55 @@ pub(crate) enum ContextMenuAction {
     GoBack,
     GoForward,
     Reload,
+    /// A command of the custom context menu item.
+    Command(u32),
+}
+
+/// An item of the custom context menu for the [`WebView`].
+///
+/// See also [`crate::BrowserHandler::on_context_menu`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContextMenuItem {
+    /// A menu item with a command id, the command id will be passed to
+    /// [`crate::BrowserHandler::on_context_menu_command`] when the item is
+    /// clicked.
+    Item {
+        command_id: u32,
+        label: SharedString,
+        disabled: bool,
+    },
+    /// A separator.
+    Separator,
+}
+
+impl ContextMenuItem {
+    /// Create a new menu item with the given command id and label.
+    pub fn new(command_id: u32, label: impl Into<SharedString>) -> Self {
+        Self::Item {
+            command_id,
+            label: label.into(),
+            disabled: false,
+        }
+    }
+
+    /// Create a separator.
+    pub fn separator() -> Self {
+        Self::Separator
+    }
+
+    /// Set the menu item to be disabled, default is false.
+    pub fn disabled(self, disabled: bool) -> Self {
+        match self {
+            Self::Item {
+                command_id, label, ..
+            } => Self::Item {
+                command_id,
+                label,
+                disabled,
+            },
+            Self::Separator => Self::Separator,
+        }
+    }
 }
 
 pub(crate) struct ContextMenuInfo {
@@ -115,3 +164,30 @@ pub(crate) fn build_context_menu(
         popmenu
     })
 }
+
+/// Build the context menu with the custom items from the host.
+pub(crate) fn build_custom_context_menu(
+    items: Vec<ContextMenuItem>,
+    window: &mut Window,
+    cx: &mut App,
+) -> Entity<PopupMenu> {
+    PopupMenu::build(window, cx, move |mut popmenu, _window, cx| {
+        for item in items {
+            popmenu = match item {
+                ContextMenuItem::Item {
+                    command_id,
+                    label,
+                    disabled,
+                } => popmenu.menu_with_disabled(
+                    label,
+                    Box::new(ContextMenuAction::Command(command_id)),
+                    disabled,
+                ),
+                ContextMenuItem::Separator => popmenu.separator(),
+            };
+        }
+
+        cx.notify();
+        popmenu
+    })
+}
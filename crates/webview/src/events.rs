@@ -1,5 +1,7 @@
 //! Events for the WebView.
 
+use std::path::PathBuf;
+
 use wef::{Frame, LogSeverity};
 
 /// Emitted when the browser is created.
@@ -74,6 +76,21 @@ pub struct LoadingStateChangedEvent {
     pub can_go_forward: bool,
 }
 
+/// Emitted when the navigation state changes, use this to update the
+/// back/forward/reload buttons.
+///
+/// Unlike [`LoadingStateChangedEvent`], this is only emitted when any of the
+/// values is different from the last time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NavigationStateChangedEvent {
+    /// Whether the browser is loading a page.
+    pub is_loading: bool,
+    /// Whether the browser can go back in history.
+    pub can_go_back: bool,
+    /// Whether the browser can go forward in history.
+    pub can_go_forward: bool,
+}
+
 /// Emitted when the browser starts loading a page.
 #[derive(Debug)]
 pub struct LoadStartEvent {
@@ -98,3 +115,29 @@ pub struct LoadErrorEvent {
     /// The uRL that failed to load.
     pub failed_url: String,
 }
+
+/// Emitted when a download is started.
+#[derive(Debug, Clone)]
+pub struct DownloadStartedEvent {
+    /// The unique identifier of the download.
+    pub id: u32,
+    /// The URL of the download.
+    pub url: String,
+    /// The suggested file name of the download.
+    pub suggested_name: String,
+    /// The path that the file is saved to.
+    pub path: PathBuf,
+}
+
+/// Emitted when the progress of a download is updated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DownloadProgressEvent {
+    /// The unique identifier of the download.
+    pub id: u32,
+    /// The number of bytes received.
+    pub received_bytes: i64,
+    /// The total number of bytes, `-1` if unknown.
+    pub total_bytes: i64,
+    /// Whether the download is complete.
+    pub is_complete: bool,
+}
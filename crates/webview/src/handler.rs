@@ -0,0 +1,32 @@
+use gpui::{App, Window};
+use wef::ContextMenuParams;
+
+use crate::ContextMenuItem;
+
+/// A handler for the host app to customize the [`crate::WebView`].
+///
+/// See also [`crate::WebView::set_handler`].
+#[allow(unused_variables)]
+pub trait BrowserHandler: 'static {
+    /// Called before displaying the context menu of the web content.
+    ///
+    /// - Return `None` to suppress the context menu.
+    /// - Return `Some(items)` to show the items instead of the built-in menu,
+    ///   the clicked item is passed to
+    ///   [`BrowserHandler::on_context_menu_command`].
+    ///
+    /// An empty list shows the built-in menu, this is the default.
+    fn on_context_menu(
+        &self,
+        params: &ContextMenuParams,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Option<Vec<ContextMenuItem>> {
+        Some(Vec::new())
+    }
+
+    /// Called when an item of the custom context menu is clicked.
+    ///
+    /// The `command_id` is the one of the [`ContextMenuItem`].
+    fn on_context_menu_command(&self, command_id: u32, window: &mut Window, cx: &mut App) {}
+}
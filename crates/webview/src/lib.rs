@@ -4,11 +4,14 @@ mod browser_handler;
 mod context_menu;
 mod element;
 mod frame_view;
+mod handler;
 mod utils;
 mod webview;
 
 pub mod events;
 
+pub use context_menu::ContextMenuItem;
+pub use handler::BrowserHandler;
 pub use webview::WebView;
 pub use wef;
 
@@ -1,14 +1,15 @@
-use std::{ops::Range, rc::Rc};
+use std::{ops::Range, path::PathBuf, rc::Rc, sync::Arc};
 
 use gpui::{
     App, Bounds, ClipboardItem, CursorStyle, Empty, Entity, EntityInputHandler, EventEmitter,
-    FocusHandle, Focusable, KeyDownEvent, KeyUpEvent, MouseDownEvent, Pixels, ScrollWheelEvent,
-    Subscription, UTF16Selection, WeakEntity, Window, anchored, deferred, div, point, prelude::*,
-    px,
+    FocusHandle, Focusable, KeyDownEvent, KeyUpEvent, MouseDownEvent, Pixels, RenderImage,
+    ScrollWheelEvent, Subscription, UTF16Selection, WeakEntity, Window, anchored, deferred, div,
+    point, prelude::*, px,
 };
 use wef::{Browser, FuncRegistry, LogicalUnit, Point, Rect};
 
 use crate::{
+    BrowserHandler,
     browser_handler::WebViewHandler,
     context_menu::{ContextMenuAction, ContextMenuInfo},
     element::WebViewElement,
@@ -17,6 +18,8 @@ use crate::{
     utils::*,
 };
 
+type DownloadHandler = Rc<dyn Fn(&str, &str, &mut Window, &mut App) -> Option<PathBuf>>;
+
 /// A web view based on the Chromium Embedded Framework (CEF).
 pub struct WebView {
     pub(crate) main: FrameView,
@@ -25,6 +28,12 @@ pub struct WebView {
     pub(crate) cursor: CursorStyle,
     pub(crate) context_menu: Option<ContextMenuInfo>,
     pub(crate) bounds: Bounds<Pixels>,
+    pub(crate) handler: Option<Rc<dyn BrowserHandler>>,
+    pub(crate) download_handler: Option<DownloadHandler>,
+    active: bool,
+    window_active: bool,
+    background_playback: bool,
+    navigation_state: NavigationStateChangedEvent,
     focus_handle: FocusHandle,
     browser: Rc<Browser>,
     _subscriptions: Vec<Subscription>,
@@ -71,6 +80,7 @@ impl WebView {
             let _subscriptions = vec![
                 cx.on_focus(&focus_handle, window, Self::on_focus),
                 cx.on_blur(&focus_handle, window, Self::on_blur),
+                cx.observe_window_activation(window, Self::on_window_activation_changed),
             ];
 
             Self {
@@ -82,6 +92,16 @@ impl WebView {
                 cursor: CursorStyle::Arrow,
                 context_menu: None,
                 bounds: Bounds::default(),
+                handler: None,
+                download_handler: None,
+                active: true,
+                window_active: window.is_window_active(),
+                background_playback: false,
+                navigation_state: NavigationStateChangedEvent {
+                    is_loading: false,
+                    can_go_back: false,
+                    can_go_forward: false,
+                },
                 _subscriptions,
             }
         });
@@ -107,6 +127,131 @@ impl WebView {
         &self.browser
     }
 
+    /// Captures the content of the web view as an image on the next paint, which
+    /// can be rendered elsewhere with `gpui::img`, e.g. for thumbnails.
+    ///
+    /// The `callback` is not called if the web view is dropped before it is
+    /// painted.
+    pub fn snapshot(
+        &self,
+        callback: impl FnOnce(Arc<RenderImage>, &mut App) + 'static,
+        cx: &mut App,
+    ) {
+        let cx = cx.to_async();
+        self.browser.capture_bitmap(move |image| {
+            // Both of the captured bitmap and `RenderImage` are in BGRA order.
+            let image = Arc::new(RenderImage::new([image::Frame::new(image.clone())]));
+            _ = cx.update(|cx| callback(image, cx));
+        });
+    }
+
+    /// Set a [`BrowserHandler`] to customize the web view, e.g. the context
+    /// menu of the web content.
+    pub fn set_handler(&mut self, handler: impl BrowserHandler) {
+        self.handler = Some(Rc::new(handler));
+    }
+
+    /// Set a handler to decide where a download is saved.
+    ///
+    /// The handler is called with the suggested file name and the URL of the
+    /// download, return the path to save the file to, or `None` to cancel it.
+    ///
+    /// Downloads are canceled if no handler is set.
+    pub fn on_before_download(
+        &mut self,
+        handler: impl Fn(&str, &str, &mut Window, &mut App) -> Option<PathBuf> + 'static,
+    ) {
+        self.download_handler = Some(Rc::new(handler));
+    }
+
+    /// Set whether the web view is active.
+    ///
+    /// An inactive web view stops rendering to save CPU and battery, use this
+    /// when the web view is not visible, e.g. in a hidden tab.
+    pub fn set_active(&mut self, active: bool) {
+        self.active = active;
+        self.update_hidden();
+    }
+
+    /// Returns `true` if the web view is active.
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Set whether to keep rendering when the window is inactive, default is
+    /// `false`.
+    ///
+    /// By default, the web view pauses rendering while the hosting window is
+    /// inactive (e.g. minimized or behind other windows). Enable this to keep
+    /// audio and video playing in the background.
+    pub fn set_background_playback(&mut self, background_playback: bool) {
+        self.background_playback = background_playback;
+        self.update_hidden();
+    }
+
+    /// Returns `true` if the web view is loading a page.
+    #[inline]
+    pub fn is_loading(&self) -> bool {
+        self.navigation_state.is_loading
+    }
+
+    /// Returns `true` if the web view can go back in history.
+    #[inline]
+    pub fn can_go_back(&self) -> bool {
+        self.navigation_state.can_go_back
+    }
+
+    /// Returns `true` if the web view can go forward in history.
+    #[inline]
+    pub fn can_go_forward(&self) -> bool {
+        self.navigation_state.can_go_forward
+    }
+
+    /// Navigates back in history.
+    pub fn go_back(&self) {
+        self.browser.back();
+    }
+
+    /// Navigates forward in history.
+    pub fn go_forward(&self) {
+        self.browser.forward();
+    }
+
+    /// Reloads the current page.
+    pub fn reload(&self) {
+        self.browser.reload();
+    }
+
+    /// Stops loading the current page.
+    pub fn stop_load(&self) {
+        self.browser.stop_load();
+    }
+
+    pub(crate) fn set_navigation_state(
+        &mut self,
+        state: NavigationStateChangedEvent,
+        cx: &mut Context<Self>,
+    ) {
+        if self.navigation_state == state {
+            return;
+        }
+
+        self.navigation_state = state;
+        cx.emit(state);
+        cx.notify();
+    }
+
+    fn update_hidden(&self) {
+        let hidden = !self.active || (!self.window_active && !self.background_playback);
+        self.browser.set_hidden(hidden);
+    }
+
+    fn on_window_activation_changed(&mut self, window: &mut Window, _cx: &mut Context<Self>) {
+        self.window_active = window.is_window_active();
+        self.update_hidden();
+    }
+
     fn scroll_wheel_handler(
         &mut self,
         event: &ScrollWheelEvent,
@@ -154,7 +299,7 @@ impl WebView {
     fn on_context_menu_action(
         &mut self,
         action: &ContextMenuAction,
-        _window: &mut Window,
+        window: &mut Window,
         cx: &mut Context<Self>,
     ) {
         use ContextMenuAction::*;
@@ -162,6 +307,17 @@ impl WebView {
         if let Some(info) = self.context_menu.take() {
             let action = *action;
 
+            if let Command(command_id) = action {
+                if let Some(handler) = self.handler.clone() {
+                    // Defer to allow the handler to update this WebView.
+                    window.defer(cx, move |window, cx| {
+                        handler.on_context_menu_command(command_id, window, cx)
+                    });
+                }
+                cx.notify();
+                return;
+            }
+
             cx.spawn(async move |webview: WeakEntity<WebView>, cx| {
                 let Ok(browser) = webview.read_with(cx, |webview, _cx| webview.browser().clone())
                 else {
@@ -185,6 +341,7 @@ impl WebView {
                     GoBack => browser.back(),
                     GoForward => browser.forward(),
                     Reload => browser.reload(),
+                    Command(_) => {}
                 }
             })
             .detach();
@@ -344,7 +501,10 @@ impl_emiter!(
     ConsoleMessageEvent,
     BeforePopupEvent,
     LoadingStateChangedEvent,
+    NavigationStateChangedEvent,
     LoadStartEvent,
     LoadEndEvent,
-    LoadErrorEvent
+    LoadErrorEvent,
+    DownloadStartedEvent,
+    DownloadProgressEvent
 );
@@ -266,6 +266,7 @@ fn build_wef_sys(cef_root: &Path) {
         "cpp/dirty_rect.cpp",
         "cpp/frame.cpp",
         "cpp/file_dialog.cpp",
+        "cpp/download.cpp",
         "cpp/cursor.cpp",
         "cpp/js_dialog.cpp",
         "cpp/query.cpp",
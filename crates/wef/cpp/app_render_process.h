@@ -4,6 +4,7 @@
 
 #include "include/cef_app.h"
 #include "include/wrapper/cef_message_router.h"
+#include "user_script.h"
 
 class WefRenderProcessHandler : public CefRenderProcessHandler {
   IMPLEMENT_REFCOUNTING(WefRenderProcessHandler);
@@ -11,6 +12,7 @@ class WefRenderProcessHandler : public CefRenderProcessHandler {
  private:
   CefRefPtr<CefMessageRouterRendererSide> message_router_;
   std::map<int, CefString> inject_javascript_map_;
+  std::map<int, CefRefPtr<CefListValue>> user_scripts_map_;
 
  public:
   WefRenderProcessHandler() {
@@ -22,10 +24,15 @@ class WefRenderProcessHandler : public CefRenderProcessHandler {
                         CefRefPtr<CefDictionaryValue> extra_info) override {
     auto inject_javascript = extra_info->GetString("__wef_inject_javascript");
     inject_javascript_map_[browser->GetIdentifier()] = inject_javascript;
+    if (extra_info->HasKey(kUserScriptsKey)) {
+      user_scripts_map_[browser->GetIdentifier()] =
+          extra_info->GetList(kUserScriptsKey)->Copy();
+    }
   }
 
   void OnBrowserDestroyed(CefRefPtr<CefBrowser> browser) override {
     inject_javascript_map_.erase(browser->GetIdentifier());
+    user_scripts_map_.erase(browser->GetIdentifier());
   }
 
   void OnContextCreated(CefRefPtr<CefBrowser> browser,
@@ -40,6 +47,11 @@ class WefRenderProcessHandler : public CefRenderProcessHandler {
         }
       }
     }
+
+    auto it = user_scripts_map_.find(browser->GetIdentifier());
+    if (it != user_scripts_map_.end()) {
+      execute_user_scripts(frame, it->second);
+    }
     message_router_->OnContextCreated(browser, frame, context);
   }
 
@@ -53,6 +65,11 @@ class WefRenderProcessHandler : public CefRenderProcessHandler {
                                 CefRefPtr<CefFrame> frame,
                                 CefProcessId source_process,
                                 CefRefPtr<CefProcessMessage> message) override {
+    if (message->GetName() == kUserScriptsKey) {
+      user_scripts_map_[browser->GetIdentifier()] =
+          message->GetArgumentList()->GetList(0)->Copy();
+      return true;
+    }
     return message_router_->OnProcessMessageReceived(browser, frame,
                                                      source_process, message);
   }
@@ -64,4 +64,10 @@ struct BrowserCallbacks {
   void (*on_query)(
       void* userdata, void* frame, const char* payload,
       CefRefPtr<CefMessageRouterBrowserSide::Handler::Callback>* callback);
+  bool (*on_before_download)(void* userdata, uint32_t id, const char* url,
+                             const char* suggested_name,
+                             CefRefPtr<CefBeforeDownloadCallback>* callback);
+  void (*on_download_updated)(void* userdata, uint32_t id,
+                              int64_t received_bytes, int64_t total_bytes,
+                              bool is_complete);
 };
\ No newline at end of file
@@ -427,6 +427,58 @@ bool WefClient::OnBeforeUnloadDialog(CefRefPtr<CefBrowser> browser,
   return true;
 }
 
+/////////////////////////////////////////////////////////////////
+// CefDownloadHandler methods
+/////////////////////////////////////////////////////////////////
+bool WefClient::OnBeforeDownload(CefRefPtr<CefBrowser> browser,
+                                 CefRefPtr<CefDownloadItem> download_item,
+                                 const CefString& suggested_name,
+                                 CefRefPtr<CefBeforeDownloadCallback> callback) {
+  DCHECK(CefCurrentlyOn(TID_UI));
+
+  auto url_str = download_item->GetURL().ToString();
+  auto suggested_name_str = suggested_name.ToString();
+
+  // The callback is only used during the call, so it is passed by the pointer
+  // to the argument.
+  bool result = false;
+  state_->callbacks_target.call(
+      [&](const BrowserCallbacks& callbacks, void* userdata) {
+        result = callbacks.on_before_download(
+            userdata, download_item->GetId(), url_str.c_str(),
+            suggested_name_str.c_str(), &callback);
+      });
+
+  // Returning `false` falls back to the default handling, which shows the
+  // download UI with the Chrome runtime, so cancel it explicitly instead.
+  if (!result) {
+    cancelled_downloads_.insert(download_item->GetId());
+  }
+  return true;
+}
+
+void WefClient::OnDownloadUpdated(CefRefPtr<CefBrowser> browser,
+                                  CefRefPtr<CefDownloadItem> download_item,
+                                  CefRefPtr<CefDownloadItemCallback> callback) {
+  DCHECK(CefCurrentlyOn(TID_UI));
+
+  if (cancelled_downloads_.count(download_item->GetId())) {
+    if (download_item->IsInProgress()) {
+      callback->Cancel();
+    } else {
+      cancelled_downloads_.erase(download_item->GetId());
+    }
+    return;
+  }
+
+  state_->callbacks_target.call(
+      [&](const BrowserCallbacks& callbacks, void* userdata) {
+        callbacks.on_download_updated(
+            userdata, download_item->GetId(), download_item->GetReceivedBytes(),
+            download_item->GetTotalBytes(), download_item->IsComplete());
+      });
+}
+
 /////////////////////////////////////////////////////////////////
 // CefRequestHandler methods
 /////////////////////////////////////////////////////////////////
@@ -445,6 +497,14 @@ bool WefClient::OnBeforeBrowse(CefRefPtr<CefBrowser> browser,
   return false;
 }
 
+void WefClient::OnRenderViewReady(CefRefPtr<CefBrowser> browser) {
+  // A new render process may be used after navigation, resend the user scripts
+  // that are added after the browser is created.
+  if (state_->user_scripts->GetSize() > 0) {
+    send_user_scripts(browser, state_->user_scripts);
+  }
+}
+
 /////////////////////////////////////////////////////////////////
 // CefFocusHandler methods
 /////////////////////////////////////////////////////////////////
@@ -8,12 +8,14 @@
 #include <limits>
 #include <memory>
 #include <optional>
+#include <set>
 
 #include "browser_callbacks.h"
 #include "frame.h"
 #include "include/cef_browser.h"
 #include "include/cef_client.h"
 #include "include/wrapper/cef_message_router.h"
+#include "user_script.h"
 #include "utils.h"
 
 struct WefBrowser;
@@ -78,6 +80,7 @@ struct BrowserSharedState {
   std::optional<CefRefPtr<CefBrowser>> browser;
   int width, height;
   float device_scale_factor;
+  CefRefPtr<CefListValue> user_scripts;
   BrowserCallbacksTarget callbacks_target;
 
   BrowserSharedState(BrowserCallbacksTarget&& other)
@@ -88,6 +91,7 @@ struct BrowserSharedState {
         width(800),
         height(600),
         device_scale_factor(1.0f),
+        user_scripts(CefListValue::Create()),
         callbacks_target(std::move(other)) {}
 
   BrowserSharedState(const BrowserSharedState& other) = delete;
@@ -106,12 +110,15 @@ class WefClient : public CefClient,
                   public CefJSDialogHandler,
                   public CefFocusHandler,
                   public CefPermissionHandler,
+                  public CefDownloadHandler,
                   public CefMessageRouterBrowserSide::Handler {
   IMPLEMENT_REFCOUNTING(WefClient);
 
  private:
   std::shared_ptr<BrowserSharedState> state_;
   CefRefPtr<CefMessageRouterBrowserSide> message_router_;
+  // The downloads cancelled in `OnBeforeDownload`.
+  std::set<uint32_t> cancelled_downloads_;
 
  public:
   WefClient(std::shared_ptr<BrowserSharedState> state);
@@ -150,6 +157,7 @@ class WefClient : public CefClient,
   CefRefPtr<CefPermissionHandler> GetPermissionHandler() override {
     return this;
   }
+  CefRefPtr<CefDownloadHandler> GetDownloadHandler() override { return this; }
 
   bool OnProcessMessageReceived(CefRefPtr<CefBrowser> browser,
                                 CefRefPtr<CefFrame> frame,
@@ -266,6 +274,7 @@ class WefClient : public CefClient,
   bool OnBeforeBrowse(CefRefPtr<CefBrowser> browser, CefRefPtr<CefFrame> frame,
                       CefRefPtr<CefRequest> request, bool user_gesture,
                       bool is_redirect) override;
+  void OnRenderViewReady(CefRefPtr<CefBrowser> browser) override;
 
   /////////////////////////////////////////////////////////////////
   // CefFocusHandler methods
@@ -281,6 +290,17 @@ class WefClient : public CefClient,
       const CefString& requesting_origin, uint32_t requested_permissions,
       CefRefPtr<CefMediaAccessCallback> callback) override;
 
+  /////////////////////////////////////////////////////////////////
+  // CefDownloadHandler methods
+  /////////////////////////////////////////////////////////////////
+  bool OnBeforeDownload(CefRefPtr<CefBrowser> browser,
+                        CefRefPtr<CefDownloadItem> download_item,
+                        const CefString& suggested_name,
+                        CefRefPtr<CefBeforeDownloadCallback> callback) override;
+  void OnDownloadUpdated(CefRefPtr<CefBrowser> browser,
+                         CefRefPtr<CefDownloadItem> download_item,
+                         CefRefPtr<CefDownloadItemCallback> callback) override;
+
   /////////////////////////////////////////////////////////////////
   // CefMessageRouterBrowserSide::Handler methods
   /////////////////////////////////////////////////////////////////
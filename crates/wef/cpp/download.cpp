@@ -0,0 +1,10 @@
+#include "include/cef_download_handler.h"
+
+extern "C" {
+
+void wef_before_download_callback_continue(
+    CefRefPtr<CefBeforeDownloadCallback>* callback, const char* download_path) {
+  (*callback)->Continue(download_path, false);
+}
+
+}  // extern "C"
@@ -0,0 +1,59 @@
+#pragma once
+
+#include <string>
+
+#include "include/cef_browser.h"
+#include "include/cef_frame.h"
+#include "include/cef_process_message.h"
+#include "include/cef_values.h"
+
+const int kInjectionTimeDocumentStart = 0;
+const int kInjectionTimeDocumentEnd = 1;
+
+const char kUserScriptsKey[] = "__wef_user_scripts";
+
+struct WefUserScript {
+  const char* source;
+  int injection_time;
+  bool all_frames;
+};
+
+inline CefRefPtr<CefDictionaryValue> user_script_to_value(
+    const WefUserScript* script) {
+  CefRefPtr<CefDictionaryValue> value = CefDictionaryValue::Create();
+  value->SetString("source", script->source);
+  value->SetInt("injection_time", script->injection_time);
+  value->SetBool("all_frames", script->all_frames);
+  return value;
+}
+
+// Sends all the user scripts to the render process of the browser, the
+// scripts in the render process are replaced by them.
+inline void send_user_scripts(CefRefPtr<CefBrowser> browser,
+                              CefRefPtr<CefListValue> user_scripts) {
+  CefRefPtr<CefProcessMessage> message =
+      CefProcessMessage::Create(kUserScriptsKey);
+  message->GetArgumentList()->SetList(0, user_scripts->Copy());
+  browser->GetMainFrame()->SendProcessMessage(PID_RENDERER, message);
+}
+
+// Executes the user scripts which are targeting the frame.
+inline void execute_user_scripts(CefRefPtr<CefFrame> frame,
+                                 CefRefPtr<CefListValue> user_scripts) {
+  for (size_t i = 0; i < user_scripts->GetSize(); i++) {
+    CefRefPtr<CefDictionaryValue> script = user_scripts->GetDictionary(i);
+    if (!frame->IsMain() && !script->GetBool("all_frames")) {
+      continue;
+    }
+
+    std::string source = script->GetString("source").ToString();
+    if (script->GetInt("injection_time") == kInjectionTimeDocumentEnd) {
+      source =
+          "(function() { const run = function() {\n" + source +
+          "\n}; if (document.readyState === 'loading') { "
+          "document.addEventListener('DOMContentLoaded', run, { once: true "
+          "}); } else { run(); } })();";
+    }
+    frame->ExecuteJavaScript(source, frame->GetURL(), 0);
+  }
+}
@@ -40,6 +40,8 @@ struct WefBrowserSettings {
   int frame_rate;
   const char* url;
   const char* inject_javascript;
+  const WefUserScript* user_scripts;
+  int num_user_scripts;
   BrowserCallbacks callbacks;
   void* userdata;
   DestroyFn destroy_userdata;
@@ -131,6 +133,11 @@ WefBrowser* wef_browser_create(const WefBrowserSettings* settings) {
   wef_browser->state =
       std::make_shared<BrowserSharedState>(BrowserCallbacksTarget{
           settings->callbacks, settings->userdata, settings->destroy_userdata});
+  for (int i = 0; i < settings->num_user_scripts; i++) {
+    wef_browser->state->user_scripts->SetDictionary(
+        i, user_script_to_value(&settings->user_scripts[i]));
+  }
+  extra_info->SetList(kUserScriptsKey, wef_browser->state->user_scripts->Copy());
   wef_browser->state->width = settings->width;
   wef_browser->state->height = settings->height;
   wef_browser->state->device_scale_factor = settings->device_scale_factor;
@@ -187,6 +194,19 @@ void wef_browser_load_url(WefBrowser* browser, const char* url) {
                                      CefString(url)));
 }
 
+void wef_browser_add_user_script(WefBrowser* browser,
+                                 const WefUserScript* script) {
+  auto user_scripts = browser->state->user_scripts;
+  user_scripts->SetDictionary(user_scripts->GetSize(),
+                              user_script_to_value(script));
+
+  // If the browser is still creating, the scripts are sent when the render
+  // view is ready.
+  if (browser->state->browser) {
+    send_user_scripts(*browser->state->browser, user_scripts);
+  }
+}
+
 bool wef_browser_can_go_forward(WefBrowser* browser) {
   if (!browser->state->browser) {
     return false;
@@ -229,6 +249,13 @@ void wef_browser_reload_ignore_cache(WefBrowser* browser) {
   (*browser->state->browser)->ReloadIgnoreCache();
 }
 
+void wef_browser_stop_load(WefBrowser* browser) {
+  if (!browser->state->browser) {
+    return;
+  }
+  (*browser->state->browser)->StopLoad();
+}
+
 void wef_browser_send_mouse_click_event(WefBrowser* browser,
                                         int mouse_button_type, bool mouse_up,
                                         int click_count, int modifiers) {
@@ -414,4 +441,20 @@ void wef_browser_set_focus(WefBrowser* browser, bool focus) {
   (*browser->state->browser)->GetHost()->SetFocus(focus);
 }
 
+void wef_browser_set_hidden(WefBrowser* browser, bool hidden) {
+  if (!browser->state->browser) {
+    return;
+  }
+  (*browser->state->browser)->GetHost()->WasHidden(hidden);
+}
+
+void wef_browser_invalidate(WefBrowser* browser, int type) {
+  if (!browser->state->browser) {
+    return;
+  }
+  (*browser->state->browser)
+      ->GetHost()
+      ->Invalidate(static_cast<cef_paint_element_type_t>(type));
+}
+
 }  // extern "C"
\ No newline at end of file
@@ -1,4 +1,4 @@
-use std::{rc::Rc, time::Duration};
+use std::{path::PathBuf, rc::Rc, time::Duration};
 
 use image::{GenericImage, RgbaImage, buffer::ConvertBuffer};
 use softbuffer::Surface;
@@ -226,6 +226,26 @@ impl BrowserHandler for MyHandler {
         _ = self.event_loop_proxy.send_event(UserEvent::Exit);
     }
 
+    fn on_before_download(&mut self, id: u32, suggested_name: &str, url: &str) -> Option<PathBuf> {
+        let path = std::env::temp_dir().join(suggested_name);
+        println!("download #{id} started: {url} -> {}", path.display());
+        Some(path)
+    }
+
+    fn on_download_updated(
+        &mut self,
+        id: u32,
+        received_bytes: i64,
+        total_bytes: i64,
+        is_complete: bool,
+    ) {
+        if is_complete {
+            println!("download #{id} completed: {received_bytes} bytes");
+        } else {
+            println!("download #{id} progress: {received_bytes}/{total_bytes} bytes");
+        }
+    }
+
     fn on_paint(
         &mut self,
         type_: PaintElementType,
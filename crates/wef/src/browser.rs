@@ -1,13 +1,16 @@
-use std::{ffi::CString, fmt};
+use std::{cell::RefCell, ffi::CString, fmt, rc::Rc};
+
+use image::RgbaImage;
 
 use crate::{
-    BrowserBuilder, Frame, KeyCode, KeyModifier, LogicalUnit, MouseButton, PhysicalUnit, Point,
-    Size, ffi::*,
+    BrowserBuilder, Frame, KeyCode, KeyModifier, LogicalUnit, MouseButton, PaintElementType,
+    PhysicalUnit, Point, Size, UserScript, ffi::*, frame_cache::FrameCache,
 };
 
 /// A browser instance.
 pub struct Browser {
     pub(crate) wef_browser: *mut wef_browser_t,
+    pub(crate) frame_cache: Rc<RefCell<FrameCache>>,
 }
 
 impl fmt::Debug for Browser {
@@ -55,6 +58,34 @@ impl Browser {
         unsafe { wef_browser_load_url(self.wef_browser, c_url.as_ptr()) };
     }
 
+    /// Adds a script that is injected into the page on every navigation.
+    ///
+    /// The script takes effect from the next navigation, the current page is
+    /// not affected.
+    ///
+    /// See also [`crate::BrowserBuilder::user_script`].
+    pub fn add_user_script(&self, script: impl Into<UserScript>) {
+        let (_source, c_script) = script.into().to_c();
+        unsafe { wef_browser_add_user_script(self.wef_browser, &c_script) };
+    }
+
+    /// Captures the next painted frame of the browser, with the popup (e.g. an
+    /// opened `<select>`) composited over the view.
+    ///
+    /// The browser is repainted for the capture, and the `callback` is called
+    /// with the image in BGRA order, the same as the buffer of
+    /// [`crate::BrowserHandler::on_paint`]. The frames are only retained while
+    /// a capture is pending.
+    pub fn capture_bitmap(&self, callback: impl FnOnce(&RgbaImage) + 'static) {
+        let popup_visible = self.frame_cache.borrow_mut().request(Box::new(callback));
+        unsafe {
+            wef_browser_invalidate(self.wef_browser, PaintElementType::View as i32);
+            if popup_visible {
+                wef_browser_invalidate(self.wef_browser, PaintElementType::Popup as i32);
+            }
+        }
+    }
+
     /// Returns `true`` if the browser can navigate forwards.
     pub fn can_forward(&self) -> bool {
         unsafe { wef_browser_can_go_forward(self.wef_browser) }
@@ -85,6 +116,11 @@ impl Browser {
         unsafe { wef_browser_reload_ignore_cache(self.wef_browser) };
     }
 
+    /// Stops loading the current page.
+    pub fn stop_load(&self) {
+        unsafe { wef_browser_stop_load(self.wef_browser) };
+    }
+
     /// Sends a mouse click event.
     pub fn send_mouse_click_event(
         &self,
@@ -221,4 +257,12 @@ impl Browser {
     pub fn set_focus(&self, focus: bool) {
         unsafe { wef_browser_set_focus(self.wef_browser, focus) };
     }
+
+    /// Notify the browser that it has been hidden or shown.
+    ///
+    /// A hidden browser stops producing paints, but audio playback is not
+    /// affected.
+    pub fn set_hidden(&self, hidden: bool) {
+        unsafe { wef_browser_set_hidden(self.wef_browser, hidden) };
+    }
 }
@@ -1,6 +1,7 @@
 use std::{
-    ffi::{CStr, c_char, c_void},
+    ffi::{CStr, CString, c_char, c_void},
     mem::MaybeUninit,
+    path::PathBuf,
 };
 
 use num_enum::TryFromPrimitive;
@@ -217,6 +218,29 @@ pub trait BrowserHandler {
     ) -> bool {
         false
     }
+
+    /// Called before a download begins.
+    ///
+    /// `id` is the unique identifier of the download, it is also passed to
+    /// [`BrowserHandler::on_download_updated`].
+    ///
+    /// Return the path to save the file to, or `None` to cancel the download.
+    fn on_before_download(&mut self, id: u32, suggested_name: &str, url: &str) -> Option<PathBuf> {
+        None
+    }
+
+    /// Called when the progress of a download is updated.
+    ///
+    /// `total_bytes` is `-1` if the size is unknown, `is_complete` is `true`
+    /// once the file is fully saved.
+    fn on_download_updated(
+        &mut self,
+        id: u32,
+        received_bytes: i64,
+        total_bytes: i64,
+        is_complete: bool,
+    ) {
+    }
 }
 
 impl BrowserHandler for () {}
@@ -238,6 +262,7 @@ pub(crate) extern "C" fn on_closed<T: BrowserHandler>(userdata: *mut c_void) {
 pub(crate) extern "C" fn on_popup_show<T: BrowserHandler>(userdata: *mut c_void, show: bool) {
     unsafe {
         let state = &mut *(userdata as *mut BrowserState<T>);
+        state.frame_cache.borrow_mut().set_popup_visible(show);
         state.handler.on_popup_show(show);
     }
 }
@@ -248,6 +273,7 @@ pub(crate) extern "C" fn on_popup_position<T: BrowserHandler>(
 ) {
     unsafe {
         let state = &mut *(userdata as *mut BrowserState<T>);
+        state.frame_cache.borrow_mut().set_popup_rect(*rect);
         state.handler.on_popup_position((*rect).map(LogicalUnit));
     }
 }
@@ -266,11 +292,14 @@ pub(crate) extern "C" fn on_paint<T: BrowserHandler>(
         let dirty_rects = DirtyRects::new(dirty_rects);
         let image_buffer =
             std::slice::from_raw_parts(image_buffer as *const u8, (width * height * 4) as usize);
-        state.handler.on_paint(
-            type_,
-            &dirty_rects,
-            ImageBuffer::from_raw(width, height, image_buffer).unwrap(),
-        );
+        let image_buffer = ImageBuffer::from_raw(width, height, image_buffer).unwrap();
+        let captured = state.frame_cache.borrow_mut().update(type_, &image_buffer);
+        if let Some((image, callbacks)) = captured {
+            for callback in callbacks {
+                callback(&image);
+            }
+        }
+        state.handler.on_paint(type_, &dirty_rects, image_buffer);
     }
 }
 
@@ -665,3 +694,46 @@ pub(crate) extern "C" fn on_query<T: BrowserHandler>(
         )
     }
 }
+
+pub(crate) extern "C" fn on_before_download<T: BrowserHandler>(
+    userdata: *mut c_void,
+    id: u32,
+    url: *const c_char,
+    suggested_name: *const c_char,
+    callback: *mut wef_before_download_callback_t,
+) -> bool {
+    unsafe {
+        let state = &mut *(userdata as *mut BrowserState<T>);
+        let url = CStr::from_ptr(url).to_string_lossy();
+        let suggested_name = CStr::from_ptr(suggested_name).to_string_lossy();
+        let download_path = state
+            .handler
+            .on_before_download(id, &suggested_name, &url)
+            .and_then(|path| CString::new(path.to_string_lossy().into_owned()).ok());
+
+        // The callback is owned by the caller, and the download is cancelled
+        // explicitly when returning `false`.
+        match &download_path {
+            Some(download_path) => {
+                wef_before_download_callback_continue(callback, download_path.as_ptr());
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+pub(crate) extern "C" fn on_download_updated<T: BrowserHandler>(
+    userdata: *mut c_void,
+    id: u32,
+    received_bytes: i64,
+    total_bytes: i64,
+    is_complete: bool,
+) {
+    unsafe {
+        let state = &mut *(userdata as *mut BrowserState<T>);
+        state
+            .handler
+            .on_download_updated(id, received_bytes, total_bytes, is_complete);
+    }
+}
@@ -1,8 +1,12 @@
-use std::ffi::{CString, c_void};
+use std::{
+    cell::RefCell,
+    ffi::{CString, c_void},
+    rc::Rc,
+};
 
 use raw_window_handle::RawWindowHandle;
 
-use crate::{Browser, BrowserHandler, FuncRegistry, ffi::*};
+use crate::{Browser, BrowserHandler, FuncRegistry, UserScript, ffi::*, frame_cache::FrameCache};
 
 /// A builder for creating a browser instance.
 pub struct BrowserBuilder<T> {
@@ -14,6 +18,7 @@ pub struct BrowserBuilder<T> {
     url: String,
     handler: T,
     func_registry: FuncRegistry,
+    user_scripts: Vec<UserScript>,
 }
 
 impl BrowserBuilder<()> {
@@ -27,6 +32,7 @@ impl BrowserBuilder<()> {
             url: "about:blank".to_string(),
             handler: (),
             func_registry: Default::default(),
+            user_scripts: Vec::new(),
         }
     }
 }
@@ -34,6 +40,7 @@ impl BrowserBuilder<()> {
 pub(crate) struct BrowserState<T> {
     pub(crate) handler: T,
     pub(crate) func_registry: FuncRegistry,
+    pub(crate) frame_cache: Rc<RefCell<FrameCache>>,
 }
 
 impl<T> BrowserBuilder<T>
@@ -102,6 +109,7 @@ where
             url: self.url,
             handler,
             func_registry: self.func_registry,
+            user_scripts: self.user_scripts,
         }
     }
 
@@ -115,6 +123,15 @@ where
         }
     }
 
+    /// Adds a script that is injected into the page on every navigation.
+    ///
+    /// A `&str` is injected at [`crate::InjectionTime::DocumentStart`] into the
+    /// main frame, use [`UserScript`] to change it.
+    pub fn user_script(mut self, script: impl Into<UserScript>) -> Self {
+        self.user_scripts.push(script.into());
+        self
+    }
+
     /// Cosumes the builder and creates a [`Browser`] instance.
     ///
     /// The creation of the browser is asynchronous, and the
@@ -146,10 +163,14 @@ where
             on_find_result: crate::browser_handler::on_find_result::<T>,
             on_js_dialog: crate::browser_handler::on_js_dialog::<T>,
             on_query: crate::browser_handler::on_query::<T>,
+            on_before_download: crate::browser_handler::on_before_download::<T>,
+            on_download_updated: crate::browser_handler::on_download_updated::<T>,
         };
+        let frame_cache = Rc::new(RefCell::new(FrameCache::default()));
         let handler = Box::into_raw(Box::new(BrowserState {
             handler: self.handler,
             func_registry: self.func_registry.clone(),
+            frame_cache: frame_cache.clone(),
         }));
         let parent_window_handle: *const c_void = match self.parent {
             Some(RawWindowHandle::Win32(handle)) => handle.hwnd.get() as *const c_void,
@@ -164,6 +185,8 @@ where
 
         let url_cstr = CString::new(self.url).unwrap();
         let inject_javascript = CString::new(self.func_registry.javascript()).unwrap();
+        let (_user_script_sources, c_user_scripts): (Vec<_>, Vec<_>) =
+            self.user_scripts.iter().map(UserScript::to_c).unzip();
         let settings = CBrowserSettings {
             parent: parent_window_handle,
             device_scale_factor: self.device_scale_factor,
@@ -172,6 +195,8 @@ where
             frame_rate: self.frame_rate as i32,
             url: url_cstr.as_ptr(),
             inject_javascript: inject_javascript.as_ptr(),
+            user_scripts: c_user_scripts.as_ptr(),
+            num_user_scripts: c_user_scripts.len() as i32,
             callbacks,
             userdata: handler as *mut c_void,
             destroy_userdata: destroy_handler::<T>,
@@ -180,6 +205,7 @@ where
         unsafe {
             Browser {
                 wef_browser: wef_browser_create(&settings),
+                frame_cache,
             }
         }
     }
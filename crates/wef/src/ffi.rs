@@ -9,6 +9,7 @@ pub(crate) type wef_frame_t = c_void;
 pub(crate) type wef_cursor_info_t = c_void;
 pub(crate) type wef_file_dialog_callback_t = c_void;
 pub(crate) type wef_js_dialog_callback_t = c_void;
+pub(crate) type wef_before_download_callback_t = c_void;
 pub(crate) type wef_query_callback_t = c_void;
 
 type DestroyFn = extern "C" fn(*mut c_void);
@@ -38,11 +39,20 @@ pub(crate) struct CBrowserSettings {
     pub(crate) frame_rate: i32,
     pub(crate) url: *const c_char,
     pub(crate) inject_javascript: *const c_char,
+    pub(crate) user_scripts: *const CUserScript,
+    pub(crate) num_user_scripts: i32,
     pub(crate) callbacks: CBrowserCallbacks,
     pub(crate) userdata: *mut c_void,
     pub(crate) destroy_userdata: DestroyFn,
 }
 
+#[repr(C)]
+pub(crate) struct CUserScript {
+    pub(crate) source: *const c_char,
+    pub(crate) injection_time: i32,
+    pub(crate) all_frames: bool,
+}
+
 #[repr(C)]
 pub(crate) struct CContextMenuParams {
     pub(crate) x_crood: i32,
@@ -111,6 +121,14 @@ pub(crate) struct CBrowserCallbacks {
     ) -> bool,
     pub(crate) on_query:
         extern "C" fn(*mut c_void, *mut wef_frame_t, *const c_char, *mut wef_query_callback_t),
+    pub(crate) on_before_download: extern "C" fn(
+        *mut c_void,
+        u32,
+        *const c_char,
+        *const c_char,
+        *mut wef_before_download_callback_t,
+    ) -> bool,
+    pub(crate) on_download_updated: extern "C" fn(*mut c_void, u32, i64, i64, bool),
 }
 
 #[inline]
@@ -156,6 +174,11 @@ unsafe extern "C" {
 
     pub(crate) unsafe fn wef_browser_load_url(cebrowserf: *mut wef_browser_t, url: *const c_char);
 
+    pub(crate) unsafe fn wef_browser_add_user_script(
+        browser: *mut wef_browser_t,
+        script: *const CUserScript,
+    );
+
     pub(crate) unsafe fn wef_browser_can_go_forward(browser: *const wef_browser_t) -> bool;
 
     pub(crate) unsafe fn wef_browser_can_go_back(browser: *const wef_browser_t) -> bool;
@@ -168,6 +191,8 @@ unsafe extern "C" {
 
     pub(crate) unsafe fn wef_browser_reload_ignore_cache(browser: *mut wef_browser_t);
 
+    pub(crate) unsafe fn wef_browser_stop_load(browser: *mut wef_browser_t);
+
     pub(crate) unsafe fn wef_browser_send_mouse_click_event(
         browser: *mut wef_browser_t,
         mouse_button_type: i32,
@@ -239,6 +264,10 @@ unsafe extern "C" {
 
     pub(crate) unsafe fn wef_browser_set_focus(browser: *mut wef_browser_t, focus: bool);
 
+    pub(crate) unsafe fn wef_browser_set_hidden(browser: *mut wef_browser_t, hidden: bool);
+
+    pub(crate) unsafe fn wef_browser_invalidate(browser: *mut wef_browser_t, type_: i32);
+
     pub(crate) unsafe fn wef_dirty_rects_len(dirty_rects: *const c_void) -> i32;
 
     pub(crate) unsafe fn wef_dirty_rects_get(
@@ -323,6 +352,11 @@ unsafe extern "C" {
 
     pub(crate) unsafe fn wef_js_dialog_callback_destroy(callback: *mut wef_js_dialog_callback_t);
 
+    pub(crate) unsafe fn wef_before_download_callback_continue(
+        callback: *mut wef_before_download_callback_t,
+        download_path: *const c_char,
+    );
+
     pub(crate) unsafe fn wef_query_callback_success(
         callback: *mut wef_query_callback_t,
         response: *const c_char,
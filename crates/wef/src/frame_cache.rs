@@ -0,0 +1,94 @@
+use image::{RgbaImage, imageops};
+
+use crate::{ImageBuffer, PaintElementType, Rect};
+
+/// The callback of a requested capture, the image is in BGRA order.
+pub(crate) type CaptureCallback = Box<dyn FnOnce(&RgbaImage)>;
+
+/// Retains the painted view and popup only while a capture is requested, to
+/// capture the bitmap of the browser on the next paint.
+#[derive(Default)]
+pub(crate) struct FrameCache {
+    /// The pending captures, called when the next frame is painted.
+    callbacks: Vec<CaptureCallback>,
+    /// The view buffer painted for the pending captures, in BGRA order.
+    view: Option<RgbaImage>,
+    /// The popup buffer painted for the pending captures, in BGRA order.
+    popup: Option<RgbaImage>,
+    popup_visible: bool,
+    popup_rect: Option<Rect<i32>>,
+}
+
+impl FrameCache {
+    /// Requests a capture on the next paint, returns `true` if the popup is
+    /// visible and must be painted too.
+    pub(crate) fn request(&mut self, callback: CaptureCallback) -> bool {
+        self.callbacks.push(callback);
+        self.popup_visible
+    }
+
+    /// Retains the painted buffer if there are pending captures, and returns
+    /// the captured image with the callbacks once the frame is complete.
+    ///
+    /// The callbacks must be called after the cache is released, they may
+    /// request another capture.
+    pub(crate) fn update(
+        &mut self,
+        type_: PaintElementType,
+        image_buffer: &ImageBuffer,
+    ) -> Option<(RgbaImage, Vec<CaptureCallback>)> {
+        if self.callbacks.is_empty() {
+            return None;
+        }
+
+        let image = RgbaImage::from_raw(
+            image_buffer.width(),
+            image_buffer.height(),
+            image_buffer.to_vec(),
+        );
+        match type_ {
+            PaintElementType::View => self.view = image,
+            PaintElementType::Popup => self.popup = image,
+        }
+        self.take_captured()
+    }
+
+    pub(crate) fn set_popup_visible(&mut self, visible: bool) {
+        self.popup_visible = visible;
+        if !visible {
+            self.popup = None;
+            self.popup_rect = None;
+        }
+    }
+
+    pub(crate) fn set_popup_rect(&mut self, rect: Rect<i32>) {
+        self.popup_rect = Some(rect);
+    }
+
+    /// Composites the popup over the view when both of them are painted, and
+    /// releases the retained buffers.
+    fn take_captured(&mut self) -> Option<(RgbaImage, Vec<CaptureCallback>)> {
+        if self.popup_visible && self.popup.is_none() {
+            return None;
+        }
+        let mut image = self.view.take()?;
+
+        if let (Some(popup), Some(rect)) = (self.popup.take(), self.popup_rect) {
+            // The popup rect is in logical units, but the buffers are in physical
+            // pixels.
+            let scale = if rect.width > 0 {
+                popup.width() as f32 / rect.width as f32
+            } else {
+                1.0
+            };
+            imageops::replace(
+                &mut image,
+                &popup,
+                (rect.x as f32 * scale).round() as i64,
+                (rect.y as f32 * scale).round() as i64,
+            );
+        }
+
+        Some((image, std::mem::take(&mut self.callbacks)))
+    }
+}
@@ -12,6 +12,7 @@ mod error;
 mod ffi;
 mod file_dialog;
 mod frame;
+mod frame_cache;
 #[cfg(target_os = "macos")]
 mod framework_loader;
 mod func_registry;
@@ -22,6 +23,7 @@ mod query;
 #[cfg(target_os = "macos")]
 mod sandbox_context;
 mod settings;
+mod user_script;
 mod wef;
 
 pub use app_handler::ApplicationHandler;
@@ -51,4 +53,5 @@ pub use js_dialog::{JsDialogCallback, JsDialogType};
 pub use sandbox_context::SandboxContext;
 pub use serde_json::Value;
 pub use settings::Settings;
+pub use user_script::{InjectionTime, UserScript};
 pub use wef::*;
@@ -0,0 +1,95 @@
+use std::ffi::CString;
+
+use crate::ffi::*;
+
+/// When to inject a [`UserScript`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(i32)]
+pub enum InjectionTime {
+    /// Inject the script as soon as the JavaScript context of the frame is
+    /// created, before any script of the page is run.
+    #[default]
+    DocumentStart = 0,
+    /// Inject the script when the document has been parsed, i.e. at the
+    /// `DOMContentLoaded` event.
+    DocumentEnd = 1,
+}
+
+/// A script that is injected into the page on every navigation.
+///
+/// Unlike [`crate::Frame::execute_javascript`], the script is injected again
+/// each time a frame loads a new document, use this for polyfills or
+/// instrumentation.
+///
+/// ```no_run
+/// use wef::{InjectionTime, UserScript};
+///
+/// let script = UserScript::new("console.log(document.title)")
+///     .injection_time(InjectionTime::DocumentEnd)
+///     .all_frames(true);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserScript {
+    source: String,
+    injection_time: InjectionTime,
+    all_frames: bool,
+}
+
+impl UserScript {
+    /// Creates a new user script with the given source.
+    ///
+    /// By default, the script is injected at [`InjectionTime::DocumentStart`]
+    /// into the main frame only.
+    pub fn new(source: impl Into<String>) -> Self {
+        Self {
+            source: source.into(),
+            injection_time: InjectionTime::default(),
+            all_frames: false,
+        }
+    }
+
+    /// Sets when to inject the script.
+    ///
+    /// Default is [`InjectionTime::DocumentStart`].
+    pub fn injection_time(self, injection_time: InjectionTime) -> Self {
+        Self {
+            injection_time,
+            ..self
+        }
+    }
+
+    /// Sets whether to inject the script into all frames, including iframes.
+    ///
+    /// Default is `false`, the script is only injected into the main frame.
+    pub fn all_frames(self, all_frames: bool) -> Self {
+        Self { all_frames, ..self }
+    }
+
+    /// Returns the source of the script.
+    #[inline]
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    pub(crate) fn to_c(&self) -> (CString, CUserScript) {
+        let source = CString::new(self.source.as_str()).unwrap_or_default();
+        let c_script = CUserScript {
+            source: source.as_ptr(),
+            injection_time: self.injection_time as i32,
+            all_frames: self.all_frames,
+        };
+        (source, c_script)
+    }
+}
+
+impl From<&str> for UserScript {
+    fn from(source: &str) -> Self {
+        Self::new(source)
+    }
+}
+
+impl From<String> for UserScript {
+    fn from(source: String) -> Self {
+        Self::new(source)
+    }
+}